@@ -2,6 +2,7 @@ use crate::ast::resolved_variable::{ResolvedVariable, VariableId};
 use crate::ast::spanned::Spanned;
 use crate::ast::type_expressions::TypeExpression;
 use crate::global::operators::BinaryOperator;
+use crate::global::operators::CastMode;
 use crate::global::operators::ComparisonOperator;
 use crate::global::operators::assignment::AssignmentOperator;
 use crate::global::operators::{ArithmeticUnaryOperator, UnaryOperator};
@@ -147,9 +148,18 @@ pub enum DatexExpressionData {
     /// Comparison operation, e.g. x < y
     ComparisonOperation(ComparisonOperation),
 
+    /// Type cast, e.g. 42 as text
+    TypeCast(TypeCast),
+
     /// Deref assignment, e.g. *x = y, **x += y
     DerefAssignment(DerefAssignment),
 
+    /// Raise an error, e.g. yeet error("Not found")
+    Yeet(Yeet),
+
+    /// Recover from errors raised by an expression, e.g. try get_user(42)
+    Try(Try),
+
     /// Property assignment, e.g. obj.property = value
     PropertyAssignment(PropertyAssignment),
 
@@ -159,7 +169,7 @@ pub enum DatexExpressionData {
     /// Apply a value to another value, e.g. function call or type cast
     Apply(Apply),
 
-    /// Apply a property access to an argument
+    /// Apply a property access to an argument, e.g. a.b or a?.b
     PropertyAccess(PropertyAccess),
 
     /// Generic instantiation, e.g. MyType<u8>
@@ -173,6 +183,15 @@ pub enum DatexExpressionData {
 
     /// Variant access, e.g. integer/u8
     VariantAccess(VariantAccess),
+
+    /// Exported declaration, e.g. export const x = 1
+    Export(Export),
+
+    /// Import of exported declarations from another module, e.g. import { a, b } from "./other.dx"
+    Import(Import),
+
+    /// Pauses the execution if a debugger is attached, e.g. debugger
+    Debugger,
 }
 
 impl Spanned for DatexExpressionData {
@@ -278,6 +297,23 @@ pub struct ComparisonOperation {
     pub right: Box<DatexExpression>,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeCast {
+    pub expression: Box<DatexExpression>,
+    pub target: TypeExpression,
+    pub mode: CastMode,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Yeet {
+    pub expression: Box<DatexExpression>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Try {
+    pub expression: Box<DatexExpression>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct DerefAssignment {
     pub operator: AssignmentOperator,
@@ -304,18 +340,28 @@ pub struct Conditional {
 pub enum TypeDeclarationKind {
     Nominal,
     Structural,
+    /// nominal type listing the properties and callables required from
+    /// implementing types, e.g. interface Named = { name: text }
+    Interface,
 }
 impl Display for TypeDeclarationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             TypeDeclarationKind::Nominal => core::write!(f, "type"),
             TypeDeclarationKind::Structural => core::write!(f, "typealias"),
+            TypeDeclarationKind::Interface => core::write!(f, "interface"),
         }
     }
 }
 impl TypeDeclarationKind {
     pub fn is_nominal(&self) -> bool {
-        matches!(self, TypeDeclarationKind::Nominal)
+        matches!(
+            self,
+            TypeDeclarationKind::Nominal | TypeDeclarationKind::Interface
+        )
+    }
+    pub fn is_interface(&self) -> bool {
+        matches!(self, TypeDeclarationKind::Interface)
     }
     pub fn is_structural(&self) -> bool {
         matches!(self, TypeDeclarationKind::Structural)
@@ -326,6 +372,7 @@ impl TypeDeclarationKind {
 pub struct TypeDeclaration {
     pub id: Option<VariableId>,
     pub name: String, // TODO #614: separate variant from name
+    pub generic_parameters: Vec<String>,
     pub definition: TypeExpression,
     pub hoisted: bool,
     pub kind: TypeDeclarationKind,
@@ -347,6 +394,22 @@ pub struct Apply {
 pub struct PropertyAccess {
     pub base: Box<DatexExpression>,
     pub property: Box<DatexExpression>,
+    /// true for optional chaining (a?.b), which results in null if the base is null
+    pub optional: bool,
+}
+
+impl PropertyAccess {
+    /// Returns true if the access is part of an optional chain, e.g. the
+    /// access of b in a?.b or a?.x.b. A null base short-circuits all
+    /// following accesses of the chain, so that a?.x.b results in null
+    /// if a is null.
+    pub fn is_optional_chain(&self) -> bool {
+        self.optional
+            || core::matches!(
+                &self.base.data,
+                DatexExpressionData::PropertyAccess(base) if base.is_optional_chain()
+            )
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -424,6 +487,7 @@ pub struct VariableAccess {
 pub struct CallableDeclaration {
     pub name: Option<String>,
     pub kind: CallableKind,
+    pub generic_parameters: Vec<String>,
     pub parameters: Vec<(String, TypeExpression)>,
     pub rest_parameter: Option<(String, TypeExpression)>,
     pub return_type: Option<TypeExpression>,
@@ -496,6 +560,35 @@ pub struct VariantAccess {
     pub base: ResolvedVariable,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct Export {
+    /// The exported variable or type declaration
+    pub declaration: Box<DatexExpression>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Import {
+    pub items: Vec<ImportItem>,
+    /// The module path as written in the source, e.g. "./other.dx".
+    /// The CompilerWorkspace replaces relative paths with the resolved module URL.
+    pub source: String,
+}
+
+#[derive(Clone, Debug)]
+/// A single imported name, declared as a new const variable in the importing module
+pub struct ImportItem {
+    pub id: Option<VariableId>,
+    pub name: String,
+    pub span: Range<usize>,
+}
+
+// PartialEquality for ImportItem ignores the span (allows for easier testing)
+impl PartialEq for ImportItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.name == other.name
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Deref {
     pub expression: Box<DatexExpression>,
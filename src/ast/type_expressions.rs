@@ -55,6 +55,9 @@ pub enum TypeExpressionData {
     // text | integer
     Union(Union),
 
+    // { name: text } + Named, a type marked as implementing interfaces
+    ImplType(ImplType),
+
     // User<text, integer>
     GenericAccess(GenericAccess),
 
@@ -132,9 +135,15 @@ pub struct Intersection(pub Vec<TypeExpression>);
 #[derive(Clone, Debug, PartialEq)]
 pub struct Union(pub Vec<TypeExpression>);
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImplType {
+    pub base: Box<TypeExpression>,
+    pub interfaces: Vec<TypeExpression>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct GenericAccess {
-    pub base: String,
+    pub base: Box<TypeExpression>,
     pub access: Vec<TypeExpression>,
 }
 
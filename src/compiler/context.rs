@@ -1,7 +1,10 @@
 use crate::collections::HashMap;
+use crate::compiler::Variable;
+use crate::compiler::module::ModuleRegistry;
 use crate::core_compiler::value_compiler::append_instruction_code;
 use crate::core_compiler::value_compiler::append_value_container;
 use crate::global::instruction_codes::InstructionCode;
+use crate::runtime::execution::SourceMap;
 use crate::runtime::execution::context::ExecutionMode;
 use crate::utils::buffers::append_u32;
 use crate::values::value_container::ValueContainer;
@@ -60,6 +63,14 @@ pub struct CompilationContext {
     /// this flag is set to true if any non-static value is encountered
     pub has_non_static_value: bool,
     pub execution_mode: ExecutionMode,
+    /// modules that are inlined when imported
+    pub modules: ModuleRegistry,
+    /// exported variables of the modules that were already compiled into the
+    /// top-level scope of this DXB, mapped by the module source. A module is
+    /// only compiled on its first import, following imports refer to its slots.
+    pub compiled_modules: HashMap<String, Vec<Variable>>,
+    /// if set, the bytecode ranges of all compiled expressions are mapped to their source spans
+    pub source_map: Option<SourceMap>,
 
     // mapping for temporary scope slot resolution
     slot_indices: HashMap<VirtualSlot, Vec<u32>>,
@@ -98,6 +109,9 @@ impl CompilationContext {
             has_non_static_value: false,
             slot_indices: HashMap::new(),
             execution_mode,
+            modules: ModuleRegistry::default(),
+            compiled_modules: HashMap::new(),
+            source_map: None,
         }
     }
 
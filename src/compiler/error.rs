@@ -5,6 +5,7 @@ use crate::serde::error::DeserializationError;
 use crate::type_inference::error::{
     DetailedTypeErrors, SpannedTypeError, TypeError,
 };
+use ariadne::{Config, IndexType, Label, Report, ReportKind, Source};
 use core::fmt::{Display, Formatter};
 use core::ops::Range;
 
@@ -27,6 +28,12 @@ pub enum CompilerError {
     AssignmentToImmutableReference(String),
     AssignmentToImmutableValue(String),
     OnceScopeUsedMultipleTimes,
+    /// module source that was not found in the module registry
+    UnresolvedModule(String),
+    /// (name, module source) of an import that is not exported by the module
+    ImportNotExported(String, String),
+    /// module sources forming an import cycle, starting and ending with the same module
+    CyclicImport(Vec<String>),
     TypeError(TypeError),
     ParserError(ParserError),
 }
@@ -48,6 +55,44 @@ impl SpannedCompilerError {
             span: Some(span),
         }
     }
+
+    /// Renders the error as a diagnostic report for the given source code
+    pub fn render_report(&self, source_name: &str, source: &str) -> String {
+        render_error_report(
+            &self.error.to_string(),
+            self.span.clone(),
+            source_name,
+            source,
+        )
+    }
+}
+
+/// Renders an error message as a diagnostic report, pointing to the span in the source code if known
+pub fn render_error_report(
+    message: &str,
+    span: Option<Range<usize>>,
+    source_name: &str,
+    source: &str,
+) -> String {
+    let mut report = Report::build(
+        ReportKind::Error,
+        (source_name, span.clone().unwrap_or(0..0)),
+    )
+    .with_config(
+        Config::default()
+            .with_color(false)
+            .with_index_type(IndexType::Byte),
+    )
+    .with_message(message);
+    if let Some(span) = span {
+        report = report.with_label(Label::new((source_name, span)));
+    }
+    let mut output = Vec::new();
+    report
+        .finish()
+        .write((source_name, Source::from(source)), &mut output)
+        .expect("Failed to write diagnostic report");
+    String::from_utf8_lossy(&output).into_owned()
 }
 
 impl Display for SpannedCompilerError {
@@ -307,6 +352,15 @@ impl Display for CompilerError {
             CompilerError::AssignmentToImmutableReference(name) => {
                 core::write!(f, "Cannot assign to immutable reference: {name}")
             }
+            CompilerError::UnresolvedModule(source) => {
+                core::write!(f, "Could not resolve module {source}")
+            }
+            CompilerError::ImportNotExported(name, source) => {
+                core::write!(f, "Module {source} does not export {name}")
+            }
+            CompilerError::CyclicImport(sources) => {
+                core::write!(f, "Cyclic import: {}", sources.join(" -> "))
+            }
             CompilerError::TypeError(err) => {
                 core::write!(f, "{}", err)
             }
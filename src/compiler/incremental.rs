@@ -0,0 +1,448 @@
+use crate::ast::expressions::{
+    DatexExpression, DatexExpressionData, Import, List, Map, Statements,
+};
+use crate::ast::spanned::Spanned;
+use crate::ast::type_expressions::{StructuralMap, TypeExpression};
+use crate::collections::HashMap;
+use crate::parser::Parser;
+use crate::parser::errors::SpannedParserError;
+use crate::parser::lexer::{Token, get_spanned_tokens_from_source};
+use crate::values::core_values::r#type::Type;
+use crate::visitor::VisitAction;
+use crate::visitor::expression::ExpressionVisitor;
+use crate::visitor::expression::visitable::ExpressionVisitResult;
+use crate::visitor::type_expression::TypeExpressionVisitor;
+use crate::visitor::type_expression::visitable::TypeExpressionVisitResult;
+use core::ops::Range;
+
+/// A top-level statement of a previous build that can be reused if the
+/// source of the statement did not change.
+#[derive(Debug, Clone)]
+pub struct CachedStatement {
+    source: String,
+    /// parsed statement with spans relative to the start of the statement
+    ast: DatexExpression,
+    /// inferred types of the data literal maps and lists in the statement,
+    /// with spans relative to the start of the statement
+    types: Vec<(Range<usize>, Type)>,
+}
+
+/// Result of parsing a source with reuse of cached statements.
+pub struct IncrementalParseResult {
+    pub ast: DatexExpression,
+    pub errors: Vec<SpannedParserError>,
+    /// byte ranges of the top-level statements
+    pub statement_spans: Vec<Range<usize>>,
+    /// cacheable statements for each top-level statement,
+    /// None if the statement could not be parsed
+    pub statements: Vec<Option<CachedStatement>>,
+    pub is_terminated: bool,
+    pub reused_statements: usize,
+    pub parsed_statements: usize,
+    pub reused_types: usize,
+    /// cached types of the data literal maps and lists of the reused
+    /// statements, with absolute spans
+    pub restored_types: HashMap<Range<usize>, Type>,
+}
+
+/// Top-level statements of a source, determined from the tokens
+struct TopLevelStatements {
+    spans: Vec<Range<usize>>,
+    is_terminated: bool,
+    /// end of the last token
+    end: usize,
+}
+
+/// Splits the source into its top-level statements, which are separated
+/// by semicolons outside of brackets.
+/// Returns None if the statements can not be determined from the tokens alone,
+/// e.g. because of lexer errors or unbalanced closing brackets.
+fn split_statements(source: &str) -> Option<TopLevelStatements> {
+    let (tokens, errors) = get_spanned_tokens_from_source(source);
+    if !errors.is_empty() {
+        return None;
+    }
+    let mut statements = TopLevelStatements {
+        spans: vec![],
+        is_terminated: false,
+        end: tokens.last().map(|token| token.span.end).unwrap_or(0),
+    };
+    let mut depth = 0usize;
+    let mut current: Option<Range<usize>> = None;
+    for (index, token) in tokens.iter().enumerate() {
+        match token.token {
+            // an optional shebang line is skipped by the parser
+            Token::Shebang(_) if index == 0 => continue,
+            Token::Semicolon if depth == 0 => {
+                statements.spans.extend(current.take());
+                statements.is_terminated = true;
+                continue;
+            }
+            Token::LeftParen | Token::LeftBracket | Token::LeftCurly => {
+                depth += 1
+            }
+            Token::RightParen | Token::RightBracket | Token::RightCurly => {
+                depth = depth.checked_sub(1)?
+            }
+            _ => {}
+        }
+        statements.is_terminated = false;
+        match &mut current {
+            Some(span) => span.end = token.span.end,
+            None => current = Some(token.span.clone()),
+        }
+    }
+    statements.spans.extend(current);
+    (!statements.spans.is_empty()).then_some(statements)
+}
+
+/// Parses the source, reusing the parsed trees and inferred data literal types
+/// of all top-level statements whose source is unchanged since the previous build.
+/// Only the changed statements are parsed again.
+/// Returns None if the top-level statements can not be determined from the
+/// tokens alone, in which case the full source must be parsed.
+pub fn parse_with_cached_statements(
+    source: &str,
+    previous: &[CachedStatement],
+) -> Option<IncrementalParseResult> {
+    let top_level_statements = split_statements(source)?;
+    let cached = previous
+        .iter()
+        .map(|statement| (statement.source.as_str(), statement))
+        .collect::<HashMap<_, _>>();
+
+    let mut result = IncrementalParseResult {
+        ast: DatexExpression::default(),
+        errors: vec![],
+        statement_spans: top_level_statements.spans.clone(),
+        statements: vec![],
+        is_terminated: top_level_statements.is_terminated,
+        reused_statements: 0,
+        parsed_statements: 0,
+        reused_types: 0,
+        restored_types: HashMap::new(),
+    };
+    let mut asts = vec![];
+    for span in top_level_statements.spans {
+        let statement_source = &source[span.clone()];
+        let mut ast = if let Some(cached) = cached.get(statement_source) {
+            result.reused_statements += 1;
+            result.statements.push(Some((*cached).clone()));
+            result.reused_types += cached.types.len();
+            result.restored_types.extend(cached.types.iter().map(
+                |(type_span, ty)| {
+                    (
+                        type_span.start + span.start
+                            ..type_span.end + span.start,
+                        ty.clone(),
+                    )
+                },
+            ));
+            cached.ast.clone()
+        } else if let Some(ast) =
+            Parser::parse_single_statement(statement_source)
+        {
+            result.parsed_statements += 1;
+            result.statements.push(Some(CachedStatement {
+                source: statement_source.to_string(),
+                ast: ast.clone(),
+                types: vec![],
+            }));
+            ast
+        } else {
+            let (ast, errors) =
+                Parser::parse_collecting_with_default_options(statement_source)
+                    .into_ast_and_errors();
+            // multiple statements without a separator are only
+            // split correctly by the full parser
+            if errors.is_empty() {
+                return None;
+            }
+            result.parsed_statements += 1;
+            result.statements.push(None);
+            result.errors.extend(errors.into_iter().map(|error| {
+                SpannedParserError {
+                    error: error.error,
+                    span: error.span.start + span.start
+                        ..error.span.end + span.start,
+                }
+            }));
+            ast
+        };
+        shift_spans(&mut ast, span.start);
+        asts.push(ast);
+    }
+
+    // same shape as the result of the full parser
+    result.ast = if asts.len() == 1 && !top_level_statements.is_terminated {
+        asts.remove(0)
+    } else {
+        DatexExpressionData::Statements(Statements {
+            statements: asts,
+            is_terminated: top_level_statements.is_terminated,
+            unbounded: None,
+        })
+        .with_span(0..top_level_statements.end)
+    };
+    Some(result)
+}
+
+/// Stores the inferred types of the data literals of the precompiled top-level
+/// statements in the cached statements, so that they do not need to be inferred
+/// again when the statements are reused.
+pub fn cache_statement_types(
+    statements: &mut [Option<CachedStatement>],
+    statement_spans: &[Range<usize>],
+    is_terminated: bool,
+    ast: &mut DatexExpression,
+) {
+    // a single unterminated statement is not wrapped by the parser
+    let typed_statements = match &mut ast.data {
+        DatexExpressionData::Statements(Statements { statements, .. })
+            if statement_spans.len() != 1 || is_terminated =>
+        {
+            statements.iter_mut().collect::<Vec<_>>()
+        }
+        _ => vec![ast],
+    };
+    if typed_statements.len() != statements.len() {
+        return;
+    }
+    for ((cached, typed), span) in statements
+        .iter_mut()
+        .zip(typed_statements)
+        .zip(statement_spans)
+    {
+        if let Some(cached) = cached {
+            let mut collector = TypeCollector {
+                offset: span.start,
+                types: vec![],
+                skip_children: false,
+            };
+            let _ = collector.visit_datex_expression(typed);
+            cached.types = collector.types;
+        }
+    }
+}
+
+/// Returns true if the expression only consists of literal values,
+/// so that its type does not depend on any other expression
+fn is_data_literal(expression: &DatexExpression) -> bool {
+    match &expression.data {
+        DatexExpressionData::Null
+        | DatexExpressionData::Boolean(_)
+        | DatexExpressionData::Text(_)
+        | DatexExpressionData::Integer(_)
+        | DatexExpressionData::TypedInteger(_)
+        | DatexExpressionData::Decimal(_)
+        | DatexExpressionData::TypedDecimal(_)
+        | DatexExpressionData::Endpoint(_) => true,
+        DatexExpressionData::List(list) => {
+            list.items.iter().all(is_data_literal)
+        }
+        DatexExpressionData::Map(map) => map
+            .entries
+            .iter()
+            .all(|(key, value)| is_data_literal(key) && is_data_literal(value)),
+        _ => false,
+    }
+}
+
+/// Moves all spans in the expression by the given offset
+fn shift_spans(expression: &mut DatexExpression, offset: usize) {
+    if offset != 0 {
+        let _ = SpanShifter { offset }.visit_datex_expression(expression);
+    }
+}
+
+/// Collects the types of the outermost data literal maps and lists
+struct TypeCollector {
+    /// start of the statement
+    offset: usize,
+    types: Vec<(Range<usize>, Type)>,
+    skip_children: bool,
+}
+
+impl TypeCollector {
+    fn skip_data_literal(&mut self) -> ExpressionVisitResult<()> {
+        Ok(if core::mem::take(&mut self.skip_children) {
+            VisitAction::SkipChildren
+        } else {
+            VisitAction::VisitChildren
+        })
+    }
+}
+
+impl TypeExpressionVisitor<()> for TypeCollector {}
+
+impl ExpressionVisitor<()> for TypeCollector {
+    fn before_visit_datex_expression(
+        &mut self,
+        expression: &mut DatexExpression,
+    ) {
+        if let DatexExpressionData::Map(_) | DatexExpressionData::List(_) =
+            expression.data
+            && let Some(ty) = &expression.ty
+            && is_data_literal(expression)
+        {
+            self.types.push((
+                expression.span.start - self.offset
+                    ..expression.span.end - self.offset,
+                ty.clone(),
+            ));
+            self.skip_children = true;
+        }
+    }
+
+    fn visit_map(
+        &mut self,
+        _: &mut Map,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<()> {
+        self.skip_data_literal()
+    }
+
+    fn visit_list(
+        &mut self,
+        _: &mut List,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<()> {
+        self.skip_data_literal()
+    }
+}
+
+/// Moves the spans of all expressions and type expressions by an offset
+struct SpanShifter {
+    offset: usize,
+}
+
+impl TypeExpressionVisitor<()> for SpanShifter {
+    fn before_visit_type_expression(
+        &mut self,
+        expression: &mut TypeExpression,
+    ) {
+        expression.span = expression.span.start + self.offset
+            ..expression.span.end + self.offset;
+    }
+
+    fn visit_structural_map_type(
+        &mut self,
+        structural_map: &mut StructuralMap,
+        _: &Range<usize>,
+    ) -> TypeExpressionVisitResult<()> {
+        // keys are not visited by the default visitor
+        for (key, _) in structural_map.0.iter_mut() {
+            self.visit_type_expression(key)?;
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+}
+
+impl ExpressionVisitor<()> for SpanShifter {
+    fn before_visit_datex_expression(
+        &mut self,
+        expression: &mut DatexExpression,
+    ) {
+        expression.span = expression.span.start + self.offset
+            ..expression.span.end + self.offset;
+    }
+
+    fn visit_map(
+        &mut self,
+        map: &mut Map,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<()> {
+        // keys are not visited by the default visitor
+        for (key, _) in map.entries.iter_mut() {
+            self.visit_datex_expression(key)?;
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_import(
+        &mut self,
+        import: &mut Import,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<()> {
+        for item in import.items.iter_mut() {
+            item.span =
+                item.span.start + self.offset..item.span.end + self.offset;
+        }
+        Ok(VisitAction::SkipChildren)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses the source with the full parser and formats the result including all spans
+    fn full_parse(source: &str) -> String {
+        let (ast, errors) =
+            Parser::parse_collecting_with_default_options(source)
+                .into_ast_and_errors();
+        assert!(errors.is_empty(), "{errors:?}");
+        format!("{ast:?}")
+    }
+
+    fn incremental_parse(
+        source: &str,
+        previous: &[CachedStatement],
+    ) -> IncrementalParseResult {
+        parse_with_cached_statements(source, previous).unwrap()
+    }
+
+    fn cached(result: &IncrementalParseResult) -> Vec<CachedStatement> {
+        result.statements.iter().flatten().cloned().collect()
+    }
+
+    #[test]
+    fn same_ast_as_full_parser() {
+        let sources = [
+            "1",
+            "1;",
+            "const a = 1; a + 2",
+            "const a = {x: [1, 2, {y: \"z\"}], (1 + 2): true};;\nvar b: {x: integer} = a;",
+            "#!/usr/bin/env datex\n// comment\nconst f = function f(x: integer) (x; x + 1); f(2)",
+            r#"import { a } from "./lib.dx"; export const b = a;"#,
+            "type User = {name: text, age: integer}; if (true) (1) else (2)",
+        ];
+        for source in sources {
+            let result = incremental_parse(source, &[]);
+            assert!(result.errors.is_empty());
+            assert_eq!(format!("{:?}", result.ast), full_parse(source));
+        }
+    }
+
+    #[test]
+    fn reuses_unchanged_statements() {
+        let source = "const a = {x: 1, y: [1, 2]};\nconst b = 2;\na";
+        let result = incremental_parse(source, &[]);
+        assert_eq!(result.parsed_statements, 3);
+
+        let changed =
+            "const c = 3;\nconst a = {x: 1, y: [1, 2]};\nconst b = 42;\na";
+        let result = incremental_parse(changed, &cached(&result));
+        assert_eq!(result.reused_statements, 2);
+        assert_eq!(result.parsed_statements, 2);
+        // spans of reused statements are moved to their new position
+        assert_eq!(format!("{:?}", result.ast), full_parse(changed));
+    }
+
+    #[test]
+    fn statements_with_errors() {
+        let result = incremental_parse("const a = 1;\nconst b = ;\na", &[]);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].span.start >= 13);
+        assert!(result.statements[1].is_none());
+        assert_eq!(cached(&result).len(), 2);
+    }
+
+    #[test]
+    fn unbalanced_brackets() {
+        assert!(parse_with_cached_statements("1); 2", &[]).is_none());
+        let result = incremental_parse("const a = (1; 2", &[]);
+        assert_eq!(result.statement_spans, vec![0..15]);
+        assert!(!result.errors.is_empty());
+    }
+}
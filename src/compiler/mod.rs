@@ -11,9 +11,9 @@ use core::cell::RefCell;
 
 use crate::ast::expressions::{
     BinaryOperation, ComparisonOperation, DatexExpression, DatexExpressionData,
-    DerefAssignment, RemoteExecution, Slot, Statements, UnaryOperation,
-    UnboundedStatement, VariableAccess, VariableAssignment,
-    VariableDeclaration, VariableKind,
+    DerefAssignment, Export, Import, RemoteExecution, Slot, Statements,
+    TypeCast, TypeDeclaration, UnaryOperation, UnboundedStatement,
+    VariableAccess, VariableAssignment, VariableDeclaration, VariableKind,
 };
 use crate::compiler::context::{CompilationContext, VirtualSlot};
 use crate::compiler::error::{
@@ -21,6 +21,10 @@ use crate::compiler::error::{
     SimpleCompilerErrorOrDetailedCompilerErrorWithRichAst,
 };
 use crate::compiler::metadata::CompileMetadata;
+use crate::compiler::module::{
+    ModuleRegistry, module_exports, module_statements,
+};
+use crate::compiler::optimizer::{OptimizationOptions, optimize_rich_ast};
 use crate::compiler::scope::CompilationScope;
 use crate::compiler::type_compiler::compile_type_expression;
 use crate::global::instruction_codes::InstructionCode;
@@ -29,6 +33,8 @@ use crate::libs::core::CoreLibPointerId;
 use crate::parser::parser_result::ValidDatexParseResult;
 
 use crate::ast::resolved_variable::VariableId;
+use crate::collections::HashMap;
+use crate::core_compiler::type_compiler::append_type;
 use crate::core_compiler::value_compiler::{
     append_boolean, append_decimal, append_encoded_integer, append_endpoint,
     append_float_as_i16, append_float_as_i32, append_instruction_code,
@@ -36,25 +42,37 @@ use crate::core_compiler::value_compiler::{
     append_value_container,
 };
 use crate::core_compiler::value_compiler::{append_get_ref, append_key_string};
+use crate::parser::errors::SpannedParserError;
 use crate::parser::{Parser, ParserOptions};
 use crate::references::reference::ReferenceMutability;
+use crate::runtime::execution::SourceMap;
 use crate::runtime::execution::context::ExecutionMode;
 use crate::stdlib::rc::Rc;
 use crate::stdlib::vec::Vec;
 use crate::time::Instant;
-use crate::utils::buffers::append_u32;
+use crate::type_inference::{
+    infer_expression_type_simple_error, infer_type_expression_type,
+};
+use crate::types::definition::TypeDefinition;
 use crate::utils::buffers::{append_u8, append_u16};
+use crate::utils::buffers::{append_u32, write_u32};
 use crate::values::core_values::decimal::Decimal;
+use crate::values::core_values::r#type::Type;
 use crate::values::pointer::PointerAddress;
 use crate::values::value_container::ValueContainer;
+use core::ops::Range;
 use log::{debug, info};
 use precompiler::options::PrecompilerOptions;
 use precompiler::precompile_ast;
 use precompiler::precompiled_ast::{AstMetadata, RichAst, VariableMetadata};
+use url::Url;
 
 pub mod context;
 pub mod error;
+pub mod incremental;
 pub mod metadata;
+pub mod module;
+pub mod optimizer;
 pub mod scope;
 pub mod type_compiler;
 
@@ -66,6 +84,12 @@ pub mod workspace;
 pub struct CompileOptions {
     pub compile_scope: CompilationScope,
     pub parser_options: ParserOptions,
+    /// Modules that can be imported by the compiled script
+    pub modules: ModuleRegistry,
+    /// Optimizations applied between precompilation and compilation
+    pub optimizations: OptimizationOptions,
+    /// Types of data literals restored from a previous build by their span
+    pub restored_types: HashMap<Range<usize>, Type>,
 }
 
 impl CompileOptions {
@@ -73,6 +97,9 @@ impl CompileOptions {
         CompileOptions {
             compile_scope,
             parser_options: ParserOptions::default(),
+            modules: ModuleRegistry::default(),
+            optimizations: OptimizationOptions::default(),
+            restored_types: HashMap::new(),
         }
     }
 }
@@ -275,15 +302,17 @@ pub fn compile_script_or_return_static_value<'a>(
     datex_script: &'a str,
     mut options: CompileOptions,
 ) -> Result<(StaticValueOrDXB, CompilationScope), SpannedCompilerError> {
-    let ast = parse_datex_script_to_rich_ast_simple_error(
+    let mut ast = parse_datex_script_to_rich_ast_simple_error(
         datex_script,
         &mut options,
     )?;
+    apply_optimizations(&mut ast, &options);
     let mut compilation_context = CompilationContext::new(
         Vec::with_capacity(256),
         vec![],
         options.compile_scope.execution_mode,
     );
+    compilation_context.modules = options.modules.clone();
     // FIXME #480: no clone here
     let scope = compile_ast(ast.clone(), &mut compilation_context, options)?;
     if compilation_context.has_non_static_value {
@@ -369,6 +398,8 @@ pub fn parse_datex_script_to_rich_ast_simple_error(
         &mut options.compile_scope,
         PrecompilerOptions {
             detailed_errors: false,
+            modules: options.modules.clone(),
+            restored_types: options.restored_types.clone(),
         },
     )
     .map_err(|e| match e {
@@ -395,52 +426,121 @@ pub fn parse_datex_script_to_rich_ast_detailed_errors(
     let (ast, parser_errors) =
         Parser::parse_collecting_with_default_options(datex_script)
             .into_ast_and_errors();
-    precompile_to_rich_ast(
+    precompile_to_rich_ast_detailed_errors(ast, parser_errors, options)
+}
+
+/// Precompiles an already parsed DATEX AST into an AST with metadata.
+/// The parser errors are appended to the returned errors if one or more precompiler errors occur.
+/// If only parser errors occurred, they are returned together with the precompiled AST.
+pub fn precompile_to_rich_ast_detailed_errors(
+    ast: DatexExpression,
+    parser_errors: Vec<SpannedParserError>,
+    options: &mut CompileOptions,
+) -> Result<RichAst, DetailedCompilerErrorsWithMaybeRichAst> {
+    let parser_errors = parser_errors
+        .into_iter()
+        .map(SpannedCompilerError::from)
+        .collect::<Vec<_>>();
+    match precompile_to_rich_ast(
         ast,
         &mut options.compile_scope,
         PrecompilerOptions {
             detailed_errors: true,
+            modules: options.modules.clone(),
+            restored_types: options.restored_types.clone(),
         },
-    )
-    .map_err(|e| match e {
-        SimpleCompilerErrorOrDetailedCompilerErrorWithRichAst::Detailed(
-            mut e,
+    ) {
+        Ok(rich_ast) if parser_errors.is_empty() => Ok(rich_ast),
+        Ok(rich_ast) => Err(DetailedCompilerErrorsWithMaybeRichAst {
+            errors: DetailedCompilerErrors {
+                errors: parser_errors,
+            },
+            ast: Some(rich_ast),
+        }),
+        Err(
+            SimpleCompilerErrorOrDetailedCompilerErrorWithRichAst::Detailed(
+                mut e,
+            ),
         ) => {
             // append parser errors to detailed errors
-            e.errors.errors.extend(
-                parser_errors.into_iter().map(SpannedCompilerError::from),
-            );
-            e.into()
+            e.errors.errors.extend(parser_errors);
+            Err(e.into())
         }
-        _ => unreachable!(), // because detailed_errors: true
-    })
+        Err(_) => unreachable!(), // because detailed_errors: true
+    }
 }
 
 /// Compiles a DATEX script template text with inserted values into a DXB body
 pub fn compile_template(
     datex_script: &str,
     inserted_values: &[ValueContainer],
-    mut options: CompileOptions,
+    options: CompileOptions,
 ) -> Result<(Vec<u8>, CompilationScope), SpannedCompilerError> {
-    let ast = parse_datex_script_to_rich_ast_simple_error(
+    compile_template_to_context(datex_script, inserted_values, options, None)
+        .map(|(compilation_context, scope)| (compilation_context.buffer, scope))
+}
+
+/// Compiles a DATEX script template text with inserted values into a DXB body,
+/// together with a source map that maps the DXB body to spans in the script.
+/// The source map is used to report the source location of execution errors.
+pub fn compile_template_with_source_map(
+    datex_script: &str,
+    inserted_values: &[ValueContainer],
+    source: Option<Url>,
+    options: CompileOptions,
+) -> Result<(Vec<u8>, SourceMap, CompilationScope), SpannedCompilerError> {
+    compile_template_to_context(
+        datex_script,
+        inserted_values,
+        options,
+        Some(SourceMap::new(source)),
+    )
+    .map(|(compilation_context, scope)| {
+        (
+            compilation_context.buffer,
+            compilation_context.source_map.unwrap_or_default(),
+            scope,
+        )
+    })
+}
+
+fn compile_template_to_context(
+    datex_script: &str,
+    inserted_values: &[ValueContainer],
+    mut options: CompileOptions,
+    source_map: Option<SourceMap>,
+) -> Result<(CompilationContext, CompilationScope), SpannedCompilerError> {
+    let mut ast = parse_datex_script_to_rich_ast_simple_error(
         datex_script,
         &mut options,
     )?;
+    apply_optimizations(&mut ast, &options);
     let mut compilation_context = CompilationContext::new(
         Vec::with_capacity(256),
         // TODO #482: no clone here
         inserted_values.to_vec(),
         options.compile_scope.execution_mode,
     );
+    compilation_context.modules = options.modules.clone();
+    compilation_context.source_map = source_map;
     let compile_start = Instant::now();
     let res = compile_ast(ast, &mut compilation_context, options)
-        .map(|scope| (compilation_context.buffer, scope))
         .map_err(SpannedCompilerError::from);
     debug!(
         " [compile_ast took {} ms]",
         compile_start.elapsed().as_millis()
     );
-    res
+    res.map(|scope| (compilation_context, scope))
+}
+
+/// Applies the optimizations enabled in the compile options to the precompiled AST
+fn apply_optimizations(rich_ast: &mut RichAst, options: &CompileOptions) {
+    let mut optimizations = options.optimizations;
+    // declarations can still be used by following sections of an unbounded execution
+    if options.compile_scope.execution_mode.is_unbounded() {
+        optimizations.remove_unused_declarations = false;
+    }
+    optimize_rich_ast(rich_ast, optimizations);
 }
 
 /// Compiles a precompiled DATEX AST, returning the compilation context and scope
@@ -545,6 +645,8 @@ fn compile_expression(
     meta: CompileMetadata,
     mut scope: CompilationScope,
 ) -> Result<CompilationScope, CompilerError> {
+    let start_index = compilation_context.buffer_index();
+    let span = rich_ast.ast.span.clone();
     let metadata = rich_ast.metadata;
     // TODO #483: no clone
     match rich_ast.ast.data.clone() {
@@ -589,6 +691,13 @@ fn compile_expression(
                 InstructionCode::NULL,
             );
         }
+        DatexExpressionData::Debugger => {
+            compilation_context.mark_has_non_static_value();
+            append_instruction_code(
+                &mut compilation_context.buffer,
+                InstructionCode::DEBUGGER,
+            );
+        }
         DatexExpressionData::List(list) => {
             match list.items.len() {
                 0..=255 => {
@@ -883,6 +992,14 @@ fn compile_expression(
 
             // depending on the key, handle different property accesses
             match &property_access.property.data {
+                // optional chaining always uses a dynamic key
+                _ if property_access.is_optional_chain() => {
+                    scope = compile_optional_property_access(
+                        compilation_context,
+                        &property_access.property,
+                        scope,
+                    )?;
+                }
                 // simple text key if length fits in u8
                 DatexExpressionData::Text(key) if key.len() <= 255 => {
                     compile_text_property_access(compilation_context, key)
@@ -911,9 +1028,28 @@ fn compile_expression(
             )?;
         }
 
+        // generic instantiations of types are compiled to the instance type,
+        // e.g. `Box<integer>`, type arguments of generic callables are only
+        // used by the type inference, so the callable itself is compiled
         DatexExpressionData::GenericInstantiation(generic_instantiation) => {
-            // NOTE: might already be handled in type compilation
-            todo!("#674 Undescribed by author.")
+            let mut instantiation =
+                RichAst::new(rich_ast.ast.clone(), &metadata);
+            let instance_type =
+                infer_expression_type_simple_error(&mut instantiation)
+                    .map_err(|e| CompilerError::TypeError(e.error))?;
+            if let TypeDefinition::Callable(_) = instance_type.type_definition {
+                scope = compile_expression(
+                    compilation_context,
+                    RichAst::new(*generic_instantiation.base, &metadata),
+                    CompileMetadata::default(),
+                    scope,
+                )?;
+            } else {
+                compilation_context.mark_has_non_static_value();
+                compilation_context
+                    .append_instruction_code(InstructionCode::TYPE_EXPRESSION);
+                append_type(&mut compilation_context.buffer, &instance_type);
+            }
         }
 
         DatexExpressionData::PropertyAssignment(property_assignment) => {
@@ -1178,6 +1314,7 @@ fn compile_expression(
                 vec![],
                 ExecutionMode::Static,
             );
+            execution_block_ctx.modules = compilation_context.modules.clone();
             let external_scope = compile_rich_ast(
                 &mut execution_block_ctx,
                 RichAst::new(*script, &metadata),
@@ -1269,6 +1406,67 @@ fn compile_expression(
             )?;
         }
 
+        // type declarations are stored as constant type values in a slot,
+        // so that nominal types can be used as constructors, e.g. `User({x: 1})`
+        DatexExpressionData::TypeDeclaration(TypeDeclaration {
+            id,
+            name,
+            ..
+        }) => {
+            compilation_context.mark_has_non_static_value();
+
+            // the type definition is resolved by the type inference
+            let mut declaration = RichAst::new(rich_ast.ast.clone(), &metadata);
+            infer_expression_type_simple_error(&mut declaration)
+                .map_err(|e| CompilerError::TypeError(e.error))?;
+            let declared_type = id
+                .and_then(|id| {
+                    metadata.borrow().variable_metadata(id)?.var_type.clone()
+                })
+                .ok_or_else(|| {
+                    CompilerError::UndeclaredVariable(name.clone())
+                })?;
+
+            let virtual_slot_addr = scope.get_next_virtual_slot();
+            compilation_context
+                .append_instruction_code(InstructionCode::ALLOCATE_SLOT);
+            compilation_context.insert_virtual_slot_address(
+                VirtualSlot::local(virtual_slot_addr),
+            );
+            compilation_context
+                .append_instruction_code(InstructionCode::TYPE_EXPRESSION);
+            append_type(&mut compilation_context.buffer, &declared_type);
+
+            scope.register_variable_slot(Variable::new_const(
+                name,
+                VirtualSlot::local(virtual_slot_addr),
+            ));
+        }
+
+        DatexExpressionData::TypeCast(TypeCast {
+            expression,
+            mut target,
+            mode,
+        }) => {
+            compilation_context.mark_has_non_static_value();
+
+            // the target type is resolved by the type inference
+            let target_type =
+                infer_type_expression_type(&mut target, metadata.clone())
+                    .map_err(|e| CompilerError::TypeError(e.error))?;
+
+            compilation_context
+                .append_instruction_code(InstructionCode::TRANSFORM);
+            append_u8(&mut compilation_context.buffer, mode as u8);
+            append_type(&mut compilation_context.buffer, &target_type);
+            scope = compile_expression(
+                compilation_context,
+                RichAst::new(*expression, &metadata),
+                CompileMetadata::default(),
+                scope,
+            )?;
+        }
+
         DatexExpressionData::TypeExpression(type_expression) => {
             compilation_context
                 .append_instruction_code(InstructionCode::TYPE_EXPRESSION);
@@ -1291,15 +1489,198 @@ fn compile_expression(
             )?;
         }
 
+        DatexExpressionData::Yeet(yeet) => {
+            compilation_context.mark_has_non_static_value();
+            compilation_context.append_instruction_code(InstructionCode::YEET);
+            scope = compile_expression(
+                compilation_context,
+                RichAst::new(*yeet.expression, &metadata),
+                CompileMetadata::default(),
+                scope,
+            )?;
+        }
+
+        DatexExpressionData::Try(r#try) => {
+            compilation_context.mark_has_non_static_value();
+            compilation_context.append_instruction_code(InstructionCode::TRY);
+            scope = compile_expression(
+                compilation_context,
+                RichAst::new(*r#try.expression, &metadata),
+                CompileMetadata::default(),
+                scope,
+            )?;
+        }
+
+        // exported declarations are compiled like normal declarations
+        DatexExpressionData::Export(Export { declaration }) => {
+            scope = compile_expression(
+                compilation_context,
+                RichAst::new(*declaration, &metadata),
+                CompileMetadata::default(),
+                scope,
+            )?;
+        }
+
+        DatexExpressionData::Import(import) => {
+            compilation_context.mark_has_non_static_value();
+            scope = compile_import(compilation_context, &import, scope)?;
+        }
+
         e => {
             println!("Unhandled expression in compiler: {:?}", e);
             return Err(CompilerError::UnexpectedTerm(Box::new(rich_ast.ast)));
         }
     }
 
+    let end_index = compilation_context.buffer_index();
+    if let Some(source_map) = &mut compilation_context.source_map {
+        source_map.add(start_index..end_index, span);
+    }
     Ok(scope)
 }
 
+/// Compiles an import of a module.
+/// On the first import, the DXB of the module is inlined as a terminated statements
+/// block, which is compiled in a child scope. All slots except the ones of the
+/// exported declarations are dropped afterward. Following imports of the same
+/// module refer to the slots of the exported declarations, so that each module
+/// is only compiled and executed once.
+/// Inside of function bodies and remote execution blocks, the slots of the
+/// top-level scope are not accessible, so the module is compiled again.
+fn compile_import(
+    compilation_context: &mut CompilationContext,
+    import: &Import,
+    scope: CompilationScope,
+) -> Result<CompilationScope, CompilerError> {
+    let is_top_level = !scope.is_inside_external_scope();
+    if is_top_level
+        && let Some(exported_variables) =
+            compilation_context.compiled_modules.get(&import.source)
+    {
+        let imported_variables = imported_module_variables(
+            import,
+            exported_variables.iter().cloned(),
+        );
+        // the import statement is compiled to an empty statements block
+        compilation_context
+            .append_instruction_code(InstructionCode::SHORT_STATEMENTS);
+        append_u8(&mut compilation_context.buffer, 0);
+        append_u8(&mut compilation_context.buffer, 1);
+        let mut scope = scope;
+        for variable in imported_variables {
+            scope.register_imported_variable(variable);
+        }
+        return Ok(scope);
+    }
+
+    let module = compilation_context
+        .modules
+        .get(&import.source)
+        .cloned()
+        .ok_or_else(|| {
+            CompilerError::UnresolvedModule(import.source.clone())
+        })?;
+
+    // type declarations are only relevant during precompilation
+    let statements = module_statements(&module.ast)
+        .into_iter()
+        .filter(|statement| {
+            !matches!(
+                &statement.data,
+                DatexExpressionData::TypeDeclaration(_)
+            ) && !matches!(
+                &statement.data,
+                DatexExpressionData::Export(Export { declaration })
+                    if matches!(declaration.data, DatexExpressionData::TypeDeclaration(_))
+            )
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    // the statement count is written after compilation, because the dropped
+    // slots are also counted as statements
+    compilation_context.append_instruction_code(InstructionCode::STATEMENTS);
+    let mut len_index = compilation_context.buffer_index();
+    append_u32(&mut compilation_context.buffer, 0);
+    // always terminated
+    append_u8(&mut compilation_context.buffer, 1);
+
+    let mut len = statements.len();
+    let mut module_scope = scope.push();
+    // spans of the module statements refer to the module source, so they are not mapped
+    let source_map = compilation_context.source_map.take();
+    for statement in statements {
+        module_scope = compile_expression(
+            compilation_context,
+            RichAst::new(statement, &module.metadata),
+            CompileMetadata::default(),
+            module_scope,
+        )?;
+    }
+    compilation_context.source_map = source_map;
+
+    // keep the variables of the exported declarations (types have no slot)
+    let exported_variables = if is_top_level {
+        module_exports(&module)
+            .iter()
+            .filter_map(|export| module_scope.take_variable(&export.name))
+            .collect::<Vec<_>>()
+    } else {
+        import
+            .items
+            .iter()
+            .filter_map(|item| module_scope.take_variable(&item.name))
+            .collect::<Vec<_>>()
+    };
+
+    let (mut scope, dropped_slots) =
+        module_scope.pop().ok_or(CompilerError::ScopePopError)?;
+    for slot_address in dropped_slots {
+        compilation_context.append_instruction_code(InstructionCode::DROP_SLOT);
+        compilation_context.insert_virtual_slot_address(slot_address);
+        len += 1;
+    }
+    write_u32(
+        &mut compilation_context.buffer,
+        &mut len_index,
+        len as u32, // FIXME #673: conversion from usize to u32
+    );
+
+    let imported_variables =
+        imported_module_variables(import, exported_variables.iter().cloned());
+    if is_top_level {
+        // the slots are shared by all imports of the module
+        compilation_context
+            .compiled_modules
+            .insert(import.source.clone(), exported_variables);
+        for variable in imported_variables {
+            scope.register_imported_variable(variable);
+        }
+    } else {
+        for variable in imported_variables {
+            scope.register_variable_slot(variable);
+        }
+    }
+    Ok(scope)
+}
+
+/// Returns the variables of the imported declarations of a module.
+/// Imported variables are always constant in the importing module.
+fn imported_module_variables(
+    import: &Import,
+    exported_variables: impl Iterator<Item = Variable>,
+) -> Vec<Variable> {
+    exported_variables
+        .filter(|variable| {
+            import.items.iter().any(|item| item.name == variable.name)
+        })
+        .map(|mut variable| {
+            variable.kind = VariableKind::Const;
+            variable
+        })
+        .collect()
+}
+
 fn compile_key_value_entry(
     compilation_context: &mut CompilationContext,
     key: DatexExpression,
@@ -1395,6 +1776,25 @@ fn compile_dynamic_property_access(
     )
 }
 
+fn compile_optional_property_access(
+    compilation_context: &mut CompilationContext,
+    key_expression: &DatexExpression,
+    scope: CompilationScope,
+) -> Result<CompilationScope, CompilerError> {
+    compilation_context
+        .append_instruction_code(InstructionCode::GET_PROPERTY_OPTIONAL);
+    // compile key expression
+    compile_expression(
+        compilation_context,
+        RichAst::new(
+            key_expression.clone(),
+            &Rc::new(RefCell::new(AstMetadata::default())),
+        ),
+        CompileMetadata::default(),
+        scope,
+    )
+}
+
 fn compile_dynamic_property_assignment(
     compilation_context: &mut CompilationContext,
     key_expression: &DatexExpression,
@@ -3238,6 +3638,23 @@ pub mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_get_property_optional() {
+        init_logger_debug();
+        let datex_script = "null?.a";
+        let result = compile_and_log(datex_script);
+        let expected = vec![
+            InstructionCode::GET_PROPERTY_OPTIONAL.into(),
+            // property key
+            InstructionCode::SHORT_TEXT.into(),
+            1, // length of "a"
+            b'a',
+            // base value
+            InstructionCode::NULL.into(),
+        ];
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_set_property_text() {
         init_logger_debug();
@@ -0,0 +1,331 @@
+use crate::ast::expressions::{
+    DatexExpression, DatexExpressionData, Export, Import, Statements,
+    TypeDeclaration, VariableDeclaration,
+};
+use crate::ast::resolved_variable::VariableId;
+use crate::collections::HashMap;
+use crate::compiler::precompiler::precompiled_ast::{RichAst, VariableShape};
+use crate::stdlib::rc::Rc;
+use crate::values::core_values::r#type::Type;
+use core::ops::Range;
+
+/// A declaration that is exported by a module
+#[derive(Debug, Clone)]
+pub struct ModuleExport {
+    pub name: String,
+    /// variable id of the declaration in the metadata of the exporting module
+    pub id: VariableId,
+    pub shape: VariableShape,
+    /// inferred type of the declaration, if available
+    pub ty: Option<Type>,
+    /// span of the declaration in the source of the exporting module
+    pub span: Range<usize>,
+}
+
+/// Precompiled modules that can be imported by other modules, mapped by their resolved source.
+/// Imports are resolved against the registry during precompilation and the imported
+/// modules are inlined into the DXB of the importing module on their first import.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleRegistry {
+    modules: HashMap<String, Rc<RichAst>>,
+}
+
+impl ModuleRegistry {
+    /// Registers a precompiled module for the given source
+    pub fn register(&mut self, source: String, module: RichAst) {
+        self.modules.insert(source, Rc::new(module));
+    }
+
+    pub fn get(&self, source: &str) -> Option<&Rc<RichAst>> {
+        self.modules.get(source)
+    }
+
+    pub fn contains(&self, source: &str) -> bool {
+        self.modules.contains_key(source)
+    }
+
+    /// Returns the export with the given name of the module with the given source
+    pub fn get_export(&self, source: &str, name: &str) -> Option<ModuleExport> {
+        self.get(source).and_then(|module| {
+            module_exports(module)
+                .into_iter()
+                .find(|export| export.name == name)
+        })
+    }
+}
+
+/// Returns the top-level statements of a module
+pub fn module_statements(ast: &DatexExpression) -> Vec<&DatexExpression> {
+    match &ast.data {
+        DatexExpressionData::Statements(Statements { statements, .. }) => {
+            statements.iter().collect()
+        }
+        _ => vec![ast],
+    }
+}
+
+/// Collects all top-level exports of a precompiled module
+pub fn module_exports(module: &RichAst) -> Vec<ModuleExport> {
+    let metadata = module.metadata.borrow();
+    module_statements(&module.ast)
+        .into_iter()
+        .filter_map(|statement| {
+            let DatexExpressionData::Export(Export { declaration }) =
+                &statement.data
+            else {
+                return None;
+            };
+            let (id, name, shape) = match &declaration.data {
+                DatexExpressionData::VariableDeclaration(
+                    VariableDeclaration { id, name, kind, .. },
+                ) => (id.as_ref()?, name, VariableShape::Value(*kind)),
+                DatexExpressionData::TypeDeclaration(TypeDeclaration {
+                    id,
+                    name,
+                    ..
+                }) => (id.as_ref()?, name, VariableShape::Type),
+                _ => return None,
+            };
+            Some(ModuleExport {
+                name: name.clone(),
+                id: *id,
+                shape,
+                ty: metadata
+                    .variable_metadata(*id)
+                    .and_then(|variable| variable.var_type.clone()),
+                span: declaration.span.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Collects all top-level imports of a module
+pub fn module_imports(ast: &DatexExpression) -> Vec<&Import> {
+    module_statements(ast)
+        .into_iter()
+        .filter_map(|statement| match &statement.data {
+            DatexExpressionData::Import(import) => Some(import),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Same as module_imports, but returns mutable references, e.g. to update import sources
+pub fn module_imports_mut(ast: &mut DatexExpression) -> Vec<&mut Import> {
+    let statements = if matches!(ast.data, DatexExpressionData::Statements(_)) {
+        let DatexExpressionData::Statements(Statements { statements, .. }) =
+            &mut ast.data
+        else {
+            unreachable!()
+        };
+        statements.iter_mut().collect()
+    } else {
+        vec![ast]
+    };
+    statements
+        .into_iter()
+        .filter_map(|statement| match &mut statement.data {
+            DatexExpressionData::Import(import) => Some(import),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::expressions::VariableKind;
+    use crate::compiler::error::{CompilerError, SpannedCompilerError};
+    use crate::compiler::{
+        CompileOptions, compile_script,
+        parse_datex_script_to_rich_ast_simple_error,
+    };
+    use crate::runtime::execution::{
+        ExecutionInput, ExecutionOptions, execute_dxb_sync,
+    };
+    use crate::values::core_values::integer::Integer;
+    use crate::values::value_container::ValueContainer;
+
+    fn module(source: &str) -> RichAst {
+        parse_datex_script_to_rich_ast_simple_error(
+            source,
+            &mut CompileOptions::default(),
+        )
+        .unwrap()
+    }
+
+    fn execute_with_modules(
+        script: &str,
+        modules: ModuleRegistry,
+    ) -> Result<Option<ValueContainer>, SpannedCompilerError> {
+        let (dxb, _) = compile_script(
+            script,
+            CompileOptions {
+                modules,
+                ..Default::default()
+            },
+        )?;
+        Ok(execute_dxb_sync(ExecutionInput::new(
+            &dxb,
+            ExecutionOptions::default(),
+            None,
+        ))
+        .unwrap())
+    }
+
+    #[test]
+    fn exports() {
+        let module = module(
+            "export const a = 1; const b = 2; export type T = integer; export var c = 3;",
+        );
+        let exports = module_exports(&module);
+        assert_eq!(
+            exports
+                .iter()
+                .map(|export| (export.name.as_str(), export.shape))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a", VariableShape::Value(VariableKind::Const)),
+                ("T", VariableShape::Type),
+                ("c", VariableShape::Value(VariableKind::Var)),
+            ]
+        );
+    }
+
+    #[test]
+    fn inlined_import() {
+        let mut modules = ModuleRegistry::default();
+        modules.register(
+            "lib".to_string(),
+            module("export const a = 40; const b = 2; export var c = a + b;"),
+        );
+        let result = execute_with_modules(
+            r#"import { a, c } from "lib"; a + c"#,
+            modules,
+        )
+        .unwrap();
+        assert_eq!(result, Some(Integer::from(82).into()));
+    }
+
+    #[test]
+    fn private_declarations_are_not_imported() {
+        let mut modules = ModuleRegistry::default();
+        modules.register(
+            "lib".to_string(),
+            module("export const a = 1; const b = 2;"),
+        );
+        let result = execute_with_modules(
+            r#"const b = 10; import { a } from "lib"; a + b"#,
+            modules.clone(),
+        )
+        .unwrap();
+        assert_eq!(result, Some(Integer::from(11).into()));
+
+        let error =
+            execute_with_modules(r#"import { a } from "lib"; b"#, modules)
+                .unwrap_err();
+        assert!(matches!(error.error, CompilerError::UndeclaredVariable(_)));
+    }
+
+    #[test]
+    fn nested_import() {
+        let mut modules = ModuleRegistry::default();
+        modules.register("inner".to_string(), module("export const x = 21;"));
+        let mut outer_options = CompileOptions {
+            modules: modules.clone(),
+            ..Default::default()
+        };
+        let outer = parse_datex_script_to_rich_ast_simple_error(
+            r#"import { x } from "inner"; export const y = x + x;"#,
+            &mut outer_options,
+        )
+        .unwrap();
+        modules.register("outer".to_string(), outer);
+        let result =
+            execute_with_modules(r#"import { y } from "outer"; y"#, modules)
+                .unwrap();
+        assert_eq!(result, Some(Integer::from(42).into()));
+    }
+
+    #[test]
+    fn module_is_compiled_once() {
+        let mut modules = ModuleRegistry::default();
+        modules.register("lib".to_string(), module("export const r = 21;"));
+        let mut outer_options = CompileOptions {
+            modules: modules.clone(),
+            ..Default::default()
+        };
+        let outer = parse_datex_script_to_rich_ast_simple_error(
+            r#"import { r } from "lib"; export const s = r;"#,
+            &mut outer_options,
+        )
+        .unwrap();
+        modules.register("outer".to_string(), outer);
+
+        // the imports of lib refer to the same slot
+        let result = execute_with_modules(
+            r#"import { r } from "lib"; import { s } from "outer"; r + s"#,
+            modules.clone(),
+        )
+        .unwrap();
+        assert_eq!(result, Some(Integer::from(42).into()));
+
+        let (once, _) = compile_script(
+            r#"import { r } from "lib"; r"#,
+            CompileOptions {
+                modules: modules.clone(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let (twice, _) = compile_script(
+            r#"import { r } from "lib"; (import { r } from "lib"; r)"#,
+            CompileOptions {
+                modules,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        // the second import does not inline the module again
+        assert!(twice.len() < once.len() * 2);
+        let result = execute_dxb_sync(ExecutionInput::new(
+            &twice,
+            ExecutionOptions::default(),
+            None,
+        ))
+        .unwrap();
+        assert_eq!(result, Some(Integer::from(21).into()));
+    }
+
+    #[test]
+    fn import_errors() {
+        let mut modules = ModuleRegistry::default();
+        modules.register("lib".to_string(), module("export const a = 1;"));
+
+        let error = execute_with_modules(
+            r#"import { a } from "missing"; a"#,
+            modules.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.error,
+            CompilerError::UnresolvedModule(source) if source == "missing"
+        ));
+
+        let error = execute_with_modules(
+            r#"import { b } from "lib"; 1"#,
+            modules.clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.error,
+            CompilerError::ImportNotExported(name, _) if name == "b"
+        ));
+        assert_eq!(error.span, Some(9..10));
+
+        let error =
+            execute_with_modules(r#"import { a } from "lib"; a = 2"#, modules)
+                .unwrap_err();
+        assert!(matches!(error.error, CompilerError::AssignmentToConst(_)));
+    }
+}
@@ -0,0 +1,446 @@
+use crate::ast::expressions::{
+    BinaryOperation, ComparisonOperation, Conditional, DatexExpression,
+    DatexExpressionData, Statements, UnaryOperation, VariableAccess,
+    VariableAssignment, VariableDeclaration,
+};
+use crate::ast::resolved_variable::VariableId;
+use crate::collections::HashSet;
+use crate::compiler::precompiler::precompiled_ast::RichAst;
+use crate::global::operators::binary::ArithmeticOperator;
+use crate::global::operators::{
+    ArithmeticUnaryOperator, BinaryOperator, ComparisonOperator, UnaryOperator,
+};
+use crate::runtime::execution::execution_loop::operations::{
+    handle_binary_operation, handle_comparison_operation,
+    handle_unary_operation,
+};
+use crate::values::core_value::CoreValue;
+use crate::values::value::Value;
+use crate::values::value_container::ValueContainer;
+use crate::visitor::VisitAction;
+use crate::visitor::expression::ExpressionVisitor;
+use crate::visitor::expression::visitable::ExpressionVisitResult;
+use crate::visitor::type_expression::TypeExpressionVisitor;
+use crate::visitor::type_expression::visitable::TypeExpressionVisitResult;
+use core::ops::Range;
+
+/// Optimizations that are applied to the RichAst after precompilation.
+/// All optimizations are disabled by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OptimizationOptions {
+    /// Evaluate arithmetic, comparison and text operations with constant operands at compile time
+    pub fold_constants: bool,
+    /// Replace conditionals with a constant condition with the taken branch
+    pub eliminate_dead_branches: bool,
+    /// Remove variable declarations that are never used and have no side effects
+    pub remove_unused_declarations: bool,
+}
+
+impl OptimizationOptions {
+    /// Enables all optimizations
+    pub fn all() -> Self {
+        OptimizationOptions {
+            fold_constants: true,
+            eliminate_dead_branches: true,
+            remove_unused_declarations: true,
+        }
+    }
+}
+
+/// Applies the enabled optimizations to the RichAst.
+/// The resulting AST evaluates to the same value as the original AST.
+pub fn optimize_rich_ast(rich_ast: &mut RichAst, options: OptimizationOptions) {
+    if options.fold_constants || options.eliminate_dead_branches {
+        let _ = ConstantFolder { options }
+            .visit_datex_expression(&mut rich_ast.ast);
+    }
+    if options.remove_unused_declarations {
+        // removing a declaration can make other declarations unused
+        loop {
+            let mut collector = UsedVariableCollector::default();
+            let _ = collector.visit_datex_expression(&mut rich_ast.ast);
+            let mut remover = UnusedDeclarationRemover {
+                used_variables: collector.used_variables,
+                removed: false,
+            };
+            let _ = remover.visit_datex_expression(&mut rich_ast.ast);
+            if !remover.removed {
+                break;
+            }
+        }
+    }
+}
+
+/// Converts a literal expression into a value container
+fn literal_value(expression: &DatexExpression) -> Option<ValueContainer> {
+    match &expression.data {
+        DatexExpressionData::Integer(_)
+        | DatexExpressionData::TypedInteger(_)
+        | DatexExpressionData::Decimal(_)
+        | DatexExpressionData::TypedDecimal(_)
+        | DatexExpressionData::Text(_)
+        | DatexExpressionData::Boolean(_)
+        | DatexExpressionData::Null
+        | DatexExpressionData::Endpoint(_) => {
+            ValueContainer::try_from(&expression.data).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Converts a primitive value back into a literal expression
+fn literal_expression(value: ValueContainer) -> Option<DatexExpressionData> {
+    let ValueContainer::Value(Value { inner, .. }) = value else {
+        return None;
+    };
+    Some(match inner {
+        CoreValue::Integer(integer) => DatexExpressionData::Integer(integer),
+        CoreValue::TypedInteger(integer) => {
+            DatexExpressionData::TypedInteger(integer)
+        }
+        CoreValue::Decimal(decimal) => DatexExpressionData::Decimal(decimal),
+        CoreValue::TypedDecimal(decimal) => {
+            DatexExpressionData::TypedDecimal(decimal)
+        }
+        CoreValue::Text(text) => DatexExpressionData::Text(text.0),
+        CoreValue::Boolean(boolean) => DatexExpressionData::Boolean(boolean.0),
+        CoreValue::Null => DatexExpressionData::Null,
+        CoreValue::Endpoint(endpoint) => {
+            DatexExpressionData::Endpoint(endpoint)
+        }
+        _ => return None,
+    })
+}
+
+/// Folds constant operations and eliminates statically decided conditionals.
+/// Only operations that are supported by the runtime are folded, using the same
+/// operation implementations as the execution loop.
+struct ConstantFolder {
+    options: OptimizationOptions,
+}
+
+impl ConstantFolder {
+    fn fold(&self, data: &DatexExpressionData) -> Option<DatexExpressionData> {
+        let value = match data {
+            DatexExpressionData::BinaryOperation(BinaryOperation {
+                operator:
+                    operator @ BinaryOperator::Arithmetic(
+                        ArithmeticOperator::Add | ArithmeticOperator::Subtract,
+                    ),
+                left,
+                right,
+                ..
+            }) => handle_binary_operation(
+                *operator,
+                &literal_value(left)?,
+                &literal_value(right)?,
+            )
+            .ok()?,
+            DatexExpressionData::ComparisonOperation(ComparisonOperation {
+                operator:
+                    operator @ (ComparisonOperator::StructuralEqual
+                    | ComparisonOperator::NotStructuralEqual
+                    | ComparisonOperator::Equal
+                    | ComparisonOperator::NotEqual),
+                left,
+                right,
+            }) => handle_comparison_operation(
+                *operator,
+                &literal_value(left)?,
+                &literal_value(right)?,
+            )
+            .ok()?,
+            DatexExpressionData::UnaryOperation(UnaryOperation {
+                operator:
+                    operator @ UnaryOperator::Arithmetic(
+                        ArithmeticUnaryOperator::Minus
+                        | ArithmeticUnaryOperator::Plus,
+                    ),
+                expression,
+            }) => handle_unary_operation(*operator, literal_value(expression)?)
+                .ok()?,
+            _ => return None,
+        };
+        literal_expression(value)
+    }
+
+    /// Returns the taken branch of a conditional with a constant condition
+    fn eliminate_branch(
+        &self,
+        expression: &DatexExpression,
+    ) -> Option<DatexExpression> {
+        let DatexExpressionData::Conditional(Conditional {
+            condition,
+            then_branch,
+            else_branch,
+        }) = &expression.data
+        else {
+            return None;
+        };
+        let DatexExpressionData::Boolean(condition) = condition.data else {
+            return None;
+        };
+        Some(if condition {
+            *then_branch.clone()
+        } else {
+            match else_branch {
+                Some(else_branch) => *else_branch.clone(),
+                None => DatexExpression::new(
+                    DatexExpressionData::Null,
+                    expression.span.clone(),
+                ),
+            }
+        })
+    }
+}
+
+impl TypeExpressionVisitor<()> for ConstantFolder {}
+
+impl ExpressionVisitor<()> for ConstantFolder {
+    // operands are folded first, so the whole operation can be folded afterward
+    fn after_visit_datex_expression(
+        &mut self,
+        expression: &mut DatexExpression,
+    ) {
+        if self.options.fold_constants
+            && let Some(folded) = self.fold(&expression.data)
+        {
+            expression.data = folded;
+            expression.ty = None;
+        }
+        if self.options.eliminate_dead_branches
+            && let Some(branch) = self.eliminate_branch(expression)
+        {
+            *expression = branch;
+        }
+    }
+}
+
+/// Collects the ids of all variables that are read or assigned
+#[derive(Default)]
+struct UsedVariableCollector {
+    used_variables: HashSet<VariableId>,
+}
+
+impl TypeExpressionVisitor<()> for UsedVariableCollector {
+    fn visit_variable_access_type(
+        &mut self,
+        var_access: &mut VariableAccess,
+        _: &Range<usize>,
+    ) -> TypeExpressionVisitResult<()> {
+        self.used_variables.insert(var_access.id);
+        Ok(VisitAction::SkipChildren)
+    }
+}
+
+impl ExpressionVisitor<()> for UsedVariableCollector {
+    fn visit_variable_access(
+        &mut self,
+        var_access: &mut VariableAccess,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<()> {
+        self.used_variables.insert(var_access.id);
+        Ok(VisitAction::SkipChildren)
+    }
+
+    fn visit_variable_assignment(
+        &mut self,
+        var_assignment: &mut VariableAssignment,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<()> {
+        if let Some(id) = var_assignment.id {
+            self.used_variables.insert(id);
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+}
+
+/// Removes unused variable declarations without side effects from statements
+struct UnusedDeclarationRemover {
+    used_variables: HashSet<VariableId>,
+    removed: bool,
+}
+
+impl UnusedDeclarationRemover {
+    /// Returns true if the evaluation of the expression has no side effects
+    fn is_pure(expression: &DatexExpression) -> bool {
+        match &expression.data {
+            DatexExpressionData::List(list) => {
+                list.items.iter().all(Self::is_pure)
+            }
+            DatexExpressionData::Map(map) => map
+                .entries
+                .iter()
+                .all(|(key, value)| Self::is_pure(key) && Self::is_pure(value)),
+            DatexExpressionData::VariableAccess(_) => true,
+            _ => literal_value(expression).is_some(),
+        }
+    }
+
+    fn is_removable(&self, statement: &DatexExpression) -> bool {
+        match &statement.data {
+            DatexExpressionData::VariableDeclaration(VariableDeclaration {
+                id: Some(id),
+                init_expression,
+                ..
+            }) => {
+                !self.used_variables.contains(id)
+                    && Self::is_pure(init_expression)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl TypeExpressionVisitor<()> for UnusedDeclarationRemover {}
+
+impl ExpressionVisitor<()> for UnusedDeclarationRemover {
+    fn visit_statements(
+        &mut self,
+        statements: &mut Statements,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<()> {
+        // the last statement of an unterminated block is the result value
+        let last_index = statements.statements.len().saturating_sub(1);
+        let keep_last = !statements.is_terminated;
+        let mut index = 0;
+        let len_before = statements.statements.len();
+        statements.statements.retain(|statement| {
+            let keep = (keep_last && index == last_index)
+                || !self.is_removable(statement);
+            index += 1;
+            keep
+        });
+        if statements.statements.len() != len_before {
+            self.removed = true;
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::{
+        CompileOptions, parse_datex_script_to_rich_ast_simple_error,
+    };
+    use crate::values::core_values::integer::Integer;
+
+    fn optimize(
+        script: &str,
+        options: OptimizationOptions,
+    ) -> DatexExpressionData {
+        let mut rich_ast = parse_datex_script_to_rich_ast_simple_error(
+            script,
+            &mut CompileOptions::default(),
+        )
+        .unwrap();
+        optimize_rich_ast(&mut rich_ast, options);
+        rich_ast.ast.data
+    }
+
+    fn statements(data: DatexExpressionData) -> Vec<DatexExpressionData> {
+        let DatexExpressionData::Statements(statements) = data else {
+            panic!("Expected statements");
+        };
+        statements.statements.into_iter().map(|s| s.data).collect()
+    }
+
+    #[test]
+    fn fold_arithmetic() {
+        assert_eq!(
+            optimize("1 + 2 - 4", OptimizationOptions::all()),
+            DatexExpressionData::Integer(Integer::from(-1))
+        );
+    }
+
+    #[test]
+    fn fold_text_concatenation() {
+        assert_eq!(
+            optimize(r#""Hello " + "World""#, OptimizationOptions::all()),
+            DatexExpressionData::Text("Hello World".to_string())
+        );
+    }
+
+    #[test]
+    fn fold_comparison() {
+        assert_eq!(
+            optimize("1 + 1 == 2", OptimizationOptions::all()),
+            DatexExpressionData::Boolean(true)
+        );
+    }
+
+    #[test]
+    fn partially_constant_operation() {
+        let data =
+            optimize("var x = 1; (1 + 2) + x", OptimizationOptions::all());
+        let DatexExpressionData::BinaryOperation(BinaryOperation {
+            left, ..
+        }) = statements(data).remove(1)
+        else {
+            panic!("Expected binary operation");
+        };
+        assert_eq!(left.data, DatexExpressionData::Integer(Integer::from(3)));
+    }
+
+    #[test]
+    fn unsupported_operations_are_not_folded() {
+        assert!(matches!(
+            optimize("2 * 3", OptimizationOptions::all()),
+            DatexExpressionData::BinaryOperation(_)
+        ));
+        assert!(matches!(
+            optimize("true == 1 + 2 * 3", OptimizationOptions::all()),
+            DatexExpressionData::ComparisonOperation(_)
+        ));
+    }
+
+    #[test]
+    fn eliminate_dead_branches() {
+        assert_eq!(
+            optimize("if (1 == 2) (1) else (2)", OptimizationOptions::all()),
+            DatexExpressionData::Integer(Integer::from(2))
+        );
+        assert_eq!(
+            optimize("if (false) (1)", OptimizationOptions::all()),
+            DatexExpressionData::Null
+        );
+    }
+
+    #[test]
+    fn remove_unused_declarations() {
+        let data = optimize(
+            "const a = 1; const b = a; var c = [1, 2]; const d = 4; d",
+            OptimizationOptions::all(),
+        );
+        assert_eq!(statements(data).len(), 2);
+    }
+
+    #[test]
+    fn keep_declarations_with_side_effects() {
+        let data = optimize(
+            "const a = 1; const b = a + 1; var c = 2; c = 3;",
+            OptimizationOptions::all(),
+        );
+        // a is used by b, b may fail at runtime, c is assigned
+        assert_eq!(statements(data).len(), 4);
+    }
+
+    #[test]
+    fn disabled_optimizations() {
+        assert!(matches!(
+            optimize("1 + 2", OptimizationOptions::default()),
+            DatexExpressionData::BinaryOperation(_)
+        ));
+        assert!(matches!(
+            optimize(
+                "if (true) (1)",
+                OptimizationOptions {
+                    fold_constants: true,
+                    ..Default::default()
+                }
+            ),
+            DatexExpressionData::Conditional(_)
+        ));
+    }
+}
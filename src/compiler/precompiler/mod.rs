@@ -8,14 +8,17 @@ pub mod precompiled_ast;
 pub mod scope;
 pub mod scope_stack;
 use crate::ast::expressions::{
-    BinaryOperation, DatexExpressionData, Statements, TypeDeclaration,
-    VariableAccess, VariableAssignment, VariableDeclaration, VariableKind,
+    BinaryOperation, CallableDeclaration, DatexExpressionData, Statements,
+    TypeDeclaration, VariableAccess, VariableAssignment, VariableDeclaration,
+    VariableKind,
 };
 use crate::ast::expressions::{
-    DatexExpression, RemoteExecution, TypeDeclarationKind, VariantAccess,
+    DatexExpression, Export, Import, RemoteExecution, TypeDeclarationKind,
+    VariantAccess,
 };
 use crate::ast::resolved_variable::ResolvedVariable;
 use crate::ast::type_expressions::{TypeExpressionData, TypeVariantAccess};
+use crate::compiler::module::ModuleRegistry;
 use crate::types::definition::TypeDefinition;
 use crate::visitor::type_expression::visitable::TypeExpressionVisitResult;
 use crate::{
@@ -48,6 +51,7 @@ pub struct Precompiler<'a> {
     scope_stack: &'a mut PrecompilerScopeStack,
     collected_errors: Option<DetailedCompilerErrors>,
     is_first_level_expression: bool,
+    modules: ModuleRegistry,
 }
 
 /// Precompile the AST by resolving variable references and collecting metadata.
@@ -63,6 +67,7 @@ pub fn precompile_ast_simple_error(
         ast_metadata,
         PrecompilerOptions {
             detailed_errors: false,
+            ..Default::default()
         },
     )
     .map_err(|e| {
@@ -88,6 +93,7 @@ pub fn precompile_ast_detailed_errors(
         ast_metadata,
         PrecompilerOptions {
             detailed_errors: true,
+            ..Default::default()
         },
     )
     .map_err(|e| {
@@ -120,6 +126,7 @@ impl<'a> Precompiler<'a> {
             scope_stack,
             collected_errors: None,
             is_first_level_expression: true,
+            modules: ModuleRegistry::default(),
         }
     }
 
@@ -167,6 +174,8 @@ impl<'a> Precompiler<'a> {
         if options.detailed_errors {
             self.collected_errors = Some(DetailedCompilerErrors::default());
         }
+        self.modules = options.modules;
+        self.ast_metadata.borrow_mut().restored_types = options.restored_types;
 
         // Hoist top-level type declaration if any
         if let DatexExpressionData::TypeDeclaration(type_declaration) =
@@ -226,6 +235,32 @@ impl<'a> Precompiler<'a> {
         new_id
     }
 
+    /// Registers a parameter of a callable in the current scope.
+    /// Parameters are constant inside of the body, their types are not tracked
+    /// in the body yet.
+    fn add_callable_parameter(&mut self, name: String) {
+        let id = self
+            .add_new_variable(name, VariableShape::Value(VariableKind::Const));
+        self.ast_metadata
+            .borrow_mut()
+            .variable_metadata_mut(id)
+            .expect("Parameter should have variable metadata")
+            .var_type = Some(Type::unknown());
+    }
+
+    /// Registers the generic type parameters of a declaration in the current scope,
+    /// so that they can be used in type expressions of the declaration
+    fn add_generic_parameters(&mut self, generic_parameters: &[String]) {
+        for name in generic_parameters {
+            let id = self.add_new_variable(name.clone(), VariableShape::Type);
+            self.ast_metadata
+                .borrow_mut()
+                .variable_metadata_mut(id)
+                .expect("Generic parameter should have variable metadata")
+                .var_type = Some(Type::parameter(name.clone()));
+        }
+    }
+
     /// Resolves a variable name to either a local variable ID if it was already declared (or hoisted),
     /// or to a core library pointer ID if it is a core variable.
     /// If the variable cannot be resolved, a CompilerError is returned.
@@ -250,7 +285,9 @@ impl<'a> Precompiler<'a> {
         expr: &DatexExpression,
     ) -> NewScopeType {
         match &expr.data {
-            DatexExpressionData::RemoteExecution(_) => NewScopeType::None,
+            // exported declarations are declared in the surrounding scope
+            DatexExpressionData::RemoteExecution(_)
+            | DatexExpressionData::Export(_) => NewScopeType::None,
             _ => NewScopeType::NewScope,
         }
     }
@@ -266,7 +303,7 @@ impl<'a> Precompiler<'a> {
             self.add_new_variable(data.name.clone(), VariableShape::Type);
 
         let reference = match data.kind {
-            TypeDeclarationKind::Nominal => {
+            TypeDeclarationKind::Nominal | TypeDeclarationKind::Interface => {
                 Rc::new(RefCell::new(TypeReference::nominal(
                     Type::UNIT,
                     NominalTypeDeclaration::from(data.name.clone()),
@@ -277,6 +314,8 @@ impl<'a> Precompiler<'a> {
                 TypeReference::anonymous(Type::UNIT, None),
             )),
         };
+        reference.borrow_mut().generic_parameters =
+            data.generic_parameters.clone();
 
         // register placeholder ref in metadata
         let type_def = Type::new(TypeDefinition::reference(reference), None);
@@ -407,9 +446,15 @@ impl<'a> ExpressionVisitor<SpannedCompilerError> for Precompiler<'a> {
         _: &Range<usize>,
     ) -> ExpressionVisitResult<SpannedCompilerError> {
         let mut registered_names = HashSet::new();
-        for statements in statements.statements.iter_mut() {
+        for statement in statements.statements.iter_mut() {
+            let declaration = match &mut statement.data {
+                DatexExpressionData::Export(Export { declaration }) => {
+                    &mut declaration.data
+                }
+                data => data,
+            };
             if let DatexExpressionData::TypeDeclaration(type_declaration) =
-                &mut statements.data
+                declaration
             {
                 let name = &type_declaration.name;
                 if registered_names.contains(name) {
@@ -425,6 +470,63 @@ impl<'a> ExpressionVisitor<SpannedCompilerError> for Precompiler<'a> {
         Ok(VisitAction::VisitChildren)
     }
 
+    fn visit_import(
+        &mut self,
+        import: &mut Import,
+        span: &Range<usize>,
+    ) -> ExpressionVisitResult<SpannedCompilerError> {
+        if !self.modules.contains(&import.source) {
+            self.collect_error(SpannedCompilerError::new_with_span(
+                CompilerError::UnresolvedModule(import.source.clone()),
+                span.clone(),
+            ))?;
+            return Ok(VisitAction::SkipChildren);
+        }
+
+        for item in import.items.iter_mut() {
+            let Some(export) =
+                self.modules.get_export(&import.source, &item.name)
+            else {
+                self.collect_error(SpannedCompilerError::new_with_span(
+                    CompilerError::ImportNotExported(
+                        item.name.clone(),
+                        import.source.clone(),
+                    ),
+                    item.span.clone(),
+                ))?;
+                continue;
+            };
+            if self
+                .scope_stack
+                .get_active_scope()
+                .variable_ids_by_name
+                .contains_key(&item.name)
+            {
+                self.collect_error(SpannedCompilerError::new_with_span(
+                    CompilerError::InvalidRedeclaration(item.name.clone()),
+                    item.span.clone(),
+                ))?;
+                continue;
+            }
+
+            // imported values are always constant
+            let shape = match export.shape {
+                VariableShape::Type => VariableShape::Type,
+                VariableShape::Value(_) => {
+                    VariableShape::Value(VariableKind::Const)
+                }
+            };
+            let id = self.add_new_variable(item.name.clone(), shape);
+            self.ast_metadata
+                .borrow_mut()
+                .variable_metadata_mut(id)
+                .expect("Imported variable should have metadata")
+                .var_type = export.ty;
+            item.id = Some(id);
+        }
+        Ok(VisitAction::SkipChildren)
+    }
+
     fn visit_type_declaration(
         &mut self,
         type_declaration: &mut TypeDeclaration,
@@ -442,7 +544,61 @@ impl<'a> ExpressionVisitor<SpannedCompilerError> for Precompiler<'a> {
             type_declaration.id =
                 Some(self.add_new_variable(name, VariableShape::Type));
         }
-        Ok(VisitAction::VisitChildren)
+
+        if type_declaration.generic_parameters.is_empty() {
+            return Ok(VisitAction::VisitChildren);
+        }
+
+        // generic parameters are only visible inside the type definition
+        self.scope_stack.push_scope();
+        self.add_generic_parameters(&type_declaration.generic_parameters);
+        self.visit_type_expression(&mut type_declaration.definition)?;
+        self.scope_stack.pop_scope();
+        Ok(VisitAction::SkipChildren)
+    }
+
+    fn visit_callable_declaration(
+        &mut self,
+        callable_declaration: &mut CallableDeclaration,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<SpannedCompilerError> {
+        self.scope_stack.push_scope();
+        self.add_generic_parameters(&callable_declaration.generic_parameters);
+
+        for (_, parameter_type) in callable_declaration.parameters.iter_mut() {
+            self.visit_type_expression(parameter_type)?;
+        }
+        if let Some((_, rest_parameter_type)) =
+            &mut callable_declaration.rest_parameter
+        {
+            self.visit_type_expression(rest_parameter_type)?;
+        }
+        if let Some(return_type) = &mut callable_declaration.return_type {
+            self.visit_type_expression(return_type)?;
+        }
+        if let Some(yeet_type) = &mut callable_declaration.yeet_type {
+            self.visit_type_expression(yeet_type)?;
+        }
+
+        let parameter_names = callable_declaration
+            .parameters
+            .iter()
+            .map(|(name, _)| name)
+            .chain(
+                callable_declaration
+                    .rest_parameter
+                    .iter()
+                    .map(|(name, _)| name),
+            )
+            .cloned()
+            .collect::<Vec<_>>();
+        for name in parameter_names {
+            self.add_callable_parameter(name);
+        }
+
+        self.visit_datex_expression(&mut callable_declaration.body)?;
+        self.scope_stack.pop_scope();
+        Ok(VisitAction::SkipChildren)
     }
 
     fn visit_binary_operation(
@@ -646,6 +802,7 @@ mod tests {
     fn undeclared_variable_error() {
         let options = PrecompilerOptions {
             detailed_errors: true,
+            ..Default::default()
         };
         let ast = Parser::parse_with_default_options("x + 10").unwrap();
         let result = precompile(ast, options);
@@ -657,6 +814,7 @@ mod tests {
     fn duplicate_variable_error() {
         let options = PrecompilerOptions {
             detailed_errors: false,
+            ..Default::default()
         };
         let ast = Parser::parse_with_default_options("var x = 1; var x = 2;")
             .unwrap();
@@ -708,6 +866,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn generic_parameters_are_scoped_to_declaration() {
+        let result = parse_and_precompile(
+            "type Box<T> = { value: T }; const b: Box<integer> = { value: 1 }",
+        );
+        assert!(result.is_ok());
+
+        let result =
+            parse_and_precompile("type Box<T> = { value: T }; const x: T = 1");
+        assert_matches!(
+            result,
+            Err(CompilerError::UndeclaredVariable(name)) if name == "T"
+        );
+    }
+
+    #[test]
+    fn callable_parameters_are_scoped_to_body() {
+        let result = parse_and_precompile(
+            "const first = function first<T>(list: [T]) -> T (list.(0)); first([1])",
+        )
+        .expect("Should precompile without errors");
+        let metadata = result.metadata.borrow();
+        let list = metadata
+            .variables
+            .iter()
+            .find(|variable| variable.name == "list")
+            .expect("Parameter should be declared");
+        assert_eq!(list.shape, VariableShape::Value(VariableKind::Const));
+
+        let result =
+            parse_and_precompile("const f = function f(x: integer) (x); x");
+        assert_matches!(
+            result,
+            Err(CompilerError::UndeclaredVariable(name)) if name == "x"
+        );
+    }
+
     #[test]
     fn nominal_type_declaration() {
         let result = parse_and_precompile("type User = {a: integer}; User");
@@ -720,6 +915,7 @@ mod tests {
                     DatexExpressionData::TypeDeclaration(TypeDeclaration {
                         id: Some(0),
                         name: "User".to_string(),
+                        generic_parameters: vec![],
                         definition: TypeExpressionData::StructuralMap(
                             StructuralMap(vec![(
                                 TypeExpressionData::Text("a".to_string())
@@ -851,6 +1047,7 @@ mod tests {
                     DatexExpressionData::TypeDeclaration(TypeDeclaration {
                         id: Some(0),
                         name: "User".to_string(),
+                        generic_parameters: vec![],
                         definition: TypeExpressionData::StructuralMap(
                             StructuralMap(vec![])
                         )
@@ -862,6 +1059,7 @@ mod tests {
                     DatexExpressionData::TypeDeclaration(TypeDeclaration {
                         id: Some(1),
                         name: "User/admin".to_string(),
+                        generic_parameters: vec![],
                         definition: TypeExpressionData::StructuralMap(
                             StructuralMap(vec![])
                         )
@@ -963,6 +1161,7 @@ mod tests {
                 DatexExpressionData::TypeDeclaration(TypeDeclaration {
                     id: Some(0),
                     name: "MyInt".to_string(),
+                    generic_parameters: vec![],
                     definition: TypeExpressionData::Integer(Integer::from(1))
                         .with_default_span(),
                     hoisted: true,
@@ -1015,6 +1214,7 @@ mod tests {
                 DatexExpressionData::TypeDeclaration(TypeDeclaration {
                     id: Some(0),
                     name: "MyInt".to_string(),
+                    generic_parameters: vec![],
                     definition: TypeExpressionData::Integer(Integer::from(1))
                         .with_default_span(),
                     hoisted: true,
@@ -1037,6 +1237,7 @@ mod tests {
                 DatexExpressionData::TypeDeclaration(TypeDeclaration {
                     id: Some(0),
                     name: "x".to_string(),
+                    generic_parameters: vec![],
                     definition: TypeExpressionData::VariableAccess(
                         VariableAccess {
                             id: 1,
@@ -1051,6 +1252,7 @@ mod tests {
                 DatexExpressionData::TypeDeclaration(TypeDeclaration {
                     id: Some(1),
                     name: "MyInt".to_string(),
+                    generic_parameters: vec![],
                     definition: TypeExpressionData::VariableAccess(
                         VariableAccess {
                             id: 0,
@@ -1088,6 +1290,7 @@ mod tests {
                     DatexExpressionData::TypeDeclaration(TypeDeclaration {
                         id: Some(0),
                         name: "x".to_string(),
+                        generic_parameters: vec![],
                         definition: TypeExpressionData::Integer(
                             Integer::from(10).into()
                         )
@@ -1104,6 +1307,7 @@ mod tests {
                                 TypeDeclaration {
                                     id: Some(1),
                                     name: "NestedVar".to_string(),
+                                    generic_parameters: vec![],
                                     definition:
                                         TypeExpressionData::VariableAccess(
                                             VariableAccess {
@@ -1136,6 +1340,7 @@ mod tests {
             DatexExpressionData::TypeDeclaration(TypeDeclaration {
                 id: Some(0),
                 name: "x".to_string(),
+                generic_parameters: vec![],
                 definition: TypeExpressionData::GetReference(
                     PointerAddress::from(CoreLibPointerId::Integer(None))
                 )
@@ -1,7 +1,16 @@
+use crate::collections::HashMap;
+use crate::compiler::module::ModuleRegistry;
+use crate::values::core_values::r#type::Type;
+use core::ops::Range;
+
 #[derive(Debug, Clone, Default)]
 pub struct PrecompilerOptions {
     /// If enabled, all collected errors as well as the RichAst
     /// are returned if one or multiple errors occurred.
     /// Otherwise, only the first error is returned (fast failing)
     pub detailed_errors: bool,
+    /// Modules that can be imported
+    pub modules: ModuleRegistry,
+    /// Types of data literals restored from a previous build by their span
+    pub restored_types: HashMap<Range<usize>, Type>,
 }
@@ -1,7 +1,9 @@
 use crate::ast::expressions::{DatexExpression, VariableKind};
+use crate::collections::HashMap;
 use crate::stdlib::{cell::RefCell, rc::Rc};
 use crate::values::core_values::r#type::Type;
 use core::fmt::Display;
+use core::ops::Range;
 
 #[derive(Clone, Debug)]
 pub struct VariableMetadata {
@@ -39,6 +41,9 @@ pub struct AstMetadata {
     /// Indicates whether the AST is terminated (; at the end)
     /// A terminated script can never return a value
     pub is_terminated: bool,
+    /// Types of data literal maps and lists restored from a previous build
+    /// by their span, which are not inferred again
+    pub restored_types: HashMap<Range<usize>, Type>,
 }
 
 impl AstMetadata {
@@ -18,6 +18,9 @@ pub struct PrecompilerData {
 pub struct CompilationScope {
     /// List of variables, mapped by name to their slot address and type.
     variables: HashMap<String, Variable>,
+    /// Imported variables of modules that are shared by all imports of a module.
+    /// Their slots are not dropped when the scope is popped.
+    imported_variables: HashMap<String, Variable>,
     /// parent scope, accessible from a child scope
     parent_scope: Option<Box<CompilationScope>>,
     /// scope of a parent context, e.g. when inside a block scope for remote execution calls or function bodies
@@ -38,6 +41,7 @@ impl Default for CompilationScope {
     fn default() -> Self {
         CompilationScope {
             variables: HashMap::new(),
+            imported_variables: HashMap::new(),
             parent_scope: None,
             external_parent_scope: None,
             next_slot_address: 0,
@@ -84,10 +88,35 @@ impl CompilationScope {
         self.external_parent_scope.is_some()
     }
 
+    /// Returns true if the scope or one of its parent scopes has an external parent scope,
+    /// e.g. inside a function body or a remote execution block
+    pub fn is_inside_external_scope(&self) -> bool {
+        self.has_external_parent_scope()
+            || self
+                .parent_scope
+                .as_ref()
+                .is_some_and(|parent| parent.is_inside_external_scope())
+    }
+
     pub fn register_variable_slot(&mut self, variable: Variable) {
+        self.imported_variables.remove(&variable.name);
         self.variables.insert(variable.name.clone(), variable);
     }
 
+    /// Registers a variable whose slots are owned by an imported module.
+    /// The slots are not dropped when this scope is popped.
+    pub fn register_imported_variable(&mut self, variable: Variable) {
+        self.variables.remove(&variable.name);
+        self.imported_variables
+            .insert(variable.name.clone(), variable);
+    }
+
+    /// Removes a variable from this scope, returning it if it was declared in this scope.
+    /// The slots of a removed variable are not dropped when the scope is popped.
+    pub fn take_variable(&mut self, name: &str) -> Option<Variable> {
+        self.variables.remove(name)
+    }
+
     pub fn get_next_virtual_slot(&mut self) -> u32 {
         let slot_address = self.next_slot_address;
         self.next_slot_address += 1;
@@ -101,7 +130,11 @@ impl CompilationScope {
         &self,
         name: &str,
     ) -> Option<(VirtualSlot, VariableKind)> {
-        if let Some(variable) = self.variables.get(name) {
+        if let Some(variable) = self
+            .variables
+            .get(name)
+            .or_else(|| self.imported_variables.get(name))
+        {
             let slot = match variable.representation {
                 VariableRepresentation::Constant(slot) => slot,
                 VariableRepresentation::VariableReference {
@@ -129,6 +162,7 @@ impl CompilationScope {
             parent_scope: Some(Box::new(self)),
             external_parent_scope: None,
             variables: HashMap::new(),
+            imported_variables: HashMap::new(),
             precompiler_data: None,
             execution_mode: ExecutionMode::Static,
             was_used: false,
@@ -1,14 +1,23 @@
 use url::Url;
 
-use crate::collections::HashMap;
+use crate::collections::{HashMap, HashSet};
+use crate::compiler::error::CompilerError;
 use crate::compiler::error::DetailedCompilerErrors;
 use crate::compiler::error::DetailedCompilerErrorsWithMaybeRichAst;
-use crate::compiler::precompiler::precompiled_ast::RichAst;
-use crate::compiler::{
-    CompileOptions, parse_datex_script_to_rich_ast_detailed_errors,
+use crate::compiler::incremental::{
+    CachedStatement, cache_statement_types, parse_with_cached_statements,
 };
+use crate::compiler::module::{
+    ModuleRegistry, module_exports, module_imports_mut,
+};
+use crate::compiler::precompiler::precompiled_ast::{RichAst, VariableShape};
+use crate::compiler::{CompileOptions, precompile_to_rich_ast_detailed_errors};
+use crate::parser::Parser;
 use crate::runtime::Runtime;
+use crate::time::Instant;
 use crate::values::core_values::r#type::Type;
+use core::mem;
+use core::time::Duration;
 
 /// Represents a file in the compiler workspace with its URL, cached content and AST.
 pub struct WorkspaceFile {
@@ -17,12 +26,76 @@ pub struct WorkspaceFile {
     pub rich_ast: Option<RichAst>,
     pub return_type: Option<Type>,
     pub errors: Option<DetailedCompilerErrors>,
+    /// resolved URLs of all modules imported by this file
+    pub imports: Vec<Url>,
+    /// top-level statements of the last build, reused by the next build
+    statements: Vec<CachedStatement>,
+}
+
+impl WorkspaceFile {
+    /// Returns the parts of the module that are visible to importing modules.
+    fn interface(&self) -> ModuleInterface {
+        ModuleInterface {
+            imports: self.imports.clone(),
+            exports: self
+                .rich_ast
+                .as_ref()
+                .map(|rich_ast| {
+                    module_exports(rich_ast)
+                        .into_iter()
+                        .map(|export| (export.name, export.shape, export.ty))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The imports and exported declarations of a module.
+/// Importing modules only need to be rebuilt if the interface changes.
+#[derive(PartialEq)]
+struct ModuleInterface {
+    imports: Vec<Url>,
+    exports: Vec<(String, VariableShape, Option<Type>)>,
+}
+
+/// Timing and reuse statistics of rebuilding a single file.
+#[derive(Debug, Clone)]
+pub struct FileRebuildMetrics {
+    pub url: Url,
+    pub parse_duration: Duration,
+    /// duration of the precompilation including type inference
+    pub precompile_duration: Duration,
+    /// number of top-level statements reused from the previous build
+    pub reused_statements: usize,
+    /// number of top-level statements that were parsed again
+    pub parsed_statements: usize,
+    /// number of data literals whose inferred type was reused
+    pub reused_types: usize,
+}
+
+/// Timing statistics of the last workspace rebuild caused by loading a file.
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceRebuildMetrics {
+    /// The rebuilt files, starting with the loaded file followed by all rebuilt dependents.
+    /// Empty if the content of the loaded file did not change.
+    pub files: Vec<FileRebuildMetrics>,
+    pub total_duration: Duration,
+}
+
+/// Result of building a single file
+struct FileBuild {
+    imports: Vec<Url>,
+    result: Result<RichAst, DetailedCompilerErrorsWithMaybeRichAst>,
+    statements: Vec<CachedStatement>,
+    metrics: FileRebuildMetrics,
 }
 
 /// Represents the compiler workspace containing multiple files.
 pub struct CompilerWorkspace {
     files: HashMap<Url, WorkspaceFile>,
     runtime: Runtime,
+    last_rebuild_metrics: WorkspaceRebuildMetrics,
 }
 
 impl CompilerWorkspace {
@@ -31,6 +104,7 @@ impl CompilerWorkspace {
         Self {
             files: HashMap::new(),
             runtime,
+            last_rebuild_metrics: WorkspaceRebuildMetrics::default(),
         }
     }
 
@@ -38,27 +112,33 @@ impl CompilerWorkspace {
         &self.files
     }
 
+    pub fn runtime(&self) -> &Runtime {
+        &self.runtime
+    }
+
+    /// Returns the timing metrics of the last rebuild caused by [Self::load_file].
+    pub fn last_rebuild_metrics(&self) -> &WorkspaceRebuildMetrics {
+        &self.last_rebuild_metrics
+    }
+
     /// Loads a file into the workspace, caching its content and AST.
-    /// Returns a compiler error if parsing or precompilation fails.
+    /// Imports are resolved relative to the file URL against the files in the workspace.
+    /// If the file was already loaded, only the changed top-level statements are parsed
+    /// again, and nothing is rebuilt if the content did not change.
+    /// All loaded files that import the file are precompiled again if the imports or
+    /// exports of the file changed.
     pub fn load_file(&mut self, url: Url, content: String) -> &WorkspaceFile {
-        let result = self.get_rich_ast_for_file(&url, content.clone());
-        let workspace_file = match result {
-            Ok(rich_ast) => WorkspaceFile {
-                url: url.clone(),
-                content,
-                rich_ast: Some(rich_ast),
-                return_type: None,
-                errors: None,
-            },
-            Err(error) => WorkspaceFile {
-                url: url.clone(),
-                content,
-                rich_ast: error.ast,
-                return_type: None,
-                errors: Some(error.errors),
-            },
-        };
-        self.files.insert(url.clone(), workspace_file);
+        let start = Instant::now();
+        self.last_rebuild_metrics = WorkspaceRebuildMetrics::default();
+        if self
+            .files
+            .get(&url)
+            .is_none_or(|file| file.content != content)
+        {
+            let mut visited = HashSet::new();
+            self.load_file_and_dependents(url.clone(), content, &mut visited);
+        }
+        self.last_rebuild_metrics.total_duration = start.elapsed();
         self.files.get(&url).unwrap()
     }
 
@@ -71,18 +151,378 @@ impl CompilerWorkspace {
         self.files.get_mut(url)
     }
 
-    /// Retrieves the AST with metadata for a given file path and content after parsing and compilation.
+    fn load_file_and_dependents(
+        &mut self,
+        url: Url,
+        content: String,
+        visited: &mut HashSet<Url>,
+    ) {
+        visited.insert(url.clone());
+        let (previous_statements, previous_interface) = self
+            .files
+            .get_mut(&url)
+            .map(|file| {
+                (mem::take(&mut file.statements), Some(file.interface()))
+            })
+            .unwrap_or_default();
+        let build =
+            self.get_rich_ast_for_file(&url, &content, &previous_statements);
+        let (rich_ast, errors) = match build.result {
+            Ok(rich_ast) => (Some(rich_ast), None),
+            Err(error) => (error.ast, Some(error.errors)),
+        };
+        let workspace_file = WorkspaceFile {
+            url: url.clone(),
+            content,
+            rich_ast,
+            return_type: None,
+            errors,
+            imports: build.imports,
+            statements: build.statements,
+        };
+        let interface_changed =
+            previous_interface.as_ref() != Some(&workspace_file.interface());
+        self.files.insert(url.clone(), workspace_file);
+        self.last_rebuild_metrics.files.push(build.metrics);
+        if !interface_changed {
+            return;
+        }
+
+        // update all files that import this file
+        let dependents = self
+            .files
+            .values()
+            .filter(|file| {
+                file.imports.contains(&url) && !visited.contains(&file.url)
+            })
+            .map(|file| (file.url.clone(), file.content.clone()))
+            .collect::<Vec<_>>();
+        for (dependent_url, dependent_content) in dependents {
+            if !visited.contains(&dependent_url) {
+                self.load_file_and_dependents(
+                    dependent_url,
+                    dependent_content,
+                    visited,
+                );
+            }
+        }
+    }
+
+    /// Returns the import chain from the given import back to the importing file
+    /// if the import would create a cycle.
+    fn find_import_cycle(&self, url: &Url, import: &Url) -> Option<Vec<Url>> {
+        let mut stack = vec![vec![url.clone(), import.clone()]];
+        let mut visited = HashSet::new();
+        while let Some(path) = stack.pop() {
+            let current = path.last().unwrap();
+            if current == url {
+                return Some(path);
+            }
+            if !visited.insert(current.clone()) {
+                continue;
+            }
+            if let Some(file) = self.files.get(current) {
+                for next in &file.imports {
+                    let mut next_path = path.clone();
+                    next_path.push(next.clone());
+                    stack.push(next_path);
+                }
+            }
+        }
+        None
+    }
+
+    /// Registers the module with the given URL and all modules it transitively imports.
+    fn register_module(&self, url: &Url, modules: &mut ModuleRegistry) {
+        if modules.contains(url.as_str()) {
+            return;
+        }
+        if let Some(file) = self.files.get(url)
+            && let Some(rich_ast) = &file.rich_ast
+        {
+            modules.register(url.to_string(), rich_ast.clone());
+            for import in &file.imports {
+                self.register_module(import, modules);
+            }
+        }
+    }
+
+    /// Retrieves the AST with metadata for a given file path and content after parsing and compilation,
+    /// together with the resolved URLs of all imported modules.
+    /// Unchanged top-level statements of the previous build are reused.
     /// Returns a compiler error if parsing or compilation fails.
     fn get_rich_ast_for_file(
         &self,
         url: &Url,
-        content: String,
-    ) -> Result<RichAst, DetailedCompilerErrorsWithMaybeRichAst> {
+        content: &str,
+        previous_statements: &[CachedStatement],
+    ) -> FileBuild {
+        let mut metrics = FileRebuildMetrics {
+            url: url.clone(),
+            parse_duration: Duration::ZERO,
+            precompile_duration: Duration::ZERO,
+            reused_statements: 0,
+            parsed_statements: 0,
+            reused_types: 0,
+        };
+        let parse_start = Instant::now();
+        let mut incremental =
+            parse_with_cached_statements(content, previous_statements);
         let mut options = CompileOptions::default();
-        let rich_ast = parse_datex_script_to_rich_ast_detailed_errors(
-            &content,
+        let (mut ast, parser_errors) = match &mut incremental {
+            Some(result) => {
+                metrics.reused_statements = result.reused_statements;
+                metrics.parsed_statements = result.parsed_statements;
+                metrics.reused_types = result.reused_types;
+                options.restored_types = mem::take(&mut result.restored_types);
+                (mem::take(&mut result.ast), mem::take(&mut result.errors))
+            }
+            None => Parser::parse_collecting_with_default_options(content)
+                .into_ast_and_errors(),
+        };
+        metrics.parse_duration = parse_start.elapsed();
+
+        let mut imports = vec![];
+        let mut cycles: HashMap<String, Vec<String>> = HashMap::new();
+
+        // resolve import sources relative to the file URL
+        for import in module_imports_mut(&mut ast) {
+            let Ok(import_url) = url.join(&import.source) else {
+                continue;
+            };
+            if let Some(cycle) = self.find_import_cycle(url, &import_url) {
+                cycles.insert(
+                    import_url.to_string(),
+                    cycle.iter().map(|url| url.to_string()).collect(),
+                );
+            } else {
+                self.register_module(&import_url, &mut options.modules);
+            }
+            import.source = import_url.to_string();
+            imports.push(import_url);
+        }
+
+        let precompile_start = Instant::now();
+        let mut result = precompile_to_rich_ast_detailed_errors(
+            ast,
+            parser_errors,
             &mut options,
-        )?;
-        Ok(rich_ast)
+        );
+        metrics.precompile_duration = precompile_start.elapsed();
+        // modules in an import cycle are not registered and reported as unresolved by the precompiler
+        if let Err(error) = &mut result {
+            for error in error.errors.errors.iter_mut() {
+                if let CompilerError::UnresolvedModule(source) = &error.error
+                    && let Some(cycle) = cycles.get(source)
+                {
+                    error.error = CompilerError::CyclicImport(cycle.clone());
+                }
+            }
+        }
+
+        let statements = match incremental {
+            Some(mut incremental) => {
+                let rich_ast = match &mut result {
+                    Ok(rich_ast) => Some(rich_ast),
+                    Err(error) => error.ast.as_mut(),
+                };
+                if let Some(rich_ast) = rich_ast {
+                    cache_statement_types(
+                        &mut incremental.statements,
+                        &incremental.statement_spans,
+                        incremental.is_terminated,
+                        &mut rich_ast.ast,
+                    );
+                }
+                incremental.statements.into_iter().flatten().collect()
+            }
+            None => vec![],
+        };
+        FileBuild {
+            imports,
+            result,
+            statements,
+            metrics,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::{AsyncContext, RuntimeConfig};
+    use crate::values::core_values::endpoint::Endpoint;
+    use core::str::FromStr;
+
+    fn workspace() -> CompilerWorkspace {
+        CompilerWorkspace::new(Runtime::new(
+            RuntimeConfig::new_with_endpoint(
+                Endpoint::from_str("@workspace").unwrap(),
+            ),
+            AsyncContext::new(),
+        ))
+    }
+
+    fn url(path: &str) -> Url {
+        Url::parse(&format!("file:///project/{path}")).unwrap()
+    }
+
+    fn errors(file: &WorkspaceFile) -> Vec<CompilerError> {
+        file.errors
+            .as_ref()
+            .map(|errors| {
+                errors.errors.iter().map(|e| e.error.clone()).collect()
+            })
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn import_from_loaded_file() {
+        let mut workspace = workspace();
+        workspace.load_file(
+            url("lib/math.dx"),
+            "export const pi = 3.14; const secret = 42;".to_string(),
+        );
+        let file = workspace.load_file(
+            url("main.dx"),
+            r#"import { pi } from "./lib/math.dx"; pi"#.to_string(),
+        );
+        assert!(file.errors.is_none(), "{:?}", errors(file));
+        assert_eq!(file.imports, vec![url("lib/math.dx")]);
+    }
+
+    #[test]
+    fn import_not_exported() {
+        let mut workspace = workspace();
+        workspace.load_file(
+            url("lib.dx"),
+            "export const a = 1; const b = 2;".to_string(),
+        );
+        let file = workspace.load_file(
+            url("main.dx"),
+            r#"import { b } from "./lib.dx"; b"#.to_string(),
+        );
+        assert!(errors(file).iter().any(|error| matches!(
+            error,
+            CompilerError::ImportNotExported(name, _) if name == "b"
+        )));
+    }
+
+    #[test]
+    fn dependents_are_updated() {
+        let mut workspace = workspace();
+        let file = workspace.load_file(
+            url("main.dx"),
+            r#"import { a } from "./lib.dx"; a"#.to_string(),
+        );
+        assert!(
+            errors(file).iter().any(|error| matches!(
+                error,
+                CompilerError::UnresolvedModule(_)
+            ))
+        );
+
+        workspace.load_file(url("lib.dx"), "export const a = 1;".to_string());
+        let file = workspace.get_file(&url("main.dx")).unwrap();
+        assert!(file.errors.is_none(), "{:?}", errors(file));
+    }
+
+    #[test]
+    fn cyclic_import() {
+        let mut workspace = workspace();
+        workspace.load_file(
+            url("a.dx"),
+            r#"import { b } from "./b.dx"; export const a = 1;"#.to_string(),
+        );
+        workspace.load_file(
+            url("b.dx"),
+            r#"import { a } from "./a.dx"; export const b = 2;"#.to_string(),
+        );
+        for path in ["a.dx", "b.dx"] {
+            let file = workspace.get_file(&url(path)).unwrap();
+            assert!(
+                errors(file).iter().any(|error| matches!(
+                    error,
+                    CompilerError::CyclicImport(_)
+                )),
+                "{path}: {:?}",
+                errors(file)
+            );
+        }
+    }
+
+    fn rebuilt_files(workspace: &CompilerWorkspace) -> Vec<Url> {
+        workspace
+            .last_rebuild_metrics()
+            .files
+            .iter()
+            .map(|metrics| metrics.url.clone())
+            .collect()
+    }
+
+    #[test]
+    fn unchanged_statements_are_reused() {
+        let mut workspace = workspace();
+        let config =
+            r#"const config = {name: "app", ports: [80, 443], debug: false};"#;
+        let file = workspace.load_file(
+            url("config.dx"),
+            format!("{config}\nconst x = 1;\nconfig"),
+        );
+        assert!(file.errors.is_none(), "{:?}", errors(file));
+        let initial_type = file.rich_ast.as_ref().unwrap().ast.ty.clone();
+        let metrics = &workspace.last_rebuild_metrics().files[0];
+        assert_eq!(metrics.parsed_statements, 3);
+        assert_eq!(metrics.reused_statements, 0);
+
+        let file = workspace.load_file(
+            url("config.dx"),
+            format!("const y = 2;\n{config}\nconst x = 1;\nconfig"),
+        );
+        assert!(file.errors.is_none(), "{:?}", errors(file));
+        assert_eq!(file.rich_ast.as_ref().unwrap().ast.ty, initial_type);
+        let metrics = &workspace.last_rebuild_metrics().files[0];
+        assert_eq!(metrics.parsed_statements, 1);
+        assert_eq!(metrics.reused_statements, 3);
+        assert_eq!(metrics.reused_types, 1);
+    }
+
+    #[test]
+    fn unchanged_content_is_not_rebuilt() {
+        let mut workspace = workspace();
+        workspace.load_file(url("main.dx"), "const a = 1; a".to_string());
+        assert_eq!(rebuilt_files(&workspace), vec![url("main.dx")]);
+        workspace.load_file(url("main.dx"), "const a = 1; a".to_string());
+        assert!(rebuilt_files(&workspace).is_empty());
+    }
+
+    #[test]
+    fn dependents_are_only_updated_if_exports_change() {
+        let mut workspace = workspace();
+        workspace.load_file(
+            url("lib.dx"),
+            "export const a = 1; const b = 2;".to_string(),
+        );
+        workspace.load_file(
+            url("main.dx"),
+            r#"import { a } from "./lib.dx"; a"#.to_string(),
+        );
+
+        // private declaration changed
+        workspace.load_file(
+            url("lib.dx"),
+            "export const a = 1; const b = 3;".to_string(),
+        );
+        assert_eq!(rebuilt_files(&workspace), vec![url("lib.dx")]);
+
+        // exported declaration changed
+        workspace.load_file(
+            url("lib.dx"),
+            "export const a = \"x\"; const b = 3;".to_string(),
+        );
+        assert_eq!(
+            rebuilt_files(&workspace),
+            vec![url("lib.dx"), url("main.dx")]
+        );
     }
 }
@@ -1,10 +1,16 @@
-use crate::core_compiler::value_compiler::append_get_ref;
+use crate::core_compiler::value_compiler::{append_get_ref, append_value};
 use crate::global::type_instruction_codes::TypeInstructionCode;
 use crate::global::type_instruction_codes::TypeMutabilityCode;
+use crate::stdlib::string::String;
 use crate::stdlib::vec::Vec;
+use crate::types::collection_type_definition::CollectionTypeDefinition;
 use crate::types::definition::TypeDefinition;
-use crate::utils::buffers::append_u8;
+use crate::types::structural_type_definition::StructuralTypeDefinition;
+use crate::utils::buffers::{append_string_utf8, append_u8, append_u32};
+use crate::values::core_value::CoreValue;
+use crate::values::core_values::callable::{CallableKind, CallableSignature};
 use crate::values::core_values::r#type::Type;
+use crate::values::value::Value;
 
 /// Compiles a given type container to a DXB body
 pub fn compile_type(ty: &Type) -> Vec<u8> {
@@ -15,6 +21,16 @@ pub fn compile_type(ty: &Type) -> Vec<u8> {
 }
 
 pub fn append_type(buffer: &mut Vec<u8>, ty: &Type) {
+    // anonymous local type references (e.g. `type A = integer`) are inlined
+    if let TypeDefinition::Reference(type_ref) = &ty.type_definition {
+        let type_ref = type_ref.borrow();
+        if type_ref.pointer_address.is_none()
+            && type_ref.nominal_type_declaration.is_none()
+        {
+            return append_type(buffer, &type_ref.type_value);
+        }
+    }
+
     // append instruction code
     let instruction_code = TypeInstructionCode::from(&ty.type_definition);
     append_type_space_instruction_code(buffer, instruction_code);
@@ -37,25 +53,188 @@ fn append_type_definition(
             let impl_count = impls.len() as u8;
             append_u8(buffer, impl_count);
 
-            // Append each impl address
-            for impl_type in impls {
-                append_get_ref(buffer, impl_type);
+            // Append each impl marker as a type reference
+            for marker in impls {
+                append_type(
+                    buffer,
+                    &Type::new(TypeDefinition::Reference(marker.clone()), None),
+                );
             }
 
             // Append the base type
             append_type(buffer, ty);
         }
         TypeDefinition::Reference(type_ref) => {
-            // TODO #636: ensure pointer_address exists here
             let type_ref = type_ref.borrow();
-            let pointer_address = type_ref
-                .pointer_address
-                .as_ref()
-                .expect("Type reference must have a pointer address");
-            append_get_ref(buffer, pointer_address);
+            match (
+                &type_ref.pointer_address,
+                &type_ref.nominal_type_declaration,
+            ) {
+                (Some(pointer_address), _) => {
+                    append_get_ref(buffer, pointer_address);
+                }
+                // local nominal type: name and generic parameters followed by
+                // the inner definition
+                (None, Some(declaration)) => {
+                    append_short_text(buffer, &declaration.to_string());
+                    append_u8(buffer, type_ref.generic_parameters.len() as u8);
+                    for parameter in &type_ref.generic_parameters {
+                        append_short_text(buffer, parameter);
+                    }
+                    append_type(buffer, &type_ref.type_value);
+                }
+                // anonymous references are inlined in append_type
+                (None, None) => unreachable!(),
+            }
+        }
+        TypeDefinition::Structural(structural) => {
+            append_structural_type_definition(buffer, structural);
+        }
+        TypeDefinition::Union(types) | TypeDefinition::Intersection(types) => {
+            append_u32(buffer, types.len() as u32);
+            for ty in types {
+                append_type(buffer, ty);
+            }
+        }
+        TypeDefinition::Parameter(name) => {
+            append_short_text(buffer, name);
+        }
+        TypeDefinition::Type(ty) => {
+            append_type(buffer, ty);
+        }
+        TypeDefinition::Callable(signature) => {
+            append_callable_signature(buffer, signature);
+        }
+        TypeDefinition::Unit
+        | TypeDefinition::Unknown
+        | TypeDefinition::Never => {}
+        TypeDefinition::Collection(collection) => match collection {
+            // layout matches CollectionTypeKind: kind tag, optional size, item types
+            CollectionTypeDefinition::List(item) => {
+                append_u8(buffer, 0);
+                append_type(buffer, item);
+            }
+            CollectionTypeDefinition::ListSlice(item, size) => {
+                append_u8(buffer, 1);
+                append_u32(buffer, *size as u32);
+                append_type(buffer, item);
+            }
+            CollectionTypeDefinition::Map { key, value } => {
+                append_u8(buffer, 2);
+                append_type(buffer, key);
+                append_type(buffer, value);
+            }
+            CollectionTypeDefinition::Instance { base, arguments } => {
+                append_u8(buffer, 3);
+                append_u32(buffer, arguments.len() as u32);
+                append_type(buffer, base);
+                for argument in arguments {
+                    append_type(buffer, argument);
+                }
+            }
+        },
+    };
+}
+
+/// Appends a callable signature: kind, generic parameters, parameter names
+/// and flags, followed by the parameter, rest parameter, return and yeet types
+fn append_callable_signature(
+    buffer: &mut Vec<u8>,
+    signature: &CallableSignature,
+) {
+    append_u8(
+        buffer,
+        match signature.kind {
+            CallableKind::Function => 0,
+            CallableKind::Procedure => 1,
+        },
+    );
+    append_u8(buffer, signature.generic_parameters.len() as u8);
+    for parameter in &signature.generic_parameters {
+        append_short_text(buffer, parameter);
+    }
+    append_u32(buffer, signature.parameter_types.len() as u32);
+    for (name, _) in &signature.parameter_types {
+        append_optional_name(buffer, name);
+    }
+    let flags = signature.rest_parameter_type.is_some() as u8
+        | (signature.return_type.is_some() as u8) << 1
+        | (signature.yeet_type.is_some() as u8) << 2;
+    append_u8(buffer, flags);
+    if let Some((name, _)) = &signature.rest_parameter_type {
+        append_optional_name(buffer, name);
+    }
+
+    for (_, ty) in &signature.parameter_types {
+        append_type(buffer, ty);
+    }
+    if let Some((_, ty)) = &signature.rest_parameter_type {
+        append_type(buffer, ty);
+    }
+    if let Some(ty) = &signature.return_type {
+        append_type(buffer, ty);
+    }
+    if let Some(ty) = &signature.yeet_type {
+        append_type(buffer, ty);
+    }
+}
+
+fn append_short_text(buffer: &mut Vec<u8>, text: &str) {
+    append_u8(buffer, text.len() as u8);
+    append_string_utf8(buffer, text);
+}
+
+/// Appends an optional parameter name, unnamed parameters have length 0
+fn append_optional_name(buffer: &mut Vec<u8>, name: &Option<String>) {
+    append_short_text(buffer, name.as_deref().unwrap_or(""));
+}
+
+fn append_structural_type_definition(
+    buffer: &mut Vec<u8>,
+    structural: &StructuralTypeDefinition,
+) {
+    // layout matches StructuralTypeKind: kind tag, optional count, nested types
+    let literal = match structural {
+        StructuralTypeDefinition::List(types) => {
+            append_u8(buffer, 0);
+            append_u32(buffer, types.len() as u32);
+            for ty in types {
+                append_type(buffer, ty);
+            }
+            return;
+        }
+        StructuralTypeDefinition::Map(fields) => {
+            append_u8(buffer, 1);
+            append_u32(buffer, fields.len() as u32);
+            for (key, value) in fields {
+                append_type(buffer, key);
+                append_type(buffer, value);
+            }
+            return;
+        }
+        StructuralTypeDefinition::Integer(integer) => {
+            CoreValue::from(integer.clone())
+        }
+        StructuralTypeDefinition::TypedInteger(integer) => {
+            CoreValue::from(integer.clone())
+        }
+        StructuralTypeDefinition::Decimal(decimal) => {
+            CoreValue::from(decimal.clone())
+        }
+        StructuralTypeDefinition::TypedDecimal(decimal) => {
+            CoreValue::from(decimal.clone())
+        }
+        StructuralTypeDefinition::Text(text) => CoreValue::from(text.clone()),
+        StructuralTypeDefinition::Boolean(boolean) => {
+            CoreValue::from(boolean.clone())
+        }
+        StructuralTypeDefinition::Endpoint(endpoint) => {
+            CoreValue::from(endpoint.clone())
         }
-        _ => todo!("#637 Type definition compilation not implemented yet"),
+        StructuralTypeDefinition::Null => CoreValue::Null,
     };
+    append_u8(buffer, 2);
+    append_value(buffer, &Value::from(literal));
 }
 
 pub fn append_type_space_instruction_code(
@@ -1,11 +1,16 @@
 use crate::ast::expressions::{
-    Apply, BinaryOperation, DatexExpression, List, Map, Slot, UnaryOperation,
-    VariableAssignment, VariableDeclaration, VariableKind,
+    Apply, BinaryOperation, ComparisonOperation, DatexExpression, List, Map,
+    Slot, Try, TypeCast, UnaryOperation, VariableAssignment,
+    VariableDeclaration, VariableKind, Yeet,
 };
 use crate::ast::expressions::{DatexExpressionData, Statements};
 use crate::ast::expressions::{PropertyAssignment, UnboundedStatement};
 use crate::ast::spanned::Spanned;
-use crate::ast::type_expressions::{TypeExpression, TypeExpressionData};
+use crate::ast::type_expressions::{
+    CallableTypeExpression, FixedSizeList, GenericAccess, Intersection,
+    SliceList, StructuralList, StructuralMap, TypeExpression,
+    TypeExpressionData, Union,
+};
 use crate::dxb_parser::body::{DXBParserError, iterate_instructions};
 use crate::dxb_parser::instruction_collector::StatementResultCollectionStrategy;
 use crate::dxb_parser::instruction_collector::{
@@ -13,12 +18,14 @@ use crate::dxb_parser::instruction_collector::{
     InstructionCollector,
 };
 use crate::global::operators::{
-    AssignmentOperator, BinaryOperator, UnaryOperator,
+    AssignmentOperator, BinaryOperator, ComparisonOperator, UnaryOperator,
 };
 use crate::global::protocol_structures::instructions::{
-    Instruction, RegularInstruction, TypeInstruction,
+    CollectionTypeKind, Instruction, RegularInstruction, StructuralTypeKind,
+    TypeInstruction,
 };
 use crate::global::slots::InternalSlot;
+use crate::global::type_instruction_codes::TypeMutabilityCode;
 use crate::runtime::execution::ExecutionError;
 use crate::stdlib::format;
 use crate::stdlib::rc::Rc;
@@ -219,6 +226,9 @@ pub fn ast_from_bytecode(
                                 RegularInstruction::Null => {
                                     DatexExpressionData::Null
                                 }
+                                RegularInstruction::Debugger => {
+                                    DatexExpressionData::Debugger
+                                }
 
                                 RegularInstruction::GetRef(raw_address) => {
                                     DatexExpressionData::GetReference(
@@ -282,6 +292,7 @@ pub fn ast_from_bytecode(
                                 | RegularInstruction::Subtract
                                 | RegularInstruction::Multiply
                                 | RegularInstruction::Divide
+                                | RegularInstruction::Default
                                 | RegularInstruction::UnaryMinus
                                 | RegularInstruction::UnaryPlus
                                 | RegularInstruction::BitwiseNot
@@ -289,10 +300,12 @@ pub fn ast_from_bytecode(
                                 | RegularInstruction::GetPropertyText(_)
                                 | RegularInstruction::GetPropertyIndex(_)
                                 | RegularInstruction::GetPropertyDynamic
+                                | RegularInstruction::GetPropertyOptional
                                 | RegularInstruction::SetPropertyText(_)
                                 | RegularInstruction::SetPropertyIndex(_)
                                 | RegularInstruction::SetPropertyDynamic
                                 | RegularInstruction::Is
+                                | RegularInstruction::Implements
                                 | RegularInstruction::Matches
                                 | RegularInstruction::StructuralEqual
                                 | RegularInstruction::Equal
@@ -310,7 +323,10 @@ pub fn ast_from_bytecode(
                                 | RegularInstruction::SetSlot(_)
                                 | RegularInstruction::SetReferenceValue(_)
                                 | RegularInstruction::Deref
+                                | RegularInstruction::Yeet
+                                | RegularInstruction::Try
                                 | RegularInstruction::TypedValue
+                                | RegularInstruction::Transform(_)
                                 | RegularInstruction::RemoteExecution(_)
                                 | RegularInstruction::TypeExpression => {
                                     unreachable!()
@@ -347,8 +363,35 @@ pub fn ast_from_bytecode(
                                 }
                                 // NOTE: make sure that each possible match case is either implemented in the default collection or here
                                 // If an instruction is implemented in the default collection, it should be marked as unreachable!() here
+                                TypeInstruction::Unit(_) => {
+                                    TypeExpressionData::Unit
+                                }
+                                TypeInstruction::Unknown(_) => {
+                                    TypeExpressionData::Identifier(
+                                        "unknown".to_string(),
+                                    )
+                                }
+                                TypeInstruction::Never(_) => {
+                                    TypeExpressionData::Identifier(
+                                        "never".to_string(),
+                                    )
+                                }
+                                TypeInstruction::Parameter(parameter) => {
+                                    TypeExpressionData::Identifier(
+                                        parameter.name,
+                                    )
+                                }
+                                // NOTE: make sure that each possible match case is either implemented in the default collection or here
+                                // If an instruction is implemented in the default collection, it should be marked as unreachable!() here
                                 TypeInstruction::List(_)
-                                | TypeInstruction::ImplType(_) => {
+                                | TypeInstruction::ImplType(_)
+                                | TypeInstruction::Collection(_)
+                                | TypeInstruction::Structural(_)
+                                | TypeInstruction::Nominal(_)
+                                | TypeInstruction::Callable(_)
+                                | TypeInstruction::Type(_)
+                                | TypeInstruction::Union(_)
+                                | TypeInstruction::Intersection(_) => {
                                     unreachable!()
                                 }
                             }
@@ -431,6 +474,7 @@ pub fn ast_from_bytecode(
                             | RegularInstruction::Subtract
                             | RegularInstruction::Multiply
                             | RegularInstruction::Divide
+                            | RegularInstruction::Default
                             | RegularInstruction::Matches
                             | RegularInstruction::StructuralEqual
                             | RegularInstruction::Equal
@@ -453,6 +497,23 @@ pub fn ast_from_bytecode(
                                 .into()
                             }
 
+                            RegularInstruction::Implements => {
+                                let right =
+                                    collected_results.pop_value_result();
+                                let left = collected_results.pop_value_result();
+                                DatexExpressionData::ComparisonOperation(
+                                    ComparisonOperation {
+                                        operator: ComparisonOperator::from(
+                                            &regular_instruction,
+                                        ),
+                                        left: Box::new(left),
+                                        right: Box::new(right),
+                                    },
+                                )
+                                .with_default_span()
+                                .into()
+                            }
+
                             RegularInstruction::UnaryMinus
                             | RegularInstruction::UnaryPlus
                             | RegularInstruction::BitwiseNot
@@ -472,6 +533,24 @@ pub fn ast_from_bytecode(
                                 .into()
                             }
 
+                            RegularInstruction::Yeet => {
+                                let expr = collected_results.pop_value_result();
+                                DatexExpressionData::Yeet(Yeet {
+                                    expression: Box::new(expr),
+                                })
+                                .with_default_span()
+                                .into()
+                            }
+
+                            RegularInstruction::Try => {
+                                let expr = collected_results.pop_value_result();
+                                DatexExpressionData::Try(Try {
+                                    expression: Box::new(expr),
+                                })
+                                .with_default_span()
+                                .into()
+                            }
+
                             RegularInstruction::TypedValue => {
                                 let expr = collected_results.pop_value_result();
                                 let expr_type =
@@ -489,6 +568,27 @@ pub fn ast_from_bytecode(
                                 .into()
                             }
 
+                            RegularInstruction::TypeExpression => {
+                                DatexExpressionData::TypeExpression(
+                                    collected_results.pop_type_result(),
+                                )
+                                .with_default_span()
+                                .into()
+                            }
+
+                            RegularInstruction::Transform(mode) => {
+                                let expr = collected_results.pop_value_result();
+                                let target =
+                                    collected_results.pop_type_result();
+                                DatexExpressionData::TypeCast(TypeCast {
+                                    expression: Box::new(expr),
+                                    target,
+                                    mode,
+                                })
+                                .with_default_span()
+                                .into()
+                            }
+
                             RegularInstruction::UnboundedStatementsEnd(
                                 terminated,
                             ) => {
@@ -580,6 +680,7 @@ pub fn ast_from_bytecode(
                                             )
                                             .with_default_span(),
                                         ),
+                                        optional: false,
                                     },
                                 )
                                 .with_default_span()
@@ -597,13 +698,15 @@ pub fn ast_from_bytecode(
                                             )
                                             .with_default_span(),
                                         ),
+                                        optional: false,
                                     },
                                 )
                                 .with_default_span()
                                 .into()
                             }
 
-                            RegularInstruction::GetPropertyDynamic => {
+                            RegularInstruction::GetPropertyDynamic
+                            | RegularInstruction::GetPropertyOptional => {
                                 let base = collected_results.pop_value_result();
                                 let property =
                                     collected_results.pop_value_result();
@@ -611,6 +714,8 @@ pub fn ast_from_bytecode(
                                     crate::ast::expressions::PropertyAccess {
                                         base: Box::new(base),
                                         property: Box::new(property),
+                                        optional: regular_instruction
+                                            == RegularInstruction::GetPropertyOptional,
                                     },
                                 )
                                 .with_default_span()
@@ -687,9 +792,168 @@ pub fn ast_from_bytecode(
                             }
                         },
 
-                        Instruction::TypeInstruction(data) => {
-                            todo!("#656 Undescribed by author.")
-                        }
+                        Instruction::TypeInstruction(data) => match data {
+                            TypeInstruction::Collection(collection_data) => {
+                                match collection_data.kind {
+                                    CollectionTypeKind::List => {
+                                        let item_type =
+                                            collected_results.pop_type_result();
+                                        TypeExpressionData::SliceList(
+                                            SliceList(Box::new(item_type)),
+                                        )
+                                    }
+                                    CollectionTypeKind::FixedSizeList {
+                                        size,
+                                    } => {
+                                        let item_type =
+                                            collected_results.pop_type_result();
+                                        TypeExpressionData::FixedSizeList(
+                                            FixedSizeList {
+                                                ty: Box::new(item_type),
+                                                size: size as usize,
+                                            },
+                                        )
+                                    }
+                                    CollectionTypeKind::Map => {
+                                        let value =
+                                            collected_results.pop_type_result();
+                                        let key =
+                                            collected_results.pop_type_result();
+                                        TypeExpressionData::GenericAccess(
+                                            GenericAccess {
+                                                base: Box::new(
+                                                    TypeExpressionData::Identifier(
+                                                        "Map".to_string(),
+                                                    )
+                                                    .with_default_span(),
+                                                ),
+                                                access: vec![key, value],
+                                            },
+                                        )
+                                    }
+                                    CollectionTypeKind::Instance { .. } => {
+                                        let mut types =
+                                            collected_results.collect_type_results();
+                                        let base = types.remove(0);
+                                        TypeExpressionData::GenericAccess(
+                                            GenericAccess {
+                                                base: Box::new(base),
+                                                access: types,
+                                            },
+                                        )
+                                    }
+                                }
+                                .with_default_span()
+                                .into()
+                            }
+                            TypeInstruction::Structural(structural_data) => {
+                                match structural_data.kind {
+                                    StructuralTypeKind::List { .. } => {
+                                        TypeExpressionData::StructuralList(
+                                            StructuralList(
+                                                collected_results
+                                                    .collect_type_results(),
+                                            ),
+                                        )
+                                    }
+                                    StructuralTypeKind::Map { count } => {
+                                        let mut fields = (0..count)
+                                            .map(|_| {
+                                                let value = collected_results
+                                                    .pop_type_result();
+                                                let key = collected_results
+                                                    .pop_type_result();
+                                                (key, value)
+                                            })
+                                            .collect::<Vec<_>>();
+                                        fields.reverse();
+                                        TypeExpressionData::StructuralMap(
+                                            StructuralMap(fields),
+                                        )
+                                    }
+                                    StructuralTypeKind::Literal => {
+                                        let literal =
+                                            collected_results.pop_value_result();
+                                        literal_type_expression(literal.data)
+                                    }
+                                }
+                                .with_default_span()
+                                .into()
+                            }
+                            // the nominal definition is not part of the expression,
+                            // only the type name is decompiled
+                            TypeInstruction::Nominal(nominal_data) => {
+                                collected_results.pop_type_result();
+                                TypeExpressionData::Identifier(
+                                    nominal_data.name,
+                                )
+                                .with_default_span()
+                                .into()
+                            }
+                            TypeInstruction::Callable(callable_data) => {
+                                let mut types = collected_results
+                                    .collect_type_results()
+                                    .into_iter();
+                                let parameter_types = callable_data
+                                    .parameter_names
+                                    .into_iter()
+                                    .zip(types.by_ref())
+                                    .collect();
+                                let rest_parameter_type =
+                                    callable_data.rest_parameter.map(|name| {
+                                        (name, Box::new(types.next().unwrap()))
+                                    });
+                                let return_type = callable_data
+                                    .has_return_type
+                                    .then(|| Box::new(types.next().unwrap()));
+                                let yeet_type = callable_data
+                                    .has_yeet_type
+                                    .then(|| Box::new(types.next().unwrap()));
+                                TypeExpressionData::Callable(
+                                    CallableTypeExpression {
+                                        kind: callable_data.kind,
+                                        parameter_types,
+                                        rest_parameter_type,
+                                        return_type,
+                                        yeet_type,
+                                    },
+                                )
+                                .with_default_span()
+                                .into()
+                            }
+                            TypeInstruction::Type(metadata) => {
+                                let inner = Box::new(
+                                    collected_results.pop_type_result(),
+                                );
+                                match metadata.mutability {
+                                    TypeMutabilityCode::MutableReference => {
+                                        TypeExpressionData::RefMut(inner)
+                                            .with_default_span()
+                                    }
+                                    TypeMutabilityCode::ImmutableReference => {
+                                        TypeExpressionData::Ref(inner)
+                                            .with_default_span()
+                                    }
+                                    TypeMutabilityCode::Value => *inner,
+                                }
+                                .into()
+                            }
+                            TypeInstruction::Union(_) => {
+                                TypeExpressionData::Union(Union(
+                                    collected_results.collect_type_results(),
+                                ))
+                                .with_default_span()
+                                .into()
+                            }
+                            TypeInstruction::Intersection(_) => {
+                                TypeExpressionData::Intersection(Intersection(
+                                    collected_results.collect_type_results(),
+                                ))
+                                .with_default_span()
+                                .into()
+                            }
+                            _ => todo!("#656 Undescribed by author."),
+                        },
                     };
                     collector.push_result(expr);
                 }
@@ -708,12 +972,41 @@ pub fn ast_from_bytecode(
     }
 }
 
+/// Converts a decompiled literal value into the matching literal type expression
+fn literal_type_expression(literal: DatexExpressionData) -> TypeExpressionData {
+    match literal {
+        DatexExpressionData::Null => TypeExpressionData::Null,
+        DatexExpressionData::Boolean(boolean) => {
+            TypeExpressionData::Boolean(boolean)
+        }
+        DatexExpressionData::Text(text) => TypeExpressionData::Text(text),
+        DatexExpressionData::Integer(integer) => {
+            TypeExpressionData::Integer(integer)
+        }
+        DatexExpressionData::TypedInteger(integer) => {
+            TypeExpressionData::TypedInteger(integer)
+        }
+        DatexExpressionData::Decimal(decimal) => {
+            TypeExpressionData::Decimal(decimal)
+        }
+        DatexExpressionData::TypedDecimal(decimal) => {
+            TypeExpressionData::TypedDecimal(decimal)
+        }
+        DatexExpressionData::Endpoint(endpoint) => {
+            TypeExpressionData::Endpoint(endpoint)
+        }
+        _ => TypeExpressionData::Recover,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ast::expressions::PropertyAccess;
     use crate::global::operators::binary::ArithmeticOperator;
-    use crate::global::type_instruction_codes::TypeInstructionCode;
+    use crate::global::type_instruction_codes::{
+        TypeInstructionCode, TypeMutabilityCode,
+    };
     use crate::{
         ast::spanned::Spanned, global::instruction_codes::InstructionCode,
     };
@@ -922,6 +1215,136 @@ mod tests {
         );
     }
 
+    #[test]
+    fn typed_fixed_size_list() {
+        let bytecode: Vec<u8> = vec![
+            InstructionCode::TYPED_VALUE as u8,
+            TypeInstructionCode::TYPE_COLLECTION as u8,
+            TypeMutabilityCode::Value as u8,
+            0x01, // fixed size list
+            0x01, // size
+            0x00,
+            0x00,
+            0x00,
+            TypeInstructionCode::TYPE_LITERAL_SHORT_TEXT as u8,
+            2,
+            b'O',
+            b'K',
+            InstructionCode::SHORT_LIST as u8,
+            0x01, // 1 element
+            InstructionCode::SHORT_TEXT as u8,
+            2,
+            b'O',
+            b'K',
+        ];
+        let ast = ast_from_bytecode(&bytecode).unwrap();
+        assert_eq!(
+            ast,
+            DatexExpressionData::Apply(Apply {
+                base: Box::new(
+                    DatexExpressionData::TypeExpression(
+                        TypeExpressionData::FixedSizeList(FixedSizeList {
+                            ty: Box::new(
+                                TypeExpressionData::Text("OK".to_string())
+                                    .with_default_span()
+                            ),
+                            size: 1,
+                        })
+                        .with_default_span()
+                    )
+                    .with_default_span()
+                ),
+                arguments: vec![
+                    DatexExpressionData::List(List::new(vec![
+                        DatexExpressionData::Text("OK".to_string())
+                            .with_default_span()
+                    ]))
+                    .with_default_span()
+                ],
+            })
+            .with_default_span()
+        );
+    }
+
+    #[test]
+    fn generic_type_instance() {
+        use crate::core_compiler::type_compiler::append_type;
+        use crate::references::type_reference::TypeReference;
+        use crate::values::core_values::r#type::Type;
+
+        let mut reference = TypeReference::nominal(
+            Type::parameter("T"),
+            "Box".to_string(),
+            None,
+        );
+        reference.generic_parameters = vec!["T".to_string()];
+        let instance =
+            Type::instance(reference.as_type(), vec![Type::parameter("U")]);
+        let mut bytecode = vec![InstructionCode::TYPE_EXPRESSION as u8];
+        append_type(&mut bytecode, &instance);
+
+        let ast = ast_from_bytecode(&bytecode).unwrap();
+        assert_eq!(
+            ast,
+            DatexExpressionData::TypeExpression(
+                TypeExpressionData::GenericAccess(GenericAccess {
+                    base: Box::new(
+                        TypeExpressionData::Identifier("Box".to_string())
+                            .with_default_span()
+                    ),
+                    access: vec![
+                        TypeExpressionData::Identifier("U".to_string())
+                            .with_default_span()
+                    ],
+                })
+                .with_default_span()
+            )
+            .with_default_span()
+        );
+    }
+
+    #[test]
+    fn typed_nominal_value() {
+        let bytecode: Vec<u8> = vec![
+            InstructionCode::TYPED_VALUE as u8,
+            TypeInstructionCode::TYPE_NOMINAL as u8,
+            TypeMutabilityCode::Value as u8,
+            2, // name length
+            b'I',
+            b'D',
+            0, // generic parameter count
+            TypeInstructionCode::TYPE_STRUCTURAL as u8,
+            TypeMutabilityCode::Value as u8,
+            0x02, // literal
+            InstructionCode::SHORT_TEXT as u8,
+            2,
+            b'O',
+            b'K',
+            InstructionCode::SHORT_TEXT as u8,
+            2,
+            b'O',
+            b'K',
+        ];
+        let ast = ast_from_bytecode(&bytecode).unwrap();
+        assert_eq!(
+            ast,
+            DatexExpressionData::Apply(Apply {
+                base: Box::new(
+                    DatexExpressionData::TypeExpression(
+                        TypeExpressionData::Identifier("ID".to_string())
+                            .with_default_span()
+                    )
+                    .with_default_span()
+                ),
+                arguments: vec![
+                    DatexExpressionData::Text("OK".to_string())
+                        .with_default_span()
+                ],
+            })
+            .with_default_span()
+        );
+    }
+
     #[test]
     fn unbounded_statements() {
         let bytecode: Vec<u8> = vec![
@@ -1066,6 +1489,7 @@ mod tests {
                     DatexExpressionData::Text("abc".to_string())
                         .with_default_span()
                 ),
+                optional: false,
             })
         );
     }
@@ -1134,6 +1558,7 @@ mod tests {
                     DatexExpressionData::Integer(Integer::from(5u8))
                         .with_default_span()
                 ),
+                optional: false,
             })
         );
     }
@@ -1205,6 +1630,7 @@ mod tests {
                     DatexExpressionData::Text("name".to_string())
                         .with_default_span()
                 ),
+                optional: false,
             })
         );
     }
@@ -75,6 +75,10 @@ fn value_to_datex_expression(value: &Value) -> DatexExpressionData {
             DatexExpressionData::CallableDeclaration(CallableDeclaration {
                 name: callable.name.clone(),
                 kind: callable.signature.kind.clone(),
+                generic_parameters: callable
+                    .signature
+                    .generic_parameters
+                    .clone(),
                 parameters: callable
                     .signature
                     .parameter_types
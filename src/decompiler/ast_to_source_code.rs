@@ -1,12 +1,12 @@
 use crate::ast::expressions::{Apply, PropertyAssignment};
 use crate::ast::expressions::{
     BinaryOperation, ComparisonOperation, Conditional, DerefAssignment, List,
-    Map, PropertyAccess, RemoteExecution, SlotAssignment, TypeDeclaration,
-    VariantAccess,
+    Map, PropertyAccess, RemoteExecution, SlotAssignment, TypeCast,
+    TypeDeclaration, VariantAccess,
 };
 use crate::ast::expressions::{
-    CallableDeclaration, DatexExpression, DatexExpressionData, VariableAccess,
-    VariableAssignment, VariableDeclaration,
+    CallableDeclaration, DatexExpression, DatexExpressionData, Export, Import,
+    VariableAccess, VariableAssignment, VariableDeclaration,
 };
 use crate::ast::type_expressions::{
     CallableTypeExpression, TypeExpression, TypeExpressionData,
@@ -15,6 +15,7 @@ use crate::ast::type_expressions::{
 use core::fmt::{self};
 
 use crate::decompiler::{FormattingMode, FormattingOptions, IndentType};
+use crate::global::operators::CastMode;
 use crate::references::reference::ReferenceMutability;
 
 #[derive(Clone, Default)]
@@ -243,10 +244,24 @@ impl AstToSourceCodeConverter {
                 self.wrap_list_elements(elements)
             }
             TypeExpressionData::FixedSizeList(fixed_size_list) => {
-                core::todo!("#472 Undescribed by author.")
+                format!(
+                    "[{};{}{}]",
+                    self.type_expression_to_source_code(&fixed_size_list.ty),
+                    self.space(),
+                    fixed_size_list.size
+                )
             }
             TypeExpressionData::SliceList(type_expression) => {
-                core::todo!("#473 Undescribed by author.")
+                let item =
+                    self.type_expression_to_source_code(&type_expression.0);
+                // unions and intersections bind weaker than the [] suffix
+                match type_expression.0.data {
+                    TypeExpressionData::Union(_)
+                    | TypeExpressionData::Intersection(_) => {
+                        format!("({})[]", item)
+                    }
+                    _ => format!("{}[]", item),
+                }
             }
             TypeExpressionData::Intersection(type_expressions) => {
                 let elements: Vec<String> = type_expressions
@@ -264,8 +279,26 @@ impl AstToSourceCodeConverter {
                     .collect();
                 self.wrap_union_elements(elements)
             }
+            TypeExpressionData::ImplType(impl_type) => {
+                let elements: Vec<String> =
+                    core::iter::once(impl_type.base.as_ref())
+                        .chain(&impl_type.interfaces)
+                        .map(|e| self.type_expression_to_source_code(e))
+                        .collect();
+                self.wrap_elements(elements, BraceStyle::None, Some("+"))
+            }
             TypeExpressionData::GenericAccess(generic_access) => {
-                core::todo!("#474 Undescribed by author.")
+                let arguments: Vec<String> = generic_access
+                    .access
+                    .iter()
+                    .map(|e| self.type_expression_to_source_code(e))
+                    .collect();
+                ast_fmt!(
+                    &self,
+                    "{}<{}>",
+                    self.type_expression_to_source_code(&generic_access.base),
+                    arguments.join(&ast_fmt!(&self, ",%s"))
+                )
             }
             TypeExpressionData::Callable(CallableTypeExpression {
                 kind,
@@ -352,6 +385,18 @@ impl AstToSourceCodeConverter {
         }
     }
 
+    /// Generic parameters of a declaration, e.g. `<T, U>`, or an empty
+    /// string if there are none
+    fn generic_parameters_to_source_code(
+        &self,
+        parameters: &[String],
+    ) -> String {
+        if parameters.is_empty() {
+            return "".to_string();
+        }
+        format!("<{}>", parameters.join(&ast_fmt!(&self, ",%s")))
+    }
+
     fn wrap_map_elements(&self, elements: Vec<String>) -> String {
         self.wrap_elements(elements, BraceStyle::Curly, Some(","))
     }
@@ -502,6 +547,7 @@ impl AstToSourceCodeConverter {
             DatexExpressionData::Text(t) => self.text_to_source_code(t),
             DatexExpressionData::Endpoint(e) => e.to_string(),
             DatexExpressionData::Null => "null".to_string(),
+            DatexExpressionData::Debugger => "debugger".to_string(),
             DatexExpressionData::Identifier(l) => l.to_string(),
             DatexExpressionData::Map(map) => self.map_to_source_code(map),
             DatexExpressionData::List(list) => self.list_to_source_code(list),
@@ -614,21 +660,37 @@ impl AstToSourceCodeConverter {
             DatexExpressionData::TypeDeclaration(TypeDeclaration {
                 id: _,
                 name,
+                generic_parameters,
                 definition: value,
                 hoisted: _,
                 kind,
             }) => {
                 ast_fmt!(
                     &self,
-                    "{} {}%s=%s{}",
+                    "{} {}{}%s=%s{}",
                     kind,
                     name,
+                    self.generic_parameters_to_source_code(generic_parameters),
                     self.type_expression_to_source_code(value)
                 )
             }
+            DatexExpressionData::Export(Export { declaration }) => {
+                format!("export {}", self.format(declaration))
+            }
+            DatexExpressionData::Import(Import { items, source }) => {
+                let names: Vec<String> =
+                    items.iter().map(|item| item.name.clone()).collect();
+                ast_fmt!(
+                    &self,
+                    "import%s{{%s{}%s}}%sfrom%s{}",
+                    names.join(&ast_fmt!(&self, ",%s")),
+                    self.text_to_source_code(source)
+                )
+            }
             DatexExpressionData::CallableDeclaration(CallableDeclaration {
                 name,
                 kind,
+                generic_parameters,
                 parameters,
                 rest_parameter,
                 return_type,
@@ -681,9 +743,10 @@ impl AstToSourceCodeConverter {
 
                 ast_fmt!(
                     &self,
-                    "{} {}({}){}{}%s(%n{}{}%n)",
+                    "{} {}{}({}){}{}%s(%n{}{}%n)",
                     kind,
                     name.clone().unwrap_or_else(|| "".to_string()),
+                    self.generic_parameters_to_source_code(generic_parameters),
                     params_code.join(&ast_fmt!(&self, ",%s")),
                     return_type_code,
                     yeet_type_code,
@@ -694,6 +757,12 @@ impl AstToSourceCodeConverter {
             DatexExpressionData::Deref(deref) => {
                 format!("*{}", self.format(&deref.expression))
             }
+            DatexExpressionData::Yeet(yeet) => {
+                format!("yeet {}", self.format(&yeet.expression))
+            }
+            DatexExpressionData::Try(r#try) => {
+                format!("try {}", self.format(&r#try.expression))
+            }
             DatexExpressionData::Slot(slot) => slot.to_string(),
             DatexExpressionData::SlotAssignment(SlotAssignment {
                 slot,
@@ -716,6 +785,22 @@ impl AstToSourceCodeConverter {
                     self.format(right)
                 )
             }
+            DatexExpressionData::TypeCast(TypeCast {
+                expression,
+                target,
+                mode,
+            }) => {
+                let mode_prefix = match mode {
+                    CastMode::Checked => "",
+                    CastMode::Saturating => "saturating ",
+                };
+                format!(
+                    "{} as {}{}",
+                    self.format(expression),
+                    mode_prefix,
+                    self.type_expression_to_source_code(target)
+                )
+            }
             DatexExpressionData::DerefAssignment(DerefAssignment {
                 operator,
                 deref_expression,
@@ -747,10 +832,12 @@ impl AstToSourceCodeConverter {
             DatexExpressionData::PropertyAccess(PropertyAccess {
                 base,
                 property,
+                optional,
             }) => {
                 format!(
-                    "{}.{}",
+                    "{}{}{}",
                     self.format(base),
+                    if *optional { "?." } else { "." },
                     self.key_expression_to_source_code(property)
                 )
             }
@@ -770,6 +857,7 @@ mod tests {
 
     use super::*;
     use crate::ast::expressions::{Deref, VariableKind};
+    use crate::ast::type_expressions::{FixedSizeList, SliceList, Union};
     use crate::global::operators::assignment::AssignmentOperator;
     use crate::parser::Parser;
     use crate::{ast::spanned::Spanned, values::core_values::decimal::Decimal};
@@ -1014,6 +1102,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_list_type_annotations() {
+        let var_decl_ast =
+            DatexExpressionData::VariableDeclaration(VariableDeclaration {
+                id: Some(0),
+                kind: VariableKind::Const,
+                name: "x".to_string(),
+                init_expression: Box::new(
+                    DatexExpressionData::Null.with_default_span(),
+                ),
+                type_annotation: Some(
+                    TypeExpressionData::FixedSizeList(FixedSizeList {
+                        ty: Box::new(
+                            TypeExpressionData::SliceList(SliceList(Box::new(
+                                TypeExpressionData::Union(Union(vec![
+                                    TypeExpressionData::Identifier(
+                                        "text".to_owned(),
+                                    )
+                                    .with_default_span(),
+                                    TypeExpressionData::Null
+                                        .with_default_span(),
+                                ]))
+                                .with_default_span(),
+                            )))
+                            .with_default_span(),
+                        ),
+                        size: 3,
+                    })
+                    .with_default_span(),
+                ),
+            })
+            .with_default_span();
+        assert_eq!(
+            compact().format(&var_decl_ast),
+            "const x:[(text|null)[];3]=null"
+        );
+    }
+
     #[test]
     fn test_variable_declaration() {
         let var_decl_ast =
@@ -1058,6 +1184,24 @@ mod tests {
         assert_eq!(json_compat().format(&typed_decimal_ast), "2.71");
     }
 
+    #[test]
+    fn type_cast() {
+        let cast_ast = DatexExpressionData::TypeCast(TypeCast {
+            expression: Box::new(
+                DatexExpressionData::Integer(300.into()).with_default_span(),
+            ),
+            target: TypeExpressionData::VariantAccess(TypeVariantAccess {
+                name: "integer".to_string(),
+                variant: "u8".to_string(),
+                base: None,
+            })
+            .with_default_span(),
+            mode: CastMode::Saturating,
+        })
+        .with_default_span();
+        assert_eq!(compact().format(&cast_ast), "300 as saturating integer/u8");
+    }
+
     #[test]
     fn property_access_text_key() {
         let prop_access_ast =
@@ -1073,12 +1217,34 @@ mod tests {
                     DatexExpressionData::Text("myProp".to_string())
                         .with_default_span(),
                 ),
+                optional: false,
             })
             .with_default_span();
         assert_eq!(compact().format(&prop_access_ast), "obj.myProp");
         assert_eq!(pretty().format(&prop_access_ast), "obj.myProp");
     }
 
+    #[test]
+    fn optional_property_access() {
+        let prop_access_ast =
+            DatexExpressionData::PropertyAccess(PropertyAccess {
+                base: Box::new(
+                    DatexExpressionData::VariableAccess(VariableAccess {
+                        id: 0,
+                        name: "obj".to_string(),
+                    })
+                    .with_default_span(),
+                ),
+                property: Box::new(
+                    DatexExpressionData::Text("myProp".to_string())
+                        .with_default_span(),
+                ),
+                optional: true,
+            })
+            .with_default_span();
+        assert_eq!(compact().format(&prop_access_ast), "obj?.myProp");
+    }
+
     #[test]
     fn property_access_boolean_key() {
         let prop_access_ast =
@@ -1093,6 +1259,7 @@ mod tests {
                 property: Box::new(
                     DatexExpressionData::Boolean(true).with_default_span(),
                 ),
+                optional: false,
             })
             .with_default_span();
         assert_eq!(compact().format(&prop_access_ast), "obj.(true)");
@@ -1113,6 +1280,7 @@ mod tests {
                 property: Box::new(
                     DatexExpressionData::Integer(42.into()).with_default_span(),
                 ),
+                optional: false,
             })
             .with_default_span();
         assert_eq!(compact().format(&prop_access_ast), "obj.42");
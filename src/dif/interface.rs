@@ -56,7 +56,11 @@ impl From<DIFReferenceNotFoundError> for DIFUpdateError {
 }
 impl From<AccessError> for DIFUpdateError {
     fn from(err: AccessError) -> Self {
-        DIFUpdateError::AccessError(err)
+        match err {
+            // surface type violations as type errors to DIF clients
+            AccessError::TypeError(err) => DIFUpdateError::TypeError(err),
+            err => DIFUpdateError::AccessError(err),
+        }
     }
 }
 impl From<AssignmentError> for DIFUpdateError {
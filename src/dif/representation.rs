@@ -14,6 +14,7 @@ use crate::values::core_values::decimal::typed_decimal::{
     DecimalTypeVariant, TypedDecimal,
 };
 use crate::values::core_values::map::Map;
+use crate::values::core_values::r#type::Type;
 use crate::values::value::Value;
 use crate::values::value_container::ValueContainer;
 use core::cell::RefCell;
@@ -192,7 +193,7 @@ impl DIFValueRepresentation {
             None => self.to_default_value(memory)?,
         };
 
-        let ty = type_definition.to_type_definition(memory);
+        let ty = type_definition.to_type_definition(memory)?;
 
         Ok(Value {
             actual_type: Box::new(ty),
@@ -257,6 +258,60 @@ impl DIFTypeRepresentation {
             }
         }
     }
+
+    /// Converts the type representation back into a structural type definition.
+    /// Numbers are represented as f64 decimals, matching the default values
+    /// created from DIF numbers.
+    pub fn to_structural_type_definition(
+        &self,
+        memory: &RefCell<Memory>,
+    ) -> Result<StructuralTypeDefinition, DIFReferenceNotFoundError> {
+        Ok(match self {
+            DIFTypeRepresentation::Null => StructuralTypeDefinition::Null,
+            DIFTypeRepresentation::Boolean(b) => {
+                StructuralTypeDefinition::Boolean((*b).into())
+            }
+            DIFTypeRepresentation::String(s) => {
+                StructuralTypeDefinition::Text(s.clone().into())
+            }
+            DIFTypeRepresentation::Number(n) => {
+                StructuralTypeDefinition::TypedDecimal(TypedDecimal::F64(
+                    OrderedFloat::from(*n),
+                ))
+            }
+            DIFTypeRepresentation::Array(types) => {
+                StructuralTypeDefinition::List(
+                    types
+                        .iter()
+                        .map(|ty| ty.to_type(memory))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            DIFTypeRepresentation::Map(fields) => {
+                StructuralTypeDefinition::Map(
+                    fields
+                        .iter()
+                        .map(|(k, v)| {
+                            Ok((k.to_type(memory)?, v.to_type(memory)?))
+                        })
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            DIFTypeRepresentation::Object(fields) => {
+                StructuralTypeDefinition::Map(
+                    fields
+                        .iter()
+                        .map(|(k, v)| {
+                            Ok((
+                                Type::structural(k.as_str()),
+                                v.to_type(memory)?,
+                            ))
+                        })
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+        })
+    }
 }
 
 impl Serialize for DIFValueRepresentation {
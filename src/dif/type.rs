@@ -1,13 +1,18 @@
 use crate::dif::DIFConvertible;
 use crate::dif::representation::DIFTypeRepresentation;
-use crate::references::reference::ReferenceMutability;
+use crate::dif::value::DIFReferenceNotFoundError;
+use crate::references::reference::{Reference, ReferenceMutability};
+use crate::references::type_reference::TypeReference;
 use crate::runtime::memory::Memory;
 use crate::stdlib::boxed::Box;
 use crate::stdlib::format;
+use crate::stdlib::rc::Rc;
 use crate::stdlib::string::String;
 use crate::stdlib::vec::Vec;
+use crate::types::collection_type_definition::CollectionTypeDefinition;
 use crate::types::definition::TypeDefinition;
 use crate::types::structural_type_definition::StructuralTypeDefinition;
+use crate::values::core_values::callable::{CallableKind, CallableSignature};
 use crate::values::core_values::r#type::Type;
 use crate::values::pointer::PointerAddress;
 use core::cell::RefCell;
@@ -47,6 +52,15 @@ pub enum DIFTypeDefinition {
         return_type: Option<Box<DIFType>>,
         yeet_type: Option<Box<DIFType>>,
     },
+
+    // e.g. integer[]
+    List(Box<DIFType>),
+
+    // e.g. [integer; 5]
+    FixedSizeList(Box<DIFType>, usize),
+
+    // e.g. Map<text, integer>
+    Map(Box<DIFType>, Box<DIFType>),
 }
 
 #[repr(u8)]
@@ -62,6 +76,9 @@ pub enum DIFTypeDefinitionKind {
     Never = 8,
     Unknown = 9,
     Callable = 10,
+    List = 11,
+    FixedSizeList = 12,
+    Map = 13,
 }
 
 impl From<&DIFTypeDefinition> for DIFTypeDefinitionKind {
@@ -85,6 +102,11 @@ impl From<&DIFTypeDefinition> for DIFTypeDefinitionKind {
             DIFTypeDefinition::Callable { .. } => {
                 DIFTypeDefinitionKind::Callable
             }
+            DIFTypeDefinition::List(_) => DIFTypeDefinitionKind::List,
+            DIFTypeDefinition::FixedSizeList(_, _) => {
+                DIFTypeDefinitionKind::FixedSizeList
+            }
+            DIFTypeDefinition::Map(_, _) => DIFTypeDefinitionKind::Map,
         }
     }
 }
@@ -146,6 +168,15 @@ impl Serialize for DIFTypeDefinition {
                     &(parameters, rest_parameter, return_type, yeet_type),
                 )?;
             }
+            DIFTypeDefinition::List(item_type) => {
+                state.serialize_field("def", item_type)?;
+            }
+            DIFTypeDefinition::FixedSizeList(item_type, size) => {
+                state.serialize_field("def", &(item_type, size))?;
+            }
+            DIFTypeDefinition::Map(key_type, value_type) => {
+                state.serialize_field("def", &(key_type, value_type))?;
+            }
             DIFTypeDefinition::Reference(_) => {
                 // already handled above
                 unreachable!();
@@ -163,6 +194,7 @@ enum DIFTypeDefinitionData {
     SingleType(DIFType),
     TypeVec(Vec<DIFType>),
     ImplType((DIFType, Vec<PointerAddress>)),
+    SizedType((DIFType, usize)),
     Callable(
         (
             Vec<(Option<String>, DIFType)>,
@@ -332,6 +364,51 @@ impl<'de> Deserialize<'de> for DIFTypeDefinition {
                             ));
                         }
                     }
+                    DIFTypeDefinitionKind::List => {
+                        let def =
+                            def.ok_or_else(|| de::Error::missing_field("def"))?;
+                        if let DIFTypeDefinitionData::SingleType(ty) = def {
+                            DIFTypeDefinition::List(Box::new(ty))
+                        } else {
+                            return Err(de::Error::custom(
+                                "Expected DIFType for kind List",
+                            ));
+                        }
+                    }
+                    DIFTypeDefinitionKind::FixedSizeList => {
+                        let def =
+                            def.ok_or_else(|| de::Error::missing_field("def"))?;
+                        if let DIFTypeDefinitionData::SizedType((ty, size)) =
+                            def
+                        {
+                            DIFTypeDefinition::FixedSizeList(Box::new(ty), size)
+                        } else {
+                            return Err(de::Error::custom(
+                                "Expected (DIFType, usize) for kind FixedSizeList",
+                            ));
+                        }
+                    }
+                    DIFTypeDefinitionKind::Map => {
+                        let def =
+                            def.ok_or_else(|| de::Error::missing_field("def"))?;
+                        // a (key, value) pair is deserialized as a list of two types
+                        match def {
+                            DIFTypeDefinitionData::TypeVec(types)
+                                if types.len() == 2 =>
+                            {
+                                let mut types = types.into_iter();
+                                DIFTypeDefinition::Map(
+                                    Box::new(types.next().unwrap()),
+                                    Box::new(types.next().unwrap()),
+                                )
+                            }
+                            _ => {
+                                return Err(de::Error::custom(
+                                    "Expected (DIFType, DIFType) for kind Map",
+                                ));
+                            }
+                        }
+                    }
                 })
             }
         }
@@ -375,7 +452,37 @@ impl DIFTypeDefinition {
     ) -> Self {
         match type_def {
             TypeDefinition::Collection(collection_def) => {
-                core::todo!("#387 handle collection type conversion");
+                match collection_def {
+                    CollectionTypeDefinition::List(item_type) => {
+                        DIFTypeDefinition::List(Box::new(DIFType::from_type(
+                            item_type, memory,
+                        )))
+                    }
+                    CollectionTypeDefinition::ListSlice(item_type, size) => {
+                        DIFTypeDefinition::FixedSizeList(
+                            Box::new(DIFType::from_type(item_type, memory)),
+                            *size,
+                        )
+                    }
+                    CollectionTypeDefinition::Map { key, value } => {
+                        DIFTypeDefinition::Map(
+                            Box::new(DIFType::from_type(key, memory)),
+                            Box::new(DIFType::from_type(value, memory)),
+                        )
+                    }
+                    // generic instances are represented by their instantiated type
+                    CollectionTypeDefinition::Instance { .. } => {
+                        match Type::new(type_def.clone(), None).instantiate() {
+                            Some(instance) => {
+                                DIFTypeDefinition::from_type_definition(
+                                    &instance.type_definition,
+                                    memory,
+                                )
+                            }
+                            None => DIFTypeDefinition::Unknown,
+                        }
+                    }
+                }
             }
             TypeDefinition::Structural(struct_def) => {
                 DIFTypeDefinition::Structural(Box::new(
@@ -385,9 +492,9 @@ impl DIFTypeDefinition {
                 ))
             }
             TypeDefinition::Reference(type_ref) => {
-                DIFTypeDefinition::Reference(
-                    type_ref.borrow().pointer_address.clone().unwrap(),
-                )
+                DIFTypeDefinition::Reference(type_reference_address(
+                    type_ref, memory,
+                ))
             }
             TypeDefinition::Type(type_val) => DIFTypeDefinition::Type(
                 Box::new(DIFType::from_type(type_val.as_ref(), memory)),
@@ -408,11 +515,16 @@ impl DIFTypeDefinition {
             ),
             TypeDefinition::ImplType(ty, impls) => DIFTypeDefinition::ImplType(
                 Box::new(DIFType::from_type(ty, memory)),
-                impls.clone(),
+                impls
+                    .iter()
+                    .map(|marker| type_reference_address(marker, memory))
+                    .collect(),
             ),
             TypeDefinition::Unit => DIFTypeDefinition::Unit,
             TypeDefinition::Never => DIFTypeDefinition::Never,
             TypeDefinition::Unknown => DIFTypeDefinition::Unknown,
+            // type parameters are erased after substitution
+            TypeDefinition::Parameter(_) => DIFTypeDefinition::Unknown,
             TypeDefinition::Callable(callable) => DIFTypeDefinition::Callable {
                 parameters: callable
                     .parameter_types
@@ -439,46 +551,113 @@ impl DIFTypeDefinition {
         }
     }
 
+    /// Converts the DIF type definition into a type definition, resolving
+    /// referenced types in the given memory.
+    /// Fails if a referenced type is not found in memory.
     pub(crate) fn to_type_definition(
         &self,
         memory: &RefCell<Memory>,
-    ) -> TypeDefinition {
-        match self {
+    ) -> Result<TypeDefinition, DIFReferenceNotFoundError> {
+        Ok(match self {
             DIFTypeDefinition::Intersection(types) => {
-                TypeDefinition::Intersection(
-                    types.iter().map(|t| t.to_type(memory)).collect(),
-                )
+                TypeDefinition::Intersection(DIFType::to_types(types, memory)?)
+            }
+            DIFTypeDefinition::Union(types) => {
+                TypeDefinition::Union(DIFType::to_types(types, memory)?)
             }
-            DIFTypeDefinition::Union(types) => TypeDefinition::Union(
-                types.iter().map(|t| t.to_type(memory)).collect(),
-            ),
             DIFTypeDefinition::Reference(type_ref_addr) => {
-                let type_ref = memory
-                    .borrow_mut()
-                    .get_type_reference(type_ref_addr)
-                    .expect("Reference not found in memory")
-                    .clone();
-                TypeDefinition::Reference(type_ref)
+                TypeDefinition::Reference(resolve_type_reference(
+                    type_ref_addr,
+                    memory,
+                )?)
             }
             DIFTypeDefinition::Type(dif_type) => {
-                TypeDefinition::Type(Box::new(dif_type.to_type(memory)))
+                TypeDefinition::Type(Box::new(dif_type.to_type(memory)?))
             }
             DIFTypeDefinition::ImplType(ty, impls) => TypeDefinition::ImplType(
-                Box::new(ty.to_type(memory)),
-                impls.clone(),
+                Box::new(ty.to_type(memory)?),
+                impls
+                    .iter()
+                    .map(|addr| resolve_type_reference(addr, memory))
+                    .collect::<Result<_, _>>()?,
             ),
             DIFTypeDefinition::Unit => TypeDefinition::Unit,
             DIFTypeDefinition::Never => TypeDefinition::Never,
             DIFTypeDefinition::Unknown => TypeDefinition::Unknown,
-            _ => {
-                core::todo!(
-                    "DIFTypeDefinition::to_type_definition for this variant is not implemented yet"
+            DIFTypeDefinition::Callable {
+                parameters,
+                rest_parameter,
+                return_type,
+                yeet_type,
+            } => TypeDefinition::Callable(CallableSignature {
+                // the callable kind is not part of the DIF type
+                kind: CallableKind::Function,
+                generic_parameters: Vec::new(),
+                parameter_types: parameters
+                    .iter()
+                    .map(|(name, ty)| Ok((name.clone(), ty.to_type(memory)?)))
+                    .collect::<Result<_, _>>()?,
+                rest_parameter_type: match rest_parameter {
+                    Some((name, ty)) => {
+                        Some((name.clone(), Box::new(ty.to_type(memory)?)))
+                    }
+                    None => None,
+                },
+                return_type: match return_type {
+                    Some(ty) => Some(Box::new(ty.to_type(memory)?)),
+                    None => None,
+                },
+                yeet_type: match yeet_type {
+                    Some(ty) => Some(Box::new(ty.to_type(memory)?)),
+                    None => None,
+                },
+            }),
+            DIFTypeDefinition::List(item_type) => {
+                TypeDefinition::slice_list(item_type.to_type(memory)?)
+            }
+            DIFTypeDefinition::FixedSizeList(item_type, size) => {
+                TypeDefinition::fixed_size_list(
+                    item_type.to_type(memory)?,
+                    *size,
                 )
             }
-        }
+            DIFTypeDefinition::Map(key_type, value_type) => {
+                TypeDefinition::Collection(CollectionTypeDefinition::Map {
+                    key: Box::new(key_type.to_type(memory)?),
+                    value: Box::new(value_type.to_type(memory)?),
+                })
+            }
+            DIFTypeDefinition::Structural(struct_def) => {
+                TypeDefinition::Structural(
+                    struct_def.value.to_structural_type_definition(memory)?,
+                )
+            }
+        })
     }
 }
 
+/// Returns the pointer address of a type reference.
+/// Local type references (e.g. interfaces declared in a script) are
+/// registered in memory if they do not have an address yet.
+fn type_reference_address(
+    type_ref: &Rc<RefCell<TypeReference>>,
+    memory: &RefCell<Memory>,
+) -> PointerAddress {
+    Reference::TypeReference(type_ref.clone()).ensure_pointer_address(memory)
+}
+
+/// Resolves a type reference by its pointer address in memory
+fn resolve_type_reference(
+    address: &PointerAddress,
+    memory: &RefCell<Memory>,
+) -> Result<Rc<RefCell<TypeReference>>, DIFReferenceNotFoundError> {
+    memory
+        .borrow()
+        .get_type_reference(address)
+        .cloned()
+        .ok_or(DIFReferenceNotFoundError)
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct DIFType {
     pub name: Option<String>,
@@ -610,20 +789,30 @@ impl DIFType {
         }
     }
 
-    pub(crate) fn to_type(&self, memory: &RefCell<Memory>) -> Type {
-        Type {
+    pub(crate) fn to_type(
+        &self,
+        memory: &RefCell<Memory>,
+    ) -> Result<Type, DIFReferenceNotFoundError> {
+        Ok(Type {
             reference_mutability: self.mutability.clone(),
-            type_definition: self.to_type_definition(memory),
+            type_definition: self.to_type_definition(memory)?,
             base_type: None,
-        }
+        })
     }
 
     pub(crate) fn to_type_definition(
         &self,
         memory: &RefCell<Memory>,
-    ) -> TypeDefinition {
+    ) -> Result<TypeDefinition, DIFReferenceNotFoundError> {
         DIFTypeDefinition::to_type_definition(&self.type_definition, memory)
     }
+
+    fn to_types(
+        types: &[DIFType],
+        memory: &RefCell<Memory>,
+    ) -> Result<Vec<Type>, DIFReferenceNotFoundError> {
+        types.iter().map(|ty| ty.to_type(memory)).collect()
+    }
 }
 
 impl From<DIFTypeRepresentation> for DIFType {
@@ -654,6 +843,30 @@ mod tests {
         assert_eq!(dif_type, deserialized);
     }
 
+    #[test]
+    fn collections() {
+        let item_type = DIFType::from(DIFTypeRepresentation::Number(42.0));
+        for type_definition in [
+            DIFTypeDefinition::List(Box::new(item_type.clone())),
+            DIFTypeDefinition::FixedSizeList(Box::new(item_type.clone()), 3),
+            DIFTypeDefinition::Map(
+                Box::new(DIFType::from(DIFTypeRepresentation::String(
+                    "key".to_string(),
+                ))),
+                Box::new(item_type.clone()),
+            ),
+        ] {
+            let dif_type = DIFType {
+                name: None,
+                mutability: None,
+                type_definition,
+            };
+            let serialized = dif_type.as_json();
+            let deserialized: DIFType = DIFType::from_json(&serialized);
+            assert_eq!(dif_type, deserialized);
+        }
+    }
+
     #[test]
     fn object() {
         let dif_type = DIFType {
@@ -730,4 +943,52 @@ mod tests {
         println!("Deserialized DIFType: {:#?}", deserialized);
         assert_eq!(dif_type, deserialized);
     }
+
+    #[test]
+    fn local_impl_markers() {
+        use crate::runtime::global_context::{
+            GlobalContext, set_global_context,
+        };
+        use crate::values::core_values::endpoint::Endpoint;
+
+        set_global_context(GlobalContext::native());
+        let memory = RefCell::new(Memory::new(Endpoint::LOCAL));
+        let named = TypeReference::nominal(
+            Type::structural(StructuralTypeDefinition::Map(vec![])),
+            "Named".to_string(),
+            None,
+        )
+        .as_ref_cell();
+        let ty = TypeDefinition::ImplType(
+            Box::new(Type::structural(StructuralTypeDefinition::Map(vec![]))),
+            vec![named.clone()],
+        );
+
+        let dif_type = DIFTypeDefinition::from_type_definition(&ty, &memory);
+        let DIFTypeDefinition::ImplType(_, markers) = &dif_type else {
+            core::panic!("Expected an impl type");
+        };
+        // the local marker is registered in memory
+        assert_eq!(markers.len(), 1);
+        assert_eq!(named.borrow().pointer_address.as_ref(), Some(&markers[0]));
+
+        let TypeDefinition::ImplType(_, impls) =
+            dif_type.to_type_definition(&memory).unwrap()
+        else {
+            core::panic!("Expected an impl type");
+        };
+        assert!(TypeReference::is_same_declaration(&impls[0], &named));
+    }
+
+    #[test]
+    fn unknown_impl_marker() {
+        use crate::values::core_values::endpoint::Endpoint;
+
+        let memory = RefCell::new(Memory::new(Endpoint::LOCAL));
+        let dif_type = DIFTypeDefinition::ImplType(
+            Box::new(DIFType::from(DIFTypeRepresentation::Null)),
+            vec![PointerAddress::Local([1, 2, 3, 4, 5])],
+        );
+        assert!(dif_type.to_type_definition(&memory).is_err());
+    }
 }
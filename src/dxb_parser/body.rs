@@ -3,19 +3,22 @@ use crate::dxb_parser::next_instructions_stack::{
     NextInstructionType, NotInUnboundedRegularScopeError,
 };
 use crate::global::instruction_codes::InstructionCode;
+use crate::global::operators::CastMode;
 use crate::global::operators::assignment::AssignmentOperator;
 use crate::global::protocol_structures::instructions::{
-    ApplyData, DecimalData, Float32Data, Float64Data, FloatAsInt16Data,
-    FloatAsInt32Data, ImplTypeData, Instruction, InstructionBlockData,
-    Int8Data, Int16Data, Int32Data, Int64Data, Int128Data, IntegerData,
-    ListData, MapData, RawFullPointerAddress, RawInternalPointerAddress,
-    RegularInstruction, ShortListData, ShortMapData, ShortStatementsData,
-    ShortTextData, ShortTextDataRaw, SlotAddress, TextData, TextDataRaw,
-    TypeInstruction, TypeReferenceData, UInt8Data, UInt16Data, UInt32Data,
-    UInt64Data, UInt128Data, UnboundedStatementsData,
+    ApplyData, CollectionTypeData, DecimalData, Float32Data, Float64Data,
+    FloatAsInt16Data, FloatAsInt32Data, ImplTypeData, Instruction,
+    InstructionBlockData, Int8Data, Int16Data, Int32Data, Int64Data,
+    Int128Data, IntegerData, ListData, MapData, RawFullPointerAddress,
+    RawInternalPointerAddress, RegularInstruction, ShortListData, ShortMapData,
+    ShortStatementsData, ShortTextData, ShortTextDataRaw, SlotAddress,
+    TextData, TextDataRaw, TypeInstruction, TypeReferenceData, UInt8Data,
+    UInt16Data, UInt32Data, UInt64Data, UInt128Data, UnboundedStatementsData,
 };
 use crate::global::protocol_structures::instructions::{
-    RawLocalPointerAddress, StatementsData,
+    CallableTypeData, CombinedTypeData, NominalTypeData, ParameterTypeData,
+    RawLocalPointerAddress, StatementsData, StructuralTypeData,
+    StructuralTypeKind, TypeMetadata,
 };
 use crate::global::type_instruction_codes::TypeInstructionCode;
 use crate::runtime::execution::macros::yield_unwrap;
@@ -23,6 +26,7 @@ use crate::stdlib::rc::Rc;
 use crate::stdlib::string::FromUtf8Error;
 use crate::stdlib::string::String;
 use crate::stdlib::vec::Vec;
+use crate::values::core_values::callable::CallableKind;
 use crate::values::core_values::endpoint::Endpoint;
 use binrw::BinRead;
 use binrw::io::Cursor;
@@ -117,6 +121,15 @@ impl Display for DXBParserError {
 pub fn iterate_instructions(
     dxb_body_ref: Rc<RefCell<Vec<u8>>>,
 ) -> impl Iterator<Item = Result<Instruction, DXBParserError>> {
+    iterate_instructions_with_offsets(dxb_body_ref)
+        .map(|result| result.map(|(_, instruction)| instruction))
+}
+
+/// Same as iterate_instructions, but also yields the byte offset of each instruction in the DXB body.
+/// If more instructions are loaded into the DXB body, offsets start again at 0.
+pub fn iterate_instructions_with_offsets(
+    dxb_body_ref: Rc<RefCell<Vec<u8>>>,
+) -> impl Iterator<Item = Result<(usize, Instruction), DXBParserError>> {
     gen move {
         // create a stack to track next instructions
         let mut next_instructions_stack = NextInstructionsStack::default();
@@ -142,6 +155,7 @@ pub fn iterate_instructions(
                 return;
             }
 
+            let offset = reader.position() as usize;
             let next_instruction_type = next_instructions_stack.pop();
 
             // parse instruction based on its type
@@ -267,6 +281,9 @@ pub fn iterate_instructions(
                         InstructionCode::TRUE => RegularInstruction::True,
                         InstructionCode::FALSE => RegularInstruction::False,
                         InstructionCode::NULL => RegularInstruction::Null,
+                        InstructionCode::DEBUGGER => {
+                            RegularInstruction::Debugger
+                        }
 
                         // collections
                         InstructionCode::LIST => {
@@ -396,6 +413,11 @@ pub fn iterate_instructions(
                             RegularInstruction::GetPropertyDynamic
                         }
 
+                        InstructionCode::GET_PROPERTY_OPTIONAL => {
+                            next_instructions_stack.push_next_regular(2);
+                            RegularInstruction::GetPropertyOptional
+                        }
+
                         InstructionCode::SET_PROPERTY_TEXT => {
                             next_instructions_stack.push_next_regular(2);
                             let text_data = ShortTextDataRaw::read(&mut reader);
@@ -424,6 +446,14 @@ pub fn iterate_instructions(
                             next_instructions_stack.push_next_regular(1);
                             RegularInstruction::Deref
                         }
+                        InstructionCode::YEET => {
+                            next_instructions_stack.push_next_regular(1);
+                            RegularInstruction::Yeet
+                        }
+                        InstructionCode::TRY => {
+                            next_instructions_stack.push_next_regular(1);
+                            RegularInstruction::Try
+                        }
                         InstructionCode::SET_REFERENCE_VALUE => {
                             next_instructions_stack.push_next_regular(2);
                             let operator = yield_unwrap!(
@@ -474,6 +504,10 @@ pub fn iterate_instructions(
                             next_instructions_stack.push_next_regular(2);
                             RegularInstruction::Divide
                         }
+                        InstructionCode::DEFAULT => {
+                            next_instructions_stack.push_next_regular(2);
+                            RegularInstruction::Default
+                        }
 
                         InstructionCode::UNARY_MINUS => {
                             next_instructions_stack.push_next_regular(1);
@@ -509,6 +543,10 @@ pub fn iterate_instructions(
                             next_instructions_stack.push_next_regular(2);
                             RegularInstruction::Is
                         }
+                        InstructionCode::IMPLEMENTS => {
+                            next_instructions_stack.push_next_regular(2);
+                            RegularInstruction::Implements
+                        }
                         InstructionCode::MATCHES => {
                             next_instructions_stack.push_next_type(1); // type to match against
                             next_instructions_stack.push_next_regular(1); // value to check
@@ -596,6 +634,12 @@ pub fn iterate_instructions(
                             next_instructions_stack.push_next_type(1);
                             RegularInstruction::TypeExpression
                         }
+                        InstructionCode::TRANSFORM => {
+                            let mode = CastMode::read(&mut reader);
+                            next_instructions_stack.push_next_regular(1);
+                            next_instructions_stack.push_next_type(1);
+                            RegularInstruction::Transform(yield_unwrap!(mode))
+                        }
 
                         _ => {
                             return yield Err(
@@ -641,9 +685,104 @@ pub fn iterate_instructions(
                             TypeInstruction::LiteralText(TextData(text))
                         }
                         TypeInstructionCode::TYPE_WITH_IMPLS => {
-                            let impl_data = ImplTypeData::read(&mut reader);
+                            let impl_data =
+                                yield_unwrap!(ImplTypeData::read(&mut reader));
+                            // impl markers followed by the base type
+                            next_instructions_stack.push_next_type(
+                                impl_data.impl_count as u32 + 1,
+                            );
+                            TypeInstruction::ImplType(impl_data)
+                        }
+                        TypeInstructionCode::TYPE_COLLECTION => {
+                            let collection_data = yield_unwrap!(
+                                CollectionTypeData::read(&mut reader)
+                            );
+                            next_instructions_stack
+                                .push_next_type(collection_data.type_count());
+                            TypeInstruction::Collection(collection_data)
+                        }
+                        TypeInstructionCode::TYPE_STRUCTURAL => {
+                            let structural_data = yield_unwrap!(
+                                StructuralTypeData::read(&mut reader)
+                            );
+                            if structural_data.kind
+                                == StructuralTypeKind::Literal
+                            {
+                                next_instructions_stack.push_next_regular(1);
+                            } else {
+                                next_instructions_stack.push_next_type(
+                                    structural_data.type_count(),
+                                );
+                            }
+                            TypeInstruction::Structural(structural_data)
+                        }
+                        TypeInstructionCode::TYPE_NOMINAL => {
+                            let metadata =
+                                yield_unwrap!(TypeMetadata::read(&mut reader));
+                            let name =
+                                yield_unwrap!(read_short_text(&mut reader));
+                            let generic_parameters =
+                                yield_unwrap!(read_short_texts(&mut reader));
                             next_instructions_stack.push_next_type(1);
-                            TypeInstruction::ImplType(yield_unwrap!(impl_data))
+                            TypeInstruction::Nominal(NominalTypeData {
+                                metadata,
+                                name,
+                                generic_parameters,
+                            })
+                        }
+                        TypeInstructionCode::TYPE_PARAMETER => {
+                            let metadata =
+                                yield_unwrap!(TypeMetadata::read(&mut reader));
+                            let name =
+                                yield_unwrap!(read_short_text(&mut reader));
+                            TypeInstruction::Parameter(ParameterTypeData {
+                                metadata,
+                                name,
+                            })
+                        }
+                        TypeInstructionCode::TYPE_FUNCTION => {
+                            let callable_data = yield_unwrap!(
+                                read_callable_type_data(&mut reader)
+                            );
+                            next_instructions_stack
+                                .push_next_type(callable_data.type_count());
+                            TypeInstruction::Callable(callable_data)
+                        }
+                        TypeInstructionCode::TYPE_TYPE => {
+                            let metadata =
+                                yield_unwrap!(TypeMetadata::read(&mut reader));
+                            next_instructions_stack.push_next_type(1);
+                            TypeInstruction::Type(metadata)
+                        }
+                        TypeInstructionCode::TYPE_UNION
+                        | TypeInstructionCode::TYPE_INTERSECTION => {
+                            let combined_data = yield_unwrap!(
+                                CombinedTypeData::read(&mut reader)
+                            );
+                            next_instructions_stack
+                                .push_next_type(combined_data.count);
+                            if instruction_code
+                                == TypeInstructionCode::TYPE_UNION
+                            {
+                                TypeInstruction::Union(combined_data)
+                            } else {
+                                TypeInstruction::Intersection(combined_data)
+                            }
+                        }
+                        TypeInstructionCode::TYPE_UNIT => {
+                            TypeInstruction::Unit(yield_unwrap!(
+                                TypeMetadata::read(&mut reader)
+                            ))
+                        }
+                        TypeInstructionCode::TYPE_UNKNOWN => {
+                            TypeInstruction::Unknown(yield_unwrap!(
+                                TypeMetadata::read(&mut reader)
+                            ))
+                        }
+                        TypeInstructionCode::TYPE_NEVER => {
+                            TypeInstruction::Never(yield_unwrap!(
+                                TypeMetadata::read(&mut reader)
+                            ))
                         }
                         TypeInstructionCode::TYPE_REFERENCE => {
                             let ref_data = TypeReferenceData::read(&mut reader);
@@ -665,11 +804,65 @@ pub fn iterate_instructions(
 
             // println!("instruction {}", instruction);
 
-            yield Ok(instruction);
+            yield Ok((offset, instruction));
         }
     }
 }
 
+fn read_short_text(
+    mut reader: &mut Cursor<Vec<u8>>,
+) -> Result<String, DXBParserError> {
+    let raw_data = ShortTextDataRaw::read(&mut reader)?;
+    Ok(String::from_utf8(raw_data.text)?)
+}
+
+/// Reads a u8 count followed by the given number of short texts
+fn read_short_texts(
+    mut reader: &mut Cursor<Vec<u8>>,
+) -> Result<Vec<String>, DXBParserError> {
+    let count = u8::read(&mut reader)?;
+    (0..count).map(|_| read_short_text(reader)).collect()
+}
+
+/// Reads an optional parameter name, unnamed parameters have length 0
+fn read_optional_name(
+    reader: &mut Cursor<Vec<u8>>,
+) -> Result<Option<String>, DXBParserError> {
+    let name = read_short_text(reader)?;
+    Ok((!name.is_empty()).then_some(name))
+}
+
+fn read_callable_type_data(
+    mut reader: &mut Cursor<Vec<u8>>,
+) -> Result<CallableTypeData, DXBParserError> {
+    let metadata = TypeMetadata::read(&mut reader)?;
+    let kind = match u8::read(&mut reader)? {
+        0 => CallableKind::Function,
+        1 => CallableKind::Procedure,
+        kind => return Err(DXBParserError::InvalidBinaryCode(kind)),
+    };
+    let generic_parameters = read_short_texts(reader)?;
+    let parameter_count = u32::read_le(&mut reader)?;
+    let parameter_names = (0..parameter_count)
+        .map(|_| read_optional_name(reader))
+        .collect::<Result<Vec<_>, _>>()?;
+    let flags = u8::read(&mut reader)?;
+    let rest_parameter = if flags & 1 != 0 {
+        Some(read_optional_name(reader)?)
+    } else {
+        None
+    };
+    Ok(CallableTypeData {
+        metadata,
+        kind,
+        generic_parameters,
+        parameter_names,
+        rest_parameter,
+        has_return_type: flags & 2 != 0,
+        has_yeet_type: flags & 4 != 0,
+    })
+}
+
 fn get_next_regular_instruction_code(
     mut reader: &mut Cursor<Vec<u8>>,
 ) -> Result<InstructionCode, DXBParserError> {
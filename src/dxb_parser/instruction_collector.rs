@@ -1,5 +1,5 @@
 use crate::global::protocol_structures::instructions::{
-    Instruction, RegularInstruction, TypeInstruction,
+    Instruction, RegularInstruction, StructuralTypeKind, TypeInstruction,
 };
 use crate::stdlib::vec::Vec;
 
@@ -234,6 +234,20 @@ impl<T> ResultCollector<T> {
         }
     }
 
+    /// Returns the instruction that is collecting the results
+    pub fn instruction(&self) -> Option<&Instruction> {
+        match self {
+            ResultCollector::Full(collector) => collector.instruction.as_ref(),
+            ResultCollector::Last(collector) => collector.instruction.as_ref(),
+            ResultCollector::FullUnbounded(collector) => {
+                collector.instruction.as_ref()
+            }
+            ResultCollector::LastUnbounded(collector) => {
+                collector.instruction.as_ref()
+            }
+        }
+    }
+
     pub fn try_pop_unbounded(&mut self) -> Option<FullOrPartialResult<T>> {
         match self {
             ResultCollector::LastUnbounded(collector) => {
@@ -326,6 +340,11 @@ impl<T> InstructionCollector<T> {
         !self.result_collectors.is_empty()
     }
 
+    /// Returns the number of nested instructions that are currently collecting results.
+    pub fn depth(&self) -> usize {
+        self.result_collectors.len()
+    }
+
     pub fn push_result(&mut self, result: impl Into<T>) {
         let result = result.into();
         if let Some(result_collector) = self.result_collectors.last_mut() {
@@ -353,6 +372,17 @@ impl<T> InstructionCollector<T> {
         results
     }
 
+    /// Returns the depth index of the innermost collecting instruction
+    /// that matches the given predicate, e.g. the innermost try instruction.
+    pub fn find_innermost(
+        &self,
+        predicate: impl Fn(&Instruction) -> bool,
+    ) -> Option<usize> {
+        self.result_collectors.iter().rposition(|collector| {
+            collector.instruction().is_some_and(&predicate)
+        })
+    }
+
     pub fn last(&self) -> Option<&ResultCollector<T>> {
         self.result_collectors.last()
     }
@@ -464,12 +494,14 @@ impl<T> InstructionCollector<T> {
             | RegularInstruction::Subtract
             | RegularInstruction::Multiply
             | RegularInstruction::Divide
+            | RegularInstruction::Default
             | RegularInstruction::Matches
             | RegularInstruction::StructuralEqual
             | RegularInstruction::Equal
             | RegularInstruction::NotStructuralEqual
             | RegularInstruction::NotEqual
-            | RegularInstruction::Is => {
+            | RegularInstruction::Is
+            | RegularInstruction::Implements => {
                 self.collect_full(
                     Instruction::RegularInstruction(regular_instruction),
                     2,
@@ -482,6 +514,8 @@ impl<T> InstructionCollector<T> {
             | RegularInstruction::CreateRef
             | RegularInstruction::CreateRefMut
             | RegularInstruction::Deref
+            | RegularInstruction::Yeet
+            | RegularInstruction::Try
             | RegularInstruction::GetOrCreateRef(_)
             | RegularInstruction::GetOrCreateRefMut(_) => {
                 self.collect_full(
@@ -490,7 +524,8 @@ impl<T> InstructionCollector<T> {
                 );
                 None
             }
-            RegularInstruction::TypedValue => {
+            RegularInstruction::TypedValue
+            | RegularInstruction::Transform(_) => {
                 self.collect_full(
                     Instruction::RegularInstruction(regular_instruction),
                     2,
@@ -555,7 +590,8 @@ impl<T> InstructionCollector<T> {
                 None
             }
 
-            RegularInstruction::GetPropertyDynamic => {
+            RegularInstruction::GetPropertyDynamic
+            | RegularInstruction::GetPropertyOptional => {
                 self.collect_full(
                     Instruction::RegularInstruction(regular_instruction),
                     2,
@@ -601,14 +637,57 @@ impl<T> InstructionCollector<T> {
                 None
             }
             TypeInstruction::ImplType(impl_type_data) => {
+                let count = impl_type_data.impl_count as u32 + 1;
                 self.collect_full(
                     Instruction::TypeInstruction(TypeInstruction::ImplType(
                         impl_type_data,
                     )),
+                    count,
+                );
+                None
+            }
+            TypeInstruction::Collection(collection_data) => {
+                let count = collection_data.type_count();
+                self.collect_full(
+                    Instruction::TypeInstruction(TypeInstruction::Collection(
+                        collection_data,
+                    )),
+                    count,
+                );
+                None
+            }
+            TypeInstruction::Structural(structural_data) => {
+                // literal types are followed by a single value
+                let count = match structural_data.kind {
+                    StructuralTypeKind::Literal => 1,
+                    _ => structural_data.type_count(),
+                };
+                self.collect_full(
+                    Instruction::TypeInstruction(TypeInstruction::Structural(
+                        structural_data,
+                    )),
+                    count,
+                );
+                None
+            }
+            TypeInstruction::Nominal(nominal_data) => {
+                self.collect_full(
+                    Instruction::TypeInstruction(TypeInstruction::Nominal(
+                        nominal_data,
+                    )),
                     1,
                 );
                 None
             }
+            TypeInstruction::Union(ref combined_data)
+            | TypeInstruction::Intersection(ref combined_data) => {
+                let count = combined_data.count;
+                self.collect_full(
+                    Instruction::TypeInstruction(type_instruction),
+                    count,
+                );
+                None
+            }
 
             _ => Some(type_instruction),
         }
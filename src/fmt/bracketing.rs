@@ -46,10 +46,6 @@ impl<'a> Formatter<'a> {
 
         // handle Statements expression specially
         if let DatexExpressionData::Statements(statements) = &expression.data {
-            println!(
-                "DEBUG: Handling Statements bracketing: {:#?}",
-                statements
-            );
             return
                 // brackets definitely needed because multiple statements or terminated
                 if statements.statements.len() > 1 || statements.is_terminated {
@@ -95,6 +91,7 @@ impl<'a> Formatter<'a> {
             BinaryOperator::Logical(lop) => match lop {
                 LogicalOperator::And => (5, Assoc::Left, false),
                 LogicalOperator::Or => (4, Assoc::Left, false),
+                LogicalOperator::NullCoalescing => (3, Assoc::Left, true),
             },
             // fallback
             _ => (1, Assoc::None, false),
@@ -38,9 +38,9 @@ impl<'a> Formatter<'a> {
             DatexExpressionData::Text(t) => self.text_to_source_code(t),
             DatexExpressionData::Endpoint(e) => a.text(e.to_string()),
             DatexExpressionData::Null => a.text("null"),
-            DatexExpressionData::Identifier(l) => unreachable!(
-                "Identifiers should have been resolved before formatting"
-            ),
+            DatexExpressionData::Debugger => a.text("debugger"),
+            // identifiers are only unresolved if the script could not be precompiled
+            DatexExpressionData::Identifier(name) => a.text(name),
             DatexExpressionData::Map(map) => self.map_to_source_code(map),
             DatexExpressionData::List(list) => self.list_to_source_code(list),
             DatexExpressionData::CreateRef(create_ref) => {
@@ -150,7 +150,8 @@ impl<'a> Formatter<'a> {
                 name,
                 ..
             }) => a.text(name),
-            e => core::panic!("Formatter not implemented for {:?}", e),
+            // expressions without dedicated formatting are kept as written
+            _ => a.text(self.tokens_at(&expr.span)),
         }
     }
 
@@ -1,11 +1,13 @@
 use core::ops::Range;
 
-use crate::ast::expressions::{DatexExpression, VariableAccess};
+use crate::ast::expressions::{
+    DatexExpression, DatexExpressionData, Statements, VariableAccess,
+};
 use crate::ast::type_expressions::{
-    CallableTypeExpression, TypeExpression, TypeExpressionData,
-    TypeVariantAccess,
+    TypeExpression, TypeExpressionData, TypeVariantAccess,
 };
-use crate::parser::ParserOptions;
+use crate::parser::errors::SpannedParserError;
+use crate::parser::{Parser, ParserOptions};
 use crate::{
     compiler::precompiler::precompiled_ast::RichAst,
     compiler::{CompileOptions, parse_datex_script_to_rich_ast_simple_error},
@@ -50,25 +52,129 @@ pub struct ParentContext<'a> {
     operation: Operation<'a>,
 }
 
+/// Returns true if the script might contain comments
+fn contains_comments(script: &str) -> bool {
+    script.contains("//") || script.contains("/*")
+}
+
+/// A formatted region of a script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormattedRegion {
+    /// byte range of the region in the original script
+    pub span: Range<usize>,
+    pub formatted: String,
+}
+
 impl<'a> Formatter<'a> {
     pub fn new(script: &'a str, options: FormattingOptions) -> Self {
-        let ast = parse_datex_script_to_rich_ast_simple_error(
+        Self::try_new(script, options).expect("Failed to parse Datex script")
+    }
+
+    /// Creates a formatter for the given script, returning the first parser error
+    /// if the script is invalid.
+    /// Scripts that can be parsed but not precompiled (e.g. because of undeclared
+    /// variables) are formatted without precompilation.
+    pub fn try_new(
+        script: &'a str,
+        options: FormattingOptions,
+    ) -> Result<Self, SpannedParserError> {
+        let parser_options = ParserOptions {
+            // Preserve scoping information for accurate formatting
+            preserve_scoping: true,
+        };
+        let ast = match parse_datex_script_to_rich_ast_simple_error(
             script,
             &mut CompileOptions {
-                // Preserve scoping information for accurate formatting
-                parser_options: ParserOptions {
-                    preserve_scoping: true,
-                },
+                parser_options: parser_options.clone(),
                 ..Default::default()
             },
-        )
-        .expect("Failed to parse Datex script");
-        Self {
+        ) {
+            Ok(ast) => ast,
+            Err(_) => RichAst::new_without_metadata(Parser::parse(
+                script,
+                parser_options,
+            )?),
+        };
+        Ok(Self {
             ast,
             script,
             options,
             alloc: RcAllocator,
+        })
+    }
+
+    /// Formats a complete script. If the script contains parser errors or comments,
+    /// which are not preserved by the formatter, only the top-level statements
+    /// that are not affected are formatted (see [Formatter::format_regions]).
+    pub fn format_document(
+        script: &str,
+        options: &FormattingOptions,
+    ) -> Vec<FormattedRegion> {
+        if !contains_comments(script)
+            && let Ok(formatter) = Formatter::try_new(script, options.clone())
+        {
+            // trailing whitespace (e.g. the final newline) is kept as written
+            let end = script.trim_end().len();
+            let formatted = formatter.render();
+            return if formatted != script[..end] {
+                vec![FormattedRegion {
+                    span: 0..end,
+                    formatted,
+                }]
+            } else {
+                vec![]
+            };
         }
+        Self::format_regions(script, options, None)
+    }
+
+    /// Formats all top-level statements of a script that are not affected by parser errors
+    /// and do not contain comments, leaving all other parts of the script as written.
+    /// If a range is given, only statements within the range are formatted.
+    /// Only regions whose formatting differs from the original script are returned.
+    pub fn format_regions(
+        script: &str,
+        options: &FormattingOptions,
+        range: Option<&Range<usize>>,
+    ) -> Vec<FormattedRegion> {
+        let (ast, errors) = Parser::parse_collecting(
+            script,
+            ParserOptions {
+                preserve_scoping: true,
+            },
+        )
+        .into_ast_and_errors();
+        let DatexExpressionData::Statements(Statements { statements, .. }) =
+            ast.data
+        else {
+            return vec![];
+        };
+        statements
+            .into_iter()
+            .filter(|statement| {
+                let span = &statement.span;
+                !matches!(statement.data, DatexExpressionData::Recover)
+                    && !span.is_empty()
+                    && range.is_none_or(|range| {
+                        range.start <= span.start && span.end <= range.end
+                    })
+                    && !errors.iter().any(|error| {
+                        error.span.start < span.end
+                            && span.start < error.span.end
+                    })
+            })
+            .filter_map(|statement| {
+                let source = script
+                    .get(statement.span.clone())
+                    .filter(|source| !contains_comments(source))?;
+                let formatted =
+                    Formatter::try_new(source, options.clone()).ok()?.render();
+                (formatted != source).then_some(FormattedRegion {
+                    span: statement.span,
+                    formatted,
+                })
+            })
+            .collect()
     }
 
     fn tokens_at(&self, span: &Range<usize>) -> &'a str {
@@ -76,7 +182,29 @@ impl<'a> Formatter<'a> {
     }
 
     pub fn render(&self) -> String {
-        self.render_expression(&self.ast.ast)
+        let rendered = self.render_expression(&self.ast.ast);
+        if self.options.indent_with_tabs && self.options.indent > 0 {
+            self.indent_with_tabs(&rendered)
+        } else {
+            rendered
+        }
+    }
+
+    /// Replaces the leading indentation spaces of all lines with tabs
+    fn indent_with_tabs(&self, rendered: &str) -> String {
+        rendered
+            .split('\n')
+            .map(|line| {
+                let spaces = line.len() - line.trim_start_matches(' ').len();
+                let tabs = spaces / self.options.indent;
+                format!(
+                    "{}{}",
+                    "\t".repeat(tabs),
+                    &line[tabs * self.options.indent..]
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
     /// Renders a DatexExpression into a source code string.
@@ -126,7 +254,6 @@ impl<'a> Formatter<'a> {
         type_expr: &'a TypeExpression,
     ) -> Format<'a> {
         let a = &self.alloc;
-        println!("formatting type expression: {:?}", type_expr);
         match &type_expr.data {
             TypeExpressionData::VariantAccess(TypeVariantAccess {
                 name,
@@ -176,11 +303,26 @@ impl<'a> Formatter<'a> {
                 self.wrap_collection(docs, ("[", "]"), ",")
             }
 
-            TypeExpressionData::FixedSizeList(list) => {
-                core::todo!("#627 Undescribed by author.")
+            // Fixed size lists — `[T; N]`
+            TypeExpressionData::FixedSizeList(fixed_size_list) => {
+                a.text("[")
+                    + self.format_type_expression(&fixed_size_list.ty)
+                    + a.text(";")
+                    + (self.options.space_in_collection.then(|| a.space()))
+                    + a.text(fixed_size_list.size.to_string())
+                    + a.text("]")
             }
-            TypeExpressionData::SliceList(_) => {
-                core::todo!("#628 Undescribed by author.")
+
+            // Slice lists — `T[]`
+            TypeExpressionData::SliceList(slice_list) => {
+                let item = self.format_type_expression(&slice_list.0);
+                match slice_list.0.data {
+                    TypeExpressionData::Union(_)
+                    | TypeExpressionData::Intersection(_) => {
+                        a.text("(") + item + a.text(")[]")
+                    }
+                    _ => item + a.text("[]"),
+                }
             }
 
             // Intersection: `A & B & C`
@@ -193,28 +335,23 @@ impl<'a> Formatter<'a> {
                 self.wrap_type_collection(&items.0, "|")
             }
 
-            TypeExpressionData::GenericAccess(access) => {
-                core::todo!("#629 Undescribed by author.")
+            // Impl type: `T + A + B`
+            TypeExpressionData::ImplType(impl_type) => self
+                .wrap_type_collection(
+                    core::iter::once(impl_type.base.as_ref())
+                        .chain(&impl_type.interfaces),
+                    "+",
+                ),
+
+            // TODO #629: format generic access
+            TypeExpressionData::GenericAccess(_) => {
+                a.text(self.tokens_at(&type_expr.span))
             }
 
             // Callable type, e.g. `function (x: integer, y: text) -> boolean`
-            TypeExpressionData::Callable(CallableTypeExpression {
-                kind,
-                parameter_types,
-                rest_parameter_type,
-                return_type,
-                yeet_type,
-            }) => {
-                // TODO #630: handle full signature
-                let params = parameter_types.iter().map(|(name, ty)| {
-                    a.text(name.clone().unwrap_or_else(|| "_".to_string()))
-                        + self.type_declaration_colon()
-                        + self.format_type_expression(ty)
-                });
-                let params_doc =
-                    RcDoc::intersperse(params, a.text(",") + a.space());
-                let arrow = self.operator_with_spaces(a.text("->"));
-                todo!("#631 Undescribed by author.")
+            // TODO #630, #631: format the full signature
+            TypeExpressionData::Callable(_) => {
+                a.text(self.tokens_at(&type_expr.span))
             }
 
             TypeExpressionData::StructuralMap(items) => {
@@ -234,7 +371,7 @@ impl<'a> Formatter<'a> {
     /// Wraps a collection of type expressions with a specified operator.
     fn wrap_type_collection(
         &'a self,
-        list: &'a [TypeExpression],
+        list: impl IntoIterator<Item = &'a TypeExpression>,
         op: &'a str,
     ) -> Format<'a> {
         let a = &self.alloc;
@@ -247,7 +384,9 @@ impl<'a> Formatter<'a> {
         };
 
         // Format all type expressions
-        let docs = list.iter().map(|expr| self.format_type_expression(expr));
+        let docs = list
+            .into_iter()
+            .map(|expr| self.format_type_expression(expr));
 
         // Combine elements with operator between
         a.nil().append(
@@ -418,6 +557,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn list_types() {
+        let expr = "var x: [integer;5] | text[] = 1;";
+        assert_eq!(
+            to_string(expr, FormattingOptions::default()),
+            "var x: [integer; 5] | text[] = 1;"
+        );
+        assert_eq!(
+            to_string(expr, FormattingOptions::compact()),
+            "var x:[integer;5]|text[]=1;"
+        );
+
+        let expr = "var x: (text|null)[] = 1;";
+        assert_eq!(
+            to_string(expr, FormattingOptions::default()),
+            "var x: (text | null)[] = 1;"
+        );
+    }
+
     #[test]
     fn variable_declaration() {
         let expr = "var x: &mut integer/u8 = 42;";
@@ -525,6 +683,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn undeclared_variables() {
+        assert_eq!(to_string("x+y", FormattingOptions::default()), "x + y");
+    }
+
+    #[test]
+    fn indent_with_tabs() {
+        assert_eq!(
+            to_string(
+                "[1,2,3]",
+                FormattingOptions {
+                    max_width: 4,
+                    indent_with_tabs: true,
+                    ..Default::default()
+                }
+            ),
+            "[\n\t1,\n\t2,\n\t3,\n]"
+        );
+    }
+
+    #[test]
+    fn format_regions() {
+        let script = "const a=1;\nconst b = 1 +;\na+2";
+        let regions = Formatter::format_regions(
+            script,
+            &FormattingOptions::default(),
+            None,
+        );
+        assert_eq!(
+            regions,
+            vec![
+                FormattedRegion {
+                    span: 0..9,
+                    formatted: "const a = 1".to_string()
+                },
+                FormattedRegion {
+                    span: 26..29,
+                    formatted: "a + 2".to_string()
+                },
+            ]
+        );
+
+        let regions = Formatter::format_regions(
+            script,
+            &FormattingOptions::default(),
+            Some(&(20..script.len())),
+        );
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].span, 26..29);
+    }
+
+    #[test]
+    fn format_document() {
+        let options = FormattingOptions::default();
+        assert_eq!(
+            Formatter::format_document("1+2;x", &options),
+            vec![FormattedRegion {
+                span: 0..5,
+                formatted: "1 + 2;\nx".to_string()
+            }]
+        );
+        assert_eq!(Formatter::format_document("1 + 2", &options), vec![]);
+        // the final newline is kept
+        assert_eq!(
+            Formatter::format_document("1+2\n", &options),
+            vec![FormattedRegion {
+                span: 0..3,
+                formatted: "1 + 2".to_string()
+            }]
+        );
+        assert_eq!(Formatter::format_document("1 + 2\n", &options), vec![]);
+        // comments are kept
+        assert_eq!(
+            Formatter::format_document("1+2; // three\n3*4", &options),
+            vec![
+                FormattedRegion {
+                    span: 0..3,
+                    formatted: "1 + 2".to_string()
+                },
+                FormattedRegion {
+                    span: 14..17,
+                    formatted: "3 * 4".to_string()
+                },
+            ]
+        );
+    }
+
     fn to_string(script: &str, options: FormattingOptions) -> String {
         let formatter = Formatter::new(script, options);
         formatter.render()
@@ -1,8 +1,14 @@
+use core::fmt::Display;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FormattingOptions {
     /// Number of spaces to use for indentation.
     pub indent: usize,
 
+    /// Whether to indent with tabs instead of spaces.
+    /// Each tab replaces `indent` spaces.
+    pub indent_with_tabs: bool,
+
     /// Maximum line width before wrapping occurs.
     pub max_width: usize,
 
@@ -85,6 +91,7 @@ impl Default for FormattingOptions {
     fn default() -> Self {
         FormattingOptions {
             indent: 4,
+            indent_with_tabs: false,
             max_width: 40,
             variant_formatting: VariantFormatting::KeepAll,
             trailing_comma: true,
@@ -102,6 +109,7 @@ impl FormattingOptions {
     pub fn compact() -> Self {
         FormattingOptions {
             indent: 2,
+            indent_with_tabs: false,
             max_width: 40,
             variant_formatting: VariantFormatting::WithoutSuffix,
             trailing_comma: false,
@@ -114,3 +122,199 @@ impl FormattingOptions {
         }
     }
 }
+
+/// Error in the formatting section of a project config
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormattingConfigError {
+    /// The line with the given number is not a `key = value` pair
+    InvalidLine(usize),
+    UnknownOption(String),
+    /// Invalid value for the option with the given name
+    InvalidValue(String, String),
+}
+
+impl Display for FormattingConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FormattingConfigError::InvalidLine(line) => {
+                core::write!(f, "Invalid config line {}", line)
+            }
+            FormattingConfigError::UnknownOption(name) => {
+                core::write!(f, "Unknown formatting option '{}'", name)
+            }
+            FormattingConfigError::InvalidValue(name, value) => {
+                core::write!(
+                    f,
+                    "Invalid value '{}' for formatting option '{}'",
+                    value,
+                    name
+                )
+            }
+        }
+    }
+}
+
+impl FormattingOptions {
+    /// Applies the options of the `[fmt]` section of a `datex.toml` project config, e.g.
+    /// ```toml
+    /// [fmt]
+    /// indent = 2
+    /// trailing_comma = false
+    /// statement_formatting = "space_between"
+    /// ```
+    /// All other sections are ignored.
+    pub fn apply_project_config(
+        &mut self,
+        config: &str,
+    ) -> Result<(), FormattingConfigError> {
+        let mut in_fmt_section = false;
+        for (index, line) in config.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with('[') {
+                in_fmt_section = line == "[fmt]";
+                continue;
+            }
+            if !in_fmt_section {
+                continue;
+            }
+            let (name, value) = line
+                .split_once('=')
+                .ok_or(FormattingConfigError::InvalidLine(index + 1))?;
+            self.set_option(name.trim(), value.trim().trim_matches('"'))?;
+        }
+        Ok(())
+    }
+
+    fn set_option(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<(), FormattingConfigError> {
+        let invalid_value = || {
+            FormattingConfigError::InvalidValue(
+                name.to_string(),
+                value.to_string(),
+            )
+        };
+        let usize_value =
+            || value.parse::<usize>().map_err(|_| invalid_value());
+        let bool_value = || value.parse::<bool>().map_err(|_| invalid_value());
+        match name {
+            "indent" => self.indent = usize_value()?,
+            "indent_with_tabs" => self.indent_with_tabs = bool_value()?,
+            "max_width" => self.max_width = usize_value()?,
+            "trailing_comma" => self.trailing_comma = bool_value()?,
+            "spaced_collections" => self.spaced_collections = bool_value()?,
+            "space_in_collection" => self.space_in_collection = bool_value()?,
+            "spaces_around_operators" => {
+                self.spaces_around_operators = bool_value()?
+            }
+            "type_declaration_formatting" => {
+                self.type_declaration_formatting = match value {
+                    "compact" => TypeDeclarationFormatting::Compact,
+                    "space_around_colon" => {
+                        TypeDeclarationFormatting::SpaceAroundColon
+                    }
+                    "space_after_colon" => {
+                        TypeDeclarationFormatting::SpaceAfterColon
+                    }
+                    _ => return Err(invalid_value()),
+                }
+            }
+            "statement_formatting" => {
+                self.statement_formatting = match value {
+                    "newline_between" => StatementFormatting::NewlineBetween,
+                    "space_between" => StatementFormatting::SpaceBetween,
+                    "compact" => StatementFormatting::Compact,
+                    _ => return Err(invalid_value()),
+                }
+            }
+            "variant_formatting" => {
+                self.variant_formatting = match value {
+                    "keep_all" => VariantFormatting::KeepAll,
+                    "with_suffix" => VariantFormatting::WithSuffix,
+                    "without_suffix" => VariantFormatting::WithoutSuffix,
+                    _ => return Err(invalid_value()),
+                }
+            }
+            "bracket_style" => {
+                self.bracket_style = match value {
+                    "keep_all" => BracketStyle::KeepAll,
+                    "remove_duplicate" => BracketStyle::RemoveDuplicate,
+                    "minimal" => BracketStyle::Minimal,
+                    _ => return Err(invalid_value()),
+                }
+            }
+            _ => {
+                return Err(FormattingConfigError::UnknownOption(
+                    name.to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Removes a trailing comment from a line of the project config.
+/// A `#` inside a quoted value does not start a comment.
+fn strip_comment(line: &str) -> &str {
+    let mut in_quotes = false;
+    for (index, char) in line.char_indices() {
+        match char {
+            '"' => in_quotes = !in_quotes,
+            '#' if !in_quotes => return &line[..index],
+            _ => {}
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_config() {
+        let mut options = FormattingOptions::default();
+        options
+            .apply_project_config(
+                r#"
+                [package]
+                indent = 8
+
+                [fmt]
+                indent = 2 # comment
+                trailing_comma = false
+                bracket_style = "keep_all"
+                "#,
+            )
+            .unwrap();
+        assert_eq!(options.indent, 2);
+        assert!(!options.trailing_comma);
+        assert_eq!(options.bracket_style, BracketStyle::KeepAll);
+
+        assert_eq!(
+            options.apply_project_config("[fmt]\nindent = two"),
+            Err(FormattingConfigError::InvalidValue(
+                "indent".to_string(),
+                "two".to_string()
+            ))
+        );
+        assert_eq!(
+            options.apply_project_config(
+                "[fmt]\nbracket_style = \"keep#all\" # comment"
+            ),
+            Err(FormattingConfigError::InvalidValue(
+                "bracket_style".to_string(),
+                "keep#all".to_string()
+            ))
+        );
+        assert_eq!(
+            options.apply_project_config("[fmt]\nwidth = 2"),
+            Err(FormattingConfigError::UnknownOption("width".to_string()))
+        );
+    }
+}
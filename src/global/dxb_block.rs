@@ -13,7 +13,6 @@ use binrw::{BinRead, BinWrite};
 use core::fmt::Display;
 use core::prelude::rust_2024::*;
 use core::result::Result;
-use core::unimplemented;
 use log::error;
 use strum::Display;
 use thiserror::Error;
@@ -351,16 +350,22 @@ impl DXBBlock {
     }
 
     /// Get a list of all receiver endpoints from the routing header.
+    /// Pointer receivers cannot be resolved from the block alone and return
+    /// an empty list - use `ComHub::resolve_receiver_endpoints` instead.
     pub fn receiver_endpoints(&self) -> Vec<Endpoint> {
         match self.routing_header.receivers() {
             Receivers::Endpoints(endpoints) => endpoints,
             Receivers::EndpointsWithKeys(endpoints_with_keys) => {
                 endpoints_with_keys.into_iter().map(|(e, _)| e).collect()
             }
-            Receivers::PointerId(_) => unimplemented!(),
-            _ => Vec::new(),
+            Receivers::PointerId(_) | Receivers::None => Vec::new(),
         }
     }
+
+    /// Returns true if the block is addressed to a pointer
+    pub fn has_pointer_receiver(&self) -> bool {
+        core::matches!(self.routing_header.receivers(), Receivers::PointerId(_))
+    }
     pub fn receivers(&self) -> Receivers {
         self.routing_header.receivers()
     }
@@ -406,11 +411,14 @@ impl DXBBlock {
 
     /// Returns true if the block has a fixed number of receivers
     /// without wildcard instances, and no @@any receiver.
+    /// Blocks with a pointer receiver never have an exact receiver count,
+    /// since the receivers are only resolved at routing time.
     pub fn has_exact_receiver_count(&self) -> bool {
-        !self
-            .receiver_endpoints()
-            .iter()
-            .any(|e| e.is_broadcast() || e.is_any())
+        !self.has_pointer_receiver()
+            && !self
+                .receiver_endpoints()
+                .iter()
+                .any(|e| e.is_broadcast() || e.is_any())
     }
 
     pub fn clone_with_new_receivers<T>(&self, new_receivers: T) -> DXBBlock
@@ -272,6 +272,9 @@ pub enum InstructionCode {
     COLLAPSE,              // collapse x
     RESPONSE,              // response x
     CLONE_COLLAPSE,        // collapse
+    GET_PROPERTY_OPTIONAL, // x?.y, get property with arbitrary key value, null if x is null
+    YEET,                  // yeet x
+    TRY,                   // try x
 }
 
 #[cfg(test)]
@@ -4,7 +4,7 @@ use crate::stdlib::string::ToString;
 use core::fmt::Display;
 use core::prelude::rust_2024::*;
 
-#[derive(Clone, Debug, PartialEq, Copy)]
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum BinaryOperator {
     Arithmetic(ArithmeticOperator),
     Logical(LogicalOperator),
@@ -64,10 +64,11 @@ impl From<&ArithmeticOperator> for InstructionCode {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Copy)]
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum LogicalOperator {
-    And, // and
-    Or,  // or
+    And,            // and
+    Or,             // or
+    NullCoalescing, // ??
 }
 
 impl From<&LogicalOperator> for InstructionCode {
@@ -75,6 +76,7 @@ impl From<&LogicalOperator> for InstructionCode {
         match op {
             LogicalOperator::And => InstructionCode::AND,
             LogicalOperator::Or => InstructionCode::OR,
+            LogicalOperator::NullCoalescing => InstructionCode::DEFAULT,
         }
     }
 }
@@ -87,12 +89,13 @@ impl Display for LogicalOperator {
             match self {
                 LogicalOperator::And => "and",
                 LogicalOperator::Or => "or",
+                LogicalOperator::NullCoalescing => "??",
             }
         )
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Copy)]
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum BitwiseOperator {
     And, // &
     Or,  // |
@@ -186,6 +189,9 @@ impl From<&InstructionCode> for BinaryOperator {
                 BinaryOperator::Logical(LogicalOperator::And)
             }
             InstructionCode::OR => BinaryOperator::Logical(LogicalOperator::Or),
+            InstructionCode::DEFAULT => {
+                BinaryOperator::Logical(LogicalOperator::NullCoalescing)
+            }
             InstructionCode::UNION => {
                 BinaryOperator::Bitwise(BitwiseOperator::And)
             }
@@ -218,6 +224,9 @@ impl From<&RegularInstruction> for BinaryOperator {
             RegularInstruction::Divide => {
                 BinaryOperator::Arithmetic(ArithmeticOperator::Divide)
             }
+            RegularInstruction::Default => {
+                BinaryOperator::Logical(LogicalOperator::NullCoalescing)
+            }
             _ => {
                 core::todo!(
                     "#155 Binary operator for instruction {:?} not implemented",
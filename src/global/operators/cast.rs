@@ -0,0 +1,30 @@
+use binrw::{BinRead, BinWrite};
+use core::fmt::Display;
+use core::prelude::rust_2024::*;
+
+/// Determines how a value is converted if it does not fit into the target type
+/// of a type cast (`as`).
+#[derive(BinRead, BinWrite, Clone, Debug, PartialEq, Copy, Default)]
+#[brw(little, repr(u8))]
+pub enum CastMode {
+    /// The cast fails if the value can not be represented by the target type,
+    /// e.g. `300 as integer/u8`
+    #[default]
+    Checked = 0,
+    /// The value is clamped to the bounds of the target type,
+    /// e.g. `300 as saturating integer/u8` is `255u8`
+    Saturating = 1,
+}
+
+impl Display for CastMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(
+            f,
+            "{}",
+            match self {
+                CastMode::Checked => "checked",
+                CastMode::Saturating => "saturating",
+            }
+        )
+    }
+}
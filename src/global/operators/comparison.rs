@@ -7,6 +7,7 @@ use core::prelude::rust_2024::*;
 pub enum ComparisonOperator {
     Is,                 // is
     Matches,            // matches FIXME remove from here
+    Implements,         // implements
     StructuralEqual,    // ==
     NotStructuralEqual, // !=
     Equal,              // ===
@@ -25,6 +26,7 @@ impl Display for ComparisonOperator {
             match self {
                 ComparisonOperator::Is => "is",
                 ComparisonOperator::Matches => "matches",
+                ComparisonOperator::Implements => "implements",
                 ComparisonOperator::StructuralEqual => "==",
                 ComparisonOperator::NotStructuralEqual => "!=",
                 ComparisonOperator::Equal => "===",
@@ -51,6 +53,7 @@ impl From<&ComparisonOperator> for InstructionCode {
             ComparisonOperator::NotEqual => InstructionCode::NOT_EQUAL,
             ComparisonOperator::Is => InstructionCode::IS,
             ComparisonOperator::Matches => InstructionCode::MATCHES,
+            ComparisonOperator::Implements => InstructionCode::IMPLEMENTS,
             operator => core::todo!(
                 "Comparison operator {:?} not implemented for InstructionCode",
                 operator
@@ -77,6 +80,7 @@ impl From<&RegularInstruction> for ComparisonOperator {
             RegularInstruction::NotEqual => ComparisonOperator::NotEqual,
             RegularInstruction::Is => ComparisonOperator::Is,
             RegularInstruction::Matches => ComparisonOperator::Matches,
+            RegularInstruction::Implements => ComparisonOperator::Implements,
             _ => {
                 core::todo!(
                     "Comparison operator for instruction {:?} not implemented",
@@ -7,6 +7,8 @@ pub mod comparison;
 pub use comparison::ComparisonOperator;
 
 pub mod assignment;
+pub mod cast;
+pub use cast::CastMode;
 pub mod unary;
 
 pub use unary::{
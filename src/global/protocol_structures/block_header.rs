@@ -16,6 +16,8 @@ pub enum BlockType {
     Hello = 2,
     Trace = 3,
     TraceBack = 4,
+    /// Cancels a running execution in the context of the block
+    Cancel = 5,
 }
 
 impl BlockType {
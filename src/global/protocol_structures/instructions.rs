@@ -1,7 +1,9 @@
 use crate::global::operators::AssignmentOperator;
+use crate::global::operators::CastMode;
 use crate::global::type_instruction_codes::TypeMutabilityCode;
 use crate::stdlib::string::String;
 use crate::stdlib::vec::Vec;
+use crate::values::core_values::callable::CallableKind;
 use crate::values::core_values::decimal::Decimal;
 use crate::values::core_values::endpoint::EndpointParsingError;
 use crate::values::core_values::integer::Integer;
@@ -86,6 +88,7 @@ pub enum RegularInstruction {
     True,
     False,
     Null,
+    Debugger,
     Statements(StatementsData),
     ShortStatements(StatementsData),
     UnboundedStatements,
@@ -103,6 +106,7 @@ pub enum RegularInstruction {
     Subtract,
     Multiply,
     Divide,
+    Default,
 
     // unary operator
     // TODO #432 add missing unary operators
@@ -120,9 +124,11 @@ pub enum RegularInstruction {
     GetPropertyIndex(UInt32Data),
     SetPropertyIndex(UInt32Data),
     GetPropertyDynamic,
+    GetPropertyOptional,
 
     // comparison operator
     Is,
+    Implements,
     Matches,
     StructuralEqual,
     Equal,
@@ -158,8 +164,12 @@ pub enum RegularInstruction {
     SetReferenceValue(AssignmentOperator),
     Deref,
 
+    Yeet,
+    Try,
+
     TypedValue,
     TypeExpression,
+    Transform(CastMode),
 }
 
 impl Display for RegularInstruction {
@@ -246,6 +256,7 @@ impl Display for RegularInstruction {
             RegularInstruction::True => core::write!(f, "TRUE"),
             RegularInstruction::False => core::write!(f, "FALSE"),
             RegularInstruction::Null => core::write!(f, "NULL"),
+            RegularInstruction::Debugger => core::write!(f, "DEBUGGER"),
             RegularInstruction::Statements(data) => {
                 core::write!(f, "STATEMENTS {}", data.statements_count)
             }
@@ -281,6 +292,7 @@ impl Display for RegularInstruction {
             RegularInstruction::Subtract => core::write!(f, "SUBTRACT"),
             RegularInstruction::Multiply => core::write!(f, "MULTIPLY"),
             RegularInstruction::Divide => core::write!(f, "DIVIDE"),
+            RegularInstruction::Default => core::write!(f, "DEFAULT"),
 
             // equality checks
             RegularInstruction::StructuralEqual => {
@@ -292,6 +304,7 @@ impl Display for RegularInstruction {
             }
             RegularInstruction::NotEqual => core::write!(f, "NOT_EQUAL"),
             RegularInstruction::Is => core::write!(f, "IS"),
+            RegularInstruction::Implements => core::write!(f, "IMPLEMENTS"),
             RegularInstruction::Matches => core::write!(f, "MATCHES"),
 
             RegularInstruction::AllocateSlot(address) => {
@@ -313,6 +326,8 @@ impl Display for RegularInstruction {
                 core::write!(f, "SET_REFERENCE_VALUE ({})", operator)
             }
             RegularInstruction::Deref => core::write!(f, "DEREF"),
+            RegularInstruction::Yeet => core::write!(f, "YEET"),
+            RegularInstruction::Try => core::write!(f, "TRY"),
             RegularInstruction::GetRef(address) => {
                 core::write!(
                     f,
@@ -379,6 +394,9 @@ impl Display for RegularInstruction {
             RegularInstruction::UnaryPlus => core::write!(f, "+"),
             RegularInstruction::BitwiseNot => core::write!(f, "BITWISE_NOT"),
             RegularInstruction::TypedValue => core::write!(f, "TYPED_VALUE"),
+            RegularInstruction::Transform(mode) => {
+                core::write!(f, "TRANSFORM ({})", mode)
+            }
             RegularInstruction::TypeExpression => {
                 core::write!(f, "TYPE_EXPRESSION")
             }
@@ -397,6 +415,9 @@ impl Display for RegularInstruction {
             RegularInstruction::GetPropertyDynamic => {
                 core::write!(f, "GET_PROPERTY_DYNAMIC")
             }
+            RegularInstruction::GetPropertyOptional => {
+                core::write!(f, "GET_PROPERTY_OPTIONAL")
+            }
             RegularInstruction::SetPropertyDynamic => {
                 core::write!(f, "SET_PROPERTY_DYNAMIC")
             }
@@ -411,6 +432,18 @@ pub enum TypeInstruction {
     LiteralText(TextData),
     LiteralInteger(IntegerData),
     List(ListData),
+    Collection(CollectionTypeData),
+    Structural(StructuralTypeData),
+    Nominal(NominalTypeData),
+    Parameter(ParameterTypeData),
+    Callable(CallableTypeData),
+    Union(CombinedTypeData),
+    Intersection(CombinedTypeData),
+    /// a nested type, e.g. the inner type of &mut &integer
+    Type(TypeMetadata),
+    Unit(TypeMetadata),
+    Unknown(TypeMetadata),
+    Never(TypeMetadata),
     // TODO #670: add more type instructions
 }
 
@@ -437,6 +470,62 @@ impl Display for TypeInstruction {
             TypeInstruction::ImplType(data) => {
                 core::write!(f, "IMPL_TYPE ({} impls)", data.impl_count)
             }
+            TypeInstruction::Collection(data) => {
+                core::write!(
+                    f,
+                    "COLLECTION_TYPE mutability: {}, kind: {}",
+                    data.metadata.mutability,
+                    data.kind
+                )
+            }
+            TypeInstruction::Structural(data) => {
+                core::write!(
+                    f,
+                    "STRUCTURAL_TYPE mutability: {}, kind: {}",
+                    data.metadata.mutability,
+                    data.kind
+                )
+            }
+            TypeInstruction::Nominal(data) => {
+                core::write!(
+                    f,
+                    "NOMINAL_TYPE mutability: {}, name: {}",
+                    data.metadata.mutability,
+                    data.name
+                )?;
+                if !data.generic_parameters.is_empty() {
+                    core::write!(
+                        f,
+                        "<{}>",
+                        data.generic_parameters.join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+            TypeInstruction::Parameter(data) => {
+                core::write!(f, "PARAMETER_TYPE {}", data.name)
+            }
+            TypeInstruction::Callable(data) => {
+                core::write!(
+                    f,
+                    "CALLABLE_TYPE mutability: {}, kind: {} ({} parameters)",
+                    data.metadata.mutability,
+                    data.kind,
+                    data.parameter_names.len()
+                )
+            }
+            TypeInstruction::Type(data) => {
+                core::write!(f, "TYPE mutability: {}", data.mutability)
+            }
+            TypeInstruction::Union(data) => {
+                core::write!(f, "UNION_TYPE ({} types)", data.count)
+            }
+            TypeInstruction::Intersection(data) => {
+                core::write!(f, "INTERSECTION_TYPE ({} types)", data.count)
+            }
+            TypeInstruction::Unit(_) => core::write!(f, "UNIT_TYPE"),
+            TypeInstruction::Unknown(_) => core::write!(f, "UNKNOWN_TYPE"),
+            TypeInstruction::Never(_) => core::write!(f, "NEVER_TYPE"),
         }
     }
 }
@@ -669,8 +758,6 @@ pub struct ApplyData {
 pub struct ImplTypeData {
     pub metadata: TypeMetadata,
     pub impl_count: u8,
-    #[br(count = impl_count)]
-    pub impls: Vec<RawPointerAddress>,
 }
 
 #[derive(BinRead, BinWrite, Clone, Debug, PartialEq)]
@@ -680,6 +767,155 @@ pub struct TypeReferenceData {
     pub address: RawPointerAddress,
 }
 
+#[derive(BinRead, BinWrite, Clone, Debug, PartialEq)]
+#[brw(little)]
+pub struct CollectionTypeData {
+    pub metadata: TypeMetadata,
+    pub kind: CollectionTypeKind,
+}
+
+impl CollectionTypeData {
+    /// Number of nested types that follow the collection type instruction
+    pub fn type_count(&self) -> u32 {
+        match self.kind {
+            CollectionTypeKind::List
+            | CollectionTypeKind::FixedSizeList { .. } => 1,
+            CollectionTypeKind::Map => 2,
+            CollectionTypeKind::Instance { argument_count } => {
+                argument_count + 1
+            }
+        }
+    }
+}
+
+#[derive(BinRead, BinWrite, Clone, Debug, PartialEq)]
+#[brw(little)]
+pub enum CollectionTypeKind {
+    // e.g. integer[], followed by the item type
+    #[brw(magic = 0u8)]
+    List,
+    // e.g. [integer; 5], followed by the item type
+    #[brw(magic = 1u8)]
+    FixedSizeList { size: u32 },
+    // e.g. Map<text, integer>, followed by the key and value types
+    #[brw(magic = 2u8)]
+    Map,
+    // e.g. Box<integer>, followed by the base type and the argument types
+    #[brw(magic = 3u8)]
+    Instance { argument_count: u32 },
+}
+
+impl Display for CollectionTypeKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CollectionTypeKind::List => core::write!(f, "LIST"),
+            CollectionTypeKind::FixedSizeList { size } => {
+                core::write!(f, "FIXED_SIZE_LIST {}", size)
+            }
+            CollectionTypeKind::Map => core::write!(f, "MAP"),
+            CollectionTypeKind::Instance { argument_count } => {
+                core::write!(f, "INSTANCE {}", argument_count)
+            }
+        }
+    }
+}
+
+#[derive(BinRead, BinWrite, Clone, Debug, PartialEq)]
+#[brw(little)]
+pub struct StructuralTypeData {
+    pub metadata: TypeMetadata,
+    pub kind: StructuralTypeKind,
+}
+
+impl StructuralTypeData {
+    /// Number of nested types that follow the structural type instruction
+    pub fn type_count(&self) -> u32 {
+        match self.kind {
+            StructuralTypeKind::List { count } => count,
+            StructuralTypeKind::Map { count } => count * 2,
+            StructuralTypeKind::Literal => 0,
+        }
+    }
+}
+
+#[derive(BinRead, BinWrite, Clone, Debug, PartialEq)]
+#[brw(little)]
+pub enum StructuralTypeKind {
+    // e.g. [integer, text], followed by the item types
+    #[brw(magic = 0u8)]
+    List { count: u32 },
+    // e.g. {a: integer}, followed by the key and value type of each field
+    #[brw(magic = 1u8)]
+    Map { count: u32 },
+    // e.g. 42 or "text", followed by the literal value
+    #[brw(magic = 2u8)]
+    Literal,
+}
+
+impl Display for StructuralTypeKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StructuralTypeKind::List { count } => {
+                core::write!(f, "LIST {}", count)
+            }
+            StructuralTypeKind::Map { count } => {
+                core::write!(f, "MAP {}", count)
+            }
+            StructuralTypeKind::Literal => core::write!(f, "LITERAL"),
+        }
+    }
+}
+
+/// A nominal type declaration, e.g. `type User = {...}`, followed by the
+/// type definition
+#[derive(Clone, Debug, PartialEq)]
+pub struct NominalTypeData {
+    pub metadata: TypeMetadata,
+    pub name: String,
+    /// names of the generic type parameters, e.g. T for `type Box<T> = {...}`
+    pub generic_parameters: Vec<String>,
+}
+
+/// A generic type parameter, e.g. T in `type Box<T> = { value: T }`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParameterTypeData {
+    pub metadata: TypeMetadata,
+    pub name: String,
+}
+
+/// A callable type, e.g. `function (x: integer) -> text`, followed by the
+/// parameter types, the rest parameter type, the return type and the
+/// yeet type, if present
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallableTypeData {
+    pub metadata: TypeMetadata,
+    pub kind: CallableKind,
+    pub generic_parameters: Vec<String>,
+    pub parameter_names: Vec<Option<String>>,
+    /// name of the rest parameter, if the callable has a rest parameter
+    pub rest_parameter: Option<Option<String>>,
+    pub has_return_type: bool,
+    pub has_yeet_type: bool,
+}
+
+impl CallableTypeData {
+    /// Number of nested types that follow the callable type instruction
+    pub fn type_count(&self) -> u32 {
+        self.parameter_names.len() as u32
+            + self.rest_parameter.is_some() as u32
+            + self.has_return_type as u32
+            + self.has_yeet_type as u32
+    }
+}
+
+/// A union or intersection type, followed by `count` types
+#[derive(BinRead, BinWrite, Clone, Debug, PartialEq)]
+#[brw(little)]
+pub struct CombinedTypeData {
+    pub metadata: TypeMetadata,
+    pub count: u32,
+}
+
 #[derive(BinRead, BinWrite, Clone, Debug, PartialEq)]
 #[brw(little)]
 pub struct TypeMetadata {
@@ -36,6 +36,8 @@ pub enum TypeInstructionCode {
     TYPE_LITERAL_TEXT,
     TYPE_LITERAL_SHORT_TEXT,
     TYPE_STRUCT,
+    TYPE_NOMINAL,
+    TYPE_PARAMETER,
 
     // TODO #427: Do we need std_type for optimization purpose?
     // Rename to CORE_ and implement if required
@@ -73,9 +75,16 @@ impl From<&TypeDefinition> for TypeInstructionCode {
             TypeDefinition::ImplType(_, _) => {
                 TypeInstructionCode::TYPE_WITH_IMPLS
             }
+            // local nominal types are sent together with their definition
+            TypeDefinition::Reference(reference)
+                if reference.borrow().pointer_address.is_none() =>
+            {
+                TypeInstructionCode::TYPE_NOMINAL
+            }
             TypeDefinition::Reference(_) => TypeInstructionCode::TYPE_REFERENCE,
             TypeDefinition::Unit => TypeInstructionCode::TYPE_UNIT,
             TypeDefinition::Unknown => TypeInstructionCode::TYPE_UNKNOWN,
+            TypeDefinition::Parameter(_) => TypeInstructionCode::TYPE_PARAMETER,
             TypeDefinition::Never => TypeInstructionCode::TYPE_NEVER,
             TypeDefinition::Structural(_) => {
                 TypeInstructionCode::TYPE_STRUCTURAL
@@ -90,7 +99,7 @@ impl From<&TypeDefinition> for TypeInstructionCode {
             TypeDefinition::Collection(_) => {
                 TypeInstructionCode::TYPE_COLLECTION
             }
-            TypeDefinition::Type(_) => unreachable!(), // TODO #668: nested types
+            TypeDefinition::Type(_) => TypeInstructionCode::TYPE_TYPE,
         }
     }
 }
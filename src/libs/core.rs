@@ -84,6 +84,7 @@ pub enum CoreLibPointerId {
     Unit,                                // #core.Unit
     Never,                               // #core.never
     Unknown,                             // #core.unknown
+    Error,                               // #core.error
     Print, // #core.print (function, might be removed later)
 }
 
@@ -106,6 +107,7 @@ impl CoreLibPointerId {
             CoreLibPointerId::Never => 13,
             CoreLibPointerId::Unknown => 14,
             CoreLibPointerId::Print => 15,
+            CoreLibPointerId::Error => 16,
             CoreLibPointerId::Integer(None) => Self::INTEGER_BASE,
             CoreLibPointerId::Integer(Some(v)) => {
                 let v: u8 = (*v).into();
@@ -134,6 +136,7 @@ impl CoreLibPointerId {
             13 => Some(CoreLibPointerId::Never),
             14 => Some(CoreLibPointerId::Unknown),
             15 => Some(CoreLibPointerId::Print),
+            16 => Some(CoreLibPointerId::Error),
 
             Self::INTEGER_BASE => Some(CoreLibPointerId::Integer(None)),
             n if (Self::INTEGER_BASE + 1..Self::DECIMAL_BASE).contains(&n) => {
@@ -164,7 +167,7 @@ impl From<CoreLibPointerId> for PointerAddress {
 
 impl TryFrom<&PointerAddress> for CoreLibPointerId {
     type Error = String;
-    fn try_from(address: &PointerAddress) -> Result<Self, Self::Error> {
+    fn try_from(address: &PointerAddress) -> Result<Self, String> {
         match address {
             PointerAddress::Internal(id_bytes) => {
                 let mut id_array = [0u8; 4];
@@ -223,6 +226,17 @@ pub fn get_core_lib_value(
     })
 }
 
+/// Returns the ids of all types and values in the core library.
+pub fn get_core_lib_ids() -> Vec<CoreLibPointerId> {
+    with_full_core_lib(|core_lib_types, core_lib_values| {
+        core_lib_types
+            .keys()
+            .chain(core_lib_values.keys())
+            .cloned()
+            .collect()
+    })
+}
+
 pub fn get_core_lib_type_definition(
     id: impl Into<CoreLibPointerId>,
 ) -> TypeDefinition {
@@ -281,18 +295,21 @@ pub fn load_core_lib(memory: &mut Memory) {
 pub fn create_core_lib_types() -> HashMap<CoreLibPointerId, Type> {
     let integer = integer();
     let decimal = decimal();
+    let text = text();
+    let unknown = unknown();
     vec![
         ty(),
-        text(),
+        text.clone(),
         list(),
         boolean(),
         endpoint(),
         unit(),
         never(),
-        unknown(),
+        unknown.clone(),
         map(),
         null(),
         callable(),
+        error(&text.1, &unknown.1),
     ]
     .into_iter()
     .chain(once(integer.clone()))
@@ -372,6 +389,22 @@ pub fn text() -> CoreLibTypeDefinition {
     create_core_type("text", None, None, CoreLibPointerId::Text)
 }
 
+/// The error type, e.g. error("Something went wrong") or
+/// error({message: "Something went wrong", payload: 42}).
+/// Error values are maps with a message and an arbitrary payload.
+pub fn error(text: &Type, unknown: &Type) -> CoreLibTypeDefinition {
+    let (id, ty) =
+        create_core_type("error", None, None, CoreLibPointerId::Error);
+    if let TypeDefinition::Reference(reference) = &ty.type_definition {
+        reference.borrow_mut().type_value =
+            Type::structural(StructuralTypeDefinition::Map(vec![
+                (Type::structural("message"), text.clone()),
+                (Type::structural("payload"), unknown.clone()),
+            ]));
+    }
+    (id, ty)
+}
+
 pub fn integer() -> CoreLibTypeDefinition {
     create_core_type("integer", None, None, CoreLibPointerId::Integer(None))
 }
@@ -396,6 +429,7 @@ pub fn print() -> (CoreLibPointerId, ValueContainer) {
             Some("print".to_string()),
             CallableSignature {
                 kind: CallableKind::Function,
+                generic_parameters: vec![],
                 parameter_types: vec![],
                 rest_parameter_type: Some((
                     Some("values".to_string()),
@@ -483,6 +517,7 @@ fn create_core_type(
                     type_definition: TypeDefinition::Unit,
                 },
                 pointer_address: Some(PointerAddress::from(pointer_id)),
+                generic_parameters: Vec::new(),
             }))),
             None,
         ),
@@ -512,6 +547,7 @@ mod tests {
         assert!(has_core_lib_type(CoreLibPointerId::Unit));
         assert!(has_core_lib_type(CoreLibPointerId::Never));
         assert!(has_core_lib_type(CoreLibPointerId::Unknown));
+        assert!(has_core_lib_type(CoreLibPointerId::Error));
         for variant in IntegerTypeVariant::iter() {
             assert!(has_core_lib_type(CoreLibPointerId::Integer(Some(
                 variant
@@ -656,29 +692,60 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
-    /// Generates a TypeScript mapping of core type addresses to their names.
-    /// Run this test and copy the output into `src/dif/definitions.ts`.
-    ///
-    /// `cargo test create_core_type_ts_mapping -- --show-output --ignored`
-    fn create_core_type_ts_mapping() {
-        let core_lib = create_core_lib_types();
-        let mut core_lib: Vec<(CoreLibPointerId, PointerAddress)> = core_lib
-            .keys()
-            .map(|key| (key.clone(), PointerAddress::from(key.clone())))
-            .collect();
-        core_lib.sort_by_key(|(key, _)| {
-            PointerAddress::from(key.clone()).bytes().to_vec()
-        });
-
-        println!("export const CoreTypeAddress = {{");
-        for (core_lib_id, address) in core_lib {
-            println!(
-                "    {}: \"{}\",",
-                core_lib_id.to_string().replace("/", "_"),
-                address.to_string().strip_prefix("$").unwrap()
-            );
+    fn core_type_typescript_mapping() {
+        use crate::types::error::TypeExportError;
+        use crate::types::export::typescript::TypeScriptExporter;
+
+        let mut exporter = TypeScriptExporter::new();
+        let core_lib_ids =
+            create_core_lib_types().into_keys().sorted_by_key(|id| {
+                PointerAddress::from(id.clone()).bytes().to_vec()
+            });
+        for core_lib_id in core_lib_ids {
+            let result =
+                exporter.export_type(&get_core_lib_type(core_lib_id.clone()));
+            match &core_lib_id {
+                // types without a DIF representation
+                CoreLibPointerId::Core
+                | CoreLibPointerId::Type
+                | CoreLibPointerId::Callable
+                | CoreLibPointerId::Unit
+                | CoreLibPointerId::Print => assert_eq!(
+                    result,
+                    Err(TypeExportError::UnsupportedType(
+                        core_lib_id.to_string()
+                    ))
+                ),
+                _ => assert!(result.is_ok(), "{core_lib_id} was not exported"),
+            }
         }
-        println!("}} as const;");
+
+        let mut export = |id: CoreLibPointerId| {
+            exporter.export_type(&get_core_lib_type(id)).unwrap()
+        };
+        assert_eq!(export(CoreLibPointerId::Text), "string");
+        assert_eq!(export(CoreLibPointerId::Boolean), "boolean");
+        assert_eq!(
+            export(CoreLibPointerId::Integer(Some(IntegerTypeVariant::I32))),
+            "number"
+        );
+        // big integers are represented as strings in DIF
+        assert_eq!(
+            export(CoreLibPointerId::Integer(Some(IntegerTypeVariant::U64))),
+            "string"
+        );
+        assert_eq!(
+            export(CoreLibPointerId::List),
+            "DIFValueContainer<unknown>[]"
+        );
+        assert_eq!(export(CoreLibPointerId::Error), "error");
+        // only core types with a type definition are exported as declarations
+        assert_eq!(
+            exporter.finish(),
+            indoc::indoc! {"
+                export type DIFValueContainer<T> = { value: T; type?: unknown } | string;
+                export type error = { message: DIFValueContainer<string>; payload: DIFValueContainer<unknown> };"
+            }
+        );
     }
 }
@@ -0,0 +1,598 @@
+use crate::ast::expressions::{
+    CreateRef, DatexExpression, DatexExpressionData, DerefAssignment, Map,
+    VariableDeclaration, VariableKind,
+};
+use crate::ast::resolved_variable::VariableId;
+use crate::collections::HashMap;
+use crate::compiler::error::{CompilerError, SpannedCompilerError};
+use crate::lsp::LanguageServerBackend;
+use crate::lsp::references::is_valid_identifier;
+use crate::lsp::symbol_collector::{SymbolCollector, SymbolOccurrenceKind};
+use crate::lsp::type_hint_collector::TypeHintCollector;
+use crate::lsp::utils::{line_starts, position_to_offset};
+use crate::references::reference::ReferenceMutability;
+use crate::type_inference::error::TypeError;
+use crate::types::definition::TypeDefinition;
+use crate::types::structural_type_definition::StructuralTypeDefinition;
+use crate::values::core_values::r#type::Type;
+use crate::visitor::VisitAction;
+use crate::visitor::expression::ExpressionVisitor;
+use crate::visitor::type_expression::TypeExpressionVisitor;
+use core::ops::Range;
+use realhydroper_lsp::lsp_types::{
+    CodeAction, CodeActionKind, Diagnostic, TextEdit, WorkspaceEdit,
+};
+use url::Url;
+
+/// Collects all variable declarations and deref assignments in an AST
+#[derive(Default)]
+struct DeclarationCollector {
+    declarations: Vec<(VariableDeclaration, Range<usize>)>,
+    deref_assignments: Vec<(DerefAssignment, Range<usize>)>,
+}
+
+impl DeclarationCollector {
+    fn declaration(
+        &self,
+        id: VariableId,
+    ) -> Option<&(VariableDeclaration, Range<usize>)> {
+        self.declarations
+            .iter()
+            .find(|(declaration, _)| declaration.id == Some(id))
+    }
+}
+
+impl TypeExpressionVisitor<()> for DeclarationCollector {}
+
+impl ExpressionVisitor<()> for DeclarationCollector {
+    fn visit_variable_declaration(
+        &mut self,
+        var_decl: &mut VariableDeclaration,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        self.declarations.push((var_decl.clone(), span.clone()));
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_deref_assignment(
+        &mut self,
+        deref_assignment: &mut DerefAssignment,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        self.deref_assignments
+            .push((deref_assignment.clone(), span.clone()));
+        Ok(VisitAction::VisitChildren)
+    }
+}
+
+/// A code action with text edits in a single file
+struct FileCodeAction {
+    title: String,
+    kind: CodeActionKind,
+    edits: Vec<(Range<usize>, String)>,
+    /// span of the error that is fixed by the action
+    error_span: Option<Range<usize>>,
+}
+
+impl FileCodeAction {
+    fn quick_fix(
+        title: String,
+        edits: Vec<(Range<usize>, String)>,
+        error_span: &Range<usize>,
+    ) -> Self {
+        FileCodeAction {
+            title,
+            kind: CodeActionKind::QUICKFIX,
+            edits,
+            error_span: Some(error_span.clone()),
+        }
+    }
+}
+
+/// Returns the source code of a default value of the given type, e.g. `0` for integer,
+/// or None if no default value is known
+fn default_value_source(ty: &Type) -> Option<String> {
+    Some(match &ty.type_definition {
+        TypeDefinition::Structural(structural) => match structural {
+            StructuralTypeDefinition::Map(entries) => {
+                let entries = entries
+                    .iter()
+                    .map(|(key, value)| {
+                        Some(format!(
+                            "{}: {}",
+                            map_key_source(key)?,
+                            default_value_source(value)?
+                        ))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                format!("{{{}}}", entries.join(", "))
+            }
+            StructuralTypeDefinition::List(_) => "[]".to_string(),
+            StructuralTypeDefinition::Null => "null".to_string(),
+            StructuralTypeDefinition::Text(text) => text_source(&text.0),
+            literal => literal.to_string(),
+        },
+        TypeDefinition::Reference(reference) => {
+            let reference = reference.borrow();
+            match reference.nominal_type_declaration.as_ref() {
+                Some(declaration) => match declaration.name.as_str() {
+                    "integer" => "0".to_string(),
+                    "decimal" => "0.0".to_string(),
+                    "text" => "\"\"".to_string(),
+                    "boolean" => "false".to_string(),
+                    "null" => "null".to_string(),
+                    _ => default_value_source(&reference.type_value)?,
+                },
+                None => default_value_source(&reference.type_value)?,
+            }
+        }
+        TypeDefinition::Type(ty) => default_value_source(ty)?,
+        TypeDefinition::Union(types) => {
+            types.iter().find_map(default_value_source)?
+        }
+        _ => return None,
+    })
+}
+
+fn text_source(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Returns the source code of a map key of the given type
+fn map_key_source(key: &Type) -> Option<String> {
+    match &key.type_definition {
+        TypeDefinition::Structural(StructuralTypeDefinition::Text(text)) => {
+            Some(if is_valid_identifier(&text.0) {
+                text.0.clone()
+            } else {
+                text_source(&text.0)
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the required map keys of a structural map type
+fn map_type_keys(ty: &Type) -> Option<Vec<(String, Type)>> {
+    match &ty.type_definition {
+        TypeDefinition::Structural(StructuralTypeDefinition::Map(entries)) => {
+            entries
+                .iter()
+                .map(|(key, value)| Some((map_key_source(key)?, value.clone())))
+                .collect()
+        }
+        TypeDefinition::Reference(reference) => {
+            map_type_keys(&reference.borrow().type_value)
+        }
+        TypeDefinition::Type(ty) => map_type_keys(ty),
+        _ => None,
+    }
+}
+
+/// Returns the keys of a map expression that are written as text or identifier
+fn map_expression_keys(content: &str, map: &Map) -> Vec<String> {
+    map.entries
+        .iter()
+        .filter_map(|(key, _)| match &key.data {
+            DatexExpressionData::Text(text) => Some(text.clone()),
+            _ => content.get(key.span.clone()).map(str::to_string),
+        })
+        .collect()
+}
+
+/// Creates the edit that replaces an immutable `&` with `&mut`, or prefixes
+/// the expression with `&mut`
+fn mutable_reference_edit(
+    expression: &DatexExpression,
+) -> (Range<usize>, String) {
+    match &expression.data {
+        DatexExpressionData::CreateRef(CreateRef {
+            mutability: ReferenceMutability::Immutable,
+            expression: inner,
+        }) => (expression.span.start..inner.span.start, "&mut ".to_string()),
+        _ => (
+            expression.span.start..expression.span.start,
+            "&mut ".to_string(),
+        ),
+    }
+}
+
+impl LanguageServerBackend {
+    /// Returns the quick fixes for all errors in the given range of a file,
+    /// as well as refactorings for the declarations in the range
+    pub fn get_code_actions(
+        &self,
+        url: &Url,
+        range: realhydroper_lsp::lsp_types::Range,
+        diagnostics: &[Diagnostic],
+    ) -> Vec<CodeAction> {
+        let mut workspace = self.compiler_workspace.borrow_mut();
+        let Some(file) = workspace.get_file_mut(url) else {
+            return vec![];
+        };
+        let content = &file.content;
+        // positions after the end of the document are clamped to its end
+        let line_starts = line_starts(content);
+        let [start, end] = [range.start, range.end].map(|position| {
+            position_to_offset(content, &line_starts, position)
+        });
+        let Some(rich_ast) = &mut file.rich_ast else {
+            return vec![];
+        };
+
+        let mut declarations = DeclarationCollector::default();
+        let _ = declarations.visit_datex_expression(&mut rich_ast.ast);
+        let mut symbols = SymbolCollector::new(content);
+        let _ = symbols.visit_datex_expression(&mut rich_ast.ast);
+        let mut type_hints = TypeHintCollector::new(content);
+        let _ = type_hints.visit_datex_expression(&mut rich_ast.ast);
+
+        let overlaps =
+            |span: &Range<usize>| span.start <= end && start <= span.end;
+        let mut actions = Vec::new();
+
+        for error in file.errors.iter().flat_map(|errors| &errors.errors) {
+            if let Some(span) = &error.span
+                && overlaps(span)
+            {
+                actions.extend(Self::quick_fixes(
+                    content,
+                    error,
+                    span,
+                    &declarations,
+                    &symbols,
+                ));
+            }
+        }
+
+        // add missing type annotations from the inferred types
+        let metadata = rich_ast.metadata.borrow();
+        for hint in type_hints.type_hints {
+            if !overlaps(&hint.declaration_span) {
+                continue;
+            }
+            if let Some(var_type) = metadata
+                .variables
+                .get(hint.id)
+                .and_then(|variable| variable.var_type.as_ref())
+            {
+                actions.push(FileCodeAction {
+                    title: format!("Add type annotation `: {}`", var_type),
+                    kind: CodeActionKind::REFACTOR_REWRITE,
+                    edits: vec![(
+                        hint.position..hint.position,
+                        format!(": {}", var_type),
+                    )],
+                    error_span: None,
+                });
+            }
+        }
+
+        actions
+            .into_iter()
+            .map(|action| {
+                self.to_code_action(url, content, action, diagnostics)
+            })
+            .collect()
+    }
+
+    fn quick_fixes(
+        content: &str,
+        error: &SpannedCompilerError,
+        span: &Range<usize>,
+        declarations: &DeclarationCollector,
+        symbols: &SymbolCollector,
+    ) -> Vec<FileCodeAction> {
+        let find_declaration_of_assigned = |name: &str| {
+            let occurrence = symbols.occurrences.iter().find(|occurrence| {
+                occurrence.kind == SymbolOccurrenceKind::Write
+                    && occurrence.name == name
+                    && span.start <= occurrence.span.start
+                    && occurrence.span.end <= span.end
+            })?;
+            declarations.declaration(occurrence.id)
+        };
+
+        match &error.error {
+            CompilerError::UndeclaredVariable(name) => {
+                // declare the variable in a new line before the statement
+                let line_start =
+                    content[..span.start].rfind('\n').map_or(0, |i| i + 1);
+                let indentation = content[line_start..]
+                    .chars()
+                    .take_while(|c| *c == ' ' || *c == '\t')
+                    .collect::<String>();
+                let statement_start = line_start + indentation.len();
+                vec![FileCodeAction::quick_fix(
+                    format!("Declare variable `{}`", name),
+                    vec![(
+                        statement_start..statement_start,
+                        format!("var {} = null;\n{}", name, indentation),
+                    )],
+                    span,
+                )]
+            }
+            CompilerError::AssignmentToConst(name)
+            | CompilerError::TypeError(TypeError::AssignmentToConstant(name)) =>
+            {
+                let Some((declaration, declaration_span)) =
+                    find_declaration_of_assigned(name)
+                else {
+                    return vec![];
+                };
+                if declaration.kind != VariableKind::Const {
+                    return vec![];
+                }
+                let keyword_start = declaration_span.start
+                    + content[declaration_span.clone()]
+                        .find("const")
+                        .unwrap_or(0);
+                vec![FileCodeAction::quick_fix(
+                    format!("Change `const {}` to `var {}`", name, name),
+                    vec![(
+                        keyword_start..keyword_start + "const".len(),
+                        "var".to_string(),
+                    )],
+                    span,
+                )]
+            }
+            CompilerError::TypeError(
+                TypeError::AssignmentToImmutableReference(_)
+                | TypeError::InvalidDerefType(_),
+            ) => {
+                // make the referenced variable a mutable reference
+                let Some(declaration) = declarations
+                    .deref_assignments
+                    .iter()
+                    .find(|(_, deref_span)| deref_span == span)
+                    .and_then(|(deref_assignment, _)| match &deref_assignment
+                        .deref_expression
+                        .data
+                    {
+                        DatexExpressionData::VariableAccess(access) => {
+                            declarations.declaration(access.id)
+                        }
+                        _ => None,
+                    })
+                else {
+                    return vec![];
+                };
+                vec![FileCodeAction::quick_fix(
+                    format!("Wrap value of `{}` in `&mut`", declaration.0.name),
+                    vec![mutable_reference_edit(
+                        &declaration.0.init_expression,
+                    )],
+                    span,
+                )]
+            }
+            CompilerError::TypeError(TypeError::AssignmentTypeMismatch {
+                annotated_type,
+                assigned_type,
+            }) => {
+                let Some((declaration, _)) = declarations
+                    .declarations
+                    .iter()
+                    .find(|(_, declaration_span)| declaration_span == span)
+                else {
+                    return vec![];
+                };
+                let init_expression = &declaration.init_expression;
+                let mut actions = Vec::new();
+
+                if annotated_type.reference_mutability()
+                    == Some(ReferenceMutability::Mutable)
+                    && assigned_type.reference_mutability()
+                        != Some(ReferenceMutability::Mutable)
+                {
+                    actions.push(FileCodeAction::quick_fix(
+                        "Wrap value in `&mut`".to_string(),
+                        vec![mutable_reference_edit(init_expression)],
+                        span,
+                    ));
+                }
+
+                if let DatexExpressionData::Map(map) = &init_expression.data
+                    && let Some(required_keys) = map_type_keys(annotated_type)
+                {
+                    let existing_keys = map_expression_keys(content, map);
+                    let missing_entries = required_keys
+                        .iter()
+                        .filter(|(key, _)| !existing_keys.contains(key))
+                        .map(|(key, ty)| {
+                            format!(
+                                "{}: {}",
+                                key,
+                                default_value_source(ty)
+                                    .unwrap_or_else(|| "null".to_string())
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    if !missing_entries.is_empty() {
+                        let (position, text) = match map.entries.last() {
+                            Some((_, value)) => (
+                                value.span.end,
+                                format!(", {}", missing_entries.join(", ")),
+                            ),
+                            None => (
+                                init_expression.span.start + 1,
+                                missing_entries.join(", "),
+                            ),
+                        };
+                        actions.push(FileCodeAction::quick_fix(
+                            format!(
+                                "Add missing {} {}",
+                                if missing_entries.len() == 1 {
+                                    "key"
+                                } else {
+                                    "keys"
+                                },
+                                required_keys
+                                    .iter()
+                                    .filter(|(key, _)| {
+                                        !existing_keys.contains(key)
+                                    })
+                                    .map(|(key, _)| format!("`{}`", key))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                            vec![(position..position, text)],
+                            span,
+                        ));
+                    }
+                }
+                actions
+            }
+            _ => vec![],
+        }
+    }
+
+    fn to_code_action(
+        &self,
+        url: &Url,
+        content: &String,
+        action: FileCodeAction,
+        diagnostics: &[Diagnostic],
+    ) -> CodeAction {
+        let edits = action
+            .edits
+            .into_iter()
+            .map(|(span, new_text)| TextEdit {
+                range: self
+                    .convert_byte_range_to_document_range(&span, content),
+                new_text,
+            })
+            .collect();
+        let diagnostics = action.error_span.map(|span| {
+            let range =
+                self.convert_byte_range_to_document_range(&span, content);
+            diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.range == range)
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+        CodeAction {
+            title: action.title,
+            is_preferred: Some(action.kind == CodeActionKind::QUICKFIX),
+            kind: Some(action.kind),
+            diagnostics,
+            edit: Some(WorkspaceEdit::new(HashMap::from([(
+                url.clone(),
+                edits,
+            )]))),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::test_utils::{service, url};
+    use realhydroper_lsp::lsp_types::Position;
+
+    /// Returns the titles of all code actions in the whole file together with
+    /// the content after applying each action
+    fn code_actions(content: &str) -> Vec<(String, String)> {
+        let url = url("main.dx");
+        let service = service(&url, content);
+        let range = realhydroper_lsp::lsp_types::Range::new(
+            Position::new(0, 0),
+            Position::new(content.lines().count() as u32, 0),
+        );
+        service
+            .inner()
+            .get_code_actions(&url, range, &[])
+            .into_iter()
+            .map(|action| {
+                let edits = action.edit.unwrap().changes.unwrap()[&url].clone();
+                (action.title, apply_edits(content, edits))
+            })
+            .collect()
+    }
+
+    fn apply_edits(content: &str, mut edits: Vec<TextEdit>) -> String {
+        edits.sort_by_key(|edit| edit.range.start);
+        let mut result = content.to_string();
+        for edit in edits.into_iter().rev() {
+            let start = LanguageServerBackend::line_char_to_byte_index(
+                content,
+                edit.range.start.line as usize,
+                edit.range.start.character as usize,
+            )
+            .unwrap();
+            let end = LanguageServerBackend::line_char_to_byte_index(
+                content,
+                edit.range.end.line as usize,
+                edit.range.end.character as usize,
+            )
+            .unwrap();
+            result.replace_range(start..end, &edit.new_text);
+        }
+        result
+    }
+
+    fn find_action(actions: &[(String, String)], title: &str) -> String {
+        actions
+            .iter()
+            .find(|(action_title, _)| action_title == title)
+            .unwrap_or_else(|| panic!("no action {title} in {actions:?}"))
+            .1
+            .clone()
+    }
+
+    #[test]
+    fn declare_undeclared_variable() {
+        let actions = code_actions("var a = 1;\n  a + x");
+        assert_eq!(
+            find_action(&actions, "Declare variable `x`"),
+            "var a = 1;\n  var x = null;\n  a + x"
+        );
+    }
+
+    #[test]
+    fn change_const_to_var() {
+        let actions = code_actions("const a = 1;\na = 2");
+        assert_eq!(
+            find_action(&actions, "Change `const a` to `var a`"),
+            "var a = 1;\na = 2"
+        );
+    }
+
+    #[test]
+    fn add_type_annotation() {
+        let actions = code_actions("const a = \"x\";");
+        assert_eq!(
+            find_action(&actions, "Add type annotation `: \"x\"`"),
+            "const a: \"x\" = \"x\";"
+        );
+    }
+
+    #[test]
+    fn add_missing_map_keys() {
+        let actions = code_actions("const a: {x: integer, y: text} = {x: 1};");
+        assert_eq!(
+            find_action(&actions, "Add missing key `y`"),
+            "const a: {x: integer, y: text} = {x: 1, y: \"\"};"
+        );
+        let actions = code_actions("const a: {x: integer, y: text} = {};");
+        assert_eq!(
+            find_action(&actions, "Add missing keys `x`, `y`"),
+            "const a: {x: integer, y: text} = {x: 0, y: \"\"};"
+        );
+    }
+
+    #[test]
+    fn wrap_in_mutable_reference() {
+        let actions = code_actions("const a = &42; *a = 1;");
+        assert_eq!(
+            find_action(&actions, "Wrap value of `a` in `&mut`"),
+            "const a = &mut 42; *a = 1;"
+        );
+        let actions = code_actions("const a = 42; *a = 1;");
+        assert_eq!(
+            find_action(&actions, "Wrap value of `a` in `&mut`"),
+            "const a = &mut 42; *a = 1;"
+        );
+    }
+}
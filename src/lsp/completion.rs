@@ -0,0 +1,674 @@
+use crate::ast::expressions::VariableKind;
+use crate::compiler::precompiler::precompiled_ast::{
+    VariableMetadata, VariableShape,
+};
+use crate::libs::core::{get_core_lib_ids, get_core_lib_value};
+use crate::lsp::LanguageServerBackend;
+use crate::lsp::visible_variable_collector::VisibleVariableCollector;
+use crate::parser::lexer::{
+    SpannedToken, Token, get_spanned_tokens_from_source,
+};
+use crate::types::definition::TypeDefinition;
+use crate::types::structural_type_definition::StructuralTypeDefinition;
+use crate::values::core_value::CoreValue;
+use crate::values::core_values::r#type::Type;
+use crate::values::value_container::ValueContainer;
+use crate::visitor::expression::ExpressionVisitor;
+use core::ops::Range;
+use realhydroper_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, InsertTextFormat,
+    TextDocumentPositionParams, TextEdit,
+};
+
+/// Characters that trigger a completion request in the client
+pub const COMPLETION_TRIGGER_CHARACTERS: &[&str] = &[".", "@"];
+
+/// Snippets for keywords that are offered in expression position
+const KEYWORD_SNIPPETS: &[(&str, &str, &str)] = &[
+    (
+        "function",
+        "function declaration",
+        "function ${1:name}(${2}) (\n\t$0\n)",
+    ),
+    ("if", "if expression", "if (${1:condition}) (\n\t$0\n)"),
+    ("type", "type declaration", "type ${1:Name} = $0;"),
+];
+
+/// The syntactic position at the cursor, determined from the tokens before it
+#[derive(Debug, Clone, PartialEq)]
+enum CompletionContext {
+    /// a property access, e.g. `user.na`, with the names of the base
+    /// expression (`["user"]`)
+    Property(Vec<String>),
+    /// a type expression, e.g. after `x: ` or `type A = `
+    Type,
+    /// an endpoint, with the span of the already typed part including the `@`
+    Endpoint(Range<usize>),
+    /// any other expression
+    Expression,
+}
+
+/// Determines the completion context and the already typed prefix
+/// from the source text before the cursor
+fn completion_context(text_before_cursor: &str) -> (CompletionContext, String) {
+    let (tokens, _) = get_spanned_tokens_from_source(text_before_cursor);
+    let end = text_before_cursor.len();
+    let mut tokens = tokens.as_slice();
+
+    // identifier that is currently typed
+    let mut prefix = String::new();
+    if let Some((SpannedToken { token, span }, rest)) = tokens.split_last()
+        && span.end == end
+    {
+        match token {
+            Token::Identifier(name) => {
+                prefix = name.clone();
+                tokens = rest;
+            }
+            Token::Endpoint(_) | Token::At => {
+                return (
+                    CompletionContext::Endpoint(span.clone()),
+                    text_before_cursor[span.clone()].to_string(),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    let context = match tokens {
+        [
+            ..,
+            base,
+            SpannedToken {
+                token: Token::Dot, ..
+            },
+        ] => CompletionContext::Property(property_path(tokens, base)),
+        // a colon only starts a type annotation after a declared name,
+        // otherwise it is followed by the value of a map entry
+        [
+            rest @ ..,
+            SpannedToken {
+                token: Token::Colon,
+                ..
+            },
+        ] => {
+            if is_declared_name(rest) {
+                CompletionContext::Type
+            } else {
+                CompletionContext::Expression
+            }
+        }
+        [
+            ..,
+            SpannedToken {
+                token: Token::Arrow,
+                ..
+            },
+        ]
+        | [
+            ..,
+            SpannedToken {
+                token: Token::TypeExpressionStart,
+                ..
+            },
+        ]
+        | [
+            ..,
+            SpannedToken {
+                token: Token::TypeDeclaration,
+                ..
+            },
+            SpannedToken {
+                token: Token::Identifier(_),
+                ..
+            },
+            SpannedToken {
+                token: Token::Assign,
+                ..
+            },
+        ] => CompletionContext::Type,
+        _ => CompletionContext::Expression,
+    };
+    (context, prefix)
+}
+
+/// Returns true if the tokens end with the name of a variable declaration or a
+/// callable parameter, so that a following colon starts a type annotation
+fn is_declared_name(tokens: &[SpannedToken]) -> bool {
+    let [
+        rest @ ..,
+        SpannedToken {
+            token: Token::Identifier(_),
+            ..
+        },
+    ] = tokens
+    else {
+        return false;
+    };
+    // rest parameter
+    let rest = match rest {
+        [
+            rest @ ..,
+            SpannedToken {
+                token: Token::Spread,
+                ..
+            },
+        ] => rest,
+        _ => rest,
+    };
+    match rest {
+        [
+            ..,
+            SpannedToken {
+                token: Token::Const | Token::Variable,
+                ..
+            },
+        ] => true,
+        [
+            ..,
+            SpannedToken {
+                token: Token::LeftParen | Token::Comma,
+                ..
+            },
+        ] => is_in_parameter_list(rest),
+        _ => false,
+    }
+}
+
+/// Returns true if the innermost unclosed bracket of the tokens opens
+/// the parameter list of a callable declaration
+fn is_in_parameter_list(tokens: &[SpannedToken]) -> bool {
+    let mut depth = 0;
+    for (index, SpannedToken { token, .. }) in tokens.iter().enumerate().rev() {
+        match token {
+            Token::RightParen | Token::RightBracket | Token::RightCurly => {
+                depth += 1
+            }
+            Token::LeftParen | Token::LeftBracket | Token::LeftCurly
+                if depth > 0 =>
+            {
+                depth -= 1
+            }
+            Token::LeftParen => return is_callable_head(&tokens[..index]),
+            Token::LeftBracket | Token::LeftCurly => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Returns true if the tokens end with the head of a callable declaration
+/// before its parameter list, e.g. `function name<T>`
+fn is_callable_head(mut tokens: &[SpannedToken]) -> bool {
+    if let [
+        rest @ ..,
+        SpannedToken {
+            token: Token::RightAngle,
+            ..
+        },
+    ] = tokens
+    {
+        let Some(start) = rest
+            .iter()
+            .rposition(|token| core::matches!(token.token, Token::LeftAngle))
+        else {
+            return false;
+        };
+        tokens = &rest[..start];
+    }
+    if let [
+        rest @ ..,
+        SpannedToken {
+            token: Token::Identifier(_),
+            ..
+        },
+    ] = tokens
+    {
+        tokens = rest;
+    }
+    core::matches!(
+        tokens,
+        [
+            ..,
+            SpannedToken {
+                token: Token::Function | Token::Procedure,
+                ..
+            },
+        ]
+    )
+}
+
+/// Collects the names of a property access chain ending with the given base token,
+/// e.g. `["a", "b"]` for `a.b.`
+fn property_path(tokens: &[SpannedToken], base: &SpannedToken) -> Vec<String> {
+    let Token::Identifier(name) = &base.token else {
+        return vec![];
+    };
+    let mut path = vec![name.clone()];
+    let mut rest = &tokens[..tokens.len() - 2];
+    while let [
+        ..,
+        SpannedToken {
+            token: Token::Identifier(name),
+            ..
+        },
+        SpannedToken {
+            token: Token::Dot, ..
+        },
+    ] = rest
+    {
+        path.insert(0, name.clone());
+        rest = &rest[..rest.len() - 2];
+    }
+    path
+}
+
+/// Returns the names and types of all known properties of a type
+fn property_types(ty: &Type) -> Vec<(String, Type)> {
+    match &ty.type_definition {
+        TypeDefinition::Structural(StructuralTypeDefinition::Map(entries)) => {
+            entries
+                .iter()
+                .filter_map(|(key, value)| match &key.type_definition {
+                    TypeDefinition::Structural(
+                        StructuralTypeDefinition::Text(text),
+                    ) => Some((text.0.clone(), value.clone())),
+                    _ => None,
+                })
+                .collect()
+        }
+        TypeDefinition::Reference(reference) => {
+            property_types(&reference.borrow().type_value)
+        }
+        TypeDefinition::Type(ty) | TypeDefinition::ImplType(ty, _) => {
+            property_types(ty)
+        }
+        TypeDefinition::Intersection(types) => {
+            types.iter().flat_map(property_types).collect()
+        }
+        _ => vec![],
+    }
+}
+
+fn is_callable_type(ty: &Type) -> bool {
+    match &ty.type_definition {
+        TypeDefinition::Callable(_) => true,
+        TypeDefinition::Type(ty) => is_callable_type(ty),
+        _ => false,
+    }
+}
+
+fn variable_completion_item(variable: &VariableMetadata) -> CompletionItem {
+    let kind = match variable.shape {
+        VariableShape::Type => CompletionItemKind::CLASS,
+        _ if variable.var_type.as_ref().is_some_and(is_callable_type) => {
+            CompletionItemKind::FUNCTION
+        }
+        VariableShape::Value(VariableKind::Const) => {
+            CompletionItemKind::CONSTANT
+        }
+        VariableShape::Value(VariableKind::Var) => CompletionItemKind::VARIABLE,
+    };
+    CompletionItem {
+        label: variable.name.clone(),
+        kind: Some(kind),
+        detail: Some(match &variable.var_type {
+            Some(var_type) => {
+                format!("{} {}: {}", variable.shape, variable.name, var_type)
+            }
+            None => format!("{} {}", variable.shape, variable.name),
+        }),
+        ..Default::default()
+    }
+}
+
+/// Returns the completion items for all types and values of the core library
+/// with a name that can be used in a script
+fn core_lib_completion_items(types_only: bool) -> Vec<CompletionItem> {
+    get_core_lib_ids()
+        .into_iter()
+        .filter_map(|id| {
+            let name = id.to_string();
+            // variants such as integer/u8 are not accessible by name
+            if name.contains('/') {
+                return None;
+            }
+            let value = get_core_lib_value(id)?;
+            let (kind, detail) = if value.is_type() {
+                (CompletionItemKind::CLASS, format!("type {}", name))
+            } else if types_only {
+                return None;
+            } else {
+                core_lib_value_kind_and_detail(&name, &value)
+            };
+            Some(CompletionItem {
+                label: name,
+                kind: Some(kind),
+                detail: Some(detail),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+fn core_lib_value_kind_and_detail(
+    name: &str,
+    value: &ValueContainer,
+) -> (CompletionItemKind, String) {
+    let value = value.to_value();
+    let value = value.borrow();
+    match &value.inner {
+        CoreValue::Callable(callable) => (
+            CompletionItemKind::FUNCTION,
+            format!(
+                "{}: {}",
+                name,
+                TypeDefinition::callable(callable.signature.clone())
+            ),
+        ),
+        _ => (
+            CompletionItemKind::CONSTANT,
+            format!("const {}: {}", name, value.actual_type),
+        ),
+    }
+}
+
+fn keyword_snippet_items() -> Vec<CompletionItem> {
+    KEYWORD_SNIPPETS
+        .iter()
+        .map(|(keyword, detail, snippet)| CompletionItem {
+            label: keyword.to_string(),
+            kind: Some(CompletionItemKind::SNIPPET),
+            detail: Some(detail.to_string()),
+            insert_text: Some(snippet.to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            ..Default::default()
+        })
+        .collect()
+}
+
+impl LanguageServerBackend {
+    /// Returns the completion items for the given position, depending on
+    /// whether a property, type, endpoint or expression is expected
+    pub fn get_completions(
+        &self,
+        position: &TextDocumentPositionParams,
+    ) -> Vec<CompletionItem> {
+        let offset = self.position_to_byte_offset(position);
+        let variables = self.visible_variables(position, offset);
+        let workspace = self.compiler_workspace.borrow();
+        let Some(file) = workspace.get_file(&position.text_document.uri) else {
+            return vec![];
+        };
+        let (context, prefix) = completion_context(&file.content[..offset]);
+
+        let items = match context {
+            CompletionContext::Property(path) => {
+                Self::property_completion_items(&variables, &path)
+            }
+            CompletionContext::Type => variables
+                .iter()
+                .filter(|variable| variable.shape == VariableShape::Type)
+                .map(variable_completion_item)
+                .chain(core_lib_completion_items(true))
+                .collect(),
+            CompletionContext::Endpoint(span) => {
+                let range = self
+                    .convert_byte_range_to_document_range(&span, &file.content);
+                let runtime = workspace.runtime();
+                let mut endpoints = runtime
+                    .com_hub()
+                    .endpoint_sockets
+                    .borrow()
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                endpoints.push(runtime.endpoint());
+                endpoints
+                    .into_iter()
+                    .map(|endpoint| endpoint.to_string())
+                    .map(|endpoint| CompletionItem {
+                        label: endpoint.clone(),
+                        kind: Some(CompletionItemKind::VALUE),
+                        detail: Some("endpoint".to_string()),
+                        text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                            range,
+                            new_text: endpoint,
+                        })),
+                        ..Default::default()
+                    })
+                    .collect()
+            }
+            CompletionContext::Expression => variables
+                .iter()
+                .filter(|variable| variable.shape != VariableShape::Type)
+                .map(variable_completion_item)
+                .chain(core_lib_completion_items(false))
+                .chain(keyword_snippet_items())
+                .collect(),
+        };
+        let mut items = items
+            .into_iter()
+            .filter(|item| item.label.starts_with(&prefix))
+            .collect::<Vec<_>>();
+        items.sort_by(|a, b| a.label.cmp(&b.label));
+        items.dedup_by(|a, b| a.label == b.label);
+        items
+    }
+
+    /// Returns the variables and types that are declared in the file
+    /// and can be accessed at the given byte offset
+    fn visible_variables(
+        &self,
+        position: &TextDocumentPositionParams,
+        offset: usize,
+    ) -> Vec<VariableMetadata> {
+        let mut workspace = self.compiler_workspace.borrow_mut();
+        let Some(rich_ast) = workspace
+            .get_file_mut(&position.text_document.uri)
+            .and_then(|file| file.rich_ast.as_mut())
+        else {
+            return vec![];
+        };
+        let mut collector = VisibleVariableCollector::new(offset);
+        if collector.visit_datex_expression(&mut rich_ast.ast).is_err() {
+            return vec![];
+        }
+        let metadata = rich_ast.metadata.borrow();
+        collector
+            .variable_ids
+            .into_iter()
+            .filter_map(|id| metadata.variable_metadata(id).cloned())
+            .chain(collector.parameters)
+            .collect()
+    }
+
+    /// Resolves the type of a property access chain starting with a variable
+    /// and returns its properties
+    fn property_completion_items(
+        variables: &[VariableMetadata],
+        path: &[String],
+    ) -> Vec<CompletionItem> {
+        let Some((name, properties)) = path.split_first() else {
+            return vec![];
+        };
+        // the last declared variable with the name
+        let Some(mut ty) = variables
+            .iter()
+            .rev()
+            .find(|variable| &variable.name == name)
+            .and_then(|variable| variable.var_type.clone())
+        else {
+            return vec![];
+        };
+        for property in properties {
+            let Some((_, property_type)) = property_types(&ty)
+                .into_iter()
+                .find(|(name, _)| name == property)
+            else {
+                return vec![];
+            };
+            ty = property_type;
+        }
+        property_types(&ty)
+            .into_iter()
+            .map(|(name, property_type)| CompletionItem {
+                kind: Some(if is_callable_type(&property_type) {
+                    CompletionItemKind::METHOD
+                } else {
+                    CompletionItemKind::PROPERTY
+                }),
+                detail: Some(format!("{}: {}", name, property_type)),
+                label: name,
+                ..Default::default()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::test_utils::{service, url};
+    use realhydroper_lsp::lsp_types::{Position, TextDocumentIdentifier};
+
+    /// Returns the labels of the completion items at the end of the given content
+    fn completion_labels(content: &str) -> Vec<String> {
+        let line = content.lines().count().max(1) - 1;
+        let character =
+            content.lines().last().unwrap_or("").encode_utf16().count();
+        completion_labels_at(
+            content,
+            Position {
+                line: line as u32,
+                character: character as u32,
+            },
+        )
+    }
+
+    /// Returns the labels of the completion items at the given position
+    fn completion_labels_at(content: &str, position: Position) -> Vec<String> {
+        let url = url("main.dx");
+        let service = service(&url, content);
+        service
+            .inner()
+            .get_completions(&TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: url },
+                position,
+            })
+            .into_iter()
+            .map(|item| item.label)
+            .collect()
+    }
+
+    #[test]
+    fn context() {
+        assert_eq!(
+            completion_context("a.b.c"),
+            (
+                CompletionContext::Property(vec!["a".into(), "b".into()]),
+                "c".to_string()
+            )
+        );
+        assert_eq!(
+            completion_context("const x: int"),
+            (CompletionContext::Type, "int".to_string())
+        );
+        assert_eq!(
+            completion_context("function f<T>(a: integer, ...rest: "),
+            (CompletionContext::Type, "".to_string())
+        );
+        assert_eq!(
+            completion_context("{a: 1, b: "),
+            (CompletionContext::Expression, "".to_string())
+        );
+        assert_eq!(
+            completion_context("f(a: "),
+            (CompletionContext::Expression, "".to_string())
+        );
+        assert_eq!(
+            completion_context("type A = "),
+            (CompletionContext::Type, "".to_string())
+        );
+        assert_eq!(
+            completion_context("x == @ex"),
+            (CompletionContext::Endpoint(5..8), "@ex".to_string())
+        );
+        assert_eq!(
+            completion_context("1 + pr"),
+            (CompletionContext::Expression, "pr".to_string())
+        );
+    }
+
+    #[test]
+    fn property_completion() {
+        assert_eq!(
+            completion_labels(
+                "const user = {name: \"x\", address: {city: \"y\", zip: 1}};\nuser.address."
+            ),
+            vec!["city", "zip"]
+        );
+        assert_eq!(
+            completion_labels("const user = {name: \"x\", age: 1};\nuser.na"),
+            vec!["name"]
+        );
+    }
+
+    #[test]
+    fn type_completion() {
+        let labels = completion_labels("type Id = integer;\nconst x: I");
+        assert_eq!(labels, vec!["Id"]);
+        let labels = completion_labels("const x: ");
+        assert!(labels.contains(&"integer".to_string()));
+        assert!(labels.contains(&"text".to_string()));
+        assert!(!labels.contains(&"print".to_string()));
+    }
+
+    #[test]
+    fn expression_completion() {
+        let labels = completion_labels("const prefix = 1;\np");
+        assert_eq!(labels, vec!["prefix", "print"]);
+        let labels = completion_labels("");
+        assert!(labels.contains(&"function".to_string()));
+        assert!(labels.contains(&"if".to_string()));
+    }
+
+    #[test]
+    fn visible_variables() {
+        // variables of other blocks are not visible
+        let labels = completion_labels(
+            "const outer = 1;
+(const inner = 2; inner);
+",
+        );
+        assert!(labels.contains(&"outer".to_string()));
+        assert!(!labels.contains(&"inner".to_string()));
+
+        // variables are not visible before they are declared
+        let labels = completion_labels_at(
+            "const before = 1;
+be;
+const behind = 2;",
+            Position {
+                line: 1,
+                character: 2,
+            },
+        );
+        assert_eq!(labels, vec!["before"]);
+
+        // parameters are visible in the body of the callable
+        let labels = completion_labels_at(
+            "function f(parameter: integer) (\n\tpa\n)",
+            Position {
+                line: 1,
+                character: 3,
+            },
+        );
+        assert_eq!(labels, vec!["parameter"]);
+    }
+
+    #[test]
+    fn endpoint_completion() {
+        assert_eq!(completion_labels("@l"), vec!["@lsp"]);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::ast::expressions::{
+    CallableDeclaration, DatexExpression, DatexExpressionData, TypeDeclaration,
+    VariableDeclaration, VariableKind,
+};
+use crate::lsp::symbol_collector::identifier_span;
+use crate::values::core_values::callable::CallableKind;
+use crate::visitor::expression::ExpressionVisitor;
+use crate::visitor::type_expression::TypeExpressionVisitor;
+use core::ops::Range;
+use realhydroper_lsp::lsp_types::SymbolKind;
+
+/// A declared variable, type or callable with the symbols declared in its body
+#[derive(Debug, Clone)]
+pub struct SymbolNode {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub detail: Option<String>,
+    /// span of the whole declaration
+    pub span: Range<usize>,
+    /// span of the declared name
+    pub name_span: Range<usize>,
+    pub children: Vec<SymbolNode>,
+}
+
+/// Collects the hierarchy of all declarations in an AST
+pub struct DocumentSymbolCollector<'a> {
+    content: &'a str,
+    /// declarations that are currently visited, the innermost one last
+    stack: Vec<SymbolNode>,
+    pub symbols: Vec<SymbolNode>,
+}
+
+impl<'a> DocumentSymbolCollector<'a> {
+    pub fn new(content: &'a str) -> Self {
+        DocumentSymbolCollector {
+            content,
+            stack: Vec::new(),
+            symbols: Vec::new(),
+        }
+    }
+
+    fn symbol_node(&self, expression: &DatexExpression) -> Option<SymbolNode> {
+        let span = &expression.span;
+        let (name, kind, detail, name_search_span) = match &expression.data {
+            DatexExpressionData::VariableDeclaration(VariableDeclaration {
+                name,
+                kind,
+                init_expression,
+                ..
+            }) => (
+                name,
+                match kind {
+                    VariableKind::Const => SymbolKind::CONSTANT,
+                    VariableKind::Var => SymbolKind::VARIABLE,
+                },
+                kind.to_string(),
+                span.start..init_expression.span.start,
+            ),
+            DatexExpressionData::TypeDeclaration(TypeDeclaration {
+                name,
+                kind,
+                definition,
+                ..
+            }) => (
+                name,
+                SymbolKind::CLASS,
+                kind.to_string(),
+                span.start..definition.span.start,
+            ),
+            DatexExpressionData::CallableDeclaration(CallableDeclaration {
+                name: Some(name),
+                kind,
+                body,
+                ..
+            }) => (
+                name,
+                SymbolKind::FUNCTION,
+                match kind {
+                    CallableKind::Function => "function",
+                    CallableKind::Procedure => "procedure",
+                }
+                .to_string(),
+                span.start..body.span.start,
+            ),
+            _ => return None,
+        };
+        Some(SymbolNode {
+            name: name.clone(),
+            kind,
+            detail: Some(detail),
+            span: span.clone(),
+            name_span: identifier_span(self.content, &name_search_span, name)
+                .unwrap_or_else(|| span.clone()),
+            children: Vec::new(),
+        })
+    }
+
+    fn is_declaration(expression: &DatexExpression) -> bool {
+        matches!(
+            expression.data,
+            DatexExpressionData::VariableDeclaration(_)
+                | DatexExpressionData::TypeDeclaration(_)
+                | DatexExpressionData::CallableDeclaration(
+                    CallableDeclaration { name: Some(_), .. }
+                )
+        )
+    }
+}
+
+impl TypeExpressionVisitor<()> for DocumentSymbolCollector<'_> {}
+
+impl ExpressionVisitor<()> for DocumentSymbolCollector<'_> {
+    fn before_visit_datex_expression(
+        &mut self,
+        expression: &mut DatexExpression,
+    ) {
+        if let Some(node) = self.symbol_node(expression) {
+            self.stack.push(node);
+        }
+    }
+
+    fn after_visit_datex_expression(
+        &mut self,
+        expression: &mut DatexExpression,
+    ) {
+        if Self::is_declaration(expression)
+            && let Some(node) = self.stack.pop()
+        {
+            match self.stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => self.symbols.push(node),
+            }
+        }
+    }
+}
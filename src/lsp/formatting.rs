@@ -0,0 +1,213 @@
+use crate::fmt::options::{FormattingConfigError, FormattingOptions};
+use crate::fmt::{FormattedRegion, Formatter};
+use crate::lsp::LanguageServerBackend;
+use realhydroper_lsp::lsp_types;
+use realhydroper_lsp::lsp_types::{MessageType, Range, TextEdit};
+use url::Url;
+
+/// Name of the project config file that contains the `[fmt]` section
+pub const PROJECT_CONFIG_FILE: &str = "datex.toml";
+
+impl LanguageServerBackend {
+    /// Returns the formatting options for a file, based on the editor options and
+    /// the nearest project config file in the parent directories of the file.
+    pub fn get_formatting_options(
+        &self,
+        url: &Url,
+        editor_options: &lsp_types::FormattingOptions,
+    ) -> Result<FormattingOptions, FormattingConfigError> {
+        let mut options = Self::editor_formatting_options(editor_options);
+        if let Some(config) = Self::find_project_config(url) {
+            options.apply_project_config(&config)?;
+        }
+        Ok(options)
+    }
+
+    /// Same as get_formatting_options, but logs an invalid project config
+    /// and falls back to the editor options
+    pub async fn get_formatting_options_or_log(
+        &self,
+        url: &Url,
+        editor_options: &lsp_types::FormattingOptions,
+    ) -> FormattingOptions {
+        match self.get_formatting_options(url, editor_options) {
+            Ok(options) => options,
+            Err(error) => {
+                self.client
+                    .log_message(
+                        MessageType::ERROR,
+                        format!("Invalid project config: {}", error),
+                    )
+                    .await;
+                Self::editor_formatting_options(editor_options)
+            }
+        }
+    }
+
+    fn editor_formatting_options(
+        editor_options: &lsp_types::FormattingOptions,
+    ) -> FormattingOptions {
+        FormattingOptions {
+            indent: editor_options.tab_size as usize,
+            indent_with_tabs: !editor_options.insert_spaces,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn find_project_config(url: &Url) -> Option<String> {
+        let path = url.to_file_path().ok()?;
+        path.ancestors().skip(1).find_map(|directory| {
+            std::fs::read_to_string(directory.join(PROJECT_CONFIG_FILE)).ok()
+        })
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn find_project_config(_url: &Url) -> Option<String> {
+        None
+    }
+
+    /// Formats a workspace file, or only the top-level statements within the given range
+    pub fn format_file(
+        &self,
+        url: &Url,
+        range: Option<Range>,
+        options: &FormattingOptions,
+    ) -> Option<Vec<TextEdit>> {
+        let workspace = self.compiler_workspace.borrow();
+        let content = &workspace.get_file(url)?.content;
+        let regions = match range {
+            Some(range) => {
+                let start = Self::line_char_to_byte_index(
+                    content,
+                    range.start.line as usize,
+                    range.start.character as usize,
+                )?;
+                let end = Self::line_char_to_byte_index(
+                    content,
+                    range.end.line as usize,
+                    range.end.character as usize,
+                )?;
+                Formatter::format_regions(content, options, Some(&(start..end)))
+            }
+            None => Formatter::format_document(content, options),
+        };
+        Some(
+            regions
+                .into_iter()
+                .map(|FormattedRegion { span, formatted }| TextEdit {
+                    range: self
+                        .convert_byte_range_to_document_range(&span, content),
+                    new_text: formatted,
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::test_utils::{service, url};
+    use realhydroper_lsp::lsp_types::Position;
+
+    fn editor_options(
+        tab_size: u32,
+        insert_spaces: bool,
+    ) -> lsp_types::FormattingOptions {
+        lsp_types::FormattingOptions {
+            tab_size,
+            insert_spaces,
+            ..Default::default()
+        }
+    }
+
+    fn position(line: u32, character: u32) -> Position {
+        Position { line, character }
+    }
+
+    #[test]
+    fn format_with_errors() {
+        let url = url("main.dx");
+        let service = service(&url, "const a=1;\nconst b = 1 +;\na+2\n");
+        let backend = service.inner();
+        let options = backend
+            .get_formatting_options(&url, &editor_options(4, true))
+            .unwrap();
+
+        let edits = backend.format_file(&url, None, &options).unwrap();
+        assert_eq!(
+            edits,
+            vec![
+                TextEdit {
+                    range: Range::new(position(0, 0), position(0, 9)),
+                    new_text: "const a = 1".to_string(),
+                },
+                TextEdit {
+                    range: Range::new(position(2, 0), position(2, 3)),
+                    new_text: "a + 2".to_string(),
+                },
+            ]
+        );
+
+        let edits = backend
+            .format_file(
+                &url,
+                Some(Range::new(position(2, 0), position(3, 0))),
+                &options,
+            )
+            .unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "a + 2");
+    }
+
+    #[test]
+    fn format_document() {
+        let url = url("main.dx");
+        let service = service(&url, "var x=[1,2,3];\nx\n");
+        let backend = service.inner();
+        let options = FormattingOptions {
+            max_width: 8,
+            ..backend
+                .get_formatting_options(&url, &editor_options(2, false))
+                .unwrap()
+        };
+        let edits = backend.format_file(&url, None, &options).unwrap();
+        assert_eq!(
+            edits,
+            vec![TextEdit {
+                range: Range::new(position(0, 0), position(1, 1)),
+                new_text: "var x = [\n\t1,\n\t2,\n\t3,\n];\nx".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn project_config() {
+        // unique directory so that concurrent test runs don't share the config file
+        let directory = std::env::temp_dir().join(format!(
+            "datex_lsp_formatting_project_config_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(directory.join("src")).unwrap();
+        std::fs::write(
+            directory.join(PROJECT_CONFIG_FILE),
+            "[fmt]\nindent = 3\ntrailing_comma = false\n",
+        )
+        .unwrap();
+        let url = Url::from_file_path(directory.join("src/main.dx")).unwrap();
+        let service = service(&url, "1");
+        let options = service
+            .inner()
+            .get_formatting_options(&url, &editor_options(4, true))
+            .unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(options.indent, 3);
+        assert!(!options.trailing_comma);
+        assert!(!options.indent_with_tabs);
+    }
+}
@@ -1,15 +1,31 @@
+mod code_actions;
+mod completion;
+mod document_symbol_collector;
 mod errors;
+mod formatting;
+mod references;
+mod semantic_token_collector;
+mod semantic_tokens;
+mod signature_help;
+mod symbol_collector;
+#[cfg(test)]
+mod test_utils;
 mod type_hint_collector;
 mod utils;
 mod variable_declaration_finder;
+mod visible_variable_collector;
 use crate::ast::expressions::{
-    DatexExpressionData, VariableAccess, VariableAssignment,
+    DatexExpressionData, Import, VariableAccess, VariableAssignment,
     VariableDeclaration,
 };
 use crate::collections::HashMap;
+use crate::compiler::module::module_exports;
 use crate::compiler::precompiler::precompiled_ast::RichAst;
 use crate::compiler::workspace::CompilerWorkspace;
+use crate::lsp::completion::COMPLETION_TRIGGER_CHARACTERS;
 use crate::lsp::errors::SpannedLSPCompilerError;
+use crate::lsp::references::is_valid_identifier;
+use crate::lsp::semantic_tokens::semantic_tokens_legend;
 use crate::lsp::variable_declaration_finder::VariableDeclarationFinder;
 use crate::runtime::Runtime;
 use crate::stdlib::borrow::Cow;
@@ -30,6 +46,9 @@ pub struct LanguageServerBackend {
     pub compiler_workspace: RefCell<CompilerWorkspace>,
     pub spanned_compiler_errors:
         RefCell<HashMap<Url, Vec<SpannedLSPCompilerError>>>,
+    /// last semantic tokens sent for each file with their result id
+    pub semantic_tokens: RefCell<HashMap<Url, (String, Vec<SemanticToken>)>>,
+    pub semantic_tokens_result_counter: RefCell<u64>,
 }
 
 impl LanguageServerBackend {
@@ -38,6 +57,8 @@ impl LanguageServerBackend {
             client,
             compiler_workspace: RefCell::new(compiler_workspace),
             spanned_compiler_errors: RefCell::new(HashMap::new()),
+            semantic_tokens: RefCell::new(HashMap::new()),
+            semantic_tokens_result_counter: RefCell::new(0),
         }
     }
 }
@@ -51,9 +72,17 @@ impl LanguageServer for LanguageServerBackend {
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
-                completion_provider: Some(CompletionOptions::default()),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(
+                        COMPLETION_TRIGGER_CHARACTERS
+                            .iter()
+                            .map(|character| character.to_string())
+                            .collect(),
+                    ),
+                    ..Default::default()
+                }),
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 diagnostic_provider: Some(
                     DiagnosticServerCapabilities::Options(DiagnosticOptions {
@@ -71,6 +100,41 @@ impl LanguageServer for LanguageServerBackend {
                     work_done_progress_options: Default::default(),
                 }),
                 definition_provider: Some(OneOf::Left(true)),
+                document_formatting_provider: Some(OneOf::Left(true)),
+                document_range_formatting_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                document_highlight_provider: Some(OneOf::Left(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                semantic_tokens_provider: Some(
+                    SemanticTokensOptions {
+                        legend: semantic_tokens_legend(),
+                        full: Some(SemanticTokensFullOptions::Delta {
+                            delta: Some(true),
+                        }),
+                        ..Default::default()
+                    }
+                    .into(),
+                ),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec![
+                        "(".to_string(),
+                        ",".to_string(),
+                    ]),
+                    ..Default::default()
+                }),
+                code_action_provider: Some(
+                    CodeActionProviderCapability::Options(CodeActionOptions {
+                        code_action_kinds: Some(vec![
+                            CodeActionKind::QUICKFIX,
+                            CodeActionKind::REFACTOR_REWRITE,
+                        ]),
+                        ..Default::default()
+                    }),
+                ),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
                 ..Default::default()
             },
             ..Default::default()
@@ -109,55 +173,24 @@ impl LanguageServer for LanguageServerBackend {
                 format!("File changed: {}", params.text_document.uri),
             )
             .await;
-        let new_content = params
-            .content_changes
-            .into_iter()
-            .next()
-            .map(|change| change.text)
+        let url = params.text_document.uri;
+        let mut content = self
+            .compiler_workspace
+            .borrow()
+            .get_file(&url)
+            .map(|file| file.content.clone())
             .unwrap_or_default();
-        self.update_file_contents(params.text_document.uri, new_content)
-            .await;
+        Self::apply_content_changes(&mut content, params.content_changes);
+        self.update_file_contents(url, content).await;
     }
 
     async fn completion(
         &self,
         params: CompletionParams,
     ) -> realhydroper_lsp::jsonrpc::Result<Option<CompletionResponse>> {
-        self.client
-            .log_message(MessageType::INFO, "completion!")
-            .await;
-
-        let position = params.text_document_position;
-
-        // For simplicity, we assume the prefix is the last word before the cursor.
-        // In a real implementation, you would extract this from the document content.
-        let prefix = self.get_previous_text_at_position(&position);
-        self.client
-            .log_message(
-                MessageType::INFO,
-                format!("Completion prefix: {}", prefix),
-            )
-            .await;
-
-        let variables = self.find_variable_starting_with(&prefix);
-
-        let items: Vec<CompletionItem> = variables
-            .iter()
-            .map(|var| CompletionItem {
-                label: var.name.clone(),
-                kind: Some(CompletionItemKind::VARIABLE),
-                detail: Some(format!(
-                    "{} {}: {}",
-                    var.shape,
-                    var.name,
-                    var.var_type.as_ref().unwrap()
-                )),
-                documentation: None,
-                ..Default::default()
-            })
-            .collect();
-
-        Ok(Some(CompletionResponse::Array(items)))
+        Ok(Some(CompletionResponse::Array(
+            self.get_completions(&params.text_document_position),
+        )))
     }
 
     async fn hover(
@@ -280,6 +313,14 @@ impl LanguageServer for LanguageServerBackend {
                     if let Some(RichAst { ast, .. }) = &mut file.rich_ast {
                         let mut finder = VariableDeclarationFinder::new(id);
                         finder.visit_datex_expression(ast);
+                        // follow imported variables to the exported declaration
+                        if let Some((source, name)) = finder.import {
+                            return Ok(self
+                                .find_export_location(
+                                    &workspace, &source, &name,
+                                )
+                                .map(GotoDefinitionResponse::Scalar));
+                        }
                         Ok(finder.variable_declaration_position.map(
                             |position| {
                                 GotoDefinitionResponse::Scalar(Location {
@@ -296,6 +337,25 @@ impl LanguageServer for LanguageServerBackend {
                         Ok(None)
                     }
                 }
+                DatexExpressionData::Import(Import { items, source }) => {
+                    let offset = self.position_to_byte_offset(
+                        &params.text_document_position_params,
+                    );
+                    let workspace = self.compiler_workspace.borrow();
+                    let location = match items.iter().find(|item| {
+                        item.span.start <= offset && offset <= item.span.end
+                    }) {
+                        Some(item) => self.find_export_location(
+                            &workspace, &source, &item.name,
+                        ),
+                        // outside of the imported names, go to the module itself
+                        None => Url::parse(&source).ok().map(|uri| Location {
+                            uri,
+                            range: Range::default(),
+                        }),
+                    };
+                    Ok(location.map(GotoDefinitionResponse::Scalar))
+                }
                 _ => Ok(None),
             }
         } else {
@@ -303,6 +363,138 @@ impl LanguageServer for LanguageServerBackend {
         }
     }
 
+    async fn references(
+        &self,
+        params: ReferenceParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<Vec<Location>>> {
+        Ok(Some(self.find_reference_locations(
+            &params.text_document_position,
+            params.context.include_declaration,
+        )))
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<Vec<DocumentHighlight>>> {
+        Ok(Some(self.find_document_highlights(
+            &params.text_document_position_params,
+        )))
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<PrepareRenameResponse>> {
+        Ok(self.get_symbol_at_position(&params).map(|symbol| {
+            PrepareRenameResponse::RangeWithPlaceholder {
+                range: symbol.range,
+                placeholder: symbol.occurrence.name,
+            }
+        }))
+    }
+
+    async fn rename(
+        &self,
+        params: RenameParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<WorkspaceEdit>> {
+        if !is_valid_identifier(&params.new_name) {
+            return Err(Error::invalid_params(format!(
+                "Invalid name: {}",
+                params.new_name
+            )));
+        }
+        if self
+            .get_symbol_at_position(&params.text_document_position)
+            .is_none()
+        {
+            return Ok(None);
+        }
+        Ok(Some(self.get_rename_edit(
+            &params.text_document_position,
+            &params.new_name,
+        )))
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<DocumentSymbolResponse>> {
+        Ok(self
+            .get_document_symbols(&params.text_document.uri)
+            .map(DocumentSymbolResponse::Nested))
+    }
+
+    async fn formatting(
+        &self,
+        params: DocumentFormattingParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<Vec<TextEdit>>> {
+        let uri = params.text_document.uri;
+        let options = self
+            .get_formatting_options_or_log(&uri, &params.options)
+            .await;
+        Ok(self.format_file(&uri, None, &options))
+    }
+
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<Vec<TextEdit>>> {
+        let uri = params.text_document.uri;
+        let options = self
+            .get_formatting_options_or_log(&uri, &params.options)
+            .await;
+        Ok(self.format_file(&uri, Some(params.range), &options))
+    }
+
+    async fn code_action(
+        &self,
+        params: CodeActionParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<CodeActionResponse>> {
+        Ok(Some(
+            self.get_code_actions(
+                &params.text_document.uri,
+                params.range,
+                &params.context.diagnostics,
+            )
+            .into_iter()
+            .map(CodeActionOrCommand::CodeAction)
+            .collect(),
+        ))
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<SemanticTokensResult>> {
+        Ok(self
+            .get_semantic_tokens(&params.text_document.uri)
+            .map(SemanticTokensResult::Tokens))
+    }
+
+    async fn semantic_tokens_full_delta(
+        &self,
+        params: SemanticTokensDeltaParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<SemanticTokensFullDeltaResult>>
+    {
+        Ok(self
+            .get_semantic_tokens_delta(
+                &params.text_document.uri,
+                &params.previous_result_id,
+            )
+            .map(|result| match result {
+                Ok(delta) => SemanticTokensFullDeltaResult::TokensDelta(delta),
+                Err(tokens) => SemanticTokensFullDeltaResult::Tokens(tokens),
+            }))
+    }
+
+    async fn signature_help(
+        &self,
+        params: SignatureHelpParams,
+    ) -> realhydroper_lsp::jsonrpc::Result<Option<SignatureHelp>> {
+        Ok(self.get_signature_help(&params.text_document_position_params))
+    }
+
     async fn document_link(
         &self,
         params: DocumentLinkParams,
@@ -339,6 +531,28 @@ impl LanguageServer for LanguageServerBackend {
 }
 
 impl LanguageServerBackend {
+    /// Returns the location of the exported declaration with the given name
+    /// in the workspace file of the module with the given source.
+    fn find_export_location(
+        &self,
+        workspace: &CompilerWorkspace,
+        source: &str,
+        name: &str,
+    ) -> Option<Location> {
+        let uri = Url::parse(source).ok()?;
+        let file = workspace.get_file(&uri)?;
+        let export = module_exports(file.rich_ast.as_ref()?)
+            .into_iter()
+            .find(|export| export.name == name)?;
+        Some(Location {
+            range: self.convert_byte_range_to_document_range(
+                &export.span,
+                &file.content,
+            ),
+            uri,
+        })
+    }
+
     fn get_language_string_hover(&self, text: &str) -> Hover {
         let contents = HoverContents::Scalar(MarkedString::LanguageString(
             LanguageString {
@@ -462,4 +676,89 @@ mod tests {
             })
             .await;
     }
+
+    fn change(
+        range: Option<((u32, u32), (u32, u32))>,
+        text: &str,
+    ) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: range.map(|(start, end)| Range {
+                start: Position::new(start.0, start.1),
+                end: Position::new(end.0, end.1),
+            }),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn apply_incremental_content_changes() {
+        let mut content = "const a = 1;\nconst b = 2;".to_string();
+        LanguageServerBackend::apply_content_changes(
+            &mut content,
+            vec![
+                change(Some(((1, 10), (1, 11))), "42"),
+                change(Some(((0, 12), (0, 12))), "\nconst c = 3;"),
+                change(Some(((3, 0), (3, 0))), "a + b"),
+            ],
+        );
+        assert_eq!(content, "const a = 1;\nconst c = 3;\nconst b = 42;a + b");
+
+        LanguageServerBackend::apply_content_changes(
+            &mut content,
+            vec![change(None, "1")],
+        );
+        assert_eq!(content, "1");
+    }
+
+    #[test]
+    fn apply_content_changes_with_crlf_line_endings() {
+        let mut content = "é\r\näb\r\nc".to_string();
+        LanguageServerBackend::apply_content_changes(
+            &mut content,
+            vec![
+                change(Some(((1, 1), (1, 2))), "x"),
+                change(Some(((2, 0), (2, 1))), "y"),
+                // characters after the end of a line are clamped before the line break
+                change(Some(((0, 5), (0, 5))), "!"),
+            ],
+        );
+        assert_eq!(content, "é!\r\näx\r\ny");
+    }
+
+    #[test]
+    fn apply_content_changes_with_utf16_characters() {
+        // "ä" is one and "😀" two UTF-16 code units long
+        let mut content = "const a = \"ä😀\";\nconst b = 2;".to_string();
+        LanguageServerBackend::apply_content_changes(
+            &mut content,
+            vec![
+                change(Some(((0, 12), (0, 14))), "🎉"),
+                change(Some(((0, 14), (0, 15))), "'"),
+                // a character in the middle of a surrogate pair is moved to its start
+                change(Some(((0, 13), (0, 13))), "b"),
+            ],
+        );
+        assert_eq!(content, "const a = \"äb🎉';\nconst b = 2;");
+
+        let start =
+            LanguageServerBackend::line_char_to_byte_index(&content, 0, 14)
+                .unwrap();
+        assert_eq!(&content[start..], "🎉';\nconst b = 2;");
+        // offsets are converted back to UTF-16 positions, offsets inside a
+        // character are moved to its start
+        let line_starts = utils::line_starts(&content);
+        assert_eq!(
+            utils::offset_to_position(&content, &line_starts, start + 4),
+            Position::new(0, 15)
+        );
+        assert_eq!(
+            utils::offset_to_position(&content, &line_starts, start + 2),
+            Position::new(0, 13)
+        );
+        assert_eq!(
+            LanguageServerBackend::line_char_to_byte_index(&content, 2, 0),
+            None
+        );
+    }
 }
@@ -0,0 +1,451 @@
+use crate::ast::resolved_variable::VariableId;
+use crate::collections::HashMap;
+use crate::compiler::module::{ModuleExport, module_exports};
+use crate::compiler::workspace::CompilerWorkspace;
+use crate::lsp::LanguageServerBackend;
+use crate::lsp::document_symbol_collector::{
+    DocumentSymbolCollector, SymbolNode,
+};
+use crate::lsp::symbol_collector::{
+    ImportedSymbol, SymbolCollector, SymbolOccurrence, SymbolOccurrenceKind,
+};
+use crate::visitor::expression::ExpressionVisitor;
+use realhydroper_lsp::lsp_types::{
+    DocumentHighlight, DocumentHighlightKind, DocumentSymbol, Location,
+    TextDocumentPositionParams, TextEdit, WorkspaceEdit,
+};
+use url::Url;
+
+/// Resolved symbols of a workspace file
+struct FileSymbols {
+    content: String,
+    occurrences: Vec<SymbolOccurrence>,
+    imports: Vec<ImportedSymbol>,
+    exports: Vec<ModuleExport>,
+}
+
+/// An occurrence of a symbol in a workspace file
+pub struct SymbolReference {
+    pub url: Url,
+    pub occurrence: SymbolOccurrence,
+    pub range: realhydroper_lsp::lsp_types::Range,
+}
+
+impl LanguageServerBackend {
+    /// Returns the occurrence of a variable or type at the given position
+    pub fn get_symbol_at_position(
+        &self,
+        position: &TextDocumentPositionParams,
+    ) -> Option<SymbolReference> {
+        let offset = self.position_to_byte_offset(position);
+        let url = &position.text_document.uri;
+        let mut workspace = self.compiler_workspace.borrow_mut();
+        let symbols = Self::get_file_symbols(&mut workspace, url)?;
+        let occurrence = Self::find_occurrence_at(&symbols, offset)?.clone();
+        Some(self.symbol_reference(url.clone(), occurrence, &symbols.content))
+    }
+
+    /// Finds all occurrences of the variable or type at the given position in all
+    /// workspace files, following imports to the exported declaration and back
+    /// to all files that import it.
+    pub fn find_references(
+        &self,
+        position: &TextDocumentPositionParams,
+    ) -> Vec<SymbolReference> {
+        let offset = self.position_to_byte_offset(position);
+        let mut workspace = self.compiler_workspace.borrow_mut();
+        let url = &position.text_document.uri;
+        let Some(symbols) = Self::get_file_symbols(&mut workspace, url) else {
+            return vec![];
+        };
+        let Some(occurrence) =
+            Self::find_occurrence_at(&symbols, offset).cloned()
+        else {
+            return vec![];
+        };
+
+        // resolve imported symbols to the declaration in the exporting module
+        let imported_declaration = symbols
+            .imports
+            .iter()
+            .find(|import| import.id == occurrence.id)
+            .and_then(|import| {
+                let source = Url::parse(&import.source).ok()?;
+                let source_symbols =
+                    Self::get_file_symbols(&mut workspace, &source)?;
+                let id = source_symbols
+                    .exports
+                    .iter()
+                    .find(|export| export.name == import.name)?
+                    .id;
+                Some((source, source_symbols, id))
+            });
+        let (declaration_url, declaration_symbols, declaration_id) =
+            imported_declaration
+                .unwrap_or_else(|| (url.clone(), symbols, occurrence.id));
+
+        let mut references =
+            self.occurrences_of(&declaration_url, &declaration_symbols, |id| {
+                id == declaration_id
+            });
+
+        // collect the imported names in all other files
+        let Some(export) = declaration_symbols
+            .exports
+            .iter()
+            .find(|export| export.id == declaration_id)
+        else {
+            return references;
+        };
+        let urls = workspace
+            .files()
+            .keys()
+            .filter(|file_url| **file_url != declaration_url)
+            .cloned()
+            .collect::<Vec<_>>();
+        for file_url in urls {
+            let Some(file_symbols) =
+                Self::get_file_symbols(&mut workspace, &file_url)
+            else {
+                continue;
+            };
+            let imported_ids = file_symbols
+                .imports
+                .iter()
+                .filter(|import| {
+                    import.source == declaration_url.as_str()
+                        && import.name == export.name
+                })
+                .map(|import| import.id)
+                .collect::<Vec<VariableId>>();
+            if !imported_ids.is_empty() {
+                references.extend(self.occurrences_of(
+                    &file_url,
+                    &file_symbols,
+                    |id| imported_ids.contains(&id),
+                ));
+            }
+        }
+        references
+    }
+
+    pub fn find_reference_locations(
+        &self,
+        position: &TextDocumentPositionParams,
+        include_declaration: bool,
+    ) -> Vec<Location> {
+        self.find_references(position)
+            .into_iter()
+            .filter(|reference| {
+                include_declaration
+                    || reference.occurrence.kind
+                        != SymbolOccurrenceKind::Declaration
+            })
+            .map(|reference| Location {
+                uri: reference.url,
+                range: reference.range,
+            })
+            .collect()
+    }
+
+    /// Returns the highlights of all occurrences of the symbol at the given position
+    /// in the same file
+    pub fn find_document_highlights(
+        &self,
+        position: &TextDocumentPositionParams,
+    ) -> Vec<DocumentHighlight> {
+        self.find_references(position)
+            .into_iter()
+            .filter(|reference| reference.url == position.text_document.uri)
+            .map(|reference| DocumentHighlight {
+                range: reference.range,
+                kind: Some(match reference.occurrence.kind {
+                    SymbolOccurrenceKind::Read => DocumentHighlightKind::READ,
+                    SymbolOccurrenceKind::Declaration
+                    | SymbolOccurrenceKind::Write => {
+                        DocumentHighlightKind::WRITE
+                    }
+                }),
+            })
+            .collect()
+    }
+
+    /// Creates the workspace edit that renames the symbol at the given position
+    /// in all workspace files
+    pub fn get_rename_edit(
+        &self,
+        position: &TextDocumentPositionParams,
+        new_name: &str,
+    ) -> WorkspaceEdit {
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        for reference in self.find_references(position) {
+            changes.entry(reference.url).or_default().push(TextEdit {
+                range: reference.range,
+                new_text: new_name.to_string(),
+            });
+        }
+        WorkspaceEdit::new(changes)
+    }
+
+    /// Returns the hierarchy of all declared variables, types and callables in a file
+    pub fn get_document_symbols(
+        &self,
+        url: &Url,
+    ) -> Option<Vec<DocumentSymbol>> {
+        let mut workspace = self.compiler_workspace.borrow_mut();
+        let file = workspace.get_file_mut(url)?;
+        let rich_ast = file.rich_ast.as_mut()?;
+        let mut collector = DocumentSymbolCollector::new(&file.content);
+        collector.visit_datex_expression(&mut rich_ast.ast).ok()?;
+        Some(
+            collector
+                .symbols
+                .into_iter()
+                .map(|node| self.to_document_symbol(node, &file.content))
+                .collect(),
+        )
+    }
+
+    #[allow(deprecated)]
+    fn to_document_symbol(
+        &self,
+        node: SymbolNode,
+        content: &String,
+    ) -> DocumentSymbol {
+        DocumentSymbol {
+            name: node.name,
+            detail: node.detail,
+            kind: node.kind,
+            tags: None,
+            deprecated: None,
+            range: self
+                .convert_byte_range_to_document_range(&node.span, content),
+            selection_range: self
+                .convert_byte_range_to_document_range(&node.name_span, content),
+            children: Some(
+                node.children
+                    .into_iter()
+                    .map(|child| self.to_document_symbol(child, content))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn get_file_symbols(
+        workspace: &mut CompilerWorkspace,
+        url: &Url,
+    ) -> Option<FileSymbols> {
+        let file = workspace.get_file_mut(url)?;
+        let rich_ast = file.rich_ast.as_mut()?;
+        let mut collector = SymbolCollector::new(&file.content);
+        collector.visit_datex_expression(&mut rich_ast.ast).ok()?;
+        Some(FileSymbols {
+            occurrences: collector.occurrences,
+            imports: collector.imports,
+            exports: module_exports(rich_ast),
+            content: file.content.clone(),
+        })
+    }
+
+    fn find_occurrence_at(
+        symbols: &FileSymbols,
+        offset: usize,
+    ) -> Option<&SymbolOccurrence> {
+        symbols.occurrences.iter().find(|occurrence| {
+            occurrence.span.start <= offset && offset <= occurrence.span.end
+        })
+    }
+
+    fn occurrences_of(
+        &self,
+        url: &Url,
+        symbols: &FileSymbols,
+        matches_id: impl Fn(VariableId) -> bool,
+    ) -> Vec<SymbolReference> {
+        symbols
+            .occurrences
+            .iter()
+            .filter(|occurrence| matches_id(occurrence.id))
+            .map(|occurrence| {
+                self.symbol_reference(
+                    url.clone(),
+                    occurrence.clone(),
+                    &symbols.content,
+                )
+            })
+            .collect()
+    }
+
+    fn symbol_reference(
+        &self,
+        url: Url,
+        occurrence: SymbolOccurrence,
+        content: &String,
+    ) -> SymbolReference {
+        SymbolReference {
+            range: self.convert_byte_range_to_document_range(
+                &occurrence.span,
+                content,
+            ),
+            url,
+            occurrence,
+        }
+    }
+}
+
+/// Returns true if the given name can be used as a variable or type name
+pub fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::test_utils::{service_with_files, url};
+    use realhydroper_lsp::lsp_types::{
+        Position, SymbolKind, TextDocumentIdentifier,
+    };
+
+    fn position(
+        path: &str,
+        line: u32,
+        character: u32,
+    ) -> TextDocumentPositionParams {
+        TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: url(path) },
+            position: Position { line, character },
+        }
+    }
+
+    fn reference_texts(
+        backend: &LanguageServerBackend,
+        references: Vec<SymbolReference>,
+    ) -> Vec<(String, String)> {
+        let workspace = backend.compiler_workspace.borrow();
+        references
+            .into_iter()
+            .map(|reference| {
+                let content =
+                    &workspace.get_file(&reference.url).unwrap().content;
+                (
+                    reference.url.path().to_string(),
+                    content[reference.occurrence.span].to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn references_in_file() {
+        let service = service_with_files(&[(
+            "main.dx",
+            "var count = 1;\ncount = count + 1;\ntype Id = integer;\nconst id: Id = 2;\ncount",
+        )]);
+        let backend = service.inner();
+
+        let references = backend.find_references(&position("main.dx", 1, 10));
+        assert_eq!(references.len(), 4);
+        assert!(
+            reference_texts(backend, references)
+                .iter()
+                .all(|(_, text)| text == "count")
+        );
+
+        let highlights =
+            backend.find_document_highlights(&position("main.dx", 0, 5));
+        assert_eq!(
+            highlights
+                .iter()
+                .map(|highlight| highlight.kind.unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                DocumentHighlightKind::WRITE,
+                DocumentHighlightKind::WRITE,
+                DocumentHighlightKind::READ,
+                DocumentHighlightKind::READ,
+            ]
+        );
+
+        let type_references =
+            backend.find_references(&position("main.dx", 3, 10));
+        assert_eq!(
+            reference_texts(backend, type_references),
+            vec![
+                ("/project/main.dx".to_string(), "Id".to_string()),
+                ("/project/main.dx".to_string(), "Id".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn rename_across_files() {
+        let service = service_with_files(&[
+            ("lib.dx", "export const value = 1;\nconst other = value;"),
+            (
+                "main.dx",
+                "import { value } from \"./lib.dx\";\nvalue + value",
+            ),
+        ]);
+        let backend = service.inner();
+
+        // rename from an access of the imported name
+        let edit = backend.get_rename_edit(&position("main.dx", 1, 9), "total");
+        let changes = edit.changes.unwrap();
+        assert_eq!(changes[&url("lib.dx")].len(), 2);
+        assert_eq!(changes[&url("main.dx")].len(), 3);
+        assert_eq!(
+            changes[&url("lib.dx")][0].range.start,
+            Position {
+                line: 0,
+                character: 13
+            }
+        );
+
+        let locations =
+            backend.find_reference_locations(&position("lib.dx", 0, 14), false);
+        assert_eq!(locations.len(), 3);
+
+        assert!(
+            backend
+                .get_symbol_at_position(&position("main.dx", 1, 6))
+                .is_none()
+        );
+        assert!(is_valid_identifier("total_2"));
+        assert!(!is_valid_identifier("2total"));
+    }
+
+    #[test]
+    fn document_symbols() {
+        let service = service_with_files(&[(
+            "main.dx",
+            "const a = 1;\ntype T = integer;\nfunction add(x: integer) (\n    var sum = x + a;\n    sum\n);",
+        )]);
+        let symbols = service
+            .inner()
+            .get_document_symbols(&url("main.dx"))
+            .unwrap();
+        assert_eq!(
+            symbols
+                .iter()
+                .map(|symbol| (symbol.name.as_str(), symbol.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a", SymbolKind::CONSTANT),
+                ("T", SymbolKind::CLASS),
+                ("add", SymbolKind::FUNCTION),
+            ]
+        );
+        let children = symbols[2].children.as_ref().unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].name, "sum");
+        assert_eq!(
+            symbols[2].selection_range.start,
+            Position {
+                line: 2,
+                character: 9
+            }
+        );
+    }
+}
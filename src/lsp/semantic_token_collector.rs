@@ -0,0 +1,282 @@
+use crate::ast::expressions::VariableKind;
+use crate::ast::expressions::{
+    CallableDeclaration, DatexExpression, Import, TypeDeclaration,
+    VariableAccess, VariableAssignment, VariableDeclaration,
+};
+use crate::ast::resolved_variable::VariableId;
+use crate::ast::type_expressions::TypeExpression;
+use crate::compiler::precompiler::precompiled_ast::{
+    AstMetadata, VariableShape,
+};
+use crate::libs::core::{CoreLibPointerId, get_core_lib_value};
+use crate::lsp::symbol_collector::identifier_span;
+use crate::types::definition::TypeDefinition;
+use crate::values::core_value::CoreValue;
+use crate::values::core_values::r#type::Type;
+use crate::values::pointer::PointerAddress;
+use crate::values::value_container::ValueContainer;
+use crate::visitor::VisitAction;
+use crate::visitor::expression::ExpressionVisitor;
+use crate::visitor::type_expression::TypeExpressionVisitor;
+use core::ops::Range;
+
+/// Semantic classification of a resolved name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemanticSymbolKind {
+    Variable,
+    Constant,
+    Type,
+    Function,
+    Parameter,
+}
+
+/// A name in the source code that could be resolved by the precompiler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemanticSymbol {
+    /// span of the name only
+    pub span: Range<usize>,
+    pub kind: SemanticSymbolKind,
+    pub is_declaration: bool,
+    /// true if the name refers to a value from the core library
+    pub is_core_lib: bool,
+}
+
+/// Collects the semantic kinds of all resolved names in a precompiled AST
+pub struct SemanticTokenCollector<'a> {
+    content: &'a str,
+    metadata: &'a AstMetadata,
+    pub symbols: Vec<SemanticSymbol>,
+}
+
+impl<'a> SemanticTokenCollector<'a> {
+    pub fn new(content: &'a str, metadata: &'a AstMetadata) -> Self {
+        SemanticTokenCollector {
+            content,
+            metadata,
+            symbols: Vec::new(),
+        }
+    }
+
+    fn variable_kind(&self, id: VariableId) -> SemanticSymbolKind {
+        let Some(variable) = self.metadata.variables.get(id) else {
+            return SemanticSymbolKind::Variable;
+        };
+        if variable.var_type.as_ref().is_some_and(is_callable_type) {
+            return SemanticSymbolKind::Function;
+        }
+        match variable.shape {
+            VariableShape::Type => SemanticSymbolKind::Type,
+            VariableShape::Value(VariableKind::Const) => {
+                SemanticSymbolKind::Constant
+            }
+            VariableShape::Value(VariableKind::Var) => {
+                SemanticSymbolKind::Variable
+            }
+        }
+    }
+
+    fn add_variable(
+        &mut self,
+        id: VariableId,
+        name: &str,
+        span: &Range<usize>,
+        is_declaration: bool,
+    ) {
+        let kind = self.variable_kind(id);
+        self.add_symbol(name, span, kind, is_declaration);
+    }
+
+    fn add_symbol(
+        &mut self,
+        name: &str,
+        span: &Range<usize>,
+        kind: SemanticSymbolKind,
+        is_declaration: bool,
+    ) {
+        if let Some(span) = identifier_span(self.content, span, name) {
+            self.symbols.push(SemanticSymbol {
+                span,
+                kind,
+                is_declaration,
+                is_core_lib: false,
+            });
+        }
+    }
+
+    /// Adds a core library value that is referenced by its name
+    fn add_core_lib_reference(
+        &mut self,
+        pointer_address: &PointerAddress,
+        span: &Range<usize>,
+        is_type_expression: bool,
+    ) {
+        let Some(value) = CoreLibPointerId::try_from(pointer_address)
+            .ok()
+            .and_then(get_core_lib_value)
+        else {
+            return;
+        };
+        let kind = if is_type_expression || value.is_type() {
+            SemanticSymbolKind::Type
+        } else if is_callable_value(&value) {
+            SemanticSymbolKind::Function
+        } else {
+            SemanticSymbolKind::Constant
+        };
+        // only names are classified, not pointer addresses written as $...
+        if self
+            .content
+            .get(span.clone())
+            .is_some_and(|text| !text.starts_with('$'))
+        {
+            self.symbols.push(SemanticSymbol {
+                span: span.clone(),
+                kind,
+                is_declaration: false,
+                is_core_lib: true,
+            });
+        }
+    }
+}
+
+/// Returns true if the type is the signature of a function or procedure
+fn is_callable_type(ty: &Type) -> bool {
+    match &ty.type_definition {
+        TypeDefinition::Callable(_) => true,
+        TypeDefinition::Type(ty) => is_callable_type(ty),
+        _ => false,
+    }
+}
+
+fn is_callable_value(value: &ValueContainer) -> bool {
+    matches!(value.to_value().borrow().inner, CoreValue::Callable(_))
+}
+
+impl TypeExpressionVisitor<()> for SemanticTokenCollector<'_> {
+    fn visit_variable_access_type(
+        &mut self,
+        var_access: &mut VariableAccess,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<TypeExpression>, ()> {
+        self.add_symbol(
+            &var_access.name,
+            span,
+            SemanticSymbolKind::Type,
+            false,
+        );
+        Ok(VisitAction::SkipChildren)
+    }
+
+    fn visit_get_reference_type(
+        &mut self,
+        pointer_address: &mut PointerAddress,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<TypeExpression>, ()> {
+        self.add_core_lib_reference(pointer_address, span, true);
+        Ok(VisitAction::SkipChildren)
+    }
+}
+
+impl ExpressionVisitor<()> for SemanticTokenCollector<'_> {
+    fn visit_variable_declaration(
+        &mut self,
+        var_decl: &mut VariableDeclaration,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if let Some(id) = var_decl.id {
+            let name_span = span.start..var_decl.init_expression.span.start;
+            self.add_variable(id, &var_decl.name, &name_span, true);
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_type_declaration(
+        &mut self,
+        type_declaration: &mut TypeDeclaration,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        let name_span = span.start..type_declaration.definition.span.start;
+        self.add_symbol(
+            &type_declaration.name,
+            &name_span,
+            SemanticSymbolKind::Type,
+            true,
+        );
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_callable_declaration(
+        &mut self,
+        callable_declaration: &mut CallableDeclaration,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        // the name and parameters are located before the body
+        let mut header_span = span.start..callable_declaration.body.span.start;
+        if let Some(name) = &callable_declaration.name {
+            self.add_symbol(
+                name,
+                &header_span,
+                SemanticSymbolKind::Function,
+                true,
+            );
+        }
+        let parameters = callable_declaration
+            .parameters
+            .iter()
+            .chain(callable_declaration.rest_parameter.iter());
+        for (name, ty) in parameters {
+            let parameter_span = header_span.start..ty.span.start;
+            self.add_symbol(
+                name,
+                &parameter_span,
+                SemanticSymbolKind::Parameter,
+                true,
+            );
+            header_span.start = ty.span.end.max(header_span.start);
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_variable_assignment(
+        &mut self,
+        var_assign: &mut VariableAssignment,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if let Some(id) = var_assign.id {
+            let name_span = span.start..var_assign.expression.span.start;
+            self.add_variable(id, &var_assign.name, &name_span, false);
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_variable_access(
+        &mut self,
+        var_access: &mut VariableAccess,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        self.add_variable(var_access.id, &var_access.name, span, false);
+        Ok(VisitAction::SkipChildren)
+    }
+
+    fn visit_get_reference(
+        &mut self,
+        pointer_address: &mut PointerAddress,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        self.add_core_lib_reference(pointer_address, span, false);
+        Ok(VisitAction::SkipChildren)
+    }
+
+    fn visit_import(
+        &mut self,
+        import: &mut Import,
+        _: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        for item in &import.items {
+            if let Some(id) = item.id {
+                self.add_variable(id, &item.name, &item.span, true);
+            }
+        }
+        Ok(VisitAction::SkipChildren)
+    }
+}
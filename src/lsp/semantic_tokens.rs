@@ -0,0 +1,514 @@
+use crate::collections::HashMap;
+use crate::lsp::LanguageServerBackend;
+use crate::lsp::semantic_token_collector::{
+    SemanticSymbol, SemanticSymbolKind, SemanticTokenCollector,
+};
+use crate::lsp::utils::{line_starts, offset_to_position};
+use crate::parser::lexer::{
+    SpannedToken, Token, get_spanned_tokens_from_source,
+};
+use crate::visitor::expression::ExpressionVisitor;
+use core::ops::Range;
+use realhydroper_lsp::lsp_types::{
+    SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens,
+    SemanticTokensDelta, SemanticTokensEdit, SemanticTokensLegend,
+};
+use url::Url;
+
+/// Token types reported to the client, the index of a type in this list
+/// is used as the token type in the encoded tokens
+const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::KEYWORD,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::NUMBER,
+    SemanticTokenType::STRING,
+    SemanticTokenType::COMMENT,
+    SemanticTokenType::OPERATOR,
+    SemanticTokenType::new("endpoint"),
+    SemanticTokenType::new("slot"),
+    SemanticTokenType::new("pointer"),
+];
+
+/// Token modifiers reported to the client, the index of a modifier in this list
+/// is the bit in the encoded modifier set
+const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::DEFAULT_LIBRARY,
+    SemanticTokenModifier::DOCUMENTATION,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenType {
+    Keyword,
+    Variable,
+    Parameter,
+    Function,
+    Type,
+    Property,
+    Number,
+    String,
+    Comment,
+    Operator,
+    Endpoint,
+    Slot,
+    Pointer,
+}
+
+const DECLARATION: u32 = 1 << 0;
+const READONLY: u32 = 1 << 1;
+const DEFAULT_LIBRARY: u32 = 1 << 2;
+const DOCUMENTATION: u32 = 1 << 3;
+
+/// Returns the legend for the semantic tokens created by the language server
+pub fn semantic_tokens_legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// Returns the semantic token type and modifiers of a lexer token,
+/// or None if the token is not highlighted semantically
+fn classify_token(token: &Token) -> Option<(TokenType, u32)> {
+    Some(match token {
+        Token::LineDoc(_) => (TokenType::Comment, DOCUMENTATION),
+        Token::Shebang(_) => (TokenType::Comment, 0),
+        Token::Const
+        | Token::Variable
+        | Token::Mutable
+        | Token::MutRef
+        | Token::Function
+        | Token::Procedure
+        | Token::If
+        | Token::Else
+        | Token::Yeet
+        | Token::Yeets
+        | Token::Try
+        | Token::TypeDeclaration
+        | Token::TypeExpressionStart
+        | Token::TypeAlias
+        | Token::Interface
+        | Token::Import
+        | Token::Export
+        | Token::Debugger
+        | Token::Is
+        | Token::Matches
+        | Token::Implements
+        | Token::As
+        | Token::And
+        | Token::Or
+        | Token::True
+        | Token::False
+        | Token::Null => (TokenType::Keyword, 0),
+        Token::IntegerLiteral(_)
+        | Token::BinaryIntegerLiteral(_)
+        | Token::OctalIntegerLiteral(_)
+        | Token::HexadecimalIntegerLiteral(_)
+        | Token::DecimalLiteral(_)
+        | Token::FractionLiteral(_)
+        | Token::Infinity
+        | Token::Nan => (TokenType::Number, 0),
+        Token::StringLiteral(_) => (TokenType::String, 0),
+        Token::Endpoint(_) => (TokenType::Endpoint, 0),
+        Token::Slot(_) | Token::NamedSlot(_) => (TokenType::Slot, 0),
+        Token::PointerAddress(_) => (TokenType::Pointer, 0),
+        Token::Plus
+        | Token::Minus
+        | Token::Star
+        | Token::Caret
+        | Token::Slash
+        | Token::Percent
+        | Token::Assign
+        | Token::AddAssign
+        | Token::SubAssign
+        | Token::MulAssign
+        | Token::DivAssign
+        | Token::ModAssign
+        | Token::Increment
+        | Token::Decrement
+        | Token::DoubleAnd
+        | Token::DoublePipe
+        | Token::DoubleQuestion
+        | Token::Exclamation
+        | Token::Ampersand
+        | Token::Pipe
+        | Token::Arrow
+        | Token::FatArrow
+        | Token::Range
+        | Token::RangeInclusive
+        | Token::Spread
+        | Token::LessEqual
+        | Token::GreaterEqual
+        | Token::StructuralEqual
+        | Token::NotStructuralEqual
+        | Token::Equal
+        | Token::NotEqual => (TokenType::Operator, 0),
+        _ => return None,
+    })
+}
+
+/// Returns the semantic token type and modifiers of a resolved name
+fn classify_symbol(symbol: &SemanticSymbol) -> (TokenType, u32) {
+    let (token_type, mut modifiers) = match symbol.kind {
+        SemanticSymbolKind::Variable => (TokenType::Variable, 0),
+        SemanticSymbolKind::Constant => (TokenType::Variable, READONLY),
+        SemanticSymbolKind::Type => (TokenType::Type, 0),
+        SemanticSymbolKind::Function => (TokenType::Function, 0),
+        SemanticSymbolKind::Parameter => (TokenType::Parameter, 0),
+    };
+    if symbol.is_declaration {
+        modifiers |= DECLARATION;
+    }
+    if symbol.is_core_lib {
+        modifiers |= DEFAULT_LIBRARY;
+    }
+    (token_type, modifiers)
+}
+
+/// Creates the semantic tokens for a source text from the lexer tokens,
+/// using the resolved names of the precompiled AST to classify identifiers
+fn create_semantic_tokens(
+    content: &str,
+    tokens: &[SpannedToken],
+    symbols: &[SemanticSymbol],
+) -> Vec<SemanticToken> {
+    let symbols = symbols
+        .iter()
+        .map(|symbol| (symbol.span.start, symbol))
+        .collect::<HashMap<_, _>>();
+    let mut encoder = SemanticTokenEncoder::new(content);
+    let mut previous_token: Option<&Token> = None;
+    for SpannedToken { token, span } in tokens {
+        let classification = match token {
+            Token::Identifier(name) => match symbols.get(&span.start) {
+                Some(symbol) => Some(classify_symbol(symbol)),
+                // contextual keyword of import statements
+                None if name == "from"
+                    && previous_token == Some(&Token::RightCurly) =>
+                {
+                    Some((TokenType::Keyword, 0))
+                }
+                // unresolved names after a dot are property names
+                None if core::matches!(
+                    previous_token,
+                    Some(Token::Dot | Token::QuestionDot)
+                ) =>
+                {
+                    Some((TokenType::Property, 0))
+                }
+                None => None,
+            },
+            // only the keyword part of "type<"
+            Token::TypeExpressionStart => {
+                encoder.push(
+                    &(span.start..span.end - 1),
+                    TokenType::Keyword,
+                    0,
+                );
+                None
+            }
+            token => classify_token(token),
+        };
+        if let Some((token_type, modifiers)) = classification {
+            encoder.push(span, token_type, modifiers);
+        }
+        previous_token = Some(token);
+    }
+    encoder.tokens
+}
+
+/// Encodes tokens relative to the previous token as required by the LSP
+struct SemanticTokenEncoder<'a> {
+    content: &'a str,
+    /// byte offsets of all line starts
+    line_starts: Vec<usize>,
+    previous: (usize, usize),
+    tokens: Vec<SemanticToken>,
+}
+
+impl<'a> SemanticTokenEncoder<'a> {
+    fn new(content: &'a str) -> Self {
+        SemanticTokenEncoder {
+            content,
+            line_starts: line_starts(content),
+            previous: (0, 0),
+            tokens: Vec::new(),
+        }
+    }
+
+    /// Adds a token, splitting it into one token per line if it spans
+    /// multiple lines
+    fn push(
+        &mut self,
+        span: &Range<usize>,
+        token_type: TokenType,
+        modifiers: u32,
+    ) {
+        let Some(text) = self.content.get(span.clone()) else {
+            return;
+        };
+        let mut start = span.start;
+        for line in text.split('\n') {
+            if !line.is_empty() {
+                self.push_line_token(start, line, token_type, modifiers);
+            }
+            start += line.len() + 1;
+        }
+    }
+
+    /// Adds a token that starts at the given byte offset and does not span multiple lines
+    fn push_line_token(
+        &mut self,
+        start: usize,
+        text: &str,
+        token_type: TokenType,
+        modifiers: u32,
+    ) {
+        let position =
+            offset_to_position(self.content, &self.line_starts, start);
+        let (line, character) =
+            (position.line as usize, position.character as usize);
+        // lengths are counted in utf-16 code units like the positions
+        let length = text.encode_utf16().count();
+        let (previous_line, previous_character) = self.previous;
+        self.tokens.push(SemanticToken {
+            delta_line: (line - previous_line) as u32,
+            delta_start: if line == previous_line {
+                character - previous_character
+            } else {
+                character
+            } as u32,
+            length: length as u32,
+            token_type: token_type as u32,
+            token_modifiers_bitset: modifiers,
+        });
+        self.previous = (line, character);
+    }
+}
+
+/// Returns the edits that transform the previous tokens into the current tokens,
+/// replacing everything between the common prefix and suffix
+fn semantic_token_edits(
+    previous: &[SemanticToken],
+    current: &[SemanticToken],
+) -> Vec<SemanticTokensEdit> {
+    let prefix = previous
+        .iter()
+        .zip(current)
+        .take_while(|(previous, current)| previous == current)
+        .count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(previous, current)| previous == current)
+        .count();
+    if prefix == previous.len() && prefix == current.len() {
+        return vec![];
+    }
+    // offsets are counted in integers, each token consists of 5 integers
+    vec![SemanticTokensEdit {
+        start: (prefix * 5) as u32,
+        delete_count: ((previous.len() - prefix - suffix) * 5) as u32,
+        data: Some(current[prefix..current.len() - suffix].to_vec()),
+    }]
+}
+
+impl LanguageServerBackend {
+    /// Creates the semantic tokens for a workspace file and remembers them
+    /// for subsequent delta requests
+    pub fn get_semantic_tokens(&self, url: &Url) -> Option<SemanticTokens> {
+        let data = self.create_file_semantic_tokens(url)?;
+        let result_id = self.next_semantic_tokens_result_id();
+        self.semantic_tokens
+            .borrow_mut()
+            .insert(url.clone(), (result_id.clone(), data.clone()));
+        Some(SemanticTokens {
+            result_id: Some(result_id),
+            data,
+        })
+    }
+
+    /// Creates the semantic tokens for a workspace file as edits to the tokens
+    /// with the given previous result id.
+    /// Returns the full tokens if the previous result is not known.
+    pub fn get_semantic_tokens_delta(
+        &self,
+        url: &Url,
+        previous_result_id: &str,
+    ) -> Option<Result<SemanticTokensDelta, SemanticTokens>> {
+        let previous = self.semantic_tokens.borrow().get(url).cloned();
+        match previous {
+            Some((result_id, previous_data))
+                if result_id == previous_result_id =>
+            {
+                let tokens = self.get_semantic_tokens(url)?;
+                Some(Ok(SemanticTokensDelta {
+                    edits: semantic_token_edits(&previous_data, &tokens.data),
+                    result_id: tokens.result_id,
+                }))
+            }
+            _ => self.get_semantic_tokens(url).map(Err),
+        }
+    }
+
+    fn next_semantic_tokens_result_id(&self) -> String {
+        let mut counter = self.semantic_tokens_result_counter.borrow_mut();
+        *counter += 1;
+        counter.to_string()
+    }
+
+    fn create_file_semantic_tokens(
+        &self,
+        url: &Url,
+    ) -> Option<Vec<SemanticToken>> {
+        let mut workspace = self.compiler_workspace.borrow_mut();
+        let file = workspace.get_file_mut(url)?;
+        let (tokens, _) = get_spanned_tokens_from_source(&file.content);
+        let symbols = match &mut file.rich_ast {
+            Some(rich_ast) => {
+                let metadata = rich_ast.metadata.borrow();
+                let mut collector =
+                    SemanticTokenCollector::new(&file.content, &metadata);
+                collector.visit_datex_expression(&mut rich_ast.ast).ok()?;
+                collector.symbols
+            }
+            None => vec![],
+        };
+        Some(create_semantic_tokens(&file.content, &tokens, &symbols))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::test_utils::{service, url};
+
+    /// Decodes the relative tokens into (text, type, modifiers) triples
+    fn decode(
+        content: &str,
+        tokens: &[SemanticToken],
+    ) -> Vec<(String, SemanticTokenType, u32)> {
+        let lines = content
+            .split('\n')
+            .map(|line| line.encode_utf16().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let (mut line, mut character) = (0, 0);
+        tokens
+            .iter()
+            .map(|token| {
+                if token.delta_line > 0 {
+                    line += token.delta_line as usize;
+                    character = 0;
+                }
+                character += token.delta_start as usize;
+                (
+                    String::from_utf16(
+                        &lines[line]
+                            [character..character + token.length as usize],
+                    )
+                    .unwrap(),
+                    TOKEN_TYPES[token.token_type as usize].clone(),
+                    token.token_modifiers_bitset,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn semantic_tokens() {
+        let url = url("main.dx");
+        let content = "type Id = integer;\nconst id: Id = 1;\nvar x = @example;\nx = #0 + $123456;\nprint(id, x)";
+        let service = service(&url, content);
+        let tokens = service.inner().get_semantic_tokens(&url).unwrap();
+        let decoded = decode(content, &tokens.data);
+        let expect = |text: &str, token_type: SemanticTokenType, modifiers| {
+            (text.to_string(), token_type, modifiers)
+        };
+        assert_eq!(
+            decoded,
+            vec![
+                expect("type", SemanticTokenType::KEYWORD, 0),
+                expect("Id", SemanticTokenType::TYPE, DECLARATION),
+                expect("=", SemanticTokenType::OPERATOR, 0),
+                expect("integer", SemanticTokenType::TYPE, DEFAULT_LIBRARY),
+                expect("const", SemanticTokenType::KEYWORD, 0),
+                expect(
+                    "id",
+                    SemanticTokenType::VARIABLE,
+                    DECLARATION | READONLY
+                ),
+                expect("Id", SemanticTokenType::TYPE, 0),
+                expect("=", SemanticTokenType::OPERATOR, 0),
+                expect("1", SemanticTokenType::NUMBER, 0),
+                expect("var", SemanticTokenType::KEYWORD, 0),
+                expect("x", SemanticTokenType::VARIABLE, DECLARATION),
+                expect("=", SemanticTokenType::OPERATOR, 0),
+                expect("@example", SemanticTokenType::new("endpoint"), 0),
+                expect("x", SemanticTokenType::VARIABLE, 0),
+                expect("=", SemanticTokenType::OPERATOR, 0),
+                expect("#0", SemanticTokenType::new("slot"), 0),
+                expect("+", SemanticTokenType::OPERATOR, 0),
+                expect("$123456", SemanticTokenType::new("pointer"), 0),
+                expect("print", SemanticTokenType::FUNCTION, DEFAULT_LIBRARY),
+                expect("id", SemanticTokenType::VARIABLE, READONLY),
+                expect("x", SemanticTokenType::VARIABLE, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn semantic_tokens_utf16_offsets() {
+        let url = url("main.dx");
+        let content = "\"😀\" + 1";
+        let service = service(&url, content);
+        let tokens = service.inner().get_semantic_tokens(&url).unwrap();
+        assert_eq!(tokens.data[0].length, 4);
+        assert_eq!(tokens.data[1].delta_start, 5);
+        assert_eq!(
+            decode(content, &tokens.data),
+            vec![
+                ("\"😀\"".to_string(), SemanticTokenType::STRING, 0),
+                ("+".to_string(), SemanticTokenType::OPERATOR, 0),
+                ("1".to_string(), SemanticTokenType::NUMBER, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn semantic_token_delta() {
+        let url = url("main.dx");
+        let service = service(&url, "var a = 1;\nvar b = 2;\na + b");
+        let backend = service.inner();
+        let tokens = backend.get_semantic_tokens(&url).unwrap();
+
+        backend
+            .compiler_workspace
+            .borrow_mut()
+            .load_file(url.clone(), "var a = 1;\nvar b = \"x\";\na + b".into());
+        let delta = backend
+            .get_semantic_tokens_delta(&url, tokens.result_id.as_ref().unwrap())
+            .unwrap()
+            .unwrap();
+        assert_ne!(delta.result_id, tokens.result_id);
+        assert_eq!(delta.edits.len(), 1);
+        assert_eq!(delta.edits[0].start, 7 * 5);
+        assert_eq!(delta.edits[0].delete_count, 5);
+        let data = delta.edits[0].data.as_ref().unwrap();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0].length, 3);
+
+        // unknown result ids return the full tokens
+        assert!(
+            backend
+                .get_semantic_tokens_delta(&url, "unknown")
+                .unwrap()
+                .is_err()
+        );
+    }
+}
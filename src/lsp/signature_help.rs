@@ -0,0 +1,248 @@
+use crate::ast::expressions::{Apply, DatexExpression, DatexExpressionData};
+use crate::compiler::precompiler::precompiled_ast::AstMetadata;
+use crate::libs::core::{CoreLibPointerId, get_core_lib_value};
+use crate::lsp::LanguageServerBackend;
+use crate::types::definition::TypeDefinition;
+use crate::values::core_value::CoreValue;
+use crate::values::core_values::callable::CallableSignature;
+use crate::values::core_values::r#type::Type;
+use crate::visitor::VisitAction;
+use crate::visitor::expression::ExpressionVisitor;
+use crate::visitor::type_expression::TypeExpressionVisitor;
+use core::ops::Range;
+use realhydroper_lsp::lsp_types::{
+    ParameterInformation, ParameterLabel, SignatureHelp, SignatureInformation,
+    TextDocumentPositionParams,
+};
+
+/// Visitor that finds the innermost apply expression whose argument list
+/// contains a given byte position
+struct ApplyFinder {
+    search_pos: usize,
+    found_apply: Option<Apply>,
+}
+
+impl TypeExpressionVisitor<()> for ApplyFinder {}
+
+impl ExpressionVisitor<()> for ApplyFinder {
+    fn visit_apply(
+        &mut self,
+        apply: &mut Apply,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if apply.base.span.end < self.search_pos && self.search_pos <= span.end
+        {
+            // inner apply expressions are visited later and replace this one
+            self.found_apply = Some(apply.clone());
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+}
+
+/// Returns the signature of a callable type
+fn callable_signature(ty: &Type) -> Option<CallableSignature> {
+    match &ty.type_definition {
+        TypeDefinition::Callable(signature) => Some(signature.clone()),
+        TypeDefinition::Type(ty) => callable_signature(ty),
+        _ => None,
+    }
+}
+
+/// Resolves the name and signature of the callee of an apply expression
+fn resolve_callee(
+    base: &DatexExpression,
+    metadata: &AstMetadata,
+) -> Option<(Option<String>, CallableSignature)> {
+    match &base.data {
+        DatexExpressionData::VariableAccess(var_access) => {
+            let signature = metadata
+                .variables
+                .get(var_access.id)?
+                .var_type
+                .as_ref()
+                .and_then(callable_signature)?;
+            Some((Some(var_access.name.clone()), signature))
+        }
+        DatexExpressionData::GetReference(pointer_address) => {
+            let value = CoreLibPointerId::try_from(pointer_address)
+                .ok()
+                .and_then(get_core_lib_value)?;
+            let value = value.to_value();
+            match &value.borrow().inner {
+                CoreValue::Callable(callable) => {
+                    Some((callable.name.clone(), callable.signature.clone()))
+                }
+                _ => None,
+            }
+        }
+        _ => Some((None, base.ty.as_ref().and_then(callable_signature)?)),
+    }
+}
+
+/// Creates the signature label, e.g. "add(x: integer, y: integer) -> integer",
+/// together with the offsets of the parameters in the label
+fn signature_information(
+    name: Option<String>,
+    signature: &CallableSignature,
+    active_parameter: u32,
+) -> SignatureInformation {
+    let mut label =
+        format!("{}(", name.unwrap_or_else(|| signature.kind.to_string()));
+    let mut parameters = Vec::new();
+    let parameter_labels = signature
+        .parameter_types
+        .iter()
+        .map(|(name, ty)| match name {
+            Some(name) => format!("{}: {}", name, ty),
+            None => ty.to_string(),
+        })
+        .chain(signature.rest_parameter_type.iter().map(
+            |(name, ty)| match name {
+                Some(name) => format!("...{}: {}", name, ty),
+                None => format!("...{}", ty),
+            },
+        ));
+    for (index, parameter_label) in parameter_labels.enumerate() {
+        if index > 0 {
+            label.push_str(", ");
+        }
+        // offsets are counted in utf-16 code units
+        let start = label.encode_utf16().count() as u32;
+        label.push_str(&parameter_label);
+        let end = label.encode_utf16().count() as u32;
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: None,
+        });
+    }
+    label.push(')');
+    if let Some(return_type) = &signature.return_type {
+        label.push_str(&format!(" -> {}", return_type));
+    }
+    if let Some(yeet_type) = &signature.yeet_type {
+        label.push_str(&format!(" yeets {}", yeet_type));
+    }
+    SignatureInformation {
+        label,
+        documentation: None,
+        parameters: Some(parameters),
+        active_parameter: Some(active_parameter),
+    }
+}
+
+impl LanguageServerBackend {
+    /// Returns the signature of the callable whose argument list contains the
+    /// given position, with the parameter at the position as active parameter
+    pub fn get_signature_help(
+        &self,
+        position: &TextDocumentPositionParams,
+    ) -> Option<SignatureHelp> {
+        let offset = self.position_to_byte_offset(position);
+        let mut workspace = self.compiler_workspace.borrow_mut();
+        let rich_ast = workspace
+            .get_file_mut(&position.text_document.uri)?
+            .rich_ast
+            .as_mut()?;
+        let mut finder = ApplyFinder {
+            search_pos: offset,
+            found_apply: None,
+        };
+        finder.visit_datex_expression(&mut rich_ast.ast).ok()?;
+        let apply = finder.found_apply?;
+
+        let (name, signature) =
+            resolve_callee(&apply.base, &rich_ast.metadata.borrow())?;
+        let argument_index = apply
+            .arguments
+            .iter()
+            .position(|argument| offset <= argument.span.end)
+            .unwrap_or(apply.arguments.len());
+        // all remaining arguments belong to the rest parameter
+        let parameter_count = signature.parameter_types.len();
+        let active_parameter = if signature.rest_parameter_type.is_some() {
+            argument_index.min(parameter_count)
+        } else {
+            argument_index
+        } as u32;
+
+        Some(SignatureHelp {
+            signatures: vec![signature_information(
+                name,
+                &signature,
+                active_parameter,
+            )],
+            active_signature: Some(0),
+            active_parameter: Some(active_parameter),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::test_utils::{service, url};
+    use realhydroper_lsp::lsp_types::{Position, TextDocumentIdentifier};
+    use url::Url;
+
+    fn position(
+        url: &Url,
+        line: u32,
+        character: u32,
+    ) -> TextDocumentPositionParams {
+        TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: url.clone() },
+            position: Position { line, character },
+        }
+    }
+
+    fn parameter_labels(signature: &SignatureInformation) -> Vec<&str> {
+        signature
+            .parameters
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|parameter| match parameter.label {
+                ParameterLabel::LabelOffsets([start, end]) => {
+                    &signature.label[start as usize..end as usize]
+                }
+                ParameterLabel::Simple(_) => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn signature_help_for_declared_function() {
+        let url = url("main.dx");
+        let service = service(
+            &url,
+            "const add = function add(x: integer, y: text) (x);\nadd(1, \"a\")",
+        );
+        let backend = service.inner();
+
+        let help = backend.get_signature_help(&position(&url, 1, 4)).unwrap();
+        assert_eq!(help.active_parameter, Some(0));
+        let signature = &help.signatures[0];
+        assert!(signature.label.starts_with("add(x: integer, y: text)"));
+        assert_eq!(parameter_labels(signature), vec!["x: integer", "y: text"]);
+
+        let help = backend.get_signature_help(&position(&url, 1, 7)).unwrap();
+        assert_eq!(help.active_parameter, Some(1));
+
+        // outside of the argument list
+        assert!(backend.get_signature_help(&position(&url, 1, 1)).is_none());
+    }
+
+    #[test]
+    fn signature_help_for_core_function() {
+        let url = url("main.dx");
+        let service = service(&url, "print(1, 2, 3)");
+        let help = service
+            .inner()
+            .get_signature_help(&position(&url, 0, 12))
+            .unwrap();
+        let signature = &help.signatures[0];
+        assert_eq!(signature.label, "print(...values: unknown)");
+        assert_eq!(parameter_labels(signature), vec!["...values: unknown"]);
+        assert_eq!(help.active_parameter, Some(0));
+    }
+}
@@ -0,0 +1,199 @@
+use crate::ast::expressions::{
+    DatexExpression, Import, TypeDeclaration, VariableAccess,
+    VariableAssignment, VariableDeclaration,
+};
+use crate::ast::resolved_variable::VariableId;
+use crate::ast::type_expressions::TypeExpression;
+use crate::visitor::VisitAction;
+use crate::visitor::expression::ExpressionVisitor;
+use crate::visitor::type_expression::TypeExpressionVisitor;
+use core::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolOccurrenceKind {
+    /// Declaration of a variable or type, including imported names
+    Declaration,
+    /// Assignment to a variable
+    Write,
+    /// Access of a variable or type
+    Read,
+}
+
+/// An occurrence of a resolved variable or type name in a file
+#[derive(Debug, Clone)]
+pub struct SymbolOccurrence {
+    pub id: VariableId,
+    pub name: String,
+    /// span of the name only
+    pub span: Range<usize>,
+    pub kind: SymbolOccurrenceKind,
+}
+
+/// A variable that is declared by an import
+#[derive(Debug, Clone)]
+pub struct ImportedSymbol {
+    pub id: VariableId,
+    /// resolved module source
+    pub source: String,
+    pub name: String,
+}
+
+/// Collects all occurrences of resolved variables and types in a precompiled AST
+pub struct SymbolCollector<'a> {
+    content: &'a str,
+    pub occurrences: Vec<SymbolOccurrence>,
+    pub imports: Vec<ImportedSymbol>,
+}
+
+impl<'a> SymbolCollector<'a> {
+    pub fn new(content: &'a str) -> Self {
+        SymbolCollector {
+            content,
+            occurrences: Vec::new(),
+            imports: Vec::new(),
+        }
+    }
+
+    fn add_occurrence(
+        &mut self,
+        id: VariableId,
+        name: &str,
+        span: &Range<usize>,
+        kind: SymbolOccurrenceKind,
+    ) {
+        if let Some(span) = identifier_span(self.content, span, name) {
+            self.occurrences.push(SymbolOccurrence {
+                id,
+                name: name.to_string(),
+                span,
+                kind,
+            });
+        }
+    }
+}
+
+/// Returns the span of the first occurrence of the identifier with the given name
+/// within the given span of the content, e.g. the name of a declaration
+pub fn identifier_span(
+    content: &str,
+    span: &Range<usize>,
+    name: &str,
+) -> Option<Range<usize>> {
+    let text = content.get(span.clone())?;
+    let is_identifier_char = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(name)
+        .find(|(index, _)| {
+            let before = text[..*index].chars().next_back();
+            let after = text[index + name.len()..].chars().next();
+            !before.is_some_and(is_identifier_char)
+                && !after.is_some_and(is_identifier_char)
+        })
+        .map(|(index, _)| span.start + index..span.start + index + name.len())
+}
+
+impl TypeExpressionVisitor<()> for SymbolCollector<'_> {
+    fn visit_variable_access_type(
+        &mut self,
+        var_access: &mut VariableAccess,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<TypeExpression>, ()> {
+        self.add_occurrence(
+            var_access.id,
+            &var_access.name,
+            span,
+            SymbolOccurrenceKind::Read,
+        );
+        Ok(VisitAction::SkipChildren)
+    }
+}
+
+impl ExpressionVisitor<()> for SymbolCollector<'_> {
+    fn visit_variable_declaration(
+        &mut self,
+        var_decl: &mut VariableDeclaration,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if let Some(id) = var_decl.id {
+            // the name is located between the keyword and the initializer
+            let name_span = span.start..var_decl.init_expression.span.start;
+            self.add_occurrence(
+                id,
+                &var_decl.name,
+                &name_span,
+                SymbolOccurrenceKind::Declaration,
+            );
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_type_declaration(
+        &mut self,
+        type_declaration: &mut TypeDeclaration,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if let Some(id) = type_declaration.id {
+            let name_span = span.start..type_declaration.definition.span.start;
+            self.add_occurrence(
+                id,
+                &type_declaration.name,
+                &name_span,
+                SymbolOccurrenceKind::Declaration,
+            );
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_variable_assignment(
+        &mut self,
+        var_assign: &mut VariableAssignment,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if let Some(id) = var_assign.id {
+            let name_span = span.start..var_assign.expression.span.start;
+            self.add_occurrence(
+                id,
+                &var_assign.name,
+                &name_span,
+                SymbolOccurrenceKind::Write,
+            );
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_variable_access(
+        &mut self,
+        var_access: &mut VariableAccess,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        self.add_occurrence(
+            var_access.id,
+            &var_access.name,
+            span,
+            SymbolOccurrenceKind::Read,
+        );
+        Ok(VisitAction::SkipChildren)
+    }
+
+    fn visit_import(
+        &mut self,
+        import: &mut Import,
+        _: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        for item in &import.items {
+            if let Some(id) = item.id {
+                self.add_occurrence(
+                    id,
+                    &item.name,
+                    &item.span,
+                    SymbolOccurrenceKind::Declaration,
+                );
+                self.imports.push(ImportedSymbol {
+                    id,
+                    source: import.source.clone(),
+                    name: item.name.clone(),
+                });
+            }
+        }
+        Ok(VisitAction::SkipChildren)
+    }
+}
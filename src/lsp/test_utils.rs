@@ -0,0 +1,42 @@
+use crate::compiler::workspace::CompilerWorkspace;
+use crate::lsp::LanguageServerBackend;
+use crate::runtime::{AsyncContext, Runtime, RuntimeConfig};
+use crate::values::core_values::endpoint::Endpoint;
+use core::str::FromStr;
+use realhydroper_lsp::LspService;
+use url::Url;
+
+/// Returns the url of a file in the test project
+pub fn url(path: &str) -> Url {
+    Url::parse(&format!("file:///project/{path}")).unwrap()
+}
+
+/// Creates a language server with a single loaded file
+pub fn service(url: &Url, content: &str) -> LspService<LanguageServerBackend> {
+    service_with_workspace(|workspace| {
+        workspace.load_file(url.clone(), content.to_string());
+    })
+}
+
+/// Creates a language server with the given (path, content) files loaded
+/// in the test project
+pub fn service_with_files(
+    files: &[(&str, &str)],
+) -> LspService<LanguageServerBackend> {
+    service_with_workspace(|workspace| {
+        for (path, content) in files {
+            workspace.load_file(url(path), content.to_string());
+        }
+    })
+}
+
+fn service_with_workspace(
+    load_files: impl FnOnce(&mut CompilerWorkspace),
+) -> LspService<LanguageServerBackend> {
+    let mut workspace = CompilerWorkspace::new(Runtime::new(
+        RuntimeConfig::new_with_endpoint(Endpoint::from_str("@lsp").unwrap()),
+        AsyncContext::new(),
+    ));
+    load_files(&mut workspace);
+    LspService::new(|client| LanguageServerBackend::new(client, workspace)).0
+}
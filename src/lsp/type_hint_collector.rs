@@ -1,27 +1,57 @@
 use crate::ast::expressions::{DatexExpression, VariableDeclaration};
 use crate::ast::resolved_variable::VariableId;
+use crate::lsp::symbol_collector::identifier_span;
 use crate::visitor::VisitAction;
 use crate::visitor::expression::ExpressionVisitor;
 use crate::visitor::type_expression::TypeExpressionVisitor;
 use core::ops::Range;
 
-#[derive(Default)]
-pub struct TypeHintCollector {
-    pub type_hints: Vec<(usize, VariableId)>,
+/// A variable declaration without type annotation
+#[derive(Debug, Clone)]
+pub struct TypeHint {
+    /// position directly after the variable name, where the type annotation
+    /// would be inserted
+    pub position: usize,
+    pub id: VariableId,
+    /// span of the whole declaration
+    pub declaration_span: Range<usize>,
 }
 
-impl TypeExpressionVisitor<()> for TypeHintCollector {}
+pub struct TypeHintCollector<'a> {
+    content: &'a str,
+    pub type_hints: Vec<TypeHint>,
+}
+
+impl<'a> TypeHintCollector<'a> {
+    pub fn new(content: &'a str) -> Self {
+        TypeHintCollector {
+            content,
+            type_hints: Vec::new(),
+        }
+    }
+}
+
+impl TypeExpressionVisitor<()> for TypeHintCollector<'_> {}
 
-impl ExpressionVisitor<()> for TypeHintCollector {
+impl ExpressionVisitor<()> for TypeHintCollector<'_> {
     fn visit_variable_declaration(
         &mut self,
         var_decl: &mut VariableDeclaration,
         span: &Range<usize>,
     ) -> Result<VisitAction<DatexExpression>, ()> {
-        if var_decl.type_annotation.is_none() {
-            let expr_start = var_decl.init_expression.span.start;
-            // TODO #677: improve
-            self.type_hints.push((expr_start - 3, var_decl.id.unwrap()));
+        if var_decl.type_annotation.is_none()
+            && let Some(id) = var_decl.id
+        {
+            let name_span = span.start..var_decl.init_expression.span.start;
+            if let Some(name_span) =
+                identifier_span(self.content, &name_span, &var_decl.name)
+            {
+                self.type_hints.push(TypeHint {
+                    position: name_span.end,
+                    id,
+                    declaration_span: span.clone(),
+                });
+            }
         }
         Ok(VisitAction::VisitChildren)
     }
@@ -1,6 +1,6 @@
 use crate::ast::expressions::{
-    DatexExpression, DatexExpressionData, List, Map, Statements,
-    VariableAccess, VariableAssignment, VariableDeclaration,
+    DatexExpression, DatexExpressionData, Export, Import, List, Map,
+    Statements, VariableAccess, VariableAssignment, VariableDeclaration,
 };
 use crate::compiler::error::DetailedCompilerErrors;
 use crate::compiler::precompiler::precompiled_ast::VariableMetadata;
@@ -17,7 +17,8 @@ use crate::visitor::VisitAction;
 use crate::visitor::expression::ExpressionVisitor;
 use crate::visitor::type_expression::TypeExpressionVisitor;
 use realhydroper_lsp::lsp_types::{
-    MessageType, Position, Range, TextDocumentPositionParams,
+    MessageType, Position, Range, TextDocumentContentChangeEvent,
+    TextDocumentPositionParams,
 };
 use url::Url;
 
@@ -36,22 +37,43 @@ impl LanguageServerBackend {
                 .await;
             self.collect_compiler_errors(errors, url, &content)
         }
-        if let Some(rich_ast) = &file.rich_ast {
+        let metrics = compiler_workspace.last_rebuild_metrics();
+        for file_metrics in &metrics.files {
             self.client
                 .log_message(
                     MessageType::INFO,
-                    format!("AST: {:#?}", rich_ast.ast),
-                )
-                .await;
-            self.client
-                .log_message(
-                    MessageType::INFO,
-                    format!("AST metadata: {:#?}", *rich_ast.metadata.borrow()),
+                    format!(
+                        "Rebuilt {} (parse: {} ms, precompile: {} ms, statements reused: {}, parsed: {})",
+                        file_metrics.url,
+                        file_metrics.parse_duration.as_millis(),
+                        file_metrics.precompile_duration.as_millis(),
+                        file_metrics.reused_statements,
+                        file_metrics.parsed_statements,
+                    ),
                 )
                 .await;
         }
     }
 
+    /// Applies the changes of an incremental text document sync to the content.
+    /// A change without a range replaces the whole content.
+    pub fn apply_content_changes(
+        content: &mut String,
+        changes: Vec<TextDocumentContentChangeEvent>,
+    ) {
+        for change in changes {
+            let Some(range) = change.range else {
+                *content = change.text;
+                continue;
+            };
+            let line_starts = line_starts(content);
+            let start = position_to_offset(content, &line_starts, range.start);
+            let end =
+                position_to_offset(content, &line_starts, range.end).max(start);
+            content.replace_range(start..end, &change.text);
+        }
+    }
+
     pub(crate) fn get_type_hints(
         &self,
         url: Url,
@@ -60,7 +82,7 @@ impl LanguageServerBackend {
         let file = workspace.get_file_mut(&url).unwrap();
         if let Some(rich_ast) = &mut file.rich_ast {
             let ast = &mut rich_ast.ast;
-            let mut collector = TypeHintCollector::default();
+            let mut collector = TypeHintCollector::new(&file.content);
             collector.visit_datex_expression(ast);
             Some(
                 collector
@@ -68,13 +90,16 @@ impl LanguageServerBackend {
                     .into_iter()
                     .map(|hint| {
                         (
-                            self.byte_offset_to_position(hint.0, &file.content)
-                                .unwrap(),
+                            self.byte_offset_to_position(
+                                hint.position,
+                                &file.content,
+                            )
+                            .unwrap(),
                             rich_ast
                                 .metadata
                                 .borrow()
                                 .variables
-                                .get(hint.1)
+                                .get(hint.id)
                                 .unwrap()
                                 .var_type
                                 .clone(),
@@ -164,7 +189,7 @@ impl LanguageServerBackend {
     }
 
     /// Converts an LSP position (line and character) to a byte offset in the file content.
-    fn position_to_byte_offset(
+    pub(crate) fn position_to_byte_offset(
         &self,
         position: &TextDocumentPositionParams,
     ) -> usize {
@@ -206,30 +231,20 @@ impl LanguageServerBackend {
     }
 
     /// Converts a byte offset to an LSP position (line and character) in the file content.
-    /// TODO #678: check if this is correct, generated with copilot
+    /// Converts a byte offset in the file content to a document position.
+    /// Returns None if the offset is after the end of the content.
     pub fn byte_offset_to_position(
         &self,
         byte_offset: usize,
         file_content: &String,
     ) -> Option<Position> {
-        let mut current_offset = 0;
-        for (line_idx, line) in file_content.lines().enumerate() {
-            let line_length = line.len() + 1; // +1 for the newline character
-            if current_offset + line_length > byte_offset {
-                // The byte offset is within this line
-                let char_offset = line
-                    .char_indices()
-                    .find(|(i, _)| current_offset + i >= byte_offset)
-                    .map(|(i, _)| i)
-                    .unwrap_or(line.len());
-                return Some(Position {
-                    line: line_idx as u32,
-                    character: char_offset as u32,
-                });
-            }
-            current_offset += line_length;
-        }
-        None
+        (byte_offset <= file_content.len()).then(|| {
+            offset_to_position(
+                file_content,
+                &line_starts(file_content),
+                byte_offset,
+            )
+        })
     }
 
     /// Retrieves the text immediately preceding the given position in the document.
@@ -279,34 +294,78 @@ impl LanguageServerBackend {
     }
 
     /// Converts a (line, character) pair to a byte index in the given text.
-    /// Lines and characters are zero-indexed.
-    /// Returns None if the line or character is out of bounds.
+    /// Lines and characters are zero-indexed, characters are counted in
+    /// UTF-16 code units.
+    /// Returns None if the line is out of bounds.
     pub fn line_char_to_byte_index(
         text: &str,
         line: usize,
         character: usize,
     ) -> Option<usize> {
-        let mut lines = text.split('\n');
-
-        // Get the line
-        let line_text = lines.nth(line)?;
+        let line_starts = line_starts(text);
+        (line < line_starts.len()).then(|| {
+            position_to_offset(
+                text,
+                &line_starts,
+                Position::new(line as u32, character as u32),
+            )
+        })
+    }
+}
 
-        // Compute byte index of the start of that line
-        let byte_offset_to_line_start = text
-            .lines()
-            .take(line)
-            .map(|l| l.len() + 1) // +1 for '\n'
-            .sum::<usize>();
+/// Returns the byte offsets of all line starts in the content
+pub(crate) fn line_starts(content: &str) -> Vec<usize> {
+    core::iter::once(0)
+        .chain(content.match_indices('\n').map(|(index, _)| index + 1))
+        .collect()
+}
 
-        // Now find the byte index within that line for the given character offset
-        let byte_offset_within_line = line_text
-            .char_indices()
-            .nth(character)
-            .map(|(i, _)| i)
-            .unwrap_or_else(|| line_text.len());
+/// Converts a byte offset to a position with the character counted in
+/// UTF-16 code units, as the LSP requires.
+/// Offsets inside a multi-byte character are moved to the start of the
+/// character and offsets after the end are clamped to the end of the content.
+pub(crate) fn offset_to_position(
+    content: &str,
+    line_starts: &[usize],
+    offset: usize,
+) -> Position {
+    let mut offset = offset.min(content.len());
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let line = line_starts.partition_point(|&start| start <= offset) - 1;
+    let character = content[line_starts[line]..offset].encode_utf16().count();
+    Position::new(line as u32, character as u32)
+}
 
-        Some(byte_offset_to_line_start + byte_offset_within_line)
+/// Converts a position with the character counted in UTF-16 code units to a
+/// byte offset in the content.
+/// Characters inside a surrogate pair are moved to the start of the pair,
+/// characters after the end of a line are clamped to the end of the line
+/// and lines after the end of the content are clamped to the end of the content.
+pub(crate) fn position_to_offset(
+    content: &str,
+    line_starts: &[usize],
+    position: Position,
+) -> usize {
+    let line = position.line as usize;
+    let Some(&line_start) = line_starts.get(line) else {
+        return content.len();
+    };
+    let line_end = line_starts
+        .get(line + 1)
+        .map_or(content.len(), |next_start| next_start - 1);
+    let line_text = &content[line_start..line_end];
+    let line_text = line_text.strip_suffix('\r').unwrap_or(line_text);
+
+    let mut character = 0;
+    for (index, char) in line_text.char_indices() {
+        character += char.len_utf16();
+        if character > position.character as usize {
+            return line_start + index;
+        }
     }
+    line_start + line_text.len()
 }
 
 /// Visitor that finds the most specific DatexExpression containing a given byte position.
@@ -372,6 +431,22 @@ impl ExpressionVisitor<()> for ExpressionFinder {
         )
     }
 
+    fn visit_export(
+        &mut self,
+        export: &mut Export,
+        span: &core::ops::Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        self.match_span(span, DatexExpressionData::Export(export.clone()))
+    }
+
+    fn visit_import(
+        &mut self,
+        import: &mut Import,
+        span: &core::ops::Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        self.match_span(span, DatexExpressionData::Import(import.clone()))
+    }
+
     fn visit_variable_assignment(
         &mut self,
         var_assign: &mut VariableAssignment,
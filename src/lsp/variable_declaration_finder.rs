@@ -1,4 +1,4 @@
-use crate::ast::expressions::{DatexExpression, VariableDeclaration};
+use crate::ast::expressions::{DatexExpression, Import, VariableDeclaration};
 use crate::visitor::VisitAction;
 use crate::visitor::expression::ExpressionVisitor;
 use crate::visitor::type_expression::TypeExpressionVisitor;
@@ -8,6 +8,8 @@ use core::ops::Range;
 pub struct VariableDeclarationFinder {
     pub var_id: usize,
     pub variable_declaration_position: Option<Range<usize>>,
+    /// (module source, name) if the variable is declared by an import
+    pub import: Option<(String, String)>,
 }
 
 impl VariableDeclarationFinder {
@@ -15,6 +17,7 @@ impl VariableDeclarationFinder {
         VariableDeclarationFinder {
             var_id,
             variable_declaration_position: None,
+            import: None,
         }
     }
 }
@@ -35,4 +38,23 @@ impl ExpressionVisitor<()> for VariableDeclarationFinder {
             Ok(VisitAction::VisitChildren)
         }
     }
+
+    fn visit_import(
+        &mut self,
+        import: &mut Import,
+        _: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if let Some(item) = import
+            .items
+            .iter()
+            .find(|item| item.id == Some(self.var_id))
+        {
+            self.variable_declaration_position = Some(item.span.clone());
+            self.import = Some((import.source.clone(), item.name.clone()));
+            // early abort
+            Err(())
+        } else {
+            Ok(VisitAction::SkipChildren)
+        }
+    }
 }
@@ -0,0 +1,159 @@
+use crate::ast::expressions::{
+    CallableDeclaration, DatexExpression, DatexExpressionData, Import,
+    TypeDeclaration, VariableDeclaration, VariableKind,
+};
+use crate::ast::resolved_variable::VariableId;
+use crate::compiler::precompiler::precompiled_ast::{
+    VariableMetadata, VariableShape,
+};
+use crate::visitor::VisitAction;
+use crate::visitor::expression::ExpressionVisitor;
+use crate::visitor::type_expression::TypeExpressionVisitor;
+use core::ops::Range;
+
+/// Collects the variables and types that can be accessed at a position in a file.
+/// Variables are visible after their declaration within the enclosing expression,
+/// type declarations are visible in the whole enclosing expression, and
+/// top-level declarations are visible in the rest of the file.
+pub struct VisibleVariableCollector {
+    offset: usize,
+    /// spans of the expressions that enclose the currently visited expression
+    scopes: Vec<Range<usize>>,
+    /// ids of the visible variables that are declared in the file
+    pub variable_ids: Vec<VariableId>,
+    /// visible parameters of callable and generic type declarations, which
+    /// are not referenced by an id in the AST
+    pub parameters: Vec<VariableMetadata>,
+}
+
+impl VisibleVariableCollector {
+    pub fn new(offset: usize) -> Self {
+        VisibleVariableCollector {
+            offset,
+            scopes: Vec::new(),
+            variable_ids: Vec::new(),
+            parameters: Vec::new(),
+        }
+    }
+
+    fn contains_offset(&self, span: &Range<usize>) -> bool {
+        span.start <= self.offset && self.offset <= span.end
+    }
+
+    /// Returns true if the offset is within the scope of a declaration
+    /// in the currently visited expression
+    fn is_in_declaration_scope(&self) -> bool {
+        match self.scopes.len() {
+            // declarations on the top level are visible in the whole file
+            0..=2 => true,
+            len => self.contains_offset(&self.scopes[len - 2]),
+        }
+    }
+
+    fn add_parameter(&mut self, name: &str, shape: VariableShape) {
+        self.parameters.push(VariableMetadata {
+            original_realm_index: 0,
+            is_cross_realm: false,
+            shape,
+            var_type: None,
+            name: name.to_string(),
+        });
+    }
+}
+
+/// Exported declarations are declared in the surrounding scope
+fn creates_scope(expression: &DatexExpression) -> bool {
+    !core::matches!(expression.data, DatexExpressionData::Export(_))
+}
+
+impl TypeExpressionVisitor<()> for VisibleVariableCollector {}
+
+impl ExpressionVisitor<()> for VisibleVariableCollector {
+    fn before_visit_datex_expression(
+        &mut self,
+        expression: &mut DatexExpression,
+    ) {
+        if creates_scope(expression) {
+            self.scopes.push(expression.span.clone());
+        }
+    }
+
+    fn after_visit_datex_expression(
+        &mut self,
+        expression: &mut DatexExpression,
+    ) {
+        if creates_scope(expression) {
+            self.scopes.pop();
+        }
+    }
+
+    fn visit_variable_declaration(
+        &mut self,
+        var_decl: &mut VariableDeclaration,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if let Some(id) = var_decl.id
+            && span.end <= self.offset
+            && self.is_in_declaration_scope()
+        {
+            self.variable_ids.push(id);
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_type_declaration(
+        &mut self,
+        type_declaration: &mut TypeDeclaration,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if let Some(id) = type_declaration.id
+            && self.is_in_declaration_scope()
+        {
+            self.variable_ids.push(id);
+        }
+        if self.contains_offset(span) {
+            for name in &type_declaration.generic_parameters {
+                self.add_parameter(name, VariableShape::Type);
+            }
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_callable_declaration(
+        &mut self,
+        callable_declaration: &mut CallableDeclaration,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if !self.contains_offset(span) {
+            return Ok(VisitAction::SkipChildren);
+        }
+        for name in &callable_declaration.generic_parameters {
+            self.add_parameter(name, VariableShape::Type);
+        }
+        let parameter_names = callable_declaration
+            .parameters
+            .iter()
+            .chain(&callable_declaration.rest_parameter)
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        for name in parameter_names {
+            self.add_parameter(
+                &name,
+                VariableShape::Value(VariableKind::Const),
+            );
+        }
+        Ok(VisitAction::VisitChildren)
+    }
+
+    fn visit_import(
+        &mut self,
+        import: &mut Import,
+        span: &Range<usize>,
+    ) -> Result<VisitAction<DatexExpression>, ()> {
+        if span.end <= self.offset && self.is_in_declaration_scope() {
+            self.variable_ids
+                .extend(import.items.iter().filter_map(|item| item.id));
+        }
+        Ok(VisitAction::SkipChildren)
+    }
+}
@@ -5,6 +5,7 @@ use crate::global::dxb_block::{
     IncomingSection, IncomingSectionIndex, OutgoingContextId,
     OutgoingSectionIndex,
 };
+use crate::global::protocol_structures::block_header::BlockType;
 use crate::network::com_interfaces::com_interface_socket::ComInterfaceSocketUUID;
 use crate::std_random::RandomState;
 use crate::stdlib::boxed::Box;
@@ -22,6 +23,10 @@ use core::prelude::rust_2024::*;
 use log::info;
 use ringmap::RingMap;
 
+/// maximum number of disposed scope context ids that are remembered
+/// to detect late blocks for already disposed contexts
+const MAX_DISPOSED_SCOPE_CONTEXTS: usize = 500;
+
 // TODO #170: store scope memory
 #[derive(Debug)]
 pub struct ScopeContext {
@@ -29,7 +34,6 @@ pub struct ScopeContext {
     pub next_block_number: IncomingBlockNumber,
     /// timestamp of the last keep alive block
     /// when a specific time has passed since the timestamp, the scope context is disposed
-    /// TODO #171: implement dispose of scope context
     pub keep_alive_timestamp: u64,
     // a reference to the sender for the current section
     pub current_queue_sender: Option<UnboundedSender<DXBBlock>>,
@@ -66,10 +70,18 @@ pub struct BlockHandler {
     /// a map of active request scopes for incoming blocks
     pub block_cache: RefCell<HashMap<IncomingEndpointContextId, ScopeContext>>,
 
+    /// ids of recently disposed scope contexts
+    pub disposed_scope_contexts:
+        RefCell<RingMap<IncomingEndpointContextId, (), RandomState>>,
+
     /// a queue of incoming request scopes
     /// the scopes can be retrieved from the request_scopes map
     pub incoming_sections_queue: RefCell<VecDeque<IncomingSection>>,
 
+    /// a queue of contexts for which a cancellation was requested by the sender
+    pub incoming_cancellations_queue:
+        RefCell<VecDeque<IncomingEndpointContextId>>,
+
     /// a map of observers for incoming response blocks (by context_id + block_index)
     /// contains an observer callback and an optional queue of blocks if the response block is a multi-block stream
     pub section_observers: RefCell<
@@ -86,7 +98,12 @@ impl Debug for BlockHandler {
         f.debug_struct("BlockHandler")
             .field("current_context_id", &self.current_context_id)
             .field("block_cache", &self.block_cache)
+            .field("disposed_scope_contexts", &self.disposed_scope_contexts)
             .field("incoming_sections_queue", &self.incoming_sections_queue)
+            .field(
+                "incoming_cancellations_queue",
+                &self.incoming_cancellations_queue,
+            )
             .field("incoming_blocks_history", &self.incoming_blocks_history)
             .finish()
     }
@@ -103,7 +120,14 @@ impl BlockHandler {
         BlockHandler {
             current_context_id: RefCell::new(0),
             block_cache: RefCell::new(HashMap::new()),
+            disposed_scope_contexts: RefCell::new(
+                RingMap::with_capacity_and_hasher(
+                    MAX_DISPOSED_SCOPE_CONTEXTS,
+                    RandomState::default(),
+                ),
+            ),
             incoming_sections_queue: RefCell::new(VecDeque::new()),
+            incoming_cancellations_queue: RefCell::new(VecDeque::new()),
             section_observers: RefCell::new(HashMap::new()),
             incoming_blocks_history: RefCell::new(
                 RingMap::with_capacity_and_hasher(500, RandomState::default()),
@@ -151,18 +175,20 @@ impl BlockHandler {
         let context_id = block.block_header.context_id;
         let section_index = block.block_header.section_index;
         let block_number = block.block_header.block_number;
-        let is_response = block
-            .block_header
-            .flags_and_timestamp
-            .block_type()
-            .is_response();
+        let block_type = block.block_header.flags_and_timestamp.block_type();
 
         info!(
             "Received block (context={context_id}, section={section_index}, block_nr={block_number})"
         );
 
+        // cancellations are handled by the runtime
+        if block_type == BlockType::Cancel {
+            self.incoming_cancellations_queue
+                .borrow_mut()
+                .push_back(block.get_endpoint_context_id());
+        }
         // handle observers if response block
-        if is_response {
+        else if block_type.is_response() {
             self.handle_incoming_response_block(block);
         } else {
             self.handle_incoming_request_block(block);
@@ -235,11 +261,24 @@ impl BlockHandler {
         let has_scope_context =
             self.block_cache.borrow().contains_key(&endpoint_context_id);
 
-        // Case 1: shortcut if no scope context exists and the block is a single block
+        // Case 1: shortcut if no scope context exists and the block is the only block in the context
+        // (if only the section ends, a scope context is required to keep track of following sections)
+        if !has_scope_context && block_number == 0 && is_end_of_context {
+            return vec![IncomingSection::SingleBlock((
+                Some(block),
+                section_context_id.clone(),
+            ))];
+        }
+
+        // Case 1b: a late block for an already disposed scope context is passed on directly
+        // as a single block section, so that it can be rejected by the receiver
         if !has_scope_context
-            && block_number == 0
-            && (is_end_of_section || is_end_of_context)
+            && block_number != 0
+            && self.is_scope_context_disposed(&endpoint_context_id)
         {
+            log::warn!(
+                "Received late block {block_number} for disposed scope context {endpoint_context_id:?}"
+            );
             return vec![IncomingSection::SingleBlock((
                 Some(block),
                 section_context_id.clone(),
@@ -252,6 +291,9 @@ impl BlockHandler {
             .entry(endpoint_context_id.clone())
             .or_default();
 
+        // every new block keeps the scope context alive
+        scope_context.keep_alive_timestamp = Time::now();
+
         // TODO #172: what happens if the endpoint has not received all blocks starting with block_number 0?
         // we should still potentially process those blocks
 
@@ -303,6 +345,9 @@ impl BlockHandler {
                 // if end of scope, remove the scope context
                 if is_end_of_context {
                     request_scopes.remove(&endpoint_context_id);
+                    self.mark_scope_context_as_disposed(
+                        endpoint_context_id.clone(),
+                    );
                     break;
                 }
                 // cleanup if section is finished
@@ -365,6 +410,61 @@ impl BlockHandler {
         }
     }
 
+    /// Disposes the scope context with the given id.
+    /// An open section queue of the scope context is closed and cached blocks are dropped.
+    /// Following blocks for the scope context are passed on as late blocks.
+    pub fn dispose_scope_context(
+        &self,
+        context_id: &IncomingEndpointContextId,
+    ) {
+        if let Some(mut scope_context) =
+            self.block_cache.borrow_mut().remove(context_id)
+            && let Some(sender) = scope_context.current_queue_sender.take()
+        {
+            sender.close_channel();
+        }
+        self.mark_scope_context_as_disposed(context_id.clone());
+    }
+
+    /// Disposes all scope contexts that have not received a block within the given timeout (in milliseconds).
+    pub fn dispose_idle_scope_contexts(&self, timeout: u64) {
+        let now = Time::now();
+        let idle_contexts = self
+            .block_cache
+            .borrow()
+            .iter()
+            .filter(|(_, scope_context)| {
+                now.saturating_sub(scope_context.keep_alive_timestamp) > timeout
+            })
+            .map(|(context_id, _)| context_id.clone())
+            .collect::<Vec<_>>();
+        for context_id in idle_contexts {
+            info!("Disposing idle scope context {context_id:?}");
+            self.dispose_scope_context(&context_id);
+        }
+    }
+
+    /// Returns true if the scope context with the given id was disposed.
+    pub fn is_scope_context_disposed(
+        &self,
+        context_id: &IncomingEndpointContextId,
+    ) -> bool {
+        self.disposed_scope_contexts
+            .borrow()
+            .contains_key(context_id)
+    }
+
+    fn mark_scope_context_as_disposed(
+        &self,
+        context_id: IncomingEndpointContextId,
+    ) {
+        let mut disposed_contexts = self.disposed_scope_contexts.borrow_mut();
+        if disposed_contexts.len() >= MAX_DISPOSED_SCOPE_CONTEXTS {
+            disposed_contexts.pop_front();
+        }
+        disposed_contexts.insert(context_id, ());
+    }
+
     pub fn get_new_context_id(&self) -> OutgoingContextId {
         *self.current_context_id.borrow_mut() += 1;
         *self.current_context_id.borrow()
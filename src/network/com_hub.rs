@@ -1,5 +1,8 @@
 use crate::global::protocol_structures::block_header::BlockType;
-use crate::global::protocol_structures::routing_header::SignatureType;
+use crate::global::protocol_structures::instructions::RawFullPointerAddress;
+use crate::global::protocol_structures::routing_header::{
+    Receivers, SignatureType,
+};
 use crate::stdlib::boxed::Box;
 use crate::stdlib::{cell::RefCell, rc::Rc};
 use crate::task::{self, sleep, spawn_with_panic_notify};
@@ -38,6 +41,7 @@ use crate::network::com_interfaces::com_interface_properties::{
 use crate::network::com_interfaces::com_interface_socket::ComInterfaceSocketUUID;
 use crate::network::com_interfaces::default_com_interfaces::local_loopback_interface::LocalLoopbackInterface;
 use crate::runtime::AsyncContext;
+use crate::values::pointer::PointerAddress;
 use crate::values::value_container::ValueContainer;
 
 #[derive(Debug, Clone)]
@@ -82,6 +86,11 @@ pub type IncomingBlockInterceptor =
 pub type OutgoingBlockInterceptor =
     Box<dyn Fn(&DXBBlock, &ComInterfaceSocketUUID, &[Endpoint]) + 'static>;
 
+/// Resolves the value of a pointer to a list of receiver endpoints.
+/// Returns None if the pointer is not known or does not hold endpoints.
+pub type PointerReceiverResolver =
+    Box<dyn Fn(&RawFullPointerAddress) -> Option<Vec<Endpoint>> + 'static>;
+
 pub struct ComHub {
     /// the runtime endpoint of the hub (@me)
     pub endpoint: Endpoint,
@@ -126,8 +135,14 @@ pub struct ComHub {
 
     pub block_handler: BlockHandler,
 
+    /// a list of endpoints that are subscribed to a pointer, keyed by the
+    /// full pointer address. Used to resolve blocks with pointer receivers
+    pub pointer_subscribers:
+        RefCell<HashMap<PointerAddress, HashSet<Endpoint>>>,
+
     incoming_block_interceptors: RefCell<Vec<IncomingBlockInterceptor>>,
     outgoing_block_interceptors: RefCell<Vec<OutgoingBlockInterceptor>>,
+    pointer_receiver_resolvers: RefCell<Vec<PointerReceiverResolver>>,
 }
 
 impl Debug for ComHub {
@@ -142,6 +157,7 @@ impl Debug for ComHub {
             )
             .field("fallback_sockets", &self.fallback_sockets)
             .field("endpoint_sockets", &self.endpoint_sockets)
+            .field("pointer_subscribers", &self.pointer_subscribers)
             .finish()
     }
 }
@@ -281,8 +297,10 @@ impl ComHub {
             endpoint_sockets_blacklist: RefCell::new(HashMap::new()),
             update_loop_running: RefCell::new(false),
             update_loop_stop_sender: RefCell::new(None),
+            pointer_subscribers: RefCell::new(HashMap::new()),
             incoming_block_interceptors: RefCell::new(Vec::new()),
             outgoing_block_interceptors: RefCell::new(Vec::new()),
+            pointer_receiver_resolvers: RefCell::new(Vec::new()),
         }
     }
 
@@ -365,6 +383,128 @@ impl ComHub {
             .push(Box::new(interceptor));
     }
 
+    /// Register a resolver that is used to resolve the receivers of blocks
+    /// that are addressed to a pointer. The resolver is called at routing time
+    /// with the full pointer address and can return a list of endpoints that
+    /// are stored in the pointer value.
+    pub fn register_pointer_receiver_resolver<F>(&self, resolver: F)
+    where
+        F: Fn(&RawFullPointerAddress) -> Option<Vec<Endpoint>> + 'static,
+    {
+        self.pointer_receiver_resolvers
+            .borrow_mut()
+            .push(Box::new(resolver));
+    }
+
+    /// Subscribes an endpoint to a pointer. Blocks that are addressed to the pointer
+    /// are sent to all subscribed endpoints.
+    /// Returns false if the endpoint was already subscribed or the pointer address
+    /// cannot be used as a block receiver (internal pointers).
+    pub fn add_pointer_subscriber(
+        &self,
+        pointer_address: &PointerAddress,
+        endpoint: Endpoint,
+    ) -> bool {
+        if let Some(address) = self.get_full_pointer_address(pointer_address) {
+            self.pointer_subscribers
+                .borrow_mut()
+                .entry(address)
+                .or_default()
+                .insert(endpoint)
+        } else {
+            false
+        }
+    }
+
+    /// Removes a subscription of an endpoint for a pointer.
+    /// Returns true if the endpoint was subscribed to the pointer.
+    pub fn remove_pointer_subscriber(
+        &self,
+        pointer_address: &PointerAddress,
+        endpoint: &Endpoint,
+    ) -> bool {
+        let Some(address) = self.get_full_pointer_address(pointer_address)
+        else {
+            return false;
+        };
+        let mut pointer_subscribers = self.pointer_subscribers.borrow_mut();
+        if let Some(subscribers) = pointer_subscribers.get_mut(&address) {
+            let removed = subscribers.remove(endpoint);
+            if subscribers.is_empty() {
+                pointer_subscribers.remove(&address);
+            }
+            removed
+        } else {
+            false
+        }
+    }
+
+    /// Returns all endpoints that are currently subscribed to a pointer
+    pub fn get_pointer_subscribers(
+        &self,
+        pointer_address: &PointerAddress,
+    ) -> Vec<Endpoint> {
+        self.get_full_pointer_address(pointer_address)
+            .and_then(|address| {
+                self.pointer_subscribers
+                    .borrow()
+                    .get(&address)
+                    .map(|subscribers| subscribers.iter().cloned().collect())
+            })
+            .unwrap_or_default()
+    }
+
+    /// Converts a pointer address to the full pointer address (with the local endpoint
+    /// as origin for local pointers) that is used as key for the pointer subscribers
+    fn get_full_pointer_address(
+        &self,
+        pointer_address: &PointerAddress,
+    ) -> Option<PointerAddress> {
+        pointer_address
+            .to_raw_full_address(&self.endpoint)
+            .map(|raw_address| PointerAddress::from(&raw_address))
+    }
+
+    /// Resolves a pointer receiver to a list of endpoints.
+    /// The list contains all endpoints that are subscribed to the pointer and
+    /// all endpoints returned by the registered pointer receiver resolvers.
+    pub fn resolve_pointer_receivers(
+        &self,
+        pointer_address: &RawFullPointerAddress,
+    ) -> Vec<Endpoint> {
+        let mut endpoints = self
+            .pointer_subscribers
+            .borrow()
+            .get(&PointerAddress::from(pointer_address))
+            .map(|subscribers| subscribers.iter().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for resolver in self.pointer_receiver_resolvers.borrow().iter() {
+            for endpoint in resolver(pointer_address).unwrap_or_default() {
+                if !endpoints.contains(&endpoint) {
+                    endpoints.push(endpoint);
+                }
+            }
+        }
+
+        endpoints
+    }
+
+    /// Returns a list of all receiver endpoints of a block.
+    /// Pointer receivers are resolved to the endpoints that are currently
+    /// associated with the pointer.
+    pub fn resolve_receiver_endpoints(
+        &self,
+        block: &DXBBlock,
+    ) -> Vec<Endpoint> {
+        match block.receivers() {
+            Receivers::PointerId(pointer_address) => {
+                self.resolve_pointer_receivers(&pointer_address)
+            }
+            _ => block.receiver_endpoints(),
+        }
+    }
+
     pub fn get_interface_by_uuid<T: ComInterface>(
         &self,
         interface_uuid: &ComInterfaceUUID,
@@ -511,7 +651,7 @@ impl ComHub {
             );
         }
 
-        let receivers = block.receiver_endpoints();
+        let receivers = self.resolve_receiver_endpoints(block);
         if !receivers.is_empty() {
             let is_for_own = receivers.iter().any(|e| {
                 e == &self.endpoint
@@ -534,6 +674,17 @@ impl ComHub {
                             socket_uuid.clone(),
                         );
                     }
+                    // executions from the local endpoint are not restricted by the access
+                    // policy, so blocks with a local sender are only accepted over the
+                    // local loopback interface
+                    _ if self.is_local_sender(&block.routing_header.sender)
+                        && !self.is_local_socket(&socket_uuid) =>
+                    {
+                        warn!(
+                            "Block with local sender {} received over remote socket {}. Dropping block...",
+                            block.routing_header.sender, socket_uuid
+                        );
+                    }
                     _ => {
                         self.block_handler.handle_incoming_block(block.clone());
                     }
@@ -651,7 +802,7 @@ impl ComHub {
         // only for debugging traces
         forked: bool,
     ) {
-        let receivers = block.receiver_endpoints();
+        let receivers = self.resolve_receiver_endpoints(&block);
 
         // check if block has already passed this endpoint (-> bounced back block)
         // and add to blacklist for all receiver endpoints
@@ -1216,6 +1367,20 @@ impl ComHub {
             .clone()
     }
 
+    /// Returns true if the endpoint refers to this endpoint
+    fn is_local_sender(&self, sender: &Endpoint) -> bool {
+        sender == &self.endpoint || sender == &Endpoint::LOCAL
+    }
+
+    /// Returns true if the socket belongs to the local loopback interface,
+    /// i.e. all blocks received over the socket were sent by this endpoint
+    fn is_local_socket(&self, socket_uuid: &ComInterfaceSocketUUID) -> bool {
+        let socket = self.get_socket_by_uuid(socket_uuid);
+        let interface_uuid = socket.try_lock().unwrap().interface_uuid.clone();
+        self.get_interface_by_uuid::<LocalLoopbackInterface>(&interface_uuid)
+            .is_some()
+    }
+
     /// Returns the com interface for a given socket UUID
     /// The interface and socket must be registered in the ComHub,
     /// otherwise a panic will be triggered
@@ -1398,7 +1563,7 @@ impl ComHub {
         block: &DXBBlock,
         mut exclude_sockets: Vec<ComInterfaceSocketUUID>,
     ) -> Option<Vec<(Option<ComInterfaceSocketUUID>, Vec<Endpoint>)>> {
-        let receivers = block.receiver_endpoints();
+        let receivers = self.resolve_receiver_endpoints(block);
 
         if !receivers.is_empty() {
             let endpoint_sockets = receivers
@@ -1581,7 +1746,7 @@ impl ComHub {
         let section_index = block.block_header.section_index;
 
         let has_exact_receiver_count = block.has_exact_receiver_count();
-        let receivers = block.receiver_endpoints();
+        let receivers = self.resolve_receiver_endpoints(&block);
 
         let res = self.send_own_block(block).await;
         let failed_endpoints = res.err().unwrap_or_default();
@@ -181,6 +181,8 @@ pub enum Token {
     #[token("--")] Decrement,
     #[token("&&")] DoubleAnd,
     #[token("||")] DoublePipe,
+    #[token("??")] DoubleQuestion,
+    #[token("?.")] QuestionDot,
     #[token("+=")] AddAssign,
     #[token("-=")] SubAssign,
     #[token("*=")] MulAssign,
@@ -206,6 +208,8 @@ pub enum Token {
     #[token("===")] Equal,
     #[token("is")] Is,
     #[token("matches")] Matches,
+    #[token("implements")] Implements,
+    #[token("as")] As,
     #[token("and")] And,
     #[token("or")] Or,
 
@@ -223,10 +227,21 @@ pub enum Token {
     #[token("procedure")] Procedure,
     #[token("if")] If,
     #[token("else")] Else,
+    #[token("yeet")] Yeet,
+    #[token("yeets")] Yeets,
+    #[token("try")] Try,
 
     #[token("type")] TypeDeclaration,
     #[token("type<")] TypeExpressionStart,
     #[token("typealias")] TypeAlias,
+    #[token("interface")] Interface,
+
+    // import and export are reserved, "from" is only a keyword inside of
+    // import statements and can be used as an identifier elsewhere
+    #[token("import")] Import,
+    #[token("export")] Export,
+
+    #[token("debugger")] Debugger,
 
     #[token(".")]
     Dot,
@@ -313,6 +328,8 @@ impl Token {
             Token::Decrement => Some("--"),
             Token::DoubleAnd => Some("&&"),
             Token::DoublePipe => Some("||"),
+            Token::DoubleQuestion => Some("??"),
+            Token::QuestionDot => Some("?."),
             Token::AddAssign => Some("+="),
             Token::SubAssign => Some("-="),
             Token::MulAssign => Some("*="),
@@ -348,6 +365,7 @@ impl Token {
             Token::TypeDeclaration => Some("type"),
             Token::TypeExpressionStart => Some("type<"),
             Token::TypeAlias => Some("typealias"),
+            Token::Interface => Some("interface"),
             Token::MutRef => Some("&mut"),
             Token::And => Some("and"),
             Token::Or => Some("or"),
@@ -355,8 +373,16 @@ impl Token {
             Token::Exclamation => Some("!"),
             Token::Caret => Some("^"),
             Token::Matches => Some("matches"),
+            Token::Implements => Some("implements"),
+            Token::As => Some("as"),
             Token::If => Some("if"),
             Token::Else => Some("else"),
+            Token::Yeet => Some("yeet"),
+            Token::Yeets => Some("yeets"),
+            Token::Try => Some("try"),
+            Token::Import => Some("import"),
+            Token::Export => Some("export"),
+            Token::Debugger => Some("debugger"),
             _ => None,
         }
     }
@@ -797,6 +823,42 @@ mod tests {
         assert_eq!(lexer.next(), None);
     }
 
+    #[test]
+    fn as_operator() {
+        let mut lexer = Token::lexer("a as b");
+        assert_eq!(
+            lexer.next().unwrap(),
+            Ok(Token::Identifier("a".to_string()))
+        );
+        assert_eq!(lexer.next().unwrap(), Ok(Token::As));
+        assert_eq!(
+            lexer.next().unwrap(),
+            Ok(Token::Identifier("b".to_string()))
+        );
+        assert_eq!(lexer.next(), None);
+    }
+
+    #[test]
+    fn optional_operators() {
+        let mut lexer = Token::lexer("a?.b ?? c?");
+        assert_eq!(
+            lexer.next().unwrap(),
+            Ok(Token::Identifier("a".to_string()))
+        );
+        assert_eq!(lexer.next().unwrap(), Ok(Token::QuestionDot));
+        assert_eq!(
+            lexer.next().unwrap(),
+            Ok(Token::Identifier("b".to_string()))
+        );
+        assert_eq!(lexer.next().unwrap(), Ok(Token::DoubleQuestion));
+        assert_eq!(
+            lexer.next().unwrap(),
+            Ok(Token::Identifier("c".to_string()))
+        );
+        assert_eq!(lexer.next().unwrap(), Ok(Token::Placeholder));
+        assert_eq!(lexer.next(), None);
+    }
+
     #[test]
     fn matches_operator() {
         let mut lexer = Token::lexer("a matches b");
@@ -812,6 +874,35 @@ mod tests {
         assert_eq!(lexer.next(), None);
     }
 
+    #[test]
+    fn implements_operator() {
+        let mut lexer = Token::lexer("interface a implements b");
+        assert_eq!(lexer.next().unwrap(), Ok(Token::Interface));
+        assert_eq!(
+            lexer.next().unwrap(),
+            Ok(Token::Identifier("a".to_string()))
+        );
+        assert_eq!(lexer.next().unwrap(), Ok(Token::Implements));
+        assert_eq!(
+            lexer.next().unwrap(),
+            Ok(Token::Identifier("b".to_string()))
+        );
+        assert_eq!(lexer.next(), None);
+    }
+
+    #[test]
+    fn yeet_and_try() {
+        let mut lexer = Token::lexer("try yeet error yeets");
+        assert_eq!(lexer.next().unwrap(), Ok(Token::Try));
+        assert_eq!(lexer.next().unwrap(), Ok(Token::Yeet));
+        assert_eq!(
+            lexer.next().unwrap(),
+            Ok(Token::Identifier("error".to_string()))
+        );
+        assert_eq!(lexer.next().unwrap(), Ok(Token::Yeets));
+        assert_eq!(lexer.next(), None);
+    }
+
     #[test]
     fn line_doc() {
         let mut lexer = Token::lexer("/// This is a line doc\n42");
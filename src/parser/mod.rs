@@ -101,6 +101,20 @@ impl Parser {
         Self::parse_collecting(src, ParserOptions::default())
     }
 
+    /// Parses the given source code as a single top-level statement.
+    /// Returns None if the source contains lexing or parsing errors or
+    /// does not consist of exactly one statement.
+    pub(crate) fn parse_single_statement(src: &str) -> Option<DatexExpression> {
+        let (tokens, errors) = lexer::get_spanned_tokens_from_source(src);
+        if !errors.is_empty() || tokens.is_empty() {
+            return None;
+        }
+        let mut parser =
+            Self::new_from_tokens(tokens, None, ParserOptions::default());
+        let statement = parser.parse_statement().ok()?;
+        (!parser.has_more_tokens()).then_some(statement)
+    }
+
     fn new_from_tokens(
         tokens: Vec<SpannedToken>,
         collected_errors: Option<Vec<SpannedParserError>>,
@@ -186,6 +200,15 @@ impl Parser {
         self.pos < self.tokens.len()
     }
 
+    /// Returns the end position of the last consumed token
+    fn last_token_end(&self) -> usize {
+        self.pos
+            .checked_sub(1)
+            .and_then(|pos| self.tokens.get(pos))
+            .map(|token| token.span.end)
+            .unwrap_or(0)
+    }
+
     fn advance(&mut self) -> Result<SpannedToken, SpannedParserError> {
         if self.pos >= self.tokens.len() {
             return Err(SpannedParserError {
@@ -22,6 +22,13 @@ impl Parser {
         // next token must be identifier
         let (name, _) = self.expect_identifier()?;
 
+        // optional generic parameters
+        let generic_parameters = if self.peek()?.token == Token::LeftAngle {
+            self.parse_generic_parameter_names()?
+        } else {
+            vec![]
+        };
+
         // parse parameters
         let parameters = self.parse_callable_parameters()?;
 
@@ -35,16 +42,27 @@ impl Parser {
             None
         };
 
+        // parse yeet type if next token is "yeets"
+        let yeet_type = if let Ok(token) = self.peek()
+            && token.token == Token::Yeets
+        {
+            self.advance()?;
+            Some(self.parse_type_expression(0)?)
+        } else {
+            None
+        };
+
         // parse function body
         let body = self.parse_parenthesized_statements()?;
         Ok(
             DatexExpressionData::CallableDeclaration(CallableDeclaration {
                 name: Some(name),
                 kind,
+                generic_parameters,
                 parameters,
                 rest_parameter: None, // TODO #662
                 return_type,
-                yeet_type: None, // TODO #663
+                yeet_type,
                 body: Box::new(body),
             })
             .with_span(start_pos..self.get_current_source_position()),
@@ -86,7 +104,7 @@ mod tests {
         DatexExpressionData, Statements,
     };
     use crate::ast::spanned::Spanned;
-    use crate::ast::type_expressions::TypeExpressionData;
+    use crate::ast::type_expressions::{StructuralList, TypeExpressionData};
     use crate::global::operators::BinaryOperator;
     use crate::global::operators::binary::ArithmeticOperator;
     use crate::parser::tests::parse;
@@ -99,6 +117,7 @@ mod tests {
             DatexExpressionData::CallableDeclaration(CallableDeclaration {
                 name: Some(String::from("test")),
                 kind: CallableKind::Function,
+                generic_parameters: vec![],
                 parameters: vec![],
                 rest_parameter: None,
                 return_type: None,
@@ -123,6 +142,7 @@ mod tests {
             DatexExpressionData::CallableDeclaration(CallableDeclaration {
                 name: Some(String::from("doSomething")),
                 kind: CallableKind::Procedure,
+                generic_parameters: vec![],
                 parameters: vec![],
                 rest_parameter: None,
                 return_type: None,
@@ -147,6 +167,7 @@ mod tests {
             DatexExpressionData::CallableDeclaration(CallableDeclaration {
                 name: Some("add".to_string()),
                 kind: CallableKind::Function,
+                generic_parameters: vec![],
                 parameters: vec![
                     (
                         "a".to_string(),
@@ -177,6 +198,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_function_with_yeet_type() {
+        let expr = parse("function load() -> text yeets error ( )");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::CallableDeclaration(CallableDeclaration {
+                name: Some("load".to_string()),
+                kind: CallableKind::Function,
+                generic_parameters: vec![],
+                parameters: vec![],
+                rest_parameter: None,
+                return_type: Some(
+                    TypeExpressionData::Identifier("text".to_string())
+                        .with_default_span()
+                ),
+                yeet_type: Some(
+                    TypeExpressionData::Identifier("error".to_string())
+                        .with_default_span()
+                ),
+                body: Box::new(
+                    DatexExpressionData::Statements(Statements {
+                        statements: vec![],
+                        is_terminated: false,
+                        unbounded: None,
+                    })
+                    .with_default_span()
+                ),
+            })
+        );
+    }
+
     #[test]
     fn parse_function_with_parameters_and_body() {
         let expr =
@@ -186,6 +238,7 @@ mod tests {
             DatexExpressionData::CallableDeclaration(CallableDeclaration {
                 name: Some("greet".to_string()),
                 kind: CallableKind::Function,
+                generic_parameters: vec![],
                 parameters: vec![(
                     "name".to_string(),
                     TypeExpressionData::Identifier("text".to_string())
@@ -217,4 +270,39 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn parse_generic_function() {
+        let expr = parse("function first<T>(list: [T]) -> T ()");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::CallableDeclaration(CallableDeclaration {
+                name: Some("first".to_string()),
+                kind: CallableKind::Function,
+                generic_parameters: vec!["T".to_string()],
+                parameters: vec![(
+                    "list".to_string(),
+                    TypeExpressionData::StructuralList(StructuralList(vec![
+                        TypeExpressionData::Identifier("T".to_string())
+                            .with_default_span()
+                    ]))
+                    .with_default_span()
+                )],
+                rest_parameter: None,
+                return_type: Some(
+                    TypeExpressionData::Identifier("T".to_string())
+                        .with_default_span()
+                ),
+                yeet_type: None,
+                body: Box::new(
+                    DatexExpressionData::Statements(Statements {
+                        statements: vec![],
+                        is_terminated: false,
+                        unbounded: None,
+                    })
+                    .with_default_span()
+                ),
+            })
+        );
+    }
 }
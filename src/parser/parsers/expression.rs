@@ -2,15 +2,15 @@ use crate::ast::expressions::PropertyAccess;
 use crate::ast::expressions::{
     Apply, BinaryOperation, ComparisonOperation, CreateRef, DatexExpression,
     DatexExpressionData, Deref, DerefAssignment, GenericInstantiation,
-    PropertyAssignment, RemoteExecution, SlotAssignment, UnaryOperation,
-    VariableAssignment,
+    PropertyAssignment, RemoteExecution, SlotAssignment, Try, TypeCast,
+    UnaryOperation, VariableAssignment, Yeet,
 };
 use crate::ast::spanned::Spanned;
 use crate::global::operators::binary::{
     ArithmeticOperator, BitwiseOperator, LogicalOperator,
 };
 use crate::global::operators::{
-    ArithmeticUnaryOperator, AssignmentOperator, BinaryOperator,
+    ArithmeticUnaryOperator, AssignmentOperator, BinaryOperator, CastMode,
     ComparisonOperator, LogicalUnaryOperator, UnaryOperator,
 };
 use crate::parser::Parser;
@@ -50,8 +50,8 @@ impl Parser {
         r_bp: u8,
     ) -> Result<DatexExpression, SpannedParserError> {
         Ok(match op.token {
-            // property access
-            Token::Dot => {
+            // property access (a.b) or optional chaining (a?.b)
+            Token::Dot | Token::QuestionDot => {
                 self.advance()?; // consume the dot
 
                 let rhs = self.parse_key()?;
@@ -61,6 +61,7 @@ impl Parser {
                 DatexExpressionData::PropertyAccess(PropertyAccess {
                     base: Box::new(lhs),
                     property: Box::new(rhs),
+                    optional: op.token == Token::QuestionDot,
                 })
                 .with_span(span)
             }
@@ -73,7 +74,8 @@ impl Parser {
             | Token::And
             | Token::Or
             | Token::Ampersand
-            | Token::Pipe => {
+            | Token::Pipe
+            | Token::DoubleQuestion => {
                 self.advance()?; // consume the operator
                 let rhs = self.parse_expression(r_bp)?;
                 let span = lhs.span.start..rhs.span.end;
@@ -90,6 +92,7 @@ impl Parser {
             // comparison operators
             Token::Equal
             | Token::Is
+            | Token::Implements
             | Token::StructuralEqual
             | Token::NotEqual
             | Token::NotStructuralEqual
@@ -108,6 +111,21 @@ impl Parser {
                 .with_span(span)
             }
 
+            // type cast (as), optionally with a saturating modifier
+            Token::As => {
+                self.advance()?; // consume the operator
+                let mode = self.parse_cast_mode()?;
+                let target = self.parse_type_expression(0)?;
+                let span = lhs.span.start..target.span.end;
+
+                DatexExpressionData::TypeCast(TypeCast {
+                    expression: Box::new(lhs),
+                    target,
+                    mode,
+                })
+                .with_span(span)
+            }
+
             // generic parameters or fall back to less than operator if not generic parameters
             Token::LeftAngle => {
                 let generic_params =
@@ -172,6 +190,25 @@ impl Parser {
         })
     }
 
+    /// Parses the optional `saturating` modifier of a type cast.
+    /// `saturating` is only treated as a modifier if it is followed by the target type,
+    /// so that a type named `saturating` can still be used as cast target.
+    fn parse_cast_mode(&mut self) -> Result<CastMode, SpannedParserError> {
+        let is_modifier = matches!(
+            &self.peek()?.token,
+            Token::Identifier(name) if name == "saturating"
+        ) && matches!(
+            self.tokens.get(self.pos + 1).map(|t| &t.token),
+            Some(Token::Identifier(_))
+        );
+        if is_modifier {
+            self.advance()?; // consume the modifier
+            Ok(CastMode::Saturating)
+        } else {
+            Ok(CastMode::Checked)
+        }
+    }
+
     fn parse_assignment(
         &mut self,
         lhs: DatexExpression,
@@ -285,6 +322,9 @@ impl Parser {
             }
             Token::And => BinaryOperator::Logical(LogicalOperator::And),
             Token::Or => BinaryOperator::Logical(LogicalOperator::Or),
+            Token::DoubleQuestion => {
+                BinaryOperator::Logical(LogicalOperator::NullCoalescing)
+            }
             Token::Ampersand => BinaryOperator::Bitwise(BitwiseOperator::And),
             Token::Pipe => BinaryOperator::Bitwise(BitwiseOperator::Or),
             _ => unreachable!(),
@@ -296,6 +336,7 @@ impl Parser {
     ) -> ComparisonOperator {
         match token.token {
             Token::Is => ComparisonOperator::Is,
+            Token::Implements => ComparisonOperator::Implements,
             Token::Equal => ComparisonOperator::Equal,
             Token::StructuralEqual => ComparisonOperator::StructuralEqual,
             Token::NotEqual => ComparisonOperator::NotEqual,
@@ -439,6 +480,26 @@ impl Parser {
                 })
                 .with_span(span))
             }
+            // raise an error (yeet), takes the whole following expression
+            Token::Yeet => {
+                let op = self.advance()?;
+                let rhs = self.parse_expression(0)?;
+                let span = op.span.start..rhs.span.end;
+                Ok(DatexExpressionData::Yeet(Yeet {
+                    expression: Box::new(rhs),
+                })
+                .with_span(span))
+            }
+            // recover from errors (try), takes the whole following expression
+            Token::Try => {
+                let op = self.advance()?;
+                let rhs = self.parse_expression(0)?;
+                let span = op.span.start..rhs.span.end;
+                Ok(DatexExpressionData::Try(Try {
+                    expression: Box::new(rhs),
+                })
+                .with_span(span))
+            }
 
             // everything else is an atom
             _ => self.parse_atom(),
@@ -466,11 +527,14 @@ impl Parser {
             | Token::NotEqual
             | Token::StructuralEqual
             | Token::NotStructuralEqual
-            | Token::Is => Some((5, 6)),
+            | Token::Is
+            | Token::Implements => Some((5, 6)),
             Token::LeftAngle
             | Token::LessEqual
             | Token::RightAngle
             | Token::GreaterEqual => Some((7, 8)),
+            // null coalescing, binds weaker than all other logical and arithmetic operators
+            Token::DoubleQuestion => Some((8, 9)),
             // logical operators
             Token::Or => Some((9, 10)),
             Token::And => Some((11, 12)),
@@ -481,8 +545,10 @@ impl Parser {
             // bitwise operators
             Token::Pipe => Some((18, 19)),
             Token::Ampersand => Some((20, 21)),
+            // type cast, binds weaker than unary operators (-x as integer/u8 is (-x) as integer/u8)
+            Token::As => Some((21, 22)),
             // property access
-            Token::Dot => Some((23, 24)),
+            Token::Dot | Token::QuestionDot => Some((23, 24)),
             // apply (function call, type cast), which has same binding power as member access
             Token::LeftParen
             | Token::LeftCurly
@@ -514,15 +580,16 @@ mod tests {
         Apply, BinaryOperation, ComparisonOperation, CreateRef,
         DatexExpressionData, Deref, DerefAssignment, GenericInstantiation,
         PropertyAccess, PropertyAssignment, RemoteExecution, Slot,
-        SlotAssignment, Statements, UnaryOperation, VariableAssignment,
+        SlotAssignment, Statements, Try, TypeCast, UnaryOperation,
+        VariableAssignment, Yeet,
     };
     use crate::ast::spanned::Spanned;
-    use crate::ast::type_expressions::TypeExpressionData;
+    use crate::ast::type_expressions::{TypeExpressionData, TypeVariantAccess};
     use crate::global::operators::binary::{
         ArithmeticOperator, BitwiseOperator, LogicalOperator,
     };
     use crate::global::operators::{
-        ArithmeticUnaryOperator, AssignmentOperator, BinaryOperator,
+        ArithmeticUnaryOperator, AssignmentOperator, BinaryOperator, CastMode,
         ComparisonOperator, LogicalUnaryOperator, UnaryOperator,
     };
     use crate::parser::errors::ParserError;
@@ -594,6 +661,151 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_type_cast() {
+        let expr = parse("x as integer/u8");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::TypeCast(TypeCast {
+                expression: Box::new(
+                    DatexExpressionData::Identifier("x".to_string())
+                        .with_default_span()
+                ),
+                target: TypeExpressionData::VariantAccess(TypeVariantAccess {
+                    name: "integer".to_string(),
+                    variant: "u8".to_string(),
+                    base: None,
+                })
+                .with_default_span(),
+                mode: CastMode::Checked,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_optional_chaining() {
+        let expr = parse("a?.b.c");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::PropertyAccess(PropertyAccess {
+                base: Box::new(
+                    DatexExpressionData::PropertyAccess(PropertyAccess {
+                        base: Box::new(
+                            DatexExpressionData::Identifier("a".to_string())
+                                .with_default_span()
+                        ),
+                        property: Box::new(
+                            DatexExpressionData::Text("b".to_string())
+                                .with_default_span()
+                        ),
+                        optional: true,
+                    })
+                    .with_default_span()
+                ),
+                property: Box::new(
+                    DatexExpressionData::Text("c".to_string())
+                        .with_default_span()
+                ),
+                optional: false,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_null_coalescing() {
+        let expr = parse("a ?? b + d == c");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::ComparisonOperation(ComparisonOperation {
+                left: Box::new(
+                    DatexExpressionData::BinaryOperation(BinaryOperation {
+                        left: Box::new(
+                            DatexExpressionData::Identifier("a".to_string())
+                                .with_default_span()
+                        ),
+                        operator: BinaryOperator::Logical(
+                            LogicalOperator::NullCoalescing
+                        ),
+                        right: Box::new(
+                            DatexExpressionData::BinaryOperation(
+                                BinaryOperation {
+                                    left: Box::new(
+                                        DatexExpressionData::Identifier(
+                                            "b".to_string()
+                                        )
+                                        .with_default_span()
+                                    ),
+                                    operator: BinaryOperator::Arithmetic(
+                                        ArithmeticOperator::Add
+                                    ),
+                                    right: Box::new(
+                                        DatexExpressionData::Identifier(
+                                            "d".to_string()
+                                        )
+                                        .with_default_span()
+                                    ),
+                                    ty: None,
+                                }
+                            )
+                            .with_default_span()
+                        ),
+                        ty: None,
+                    })
+                    .with_default_span()
+                ),
+                operator: ComparisonOperator::StructuralEqual,
+                right: Box::new(
+                    DatexExpressionData::Identifier("c".to_string())
+                        .with_default_span()
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_saturating_type_cast() {
+        let expr = parse("-x as saturating text");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::TypeCast(TypeCast {
+                expression: Box::new(
+                    DatexExpressionData::UnaryOperation(UnaryOperation {
+                        operator: UnaryOperator::Arithmetic(
+                            ArithmeticUnaryOperator::Minus
+                        ),
+                        expression: Box::new(
+                            DatexExpressionData::Identifier("x".to_string())
+                                .with_default_span()
+                        ),
+                    })
+                    .with_default_span()
+                ),
+                target: TypeExpressionData::Identifier("text".to_string())
+                    .with_default_span(),
+                mode: CastMode::Saturating,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_type_cast_to_type_named_saturating() {
+        let expr = parse("x as saturating");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::TypeCast(TypeCast {
+                expression: Box::new(
+                    DatexExpressionData::Identifier("x".to_string())
+                        .with_default_span()
+                ),
+                target: TypeExpressionData::Identifier(
+                    "saturating".to_string()
+                )
+                .with_default_span(),
+                mode: CastMode::Checked,
+            })
+        );
+    }
+
     #[test]
     fn parse_property_access() {
         let expr = parse("myObject.myProperty");
@@ -608,6 +820,7 @@ mod tests {
                     DatexExpressionData::Text("myProperty".to_string())
                         .with_default_span()
                 ),
+                optional: false,
             })
         );
     }
@@ -626,6 +839,7 @@ mod tests {
                     DatexExpressionData::Text("if".to_string())
                         .with_default_span()
                 ),
+                optional: false,
             })
         );
     }
@@ -650,6 +864,7 @@ mod tests {
                             )
                             .with_default_span()
                         ),
+                        optional: false,
                     })
                     .with_default_span()
                 ),
@@ -657,6 +872,7 @@ mod tests {
                     DatexExpressionData::Text("myProperty".to_string())
                         .with_default_span()
                 ),
+                optional: false,
             })
         );
     }
@@ -687,6 +903,7 @@ mod tests {
                                         )
                                         .with_default_span()
                                     ),
+                                    optional: false,
                                 }
                             )
                             .with_default_span()
@@ -712,6 +929,7 @@ mod tests {
                                         )
                                         .with_default_span()
                                     ),
+                                    optional: false,
                                 }
                             )
                             .with_default_span()
@@ -803,6 +1021,7 @@ mod tests {
                             DatexExpressionData::Text("myFunction".to_string())
                                 .with_default_span()
                         ),
+                        optional: false,
                     })
                     .with_default_span()
                 ),
@@ -1072,6 +1291,7 @@ mod tests {
                             DatexExpressionData::Text("myProperty".to_string())
                                 .with_default_span()
                         ),
+                        optional: false,
                     })
                     .with_default_span()
                 ),
@@ -1116,6 +1336,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_yeet() {
+        let expr = parse("yeet x + 1");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::Yeet(Yeet {
+                expression: Box::new(
+                    DatexExpressionData::BinaryOperation(BinaryOperation {
+                        left: Box::new(
+                            DatexExpressionData::Identifier("x".to_string())
+                                .with_default_span()
+                        ),
+                        operator: BinaryOperator::Arithmetic(
+                            ArithmeticOperator::Add
+                        ),
+                        right: Box::new(
+                            DatexExpressionData::Integer(1.into())
+                                .with_default_span()
+                        ),
+                        ty: None,
+                    })
+                    .with_default_span()
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_try() {
+        let expr = parse("try yeet x");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::Try(Try {
+                expression: Box::new(
+                    DatexExpressionData::Yeet(Yeet {
+                        expression: Box::new(
+                            DatexExpressionData::Identifier("x".to_string())
+                                .with_default_span()
+                        ),
+                    })
+                    .with_default_span()
+                ),
+            })
+        );
+    }
+
     #[test]
     fn parse_dereference_of_reference() {
         let expr = parse("*&myVar");
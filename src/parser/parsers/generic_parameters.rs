@@ -40,6 +40,43 @@ impl Parser {
         Ok((generic_params, end_span))
     }
 
+    /// Parses the generic parameter names of a declaration enclosed in `<` and `>`,
+    /// e.g. `<T, U>` in `type Pair<T, U> = [T, U]`
+    pub(crate) fn parse_generic_parameter_names(
+        &mut self,
+    ) -> Result<Vec<String>, SpannedParserError> {
+        // expect <
+        self.expect(Token::LeftAngle)?;
+
+        let mut names = Vec::new();
+        loop {
+            let (name, _) = self.expect_identifier()?;
+            names.push(name);
+
+            // check for comma or >
+            match self.peek()?.token {
+                Token::Comma => {
+                    self.advance()?;
+                }
+                Token::RightAngle => {
+                    self.advance()?;
+                    break;
+                }
+                _ => {
+                    return Err(SpannedParserError {
+                        error: ParserError::UnexpectedToken {
+                            expected: vec![Token::Comma, Token::RightAngle],
+                            found: self.peek()?.token.clone(),
+                        },
+                        span: self.peek()?.span.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(names)
+    }
+
     /// Tries to parse generic parameters enclosed in `<` and `>`.
     /// If the next tokens do not form valid generic parameters, it rolls back to the original position and returns an Err.
     pub(crate) fn try_parse_generic_parameters_or_roll_back(
@@ -31,10 +31,19 @@ impl Parser {
             | t @ Token::TypeDeclaration
             | t @ Token::If
             | t @ Token::Else
+            | t @ Token::Yeet
+            | t @ Token::Yeets
+            | t @ Token::Try
             | t @ Token::Is
             | t @ Token::Matches
+            | t @ Token::Implements
+            | t @ Token::Interface
+            | t @ Token::As
             | t @ Token::And
-            | t @ Token::Or => {
+            | t @ Token::Or
+            | t @ Token::Import
+            | t @ Token::Export
+            | t @ Token::Debugger => {
                 DatexExpressionData::Text(t.as_const_str().unwrap().to_string())
                     .with_span(self.advance()?.span)
             }
@@ -31,6 +31,7 @@ mod tests {
     use crate::global::operators::BinaryOperator;
     use crate::global::operators::binary::ArithmeticOperator;
     use crate::parser::tests::{parse, try_parse_and_return_on_first_error};
+    use crate::values::core_values::integer::Integer;
 
     #[test]
     fn parse_empty_map() {
@@ -87,7 +88,7 @@ mod tests {
 
     #[test]
     fn parse_map_with_reserved_keyword_keys() {
-        let expr = parse("{if: true, type: false}");
+        let expr = parse("{if: true, type: false, debugger: 1}");
         assert_eq!(
             expr.data,
             DatexExpressionData::Map(Map {
@@ -102,6 +103,12 @@ mod tests {
                             .with_default_span(),
                         DatexExpressionData::Boolean(false).with_default_span()
                     ),
+                    (
+                        DatexExpressionData::Text("debugger".to_string())
+                            .with_default_span(),
+                        DatexExpressionData::Integer(Integer::from(1))
+                            .with_default_span()
+                    ),
                 ]
             })
         );
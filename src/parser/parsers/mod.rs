@@ -6,6 +6,7 @@ pub mod if_else;
 pub mod key;
 pub mod list;
 pub mod map;
+pub mod module;
 pub mod statements;
 pub mod type_declaration;
 pub mod type_expressions;
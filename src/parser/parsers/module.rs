@@ -0,0 +1,224 @@
+use crate::ast::expressions::{
+    DatexExpression, DatexExpressionData, Export, Import, ImportItem,
+};
+use crate::ast::spanned::Spanned;
+use crate::parser::errors::ParserError;
+use crate::parser::lexer::Token;
+use crate::parser::utils::unescape_text;
+use crate::parser::{Parser, SpannedParserError};
+
+impl Parser {
+    /// Parses an exported declaration, e.g. `export const x = 1` or `export type A = integer`
+    pub(crate) fn parse_export(
+        &mut self,
+    ) -> Result<DatexExpression, SpannedParserError> {
+        let start = self.expect(Token::Export)?.span.start;
+        let declaration = match self.peek()?.token {
+            Token::Variable | Token::Const => {
+                self.parse_variable_declaration()?
+            }
+            Token::TypeDeclaration | Token::TypeAlias | Token::Interface => {
+                self.parse_type_declaration()?
+            }
+            _ => {
+                return Err(SpannedParserError {
+                    error: ParserError::UnexpectedToken {
+                        expected: vec![
+                            Token::Const,
+                            Token::Variable,
+                            Token::TypeDeclaration,
+                            Token::TypeAlias,
+                            Token::Interface,
+                        ],
+                        found: self.peek()?.token.clone(),
+                    },
+                    span: self.peek()?.span.clone(),
+                });
+            }
+        };
+        let end = self.last_token_end();
+        Ok(DatexExpressionData::Export(Export {
+            declaration: Box::new(declaration),
+        })
+        .with_span(start..end))
+    }
+
+    /// Parses an import statement, e.g. `import { a, b } from "./other.dx"`
+    pub(crate) fn parse_import(
+        &mut self,
+    ) -> Result<DatexExpression, SpannedParserError> {
+        let start = self.expect(Token::Import)?.span.start;
+        self.expect(Token::LeftCurly)?;
+
+        let mut items = Vec::new();
+        loop {
+            if self.peek()?.token == Token::RightCurly {
+                break;
+            }
+            let (name, span) = self.expect_identifier()?;
+            items.push(ImportItem {
+                id: None,
+                name,
+                span,
+            });
+            // optional trailing comma
+            if self.peek()?.token == Token::Comma {
+                self.advance()?;
+            } else {
+                break;
+            }
+        }
+        self.expect(Token::RightCurly)?;
+        // "from" is a contextual keyword
+        let token = self.advance()?;
+        if token.token != Token::Identifier("from".to_string()) {
+            return Err(SpannedParserError {
+                error: ParserError::UnexpectedToken {
+                    expected: vec![Token::Identifier("from".to_string())],
+                    found: token.token,
+                },
+                span: token.span,
+            });
+        }
+
+        let token = self.advance()?;
+        let source = match token.token {
+            Token::StringLiteral(value) => unescape_text(&value),
+            _ => {
+                return Err(SpannedParserError {
+                    error: ParserError::UnexpectedToken {
+                        expected: vec![Token::StringLiteral("".to_string())],
+                        found: token.token,
+                    },
+                    span: token.span,
+                });
+            }
+        };
+
+        Ok(DatexExpressionData::Import(Import { items, source })
+            .with_span(start..self.last_token_end()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::expressions::{
+        DatexExpressionData, Export, Import, ImportItem, Statements,
+        VariableDeclaration, VariableKind,
+    };
+    use crate::ast::spanned::Spanned;
+    use crate::parser::tests::{parse, try_parse_and_return_on_first_error};
+
+    fn import_item(name: &str) -> ImportItem {
+        ImportItem {
+            id: None,
+            name: name.to_string(),
+            span: 0..0,
+        }
+    }
+
+    #[test]
+    fn parse_import() {
+        let expr = parse(r#"import { a, b } from "./other.dx""#);
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::Import(Import {
+                items: vec![import_item("a"), import_item("b")],
+                source: "./other.dx".to_string(),
+            })
+        );
+        assert_eq!(expr.span, 0..33);
+    }
+
+    #[test]
+    fn parse_import_with_trailing_comma() {
+        let expr = parse(r#"import { a, } from "./other.dx""#);
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::Import(Import {
+                items: vec![import_item("a")],
+                source: "./other.dx".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_import_item_spans() {
+        let expr = parse(r#"import { first, second } from "./other.dx""#);
+        let DatexExpressionData::Import(import) = expr.data else {
+            panic!("Expected import");
+        };
+        assert_eq!(import.items[0].span, 9..14);
+        assert_eq!(import.items[1].span, 16..22);
+    }
+
+    #[test]
+    fn parse_import_without_source() {
+        assert!(try_parse_and_return_on_first_error("import { a }").is_err());
+        assert!(
+            try_parse_and_return_on_first_error("import { a } from x").is_err()
+        );
+    }
+
+    #[test]
+    fn from_is_a_contextual_keyword() {
+        let expr = parse(r#"import { from } from "./other.dx""#);
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::Import(Import {
+                items: vec![import_item("from")],
+                source: "./other.dx".to_string(),
+            })
+        );
+        assert!(
+            try_parse_and_return_on_first_error("const from = 1; from").is_ok()
+        );
+        assert!(
+            try_parse_and_return_on_first_error(r#"import { a } to "x""#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_export() {
+        let expr = parse("export const x = 1; x");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::Statements(Statements::new_unterminated(
+                vec![
+                    DatexExpressionData::Export(Export {
+                        declaration: Box::new(
+                            DatexExpressionData::VariableDeclaration(
+                                VariableDeclaration {
+                                    id: None,
+                                    kind: VariableKind::Const,
+                                    name: "x".to_string(),
+                                    type_annotation: None,
+                                    init_expression: Box::new(
+                                        DatexExpressionData::Integer(1.into())
+                                            .with_default_span()
+                                    ),
+                                }
+                            )
+                            .with_default_span()
+                        ),
+                    })
+                    .with_default_span(),
+                    DatexExpressionData::Identifier("x".to_string())
+                        .with_default_span(),
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_export_of_expression() {
+        assert!(try_parse_and_return_on_first_error("export 1 + 2").is_err());
+    }
+
+    #[test]
+    fn keywords_as_keys() {
+        let expr = parse("{import: 1, export: 2, from: 3}");
+        assert!(matches!(expr.data, DatexExpressionData::Map(_)));
+    }
+}
@@ -91,14 +91,20 @@ impl Parser {
         })
     }
 
-    fn parse_statement(
+    pub(crate) fn parse_statement(
         &mut self,
     ) -> Result<DatexExpression, SpannedParserError> {
         match self.peek()?.token {
             Token::Variable | Token::Const => self.parse_variable_declaration(),
-            Token::TypeDeclaration | Token::TypeAlias => {
+            Token::TypeDeclaration | Token::TypeAlias | Token::Interface => {
                 self.parse_type_declaration()
             }
+            Token::Export => self.parse_export(),
+            Token::Import => self.parse_import(),
+            Token::Debugger => {
+                Ok(DatexExpressionData::Debugger
+                    .with_span(self.advance()?.span))
+            }
             _ => self.parse_expression(0),
         }
     }
@@ -248,4 +254,20 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn debugger_statement() {
+        let expr = parse("debugger; null");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::Statements(Statements {
+                statements: vec![
+                    DatexExpressionData::Debugger.with_default_span(),
+                    DatexExpressionData::Null.with_default_span(),
+                ],
+                is_terminated: false,
+                unbounded: None,
+            })
+        );
+    }
 }
@@ -13,10 +13,13 @@ impl Parser {
     ) -> Result<DatexExpression, SpannedParserError> {
         Ok(match self.peek()?.token.clone() {
             // handle var and const declarations
-            Token::TypeDeclaration | Token::TypeAlias => {
-                let kind = match self.advance()?.token {
+            Token::TypeDeclaration | Token::TypeAlias | Token::Interface => {
+                let keyword = self.advance()?;
+                let start = keyword.span.start;
+                let kind = match keyword.token {
                     Token::TypeDeclaration => TypeDeclarationKind::Nominal,
                     Token::TypeAlias => TypeDeclarationKind::Structural,
+                    Token::Interface => TypeDeclarationKind::Interface,
                     _ => unreachable!(),
                 };
 
@@ -33,28 +36,29 @@ impl Parser {
                 }
 
                 // optional generic parameters
-                // TODO #664: use generic parameters
-                let _generic_params = if self.peek()?.token == Token::LeftAngle
-                {
-                    Some(self.parse_generic_parameters()?)
-                } else {
-                    None
-                };
+                let generic_parameters =
+                    if self.peek()?.token == Token::LeftAngle {
+                        self.parse_generic_parameter_names()?
+                    } else {
+                        vec![]
+                    };
 
                 // expect equals sign
                 self.expect(Token::Assign)?;
 
                 // initializer expression
                 let definition = self.parse_type_expression(0)?;
+                let end = definition.span.end;
 
                 DatexExpressionData::TypeDeclaration(TypeDeclaration {
                     id: None,
                     kind,
                     name,
+                    generic_parameters,
                     definition,
                     hoisted: false,
                 })
-                .with_default_span()
+                .with_span(start..end)
             }
 
             _ => {
@@ -77,7 +81,7 @@ mod tests {
         VariableDeclaration, VariableKind,
     };
     use crate::ast::spanned::Spanned;
-    use crate::ast::type_expressions::TypeExpressionData;
+    use crate::ast::type_expressions::{StructuralMap, TypeExpressionData};
     use crate::parser::tests::{parse, try_parse_and_return_on_first_error};
 
     #[test]
@@ -89,6 +93,7 @@ mod tests {
                 id: None,
                 kind: TypeDeclarationKind::Nominal,
                 name: "myType".to_string(),
+                generic_parameters: vec![],
                 definition: TypeExpressionData::Boolean(true)
                     .with_default_span(),
                 hoisted: false,
@@ -105,6 +110,7 @@ mod tests {
                 id: None,
                 kind: TypeDeclarationKind::Structural,
                 name: "myAlias".to_string(),
+                generic_parameters: vec![],
                 definition: TypeExpressionData::Boolean(false)
                     .with_default_span(),
                 hoisted: false,
@@ -121,13 +127,13 @@ mod tests {
                 id: None,
                 kind: TypeDeclarationKind::Nominal,
                 name: "myType/variantA".to_string(),
+                generic_parameters: vec![],
                 definition: TypeExpressionData::Null.with_default_span(),
                 hoisted: false,
             })
         );
     }
 
-    // TODO #665: generic parameters parsing
     #[test]
     fn parse_type_declaration_with_generic_parameters() {
         let expr = parse("type myType<T, U> = true");
@@ -137,6 +143,7 @@ mod tests {
                 id: None,
                 kind: TypeDeclarationKind::Nominal,
                 name: "myType".to_string(),
+                generic_parameters: vec!["T".to_string(), "U".to_string()],
                 definition: TypeExpressionData::Boolean(true)
                     .with_default_span(),
                 hoisted: false,
@@ -153,10 +160,41 @@ mod tests {
                 id: None,
                 kind: TypeDeclarationKind::Nominal,
                 name: "myType/variantA".to_string(),
+                generic_parameters: vec!["T".to_string()],
                 definition: TypeExpressionData::Boolean(false)
                     .with_default_span(),
                 hoisted: false,
             })
         );
     }
+
+    #[test]
+    fn parse_generic_type_declaration_with_parameter_usage() {
+        let expr = parse("type Box<T> = { value: T }");
+        assert_eq!(
+            expr.data,
+            DatexExpressionData::TypeDeclaration(TypeDeclaration {
+                id: None,
+                kind: TypeDeclarationKind::Nominal,
+                name: "Box".to_string(),
+                generic_parameters: vec!["T".to_string()],
+                definition: TypeExpressionData::StructuralMap(StructuralMap(
+                    vec![(
+                        TypeExpressionData::Text("value".to_string())
+                            .with_default_span(),
+                        TypeExpressionData::Identifier("T".to_string())
+                            .with_default_span()
+                    )]
+                ))
+                .with_default_span(),
+                hoisted: false,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_invalid_generic_parameter() {
+        let result = try_parse_and_return_on_first_error("type Box<1> = 1");
+        assert!(result.is_err());
+    }
 }
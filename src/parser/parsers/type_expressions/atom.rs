@@ -1,5 +1,7 @@
 use crate::ast::spanned::Spanned;
-use crate::ast::type_expressions::{TypeExpression, TypeExpressionData};
+use crate::ast::type_expressions::{
+    GenericAccess, TypeExpression, TypeExpressionData,
+};
 use crate::parser::errors::ParserError;
 use crate::parser::lexer::{DecimalWithVariant, IntegerWithVariant, Token};
 use crate::parser::utils::{
@@ -93,10 +95,23 @@ impl Parser {
         &mut self,
         name: String,
     ) -> Result<TypeExpression, SpannedParserError> {
-        Ok(
-            TypeExpressionData::Identifier(name)
-                .with_span(self.advance()?.span),
-        )
+        let identifier = TypeExpressionData::Identifier(name)
+            .with_span(self.advance()?.span);
+
+        // generic type arguments, e.g. Box<integer>
+        if let Ok(token) = self.peek()
+            && token.token == Token::LeftAngle
+        {
+            let (access, end_span) = self.parse_generic_parameters()?;
+            let span = identifier.span.start..end_span.end;
+            return Ok(TypeExpressionData::GenericAccess(GenericAccess {
+                base: Box::new(identifier),
+                access,
+            })
+            .with_span(span));
+        }
+
+        Ok(identifier)
     }
 
     pub(crate) fn parse_type_string_literal(
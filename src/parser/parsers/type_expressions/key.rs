@@ -33,8 +33,14 @@ impl Parser {
             | t @ Token::TypeDeclaration
             | t @ Token::If
             | t @ Token::Else
+            | t @ Token::Yeet
+            | t @ Token::Yeets
+            | t @ Token::Try
             | t @ Token::Is
             | t @ Token::Matches
+            | t @ Token::Implements
+            | t @ Token::Interface
+            | t @ Token::As
             | t @ Token::And
             | t @ Token::Or => {
                 TypeExpressionData::Text(t.as_const_str().unwrap().to_string())
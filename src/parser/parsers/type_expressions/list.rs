@@ -1,8 +1,12 @@
 use crate::ast::spanned::Spanned;
 use crate::ast::type_expressions::TypeExpression;
-use crate::ast::type_expressions::{StructuralList, TypeExpressionData};
-use crate::parser::lexer::Token;
+use crate::ast::type_expressions::{
+    FixedSizeList, SliceList, StructuralList, TypeExpressionData,
+};
+use crate::parser::errors::ParserError;
+use crate::parser::lexer::{SpannedToken, Token};
 use crate::parser::{Parser, SpannedParserError};
+use crate::values::core_values::error::NumberParseError;
 
 impl Parser {
     pub fn parse_type_list(
@@ -14,6 +18,18 @@ impl Parser {
         while self.peek()?.token != Token::RightBracket {
             items.push(self.parse_type_expression(0)?);
 
+            // fixed size list, e.g. [integer; 5]
+            if items.len() == 1 && self.peek()?.token == Token::Semicolon {
+                self.advance()?;
+                let size = self.parse_list_size()?;
+                let end = self.expect(Token::RightBracket)?.span.end;
+                return Ok(TypeExpressionData::FixedSizeList(FixedSizeList {
+                    ty: Box::new(items.remove(0)),
+                    size,
+                })
+                .with_span(start..end));
+            }
+
             if self.peek()?.token == Token::Comma {
                 self.advance()?;
             }
@@ -23,13 +39,67 @@ impl Parser {
         Ok(TypeExpressionData::StructuralList(StructuralList(items))
             .with_span(start..end))
     }
+
+    /// Parses the postfix list notation after an item type,
+    /// e.g. integer[] for a slice list or integer[5] for a fixed size list
+    pub(crate) fn parse_type_list_postfix(
+        &mut self,
+        item_type: TypeExpression,
+    ) -> Result<TypeExpression, SpannedParserError> {
+        self.expect(Token::LeftBracket)?;
+        let start = item_type.span.start;
+
+        if self.peek()?.token == Token::RightBracket {
+            let end = self.advance()?.span.end;
+            return Ok(TypeExpressionData::SliceList(SliceList(Box::new(
+                item_type,
+            )))
+            .with_span(start..end));
+        }
+
+        let size = self.parse_list_size()?;
+        let end = self.expect(Token::RightBracket)?.span.end;
+        Ok(TypeExpressionData::FixedSizeList(FixedSizeList {
+            ty: Box::new(item_type),
+            size,
+        })
+        .with_span(start..end))
+    }
+
+    /// Parses the size of a fixed size list, which must be a non-negative integer literal
+    fn parse_list_size(&mut self) -> Result<usize, SpannedParserError> {
+        match self.advance()? {
+            SpannedToken {
+                token: Token::IntegerLiteral(literal),
+                span,
+            } => literal.replace('_', "").parse::<usize>().map_err(|_| {
+                SpannedParserError {
+                    error: ParserError::NumberParseError(
+                        NumberParseError::OutOfRange,
+                    ),
+                    span,
+                }
+            }),
+            token => Err(SpannedParserError {
+                error: ParserError::UnexpectedToken {
+                    expected: vec![Token::IntegerLiteral(
+                        "<integer>".to_string(),
+                    )],
+                    found: token.token.clone(),
+                },
+                span: token.span.clone(),
+            }),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::ast::spanned::Spanned;
-    use crate::ast::type_expressions::StructuralList;
     use crate::ast::type_expressions::TypeExpressionData;
+    use crate::ast::type_expressions::{
+        FixedSizeList, SliceList, StructuralList, Union,
+    };
     use crate::parser::parsers::type_expressions::tests::parse_type_expression;
 
     #[test]
@@ -66,4 +136,70 @@ mod tests {
             ]))
         );
     }
+
+    #[test]
+    fn parse_fixed_size_list() {
+        let expr = parse_type_expression("[integer; 5]");
+        assert_eq!(
+            expr.data,
+            TypeExpressionData::FixedSizeList(FixedSizeList {
+                ty: Box::new(
+                    TypeExpressionData::Identifier("integer".to_string())
+                        .with_default_span()
+                ),
+                size: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_postfix_fixed_size_list() {
+        let expr = parse_type_expression("integer[10]");
+        assert_eq!(
+            expr.data,
+            TypeExpressionData::FixedSizeList(FixedSizeList {
+                ty: Box::new(
+                    TypeExpressionData::Identifier("integer".to_string())
+                        .with_default_span()
+                ),
+                size: 10,
+            })
+        );
+        assert_eq!(expr.span, 0..11);
+    }
+
+    #[test]
+    fn parse_slice_list() {
+        let expr = parse_type_expression("text | integer[]");
+        assert_eq!(
+            expr.data,
+            TypeExpressionData::Union(Union(vec![
+                TypeExpressionData::Identifier("text".to_string())
+                    .with_default_span(),
+                TypeExpressionData::SliceList(SliceList(Box::new(
+                    TypeExpressionData::Identifier("integer".to_string())
+                        .with_default_span()
+                )))
+                .with_default_span(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn parse_nested_slice_list() {
+        let expr = parse_type_expression("text[][2]");
+        assert_eq!(
+            expr.data,
+            TypeExpressionData::FixedSizeList(FixedSizeList {
+                ty: Box::new(
+                    TypeExpressionData::SliceList(SliceList(Box::new(
+                        TypeExpressionData::Identifier("text".to_string())
+                            .with_default_span()
+                    )))
+                    .with_default_span()
+                ),
+                size: 2,
+            })
+        );
+    }
 }
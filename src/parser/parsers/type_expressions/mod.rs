@@ -6,7 +6,8 @@ mod map;
 
 use crate::ast::spanned::Spanned;
 use crate::ast::type_expressions::{
-    Intersection, TypeExpression, TypeExpressionData, TypeVariantAccess, Union,
+    ImplType, Intersection, TypeExpression, TypeExpressionData,
+    TypeVariantAccess, Union,
 };
 use crate::global::operators::{ArithmeticUnaryOperator, UnaryOperator};
 use crate::parser::Parser;
@@ -24,6 +25,17 @@ impl Parser {
         let mut lhs = self.parse_type_prefix()?;
 
         while self.has_more_tokens() {
+            // postfix list notation, e.g. integer[] or integer[5]
+            if self.peek()?.token == Token::LeftBracket {
+                lhs = self.parse_type_list_postfix(lhs)?;
+                continue;
+            }
+            // postfix optional notation, e.g. integer? for integer | null
+            if self.peek()?.token == Token::Placeholder {
+                lhs = self.parse_type_optional_postfix(lhs)?;
+                continue;
+            }
+
             let (_, r_bp) =
                 match Parser::type_infix_binding_power(&self.peek()?.token) {
                     Some(bp) if bp.0 >= min_bp => bp,
@@ -38,6 +50,20 @@ impl Parser {
         Ok(lhs)
     }
 
+    /// Parses the `?` suffix of an optional type, which is sugar for `T | null`
+    fn parse_type_optional_postfix(
+        &mut self,
+        ty: TypeExpression,
+    ) -> Result<TypeExpression, SpannedParserError> {
+        let question_mark = self.expect(Token::Placeholder)?.span;
+        let span = ty.span.start..question_mark.end;
+        Ok(TypeExpressionData::Union(Union(vec![
+            ty,
+            TypeExpressionData::Null.with_span(question_mark),
+        ]))
+        .with_span(span))
+    }
+
     fn parse_type_binary_operation(
         &mut self,
         lhs: TypeExpression,
@@ -61,6 +87,24 @@ impl Parser {
                     .with_span(span)
             }
 
+            // interface operator, e.g. { name: text } + Named + Printable
+            Token::Plus => {
+                self.advance()?; // consume operator
+                let rhs = self.parse_type_expression(r_bp)?;
+                let span = lhs.span.start..rhs.span.end;
+                match lhs.data {
+                    TypeExpressionData::ImplType(mut impl_type) => {
+                        impl_type.interfaces.push(rhs);
+                        TypeExpressionData::ImplType(impl_type)
+                    }
+                    _ => TypeExpressionData::ImplType(ImplType {
+                        base: Box::new(lhs),
+                        interfaces: vec![rhs],
+                    }),
+                }
+                .with_span(span)
+            }
+
             // variant access operator (/)
             Token::Slash => {
                 self.advance()?; // consume operator
@@ -196,7 +240,7 @@ mod tests {
     use crate::ast::spanned::Spanned;
     use crate::ast::type_expressions::TypeExpression;
     use crate::ast::type_expressions::{
-        Intersection, TypeExpressionData, Union,
+        GenericAccess, ImplType, Intersection, TypeExpressionData, Union,
     };
     use crate::parser::lexer::get_spanned_tokens_from_source;
     use crate::parser::{Parser, ParserOptions};
@@ -239,6 +283,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_optional_type_expression() {
+        let expr = parse_type_expression("integer? | text");
+        assert_eq!(
+            expr.data,
+            TypeExpressionData::Union(Union(vec![
+                TypeExpressionData::Union(Union(vec![
+                    TypeExpressionData::Identifier("integer".to_string())
+                        .with_default_span(),
+                    TypeExpressionData::Null.with_default_span()
+                ]))
+                .with_default_span(),
+                TypeExpressionData::Identifier("text".to_string())
+                    .with_default_span()
+            ]))
+        );
+    }
+
+    #[test]
+    fn parse_impl_type_expression() {
+        let expr = parse_type_expression("A + B + C | D");
+        assert_eq!(
+            expr.data,
+            TypeExpressionData::Union(Union(vec![
+                TypeExpressionData::ImplType(ImplType {
+                    base: Box::new(
+                        TypeExpressionData::Identifier("A".to_string())
+                            .with_default_span()
+                    ),
+                    interfaces: vec![
+                        TypeExpressionData::Identifier("B".to_string())
+                            .with_default_span(),
+                        TypeExpressionData::Identifier("C".to_string())
+                            .with_default_span()
+                    ],
+                })
+                .with_default_span(),
+                TypeExpressionData::Identifier("D".to_string())
+                    .with_default_span()
+            ]))
+        );
+    }
+
     #[test]
     fn parse_variant_access_expression() {
         let expr = parse_type_expression("MyType/variant");
@@ -254,6 +341,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_generic_access_type_expression() {
+        let expr = parse_type_expression("Pair<integer, Box<text>>");
+        assert_eq!(
+            expr.data,
+            TypeExpressionData::GenericAccess(GenericAccess {
+                base: Box::new(
+                    TypeExpressionData::Identifier("Pair".to_string())
+                        .with_default_span()
+                ),
+                access: vec![
+                    TypeExpressionData::Identifier("integer".to_string())
+                        .with_default_span(),
+                    TypeExpressionData::GenericAccess(GenericAccess {
+                        base: Box::new(
+                            TypeExpressionData::Identifier("Box".to_string())
+                                .with_default_span()
+                        ),
+                        access: vec![
+                            TypeExpressionData::Identifier("text".to_string())
+                                .with_default_span()
+                        ],
+                    })
+                    .with_default_span(),
+                ],
+            })
+        );
+    }
+
     #[test]
     fn parse_ref_type_expression() {
         let expr = parse_type_expression("&MyType");
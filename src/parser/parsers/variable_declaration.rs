@@ -13,7 +13,9 @@ impl Parser {
         Ok(match self.peek()?.token.clone() {
             // handle var and const declarations
             Token::Variable | Token::Const => {
-                let kind = match self.advance()?.token {
+                let keyword = self.advance()?;
+                let start = keyword.span.start;
+                let kind = match keyword.token {
                     Token::Variable => VariableKind::Var,
                     Token::Const => VariableKind::Const,
                     _ => unreachable!(),
@@ -35,6 +37,7 @@ impl Parser {
 
                 // initializer expression
                 let init_expression = self.parse_expression(0)?;
+                let end = init_expression.span.end;
 
                 DatexExpressionData::VariableDeclaration(VariableDeclaration {
                     id: None,
@@ -43,7 +46,7 @@ impl Parser {
                     type_annotation,
                     init_expression: Box::new(init_expression),
                 })
-                .with_default_span()
+                .with_span(start..end)
             }
 
             _ => {
@@ -5,9 +5,14 @@ use crate::runtime::memory::Memory;
 use crate::stdlib::format;
 use crate::stdlib::string::ToString;
 use crate::stdlib::vec::Vec;
+use crate::types::collection_type_definition::CollectionTypeDefinition;
+use crate::types::definition::TypeDefinition;
+use crate::types::structural_type_definition::StructuralTypeDefinition;
+use crate::values::core_values::r#type::Type;
+use crate::values::value::Value;
 use crate::values::value_container::ValueKey;
 use crate::{
-    references::reference::{AccessError, Reference},
+    references::reference::{AccessError, Reference, TypeError},
     values::{core_value::CoreValue, value_container::ValueContainer},
 };
 use core::cell::RefCell;
@@ -31,6 +36,112 @@ impl<'a> From<&'a RefCell<Memory>> for DIFUpdateDataOrMemory<'a> {
     }
 }
 
+/// Returns an error if a list mutation resulting in `new_length` items
+/// would break the list length required by a fixed size list type
+fn invalid_list_length(
+    fixed_size: Option<usize>,
+    new_length: usize,
+) -> Option<AccessError> {
+    fixed_size
+        .filter(|expected| *expected != new_length)
+        .map(|expected| AccessError::InvalidListLength {
+            expected,
+            found: new_length,
+        })
+}
+
+fn type_mismatch(expected: &Type, found: &ValueContainer) -> AccessError {
+    TypeError::TypeMismatch {
+        found: Type::from(&found.to_value().borrow().inner),
+        expected: expected.clone(),
+    }
+    .into()
+}
+
+/// How a mutation is checked against the allowed type of a reference
+enum MutationCheck {
+    /// the mutation needs no further checks
+    Checked,
+    /// the mutated value must still match the allowed type
+    WholeValue(Type),
+}
+
+/// The values that a mutation inserts into a collection value
+enum InsertedValues<'a> {
+    /// list items, e.g. for append and splice
+    Items(&'a [ValueContainer]),
+    /// a map entry or a list item at an index
+    Entry(&'a ValueKey<'a>, &'a ValueContainer),
+    /// no values are inserted, e.g. for delete and clear
+    Nothing,
+}
+
+impl InsertedValues<'_> {
+    /// Checks the inserted values against the element types of the allowed
+    /// type. Returns None if the allowed type has no uniform element types,
+    /// so that the whole mutated value must be checked.
+    fn check(&self, allowed_type: &Type) -> Option<Result<(), AccessError>> {
+        let check_value = |expected: &Type, value: &ValueContainer| {
+            if expected.value_matches(value) {
+                Ok(())
+            } else {
+                Err(type_mismatch(expected, value))
+            }
+        };
+        match (&allowed_type.type_definition, self) {
+            (
+                TypeDefinition::Collection(
+                    CollectionTypeDefinition::List(item_type)
+                    | CollectionTypeDefinition::ListSlice(item_type, _),
+                ),
+                InsertedValues::Items(items),
+            ) => Some(
+                items
+                    .iter()
+                    .try_for_each(|item| check_value(item_type, item)),
+            ),
+            (
+                TypeDefinition::Collection(
+                    CollectionTypeDefinition::List(item_type)
+                    | CollectionTypeDefinition::ListSlice(item_type, _),
+                ),
+                InsertedValues::Entry(_, item),
+            ) => Some(check_value(item_type, item)),
+            (
+                TypeDefinition::Collection(CollectionTypeDefinition::Map {
+                    key: key_type,
+                    value: value_type,
+                }),
+                InsertedValues::Entry(key, value),
+            ) => Some(
+                key.with_value_container(|key| check_value(key_type, key))
+                    .and_then(|_| check_value(value_type, value)),
+            ),
+            (
+                TypeDefinition::Collection(
+                    CollectionTypeDefinition::List(_)
+                    | CollectionTypeDefinition::ListSlice(..)
+                    | CollectionTypeDefinition::Map { .. },
+                ),
+                InsertedValues::Nothing,
+            ) => Some(Ok(())),
+            // existing fields of structural types only check the new field value
+            (
+                TypeDefinition::Structural(StructuralTypeDefinition::Map(
+                    fields,
+                )),
+                InsertedValues::Entry(key, value),
+            ) => key.with_value_container(|key| {
+                fields
+                    .iter()
+                    .find(|(field_key, _)| field_key.value_matches(key))
+                    .map(|(_, field_type)| check_value(field_type, value))
+            }),
+            _ => None,
+        }
+    }
+}
+
 impl Reference {
     /// Internal function that handles updates
     /// - Checks if the reference is mutable
@@ -57,6 +168,72 @@ impl Reference {
         Ok(())
     }
 
+    /// Returns the fixed list size required by the allowed type, if any
+    fn fixed_list_size(&self) -> Option<usize> {
+        match self {
+            Reference::ValueReference(vr) => match &vr.borrow().allowed_type {
+                TypeDefinition::Collection(
+                    CollectionTypeDefinition::ListSlice(_, size),
+                ) => Some(*size),
+                _ => None,
+            },
+            Reference::TypeReference(_) => None,
+        }
+    }
+
+    /// Returns the type that the value must still match after a mutation,
+    /// or None if the reference is not constrained by an allowed type
+    fn mutation_type_constraint(&self) -> Option<Type> {
+        match self.allowed_type() {
+            TypeDefinition::Unknown => None,
+            allowed_type => Some(Type::new(allowed_type, None)),
+        }
+    }
+
+    /// Determines how a mutation inserting the given values is checked
+    /// against the allowed type of the reference.
+    /// The inserted values are checked against the element types of the
+    /// allowed type directly. For allowed types without uniform element
+    /// types (e.g. structural types), the whole mutated value is checked.
+    fn mutation_check(
+        &self,
+        inserted: InsertedValues,
+    ) -> Result<MutationCheck, AccessError> {
+        let Some(allowed_type) = self.mutation_type_constraint() else {
+            return Ok(MutationCheck::Checked);
+        };
+        match inserted.check(&allowed_type) {
+            Some(checked) => checked.map(|_| MutationCheck::Checked),
+            None => Ok(MutationCheck::WholeValue(allowed_type)),
+        }
+    }
+
+    /// Applies a mutation to the value of the reference.
+    /// If the whole value must be checked, the mutation is applied to a
+    /// copy of the value, which only replaces the current value if it still
+    /// matches the allowed type.
+    fn try_mutate_value(
+        &self,
+        check: MutationCheck,
+        mutation: impl FnOnce(&mut Value) -> Result<(), AccessError>,
+    ) -> Result<(), AccessError> {
+        let MutationCheck::WholeValue(allowed_type) = check else {
+            return self.with_value_unchecked(mutation);
+        };
+        self.with_value_unchecked(|value| {
+            let mut updated = value.clone();
+            mutation(&mut updated)?;
+            let updated = ValueContainer::Value(updated);
+            if !allowed_type.value_matches(&updated) {
+                return Err(type_mismatch(&allowed_type, &updated));
+            }
+            if let ValueContainer::Value(updated) = updated {
+                *value = updated;
+            }
+            Ok(())
+        })
+    }
+
     /// Sets a property on the value if applicable (e.g. for maps)
     pub fn try_set_property<'a>(
         &self,
@@ -78,7 +255,8 @@ impl Reference {
             ),
         };
 
-        self.with_value_unchecked(|value| {
+        let check = self.mutation_check(InsertedValues::Entry(&key, &val))?;
+        self.try_mutate_value(check, |value| {
             value.try_set_property(key, val.clone())
         })?;
 
@@ -96,7 +274,6 @@ impl Reference {
         self.assert_mutable()?;
         let dif_update_data_or_memory = dif_update_data_or_memory.into();
 
-        // TODO #306: ensure type compatibility with allowed_type
         let value_container = &value.into();
 
         let dif_update = match dif_update_data_or_memory {
@@ -109,11 +286,23 @@ impl Reference {
             ),
         };
 
+        // only the new value is checked against the allowed type
+        let allowed_type = self.mutation_type_constraint();
         self.with_value_unchecked(|core_value| {
-            // Set the value directly, ensuring it is a ValueContainer
-            core_value.inner =
-                value_container.to_value().borrow().inner.clone();
-        });
+            let replaced = ValueContainer::Value(Value {
+                inner: value_container.to_value().borrow().inner.clone(),
+                actual_type: core_value.actual_type.clone(),
+            });
+            if let Some(allowed_type) = allowed_type
+                && !allowed_type.value_matches(&replaced)
+            {
+                return Err(type_mismatch(&allowed_type, &replaced));
+            }
+            if let ValueContainer::Value(replaced) = replaced {
+                *core_value = replaced;
+            }
+            Ok(())
+        })?;
 
         self.notify_observers(&dif_update.with_source(source_id));
         Ok(())
@@ -129,6 +318,7 @@ impl Reference {
         self.assert_mutable()?;
         let dif_update_data_or_memory = dif_update_data_or_memory.into();
         let value_container = value.into();
+        let fixed_size = self.fixed_list_size();
 
         let dif_update = match dif_update_data_or_memory {
             DIFUpdateDataOrMemory::Update(update) => update,
@@ -140,9 +330,17 @@ impl Reference {
             }
         };
 
-        self.with_value_unchecked(move |core_value| {
+        let check = self.mutation_check(InsertedValues::Items(
+            core::slice::from_ref(&value_container),
+        ))?;
+        self.try_mutate_value(check, move |core_value| {
             match &mut core_value.inner {
                 CoreValue::List(list) => {
+                    if let Some(err) =
+                        invalid_list_length(fixed_size, list.len() as usize + 1)
+                    {
+                        return Err(err);
+                    }
                     list.push(value_container);
                 }
                 _ => {
@@ -171,6 +369,7 @@ impl Reference {
         self.assert_mutable()?;
         let key = key.into();
         let dif_update_data_or_memory = dif_update_data_or_memory.into();
+        let fixed_size = self.fixed_list_size();
 
         let dif_update = match dif_update_data_or_memory {
             DIFUpdateDataOrMemory::Update(update) => update,
@@ -179,13 +378,20 @@ impl Reference {
             }
         };
 
-        self.with_value_unchecked(|value| {
+        let check = self.mutation_check(InsertedValues::Nothing)?;
+        self.try_mutate_value(check, |value| {
             match value.inner {
                 CoreValue::Map(ref mut map) => {
                     key.with_value_container(|key| map.delete(key))?;
                 }
                 CoreValue::List(ref mut list) => {
                     if let Some(index) = key.try_as_index() {
+                        if let Some(err) = invalid_list_length(
+                            fixed_size,
+                            (list.len() as usize).saturating_sub(1),
+                        ) {
+                            return Err(err);
+                        }
                         list.delete(index).map_err(|err| {
                             AccessError::IndexOutOfBounds(err)
                         })?;
@@ -213,13 +419,18 @@ impl Reference {
         source_id: TransceiverId,
     ) -> Result<(), AccessError> {
         self.assert_mutable()?;
+        let fixed_size = self.fixed_list_size();
 
-        self.with_value_unchecked(|value| {
+        let check = self.mutation_check(InsertedValues::Nothing)?;
+        self.try_mutate_value(check, |value| {
             match value.inner {
                 CoreValue::Map(ref mut map) => {
                     map.clear()?;
                 }
                 CoreValue::List(ref mut list) => {
+                    if let Some(err) = invalid_list_length(fixed_size, 0) {
+                        return Err(err);
+                    }
                     list.clear();
                 }
                 _ => {
@@ -246,6 +457,7 @@ impl Reference {
     ) -> Result<(), AccessError> {
         self.assert_mutable()?;
         let dif_update_data_or_memory = dif_update_data_or_memory.into();
+        let fixed_size = self.fixed_list_size();
 
         let dif_update = match dif_update_data_or_memory {
             DIFUpdateDataOrMemory::Update(update) => update,
@@ -264,9 +476,19 @@ impl Reference {
             }
         };
 
-        self.with_value_unchecked(|value| {
+        let check = self.mutation_check(InsertedValues::Items(&items))?;
+        self.try_mutate_value(check, |value| {
             match value.inner {
                 CoreValue::List(ref mut list) => {
+                    let len = list.len();
+                    let removed =
+                        range.end.min(len).saturating_sub(range.start);
+                    if let Some(err) = invalid_list_length(
+                        fixed_size,
+                        (len - removed) as usize + items.len(),
+                    ) {
+                        return Err(err);
+                    }
                     list.splice(range, items);
                 }
                 _ => {
@@ -287,12 +509,14 @@ impl Reference {
 
 #[cfg(test)]
 mod tests {
+    use crate::libs::core::{CoreLibPointerId, get_core_lib_type};
     use crate::references::reference::{
         AccessError, AssignmentError, IndexOutOfBoundsError,
         ReferenceMutability,
     };
     use crate::runtime::memory::Memory;
     use crate::stdlib::assert_matches::assert_matches;
+    use crate::types::definition::TypeDefinition;
     use crate::values::core_values::list::List;
     use crate::values::core_values::map::Map;
     use crate::{
@@ -331,6 +555,71 @@ mod tests {
         assert_matches!(result, Err(AccessError::InvalidOperation(_)));
     }
 
+    #[test]
+    fn fixed_size_list() {
+        let memory = &RefCell::new(Memory::default());
+        let list = List::from(vec![
+            ValueContainer::from(1),
+            ValueContainer::from(2),
+            ValueContainer::from(3),
+        ]);
+        let list_ref = Reference::try_new_from_value_container(
+            list.into(),
+            Some(TypeDefinition::fixed_size_list(
+                get_core_lib_type(CoreLibPointerId::Integer(None)),
+                3,
+            )),
+            None,
+            ReferenceMutability::Mutable,
+        )
+        .unwrap();
+
+        // pushing would exceed the fixed size
+        let result =
+            list_ref.try_append_value(0, memory, ValueContainer::from(4));
+        assert_matches!(
+            result,
+            Err(AccessError::InvalidListLength {
+                expected: 3,
+                found: 4
+            })
+        );
+
+        // splicing must keep the length
+        let result = list_ref.try_list_splice(
+            0,
+            memory,
+            0..2,
+            vec![ValueContainer::from(5)],
+        );
+        assert_matches!(
+            result,
+            Err(AccessError::InvalidListLength {
+                expected: 3,
+                found: 2
+            })
+        );
+        list_ref
+            .try_list_splice(
+                0,
+                memory,
+                0..2,
+                vec![ValueContainer::from(5), ValueContainer::from(6)],
+            )
+            .expect("Failed to splice list");
+        assert_eq!(list_ref.try_get_property(1).unwrap(), 6.into());
+
+        // deleting and clearing would shrink the list
+        assert_matches!(
+            list_ref.try_delete_property(0, memory, 0),
+            Err(AccessError::InvalidListLength { .. })
+        );
+        assert_matches!(
+            list_ref.try_clear(0),
+            Err(AccessError::InvalidListLength { .. })
+        );
+    }
+
     #[test]
     fn property() {
         let memory = &RefCell::new(Memory::default());
@@ -5,12 +5,14 @@ use crate::values::core_value::CoreValue;
 use core::prelude::rust_2024::*;
 use core::result::Result;
 
+use crate::libs::core::{CoreLibPointerId, get_core_lib_type_definition};
 use crate::references::value_reference::ValueReference;
 use crate::runtime::execution::ExecutionError;
 use crate::stdlib::boxed::Box;
 use crate::stdlib::rc::Rc;
 use crate::stdlib::string::String;
 use crate::stdlib::string::ToString;
+use crate::stdlib::vec::Vec;
 use crate::traits::apply::Apply;
 use crate::traits::identity::Identity;
 use crate::traits::structural_eq::StructuralEq;
@@ -61,6 +63,14 @@ pub enum AccessError {
     IndexOutOfBounds(IndexOutOfBoundsError),
     MapAccessError(MapAccessError),
     InvalidIndexKey,
+    InvalidListLength { expected: usize, found: usize },
+    TypeError(Box<TypeError>),
+}
+
+impl From<TypeError> for AccessError {
+    fn from(err: TypeError) -> Self {
+        AccessError::TypeError(Box::new(err))
+    }
 }
 
 impl From<IndexOutOfBoundsError> for AccessError {
@@ -102,6 +112,16 @@ impl Display for AccessError {
             AccessError::InvalidIndexKey => {
                 write!(f, "Invalid index key")
             }
+            AccessError::InvalidListLength { expected, found } => {
+                write!(
+                    f,
+                    "Invalid list length: expected {} items, found {}",
+                    expected, found
+                )
+            }
+            AccessError::TypeError(error) => {
+                write!(f, "{}", error)
+            }
         }
     }
 }
@@ -559,6 +579,7 @@ impl Reference {
             pointer_address: maybe_pointer_address,
             nominal_type_declaration: maybe_nominal_type_declaration,
             type_value,
+            generic_parameters: Vec::new(),
         };
         Reference::TypeReference(Rc::new(RefCell::new(type_reference)))
     }
@@ -631,7 +652,10 @@ impl Reference {
     pub fn allowed_type(&self) -> TypeDefinition {
         match self {
             Reference::ValueReference(vr) => vr.borrow().allowed_type.clone(),
-            Reference::TypeReference(_) => core::todo!("#293 type Type"),
+            // type references can only hold types
+            Reference::TypeReference(_) => {
+                get_core_lib_type_definition(CoreLibPointerId::Type)
+            }
         }
     }
 
@@ -731,7 +755,7 @@ impl Apply for Reference {
         arg: &ValueContainer,
     ) -> Result<Option<ValueContainer>, ExecutionError> {
         match self {
-            Reference::TypeReference(tr) => tr.borrow().apply_single(arg),
+            Reference::TypeReference(tr) => TypeReference::construct(tr, arg),
             Reference::ValueReference(vr) => vr
                 .borrow()
                 .resolve_current_value()
@@ -2,19 +2,24 @@ use core::prelude::rust_2024::*;
 use core::result::Result;
 use serde::{Deserialize, Serialize};
 
-use crate::libs::core::CoreLibPointerId;
-use crate::references::reference::ReferenceMutability;
+use crate::global::operators::CastMode;
+use crate::libs::core::{CoreLibPointerId, get_core_lib_type_definition};
+use crate::references::reference::{ReferenceMutability, TypeError};
 use crate::runtime::execution::ExecutionError;
 use crate::stdlib::string::ToString;
 use crate::stdlib::{
+    boxed::Box,
     cell::RefCell,
     fmt::{Display, Formatter},
     rc::Rc,
     string::String,
+    vec::Vec,
 };
 use crate::traits::apply::Apply;
 use crate::types::structural_type_definition::StructuralTypeDefinition;
+use crate::values::core_value::CoreValue;
 use crate::values::pointer::PointerAddress;
+use crate::values::value::Value;
 use crate::values::value_container::ValueContainer;
 use crate::{
     types::definition::TypeDefinition, values::core_values::r#type::Type,
@@ -62,6 +67,8 @@ pub struct TypeReference {
     pub nominal_type_declaration: Option<NominalTypeDeclaration>,
     /// pointer id, can be initialized as None for local pointers
     pub pointer_address: Option<PointerAddress>,
+    /// names of the generic type parameters, e.g. T for `type Box<T> = { value: T }`
+    pub generic_parameters: Vec<String>,
 }
 
 impl TypeReference {
@@ -77,6 +84,7 @@ impl TypeReference {
             type_value,
             nominal_type_declaration: Some(nominal_type_declaration.into()),
             pointer_address,
+            generic_parameters: Vec::new(),
         }
     }
     pub fn anonymous(
@@ -87,6 +95,7 @@ impl TypeReference {
             type_value,
             nominal_type_declaration: None,
             pointer_address,
+            generic_parameters: Vec::new(),
         }
     }
     pub fn as_ref_cell(self) -> Rc<RefCell<TypeReference>> {
@@ -115,6 +124,115 @@ impl TypeReference {
     pub fn mutability(&self) -> Option<ReferenceMutability> {
         self.type_value.reference_mutability()
     }
+
+    /// Applies a single argument to a type reference.
+    /// Core types cast the argument, nominal types create a new value of
+    /// the nominal type if the argument matches the type definition,
+    /// e.g. `User({x: 1})`.
+    pub fn construct(
+        reference: &Rc<RefCell<TypeReference>>,
+        arg: &ValueContainer,
+    ) -> Result<Option<ValueContainer>, ExecutionError> {
+        TypeReference::cast(reference, arg, CastMode::Checked).map(Some)
+    }
+
+    /// Casts a value to the type of the type reference, e.g. `42 as text`.
+    /// Core types convert the value (see [TypeReference::convert]),
+    /// nominal types create a new value of the nominal type if the value
+    /// matches the type definition. All other types only accept values
+    /// that already match the type.
+    pub fn cast(
+        reference: &Rc<RefCell<TypeReference>>,
+        arg: &ValueContainer,
+        mode: CastMode,
+    ) -> Result<ValueContainer, ExecutionError> {
+        let type_ref = reference.borrow();
+        if let Some(core_lib_id) = type_ref.core_lib_id() {
+            return type_ref.convert(core_lib_id, arg, mode);
+        }
+
+        if !type_ref.type_value.value_matches(arg) {
+            return Err(TypeError::TypeMismatch {
+                expected: type_ref.type_value.clone(),
+                found: Type::from(&arg.to_value().borrow().inner),
+            }
+            .into());
+        }
+        if type_ref.nominal_type_declaration.is_none() {
+            return Ok(arg.clone());
+        }
+        let mut value = arg.to_value().borrow().clone();
+        value.actual_type =
+            Box::new(TypeDefinition::Reference(reference.clone()));
+        Ok(ValueContainer::from(value))
+    }
+
+    /// Returns the core library id if this is a core type
+    fn core_lib_id(&self) -> Option<CoreLibPointerId> {
+        self.pointer_address
+            .as_ref()
+            .and_then(|address| CoreLibPointerId::try_from(address).ok())
+    }
+
+    /// Converts a value to a core type.
+    /// Integers, decimals, text, booleans and endpoints are converted
+    /// between each other where possible, integer variants are either
+    /// checked or clamped to the bounds of the variant depending on the
+    /// cast mode. Values of other core types are passed through if they
+    /// match the type.
+    fn convert(
+        &self,
+        core_lib_id: CoreLibPointerId,
+        arg: &ValueContainer,
+        mode: CastMode,
+    ) -> Result<ValueContainer, ExecutionError> {
+        let value = arg.to_value();
+        let value = value.borrow();
+        let inner = &value.inner;
+        match core_lib_id {
+            CoreLibPointerId::Integer(None) => {
+                inner.cast_to_integer().map(ValueContainer::from)
+            }
+            CoreLibPointerId::Integer(Some(variant)) => inner
+                .cast_to_typed_integer_with_mode(variant, mode)
+                .map(ValueContainer::from),
+            CoreLibPointerId::Decimal(None) => {
+                inner.cast_to_decimal().map(ValueContainer::from)
+            }
+            CoreLibPointerId::Decimal(Some(variant)) => inner
+                .cast_to_typed_decimal(variant)
+                .map(ValueContainer::from),
+            CoreLibPointerId::Text => {
+                Some(ValueContainer::from(inner.cast_to_text()))
+            }
+            CoreLibPointerId::Boolean => {
+                inner.cast_to_bool().map(ValueContainer::from)
+            }
+            CoreLibPointerId::Endpoint => {
+                inner.cast_to_endpoint().map(ValueContainer::from)
+            }
+            // e.g. error("Not found") or error({message: "Not found", payload: 404})
+            CoreLibPointerId::Error => match inner {
+                _ if value.is_error() => Some(arg.clone()),
+                CoreValue::Text(text) => Some(ValueContainer::from(
+                    Value::error(text.0.clone(), Value::null().into()),
+                )),
+                CoreValue::Map(_) if self.type_value.value_matches(arg) => {
+                    let mut value = value.clone();
+                    value.actual_type = Box::new(get_core_lib_type_definition(
+                        CoreLibPointerId::Error,
+                    ));
+                    Some(ValueContainer::from(value))
+                }
+                _ => None,
+            },
+            _ => self.type_value.value_matches(arg).then(|| arg.clone()),
+        }
+        .ok_or_else(|| ExecutionError::InvalidTypeCast {
+            value: inner.to_string(),
+            target: self.to_string(),
+        })
+    }
 }
 
 impl TypeReference {
@@ -132,6 +250,28 @@ impl TypeReference {
         self.type_value.base_type_reference()
     }
 
+    /// Returns true if both references point to the same type declaration,
+    /// either by identity, by pointer address or by nominal name for
+    /// local types.
+    pub fn is_same_declaration(
+        reference: &Rc<RefCell<TypeReference>>,
+        other: &Rc<RefCell<TypeReference>>,
+    ) -> bool {
+        if Rc::ptr_eq(reference, other) {
+            return true;
+        }
+        let (reference, other) = (reference.borrow(), other.borrow());
+        match (&reference.pointer_address, &other.pointer_address) {
+            (Some(address), Some(other_address)) => address == other_address,
+            (None, None) => {
+                reference.nominal_type_declaration.is_some()
+                    && reference.nominal_type_declaration
+                        == other.nominal_type_declaration
+            }
+            _ => false,
+        }
+    }
+
     pub fn matches_reference(&self, other: Rc<RefCell<TypeReference>>) -> bool {
         core::todo!("#300 implement type matching");
     }
@@ -157,39 +297,13 @@ impl Apply for TypeReference {
         &self,
         arg: &ValueContainer,
     ) -> Result<Option<ValueContainer>, ExecutionError> {
-        // TODO #303: ensure that we can guarantee that pointer_address is always Some here
-        let core_lib_id =
-            CoreLibPointerId::try_from(self.pointer_address.as_ref().unwrap());
-        if let Ok(core_lib_id) = core_lib_id {
-            match core_lib_id {
-                CoreLibPointerId::Integer(None) => arg
-                    .to_value()
-                    .borrow()
-                    .cast_to_integer()
-                    .map(|i| Some(ValueContainer::from(i)))
-                    .ok_or_else(|| ExecutionError::InvalidTypeCast),
-                CoreLibPointerId::Integer(Some(variant)) => arg
-                    .to_value()
-                    .borrow()
-                    .cast_to_typed_integer(variant)
-                    .map(|i| Some(ValueContainer::from(i)))
-                    .ok_or_else(|| ExecutionError::InvalidTypeCast),
-                CoreLibPointerId::Decimal(None) => arg
-                    .to_value()
-                    .borrow()
-                    .cast_to_decimal()
-                    .map(|d| Some(ValueContainer::from(d)))
-                    .ok_or_else(|| ExecutionError::InvalidTypeCast),
-                CoreLibPointerId::Decimal(Some(variant)) => arg
-                    .to_value()
-                    .borrow()
-                    .cast_to_typed_decimal(variant)
-                    .map(|d| Some(ValueContainer::from(d)))
-                    .ok_or_else(|| ExecutionError::InvalidTypeCast),
-                _ => core::todo!("#304 Undescribed by author."),
+        match self.core_lib_id() {
+            Some(core_lib_id) => {
+                self.convert(core_lib_id, arg, CastMode::Checked).map(Some)
             }
-        } else {
-            core::todo!("#305 Undescribed by author.")
+            // other types are applied with TypeReference::construct, which
+            // needs the shared reference to create values of nominal types
+            None => Err(ExecutionError::InvalidApply),
         }
     }
 }
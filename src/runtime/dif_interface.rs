@@ -137,13 +137,10 @@ impl DIFInterface for RuntimeInternal {
         mutability: ReferenceMutability,
     ) -> Result<PointerAddress, DIFCreatePointerError> {
         let container = value.to_value_container(&self.memory)?;
-        let type_container = if let Some(allowed_type) = &allowed_type {
-            core::todo!(
-                "FIXME: Implement type_container creation from DIFTypeDefinition"
-            )
-        } else {
-            None
-        };
+        let type_container = allowed_type
+            .as_ref()
+            .map(|allowed_type| allowed_type.to_type_definition(&self.memory))
+            .transpose()?;
         let reference = Reference::try_new_from_value_container(
             container,
             type_container,
@@ -225,14 +222,19 @@ impl DIFInterface for RuntimeInternal {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::dif::interface::DIFInterface;
+    use crate::dif::representation::DIFTypeRepresentation;
     use crate::dif::representation::DIFValueRepresentation;
+    use crate::dif::r#type::{DIFStructuralTypeDefinition, DIFType};
     use crate::dif::update::{DIFUpdate, DIFUpdateData};
     use crate::dif::value::{DIFValue, DIFValueContainer};
+    use crate::libs::core::{CoreLibPointerId, get_core_lib_type_definition};
     use crate::references::observers::ObserveOptions;
     use crate::references::reference::ReferenceMutability;
     use crate::runtime::Runtime;
     use crate::runtime::memory::Memory;
+    use crate::stdlib::assert_matches::assert_matches;
     use crate::stdlib::rc::Rc;
     use crate::values::core_values::endpoint::Endpoint;
     use crate::values::core_values::map::Map;
@@ -324,4 +326,174 @@ mod tests {
                 .is_err()
         );
     }
+
+    fn text(text: &str) -> DIFValueContainer {
+        DIFValue::from(DIFValueRepresentation::String(text.to_string())).into()
+    }
+
+    fn number(number: f64) -> DIFValueContainer {
+        DIFValue::from(DIFValueRepresentation::Number(number)).into()
+    }
+
+    fn text_type(runtime: &RuntimeInternal) -> DIFType {
+        DIFType::from_type_definition(
+            &get_core_lib_type_definition(CoreLibPointerId::Text),
+            &runtime.memory,
+        )
+    }
+
+    /// Creates a mutable pointer for the value with the given allowed type
+    fn create_typed_pointer(
+        runtime: &RuntimeInternal,
+        value: DIFValueRepresentation,
+        allowed_type: DIFTypeDefinition,
+    ) -> PointerAddress {
+        runtime
+            .create_pointer(
+                DIFValue::from(value).into(),
+                Some(allowed_type),
+                ReferenceMutability::Mutable,
+            )
+            .expect("Failed to create pointer")
+    }
+
+    #[test]
+    fn typed_pointer_rejects_invalid_updates() {
+        let runtime = Runtime::init_native(RuntimeConfig::default()).internal;
+
+        // {x: text}
+        let struct_type = DIFTypeDefinition::Structural(Box::new(
+            DIFStructuralTypeDefinition {
+                value: DIFTypeRepresentation::Object(vec![(
+                    "x".to_string(),
+                    text_type(&runtime),
+                )]),
+                ty: None,
+            },
+        ));
+        let struct_value =
+            DIFValueRepresentation::Object(vec![("x".to_string(), text("a"))]);
+        // text[]
+        let list_type = DIFTypeDefinition::List(Box::new(text_type(&runtime)));
+        let list_value = DIFValueRepresentation::Array(vec![text("a")]);
+
+        let cases = [
+            (
+                &struct_type,
+                &struct_value,
+                DIFUpdateData::replace(DIFValue::from(
+                    DIFValueRepresentation::Object(vec![(
+                        "x".to_string(),
+                        text("b"),
+                    )]),
+                )),
+                DIFUpdateData::replace(DIFValue::from(
+                    DIFValueRepresentation::Object(vec![(
+                        "x".to_string(),
+                        number(1.0),
+                    )]),
+                )),
+            ),
+            (
+                &struct_type,
+                &struct_value,
+                DIFUpdateData::set("x", text("b")),
+                DIFUpdateData::set("x", number(1.0)),
+            ),
+            (
+                &struct_type,
+                &struct_value,
+                DIFUpdateData::set("y", number(1.0)),
+                DIFUpdateData::delete("x"),
+            ),
+            (
+                &list_type,
+                &list_value,
+                DIFUpdateData::clear(),
+                DIFUpdateData::append(number(1.0)),
+            ),
+            (
+                &list_type,
+                &list_value,
+                DIFUpdateData::append(text("b")),
+                DIFUpdateData::list_splice(0..1, vec![number(1.0)]),
+            ),
+            (
+                &list_type,
+                &list_value,
+                DIFUpdateData::list_splice(0..1, vec![text("b"), text("c")]),
+                DIFUpdateData::append(DIFValue::from(
+                    DIFValueRepresentation::Null,
+                )),
+            ),
+            (
+                &struct_type,
+                &struct_value,
+                DIFUpdateData::set("x", text("c")),
+                DIFUpdateData::clear(),
+            ),
+        ];
+
+        for (allowed_type, value, valid_update, invalid_update) in cases {
+            let address = create_typed_pointer(
+                &runtime,
+                value.clone(),
+                allowed_type.clone(),
+            );
+            let reference = runtime
+                .memory
+                .borrow()
+                .get_reference(&address)
+                .unwrap()
+                .clone();
+
+            runtime
+                .update(0, address.clone(), &valid_update)
+                .unwrap_or_else(|err| {
+                    core::panic!("{valid_update:?} was rejected: {err}")
+                });
+
+            let before = reference.value_container().to_value();
+            let result = runtime.update(0, address, &invalid_update);
+            assert_matches!(
+                result,
+                Err(DIFUpdateError::TypeError(_)),
+                "{invalid_update:?} was not rejected"
+            );
+            // the reference keeps its previous value
+            assert_eq!(reference.value_container().to_value(), before);
+        }
+    }
+
+    #[test]
+    fn create_pointer_with_unknown_type_reference_fails() {
+        let runtime = Runtime::init_native(RuntimeConfig::default()).internal;
+
+        // {x: <unknown reference>}
+        let allowed_type = DIFTypeDefinition::Structural(Box::new(
+            DIFStructuralTypeDefinition {
+                value: DIFTypeRepresentation::Object(vec![(
+                    "x".to_string(),
+                    DIFType {
+                        name: None,
+                        mutability: None,
+                        type_definition: DIFTypeDefinition::Reference(
+                            PointerAddress::Local([1, 2, 3, 4, 5]),
+                        ),
+                    },
+                )]),
+                ty: None,
+            },
+        ));
+        let result = runtime.create_pointer(
+            DIFValue::from(DIFValueRepresentation::Object(vec![(
+                "x".to_string(),
+                text("a"),
+            )]))
+            .into(),
+            Some(allowed_type),
+            ReferenceMutability::Mutable,
+        );
+        assert_matches!(result, Err(DIFCreatePointerError::ReferenceNotFound));
+    }
 }
@@ -1,10 +1,14 @@
 #[cfg(feature = "compiler")]
 use crate::compiler::scope::CompilationScope;
 use crate::runtime::RuntimeInternal;
-use crate::runtime::execution::ExecutionOptions;
 use crate::runtime::execution::context::ExecutionContext;
 use crate::runtime::execution::execution_loop::state::ExecutionLoopState;
+use crate::runtime::execution::{
+    CancellationHandle, Debugger, ExecutionOptions, SourceLocation, SourceMap,
+};
 use crate::stdlib::rc::Rc;
+use crate::values::core_values::endpoint::Endpoint;
+use core::cell::RefCell;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ExecutionMode {
@@ -35,6 +39,20 @@ pub struct LocalExecutionContext {
     pub execution_options: ExecutionOptions,
     pub verbose: bool,
     pub execution_mode: ExecutionMode,
+    /// The endpoint that sent the executed DXB (e.g. the sender of an incoming block).
+    /// Local pointer addresses are resolved relative to this endpoint.
+    /// If not set, local pointer addresses are resolved against the local runtime.
+    pub sender: Option<Endpoint>,
+    /// Handle to cancel executions in this context.
+    /// Once cancelled, all following executions are aborted until the handle is reset.
+    pub cancellation: CancellationHandle,
+    /// Source map of the last compiled script, used to locate errors of its execution.
+    pub source_map: Option<Rc<SourceMap>>,
+    /// Source location of the error of the last execution, if it could be located
+    /// with the source map.
+    pub error_location: Rc<RefCell<Option<SourceLocation>>>,
+    /// Debugger that is attached to all executions in this context.
+    pub debugger: Option<Debugger>,
 }
 
 impl LocalExecutionContext {
@@ -47,6 +65,11 @@ impl LocalExecutionContext {
             execution_options: ExecutionOptions::default(),
             verbose: false,
             execution_mode,
+            sender: None,
+            cancellation: CancellationHandle::default(),
+            source_map: None,
+            error_location: Rc::default(),
+            debugger: None,
         }
     }
 
@@ -55,7 +78,10 @@ impl LocalExecutionContext {
         LocalExecutionContext {
             #[cfg(feature = "compiler")]
             compile_scope: CompilationScope::new(execution_mode),
-            execution_options: ExecutionOptions { verbose: true },
+            execution_options: ExecutionOptions {
+                verbose: true,
+                ..Default::default()
+            },
             verbose: true,
             execution_mode,
             ..Default::default()
@@ -71,9 +97,17 @@ impl LocalExecutionContext {
             compile_scope: CompilationScope::new(execution_mode),
             loop_state: None,
             runtime: Some(runtime_internal),
-            execution_options: ExecutionOptions { verbose: true },
+            execution_options: ExecutionOptions {
+                verbose: true,
+                ..Default::default()
+            },
             verbose: true,
             execution_mode,
+            sender: None,
+            cancellation: CancellationHandle::default(),
+            source_map: None,
+            error_location: Rc::default(),
+            debugger: None,
         }
     }
 
@@ -97,6 +131,11 @@ impl LocalExecutionContext {
     ) {
         self.runtime = Some(runtime_internal);
     }
+
+    /// Sets the endpoint relative to which local pointer addresses are resolved.
+    pub fn set_sender(&mut self, sender: Endpoint) {
+        self.sender = Some(sender);
+    }
 }
 
 impl ExecutionContext {
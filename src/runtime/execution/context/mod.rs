@@ -1,12 +1,14 @@
 #[cfg(feature = "compiler")]
 use crate::compiler::{
-    CompileOptions, compile_template, error::SpannedCompilerError,
-    scope::CompilationScope,
+    CompileOptions, compile_template_with_source_map,
+    error::SpannedCompilerError, scope::CompilationScope,
 };
 use crate::runtime::execution::{
-    ExecutionError, ExecutionInput, MemoryDump, execute_dxb, execute_dxb_sync,
+    CancellationHandle, Debugger, ExecutionError, ExecutionInput, MemoryDump,
+    SourceLocation, execute_dxb, execute_dxb_sync,
 };
 use crate::stdlib::format;
+use crate::stdlib::rc::Rc;
 use crate::stdlib::vec::Vec;
 use crate::values::value_container::ValueContainer;
 pub use local::*;
@@ -65,14 +67,18 @@ impl ExecutionContext {
     ) -> Result<Vec<u8>, SpannedCompilerError> {
         let compile_scope = self.compile_scope();
         // TODO #107: don't clone compile_scope if possible
-        let res = compile_template(
+        let res = compile_template_with_source_map(
             script,
             inserted_values,
+            None,
             CompileOptions::new_with_scope(compile_scope.clone()),
         );
         match res {
-            Ok((bytes, compile_scope)) => {
+            Ok((bytes, source_map, compile_scope)) => {
                 self.set_compile_scope(compile_scope);
+                if let ExecutionContext::Local(local_context) = self {
+                    local_context.source_map = Some(Rc::new(source_map));
+                }
                 Ok(bytes)
             }
             Err(err) => Err(err),
@@ -118,13 +124,24 @@ impl ExecutionContext {
                 loop_state,
                 execution_options,
                 verbose,
+                sender,
+                cancellation,
+                source_map,
+                error_location,
+                debugger,
                 ..
             }) => {
+                error_location.borrow_mut().take();
                 let input = ExecutionInput {
                     runtime: runtime.clone(),
                     loop_state: loop_state.take(),
                     options: (*execution_options).clone(),
                     dxb_body: dxb,
+                    sender: sender.clone(),
+                    cancellation: cancellation.clone(),
+                    source_map: source_map.take(),
+                    error_location: error_location.clone(),
+                    debugger: debugger.clone(),
                 };
 
                 // show DXB and decompiled code if verbose is enabled
@@ -137,6 +154,30 @@ impl ExecutionContext {
         }
     }
 
+    /// Returns a handle that can be used to cancel running executions of a local execution context.
+    /// Remote executions can be cancelled with [Runtime::cancel_remote_execution](crate::runtime::Runtime::cancel_remote_execution).
+    pub fn cancellation_handle(&self) -> Option<CancellationHandle> {
+        match self {
+            ExecutionContext::Local(LocalExecutionContext {
+                cancellation,
+                ..
+            }) => Some(cancellation.clone()),
+            ExecutionContext::Remote(_) => None,
+        }
+    }
+
+    /// Attaches a debugger to all following executions of a local execution context.
+    /// Returns false for remote execution contexts, which cannot be debugged.
+    pub fn set_debugger(&mut self, debugger: Debugger) -> bool {
+        match self {
+            ExecutionContext::Local(local_context) => {
+                local_context.debugger = Some(debugger);
+                true
+            }
+            ExecutionContext::Remote(_) => false,
+        }
+    }
+
     /// Executes DXB in a local execution context.
     pub fn execute_dxb_sync(
         &mut self,
@@ -156,7 +197,24 @@ impl ExecutionContext {
     ) -> Result<Option<ValueContainer>, ScriptExecutionError> {
         let dxb = self.compile(script, inserted_values)?;
         self.execute_dxb_sync(&dxb)
-            .map_err(ScriptExecutionError::from)
+            .map_err(|error| self.located_error(error))
+    }
+
+    /// Returns the source location of the error of the last local execution,
+    /// if it could be located with the source map of the compiled script.
+    pub fn error_location(&self) -> Option<SourceLocation> {
+        match self {
+            ExecutionContext::Local(local_context) => {
+                local_context.error_location.borrow().clone()
+            }
+            ExecutionContext::Remote(_) => None,
+        }
+    }
+
+    /// Attaches the source location of the last execution to an execution error
+    #[cfg(feature = "compiler")]
+    fn located_error(&self, error: ExecutionError) -> ScriptExecutionError {
+        ScriptExecutionError::ExecutionError(error, self.error_location())
     }
 
     pub async fn execute_dxb(
@@ -196,7 +254,7 @@ impl ExecutionContext {
                         loop_state,
                         ..
                     }) => {
-                        loop_state.replace(state);
+                        loop_state.replace(*state);
                         Ok(intermediate_result)
                     }
                     _ => unreachable!(), // note: this must be ensured by the caller
@@ -215,16 +273,19 @@ impl ExecutionContext {
         let dxb = self.compile(script, inserted_values)?;
         self.execute_dxb(&dxb)
             .await
-            .map_err(ScriptExecutionError::from)
+            .map_err(|error| self.located_error(error))
     }
 
     /// Returns a memory dump of the current state of the execution context if available.
+    /// The memory of a local execution context is only available while an execution is paused
+    /// by an attached debugger, or after it was last paused.
     pub fn memory_dump(&self) -> Option<MemoryDump> {
         match self {
-            ExecutionContext::Local(local_context) => {
-                todo!("#650 Undescribed by author.")
-                // Some(local_context.memory_dump())
-            }
+            ExecutionContext::Local(local_context) => local_context
+                .debugger
+                .as_ref()
+                .and_then(|debugger| debugger.last_pause())
+                .map(|pause| pause.memory_dump),
             // TODO #397: also support remote memory dump if possible
             ExecutionContext::Remote(_) => None,
         }
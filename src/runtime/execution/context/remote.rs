@@ -1,6 +1,8 @@
 #[cfg(feature = "compiler")]
 use crate::compiler::scope::CompilationScope;
-use crate::global::dxb_block::OutgoingContextId;
+use crate::global::dxb_block::{
+    OutgoingBlockNumber, OutgoingContextId, OutgoingSectionIndex,
+};
 use crate::runtime::execution::context::{ExecutionContext, ExecutionMode};
 use crate::values::core_values::endpoint::Endpoint;
 
@@ -10,6 +12,10 @@ pub struct RemoteExecutionContext {
     pub compile_scope: CompilationScope,
     pub endpoint: Endpoint,
     pub context_id: Option<OutgoingContextId>,
+    /// section index for the next execution in an unbounded context
+    pub next_section_index: OutgoingSectionIndex,
+    /// block number for the next execution in an unbounded context
+    pub next_block_number: OutgoingBlockNumber,
     pub execution_mode: ExecutionMode,
 }
 
@@ -24,6 +30,8 @@ impl RemoteExecutionContext {
             compile_scope: CompilationScope::new(execution_mode),
             endpoint: endpoint.into(),
             context_id: None,
+            next_section_index: 0,
+            next_block_number: 0,
             execution_mode,
         }
     }
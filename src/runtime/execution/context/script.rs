@@ -1,13 +1,15 @@
 #[cfg(feature = "compiler")]
-use crate::compiler::error::SpannedCompilerError;
-use crate::runtime::execution::ExecutionError;
+use crate::compiler::error::{SpannedCompilerError, render_error_report};
+use crate::runtime::execution::{ExecutionError, SourceLocation};
 use core::fmt::Display;
 
 #[derive(Debug)]
 pub enum ScriptExecutionError {
     #[cfg(feature = "compiler")]
     CompilerError(SpannedCompilerError),
-    ExecutionError(ExecutionError),
+    /// An execution error with the source location of the instruction that
+    /// caused it, if it could be located with the source map of the script
+    ExecutionError(ExecutionError, Option<SourceLocation>),
 }
 
 #[cfg(feature = "compiler")]
@@ -17,9 +19,30 @@ impl From<SpannedCompilerError> for ScriptExecutionError {
     }
 }
 
+impl ScriptExecutionError {
+    /// Renders the error as a diagnostic report for the executed source code.
+    /// Execution errors are located with the source map of the compiled script.
+    #[cfg(feature = "compiler")]
+    pub fn render_report(&self, source_name: &str, source: &str) -> String {
+        match self {
+            ScriptExecutionError::CompilerError(err) => {
+                err.render_report(source_name, source)
+            }
+            ScriptExecutionError::ExecutionError(err, location) => {
+                render_error_report(
+                    &err.to_string(),
+                    location.as_ref().map(|location| location.span.clone()),
+                    source_name,
+                    source,
+                )
+            }
+        }
+    }
+}
+
 impl From<ExecutionError> for ScriptExecutionError {
     fn from(err: ExecutionError) -> Self {
-        ScriptExecutionError::ExecutionError(err)
+        ScriptExecutionError::ExecutionError(err, None)
     }
 }
 
@@ -30,9 +53,12 @@ impl Display for ScriptExecutionError {
             ScriptExecutionError::CompilerError(err) => {
                 core::write!(f, "Compiler Error: {}", err)
             }
-            ScriptExecutionError::ExecutionError(err) => {
+            ScriptExecutionError::ExecutionError(err, None) => {
                 core::write!(f, "Execution Error: {}", err)
             }
+            ScriptExecutionError::ExecutionError(err, Some(location)) => {
+                core::write!(f, "Execution Error: {} (at {})", err, location)
+            }
         }
     }
 }
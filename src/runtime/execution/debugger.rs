@@ -0,0 +1,309 @@
+use crate::runtime::execution::MemoryDump;
+use crate::runtime::execution::execution_loop::state::RuntimeExecutionSlots;
+use crate::runtime::execution::source_map::{SourceLocation, SourceMap};
+use crate::stdlib::boxed::Box;
+use crate::stdlib::rc::Rc;
+use crate::stdlib::vec::Vec;
+use core::cell::RefCell;
+use core::fmt::Debug;
+use core::ops::Range;
+
+/// A position in a DXB program at which the execution is paused
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Pauses before the instruction at the given byte offset in the DXB body
+    Offset(usize),
+    /// Pauses before the first instruction of the expressions within the given source span.
+    /// Only works if a source map is provided for the execution.
+    Span(Range<usize>),
+}
+
+/// Action that is taken by the debugger after a pause
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugAction {
+    /// Continues until the next breakpoint or debugger statement
+    Continue,
+    /// Pauses before the next instruction
+    StepInto,
+    /// Pauses before the next instruction that is not nested in the current instruction
+    StepOver,
+    /// Pauses before the next instruction after the instruction that contains the current instruction
+    StepOut,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseReason {
+    /// A `debugger` statement was executed
+    DebuggerStatement,
+    /// The breakpoint with the given index was hit
+    Breakpoint(usize),
+    /// A step action was completed
+    Step,
+}
+
+/// State of a paused execution, passed to the debug handler
+#[derive(Debug, Clone)]
+pub struct DebugPause {
+    pub reason: PauseReason,
+    /// byte offset of the next instruction in the DXB body
+    pub offset: usize,
+    /// number of instructions the next instruction is nested in
+    pub depth: usize,
+    /// source location of the next instruction, if a source map is provided
+    pub location: Option<SourceLocation>,
+    /// contents of all allocated slots
+    pub memory_dump: MemoryDump,
+}
+
+/// Decides how to continue a paused execution, e.g. by waiting for user input
+pub trait DebugHandler {
+    fn on_pause(&mut self, pause: &DebugPause) -> DebugAction;
+}
+
+impl<F: FnMut(&DebugPause) -> DebugAction> DebugHandler for F {
+    fn on_pause(&mut self, pause: &DebugPause) -> DebugAction {
+        self(pause)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum StepMode {
+    Into,
+    Over(usize),
+    Out(usize),
+}
+
+struct DebuggerState {
+    breakpoints: Vec<Breakpoint>,
+    /// None while the handler is called
+    handler: Option<Box<dyn DebugHandler>>,
+    step: Option<StepMode>,
+    source_map: Option<Rc<SourceMap>>,
+    last_pause: Option<DebugPause>,
+}
+
+/// A debugger that can be attached to a local execution context.
+/// All clones of the debugger share the same state, so breakpoints can also be changed
+/// from the debug handler while the execution is paused.
+#[derive(Clone)]
+pub struct Debugger(Rc<RefCell<DebuggerState>>);
+
+impl Debug for Debugger {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let state = self.0.borrow();
+        f.debug_struct("Debugger")
+            .field("breakpoints", &state.breakpoints)
+            .field("step", &state.step)
+            .finish()
+    }
+}
+
+impl Debugger {
+    pub fn new(handler: impl DebugHandler + 'static) -> Self {
+        Debugger(Rc::new(RefCell::new(DebuggerState {
+            breakpoints: Vec::new(),
+            handler: Some(Box::new(handler)),
+            step: None,
+            source_map: None,
+            last_pause: None,
+        })))
+    }
+
+    /// Adds a breakpoint, returning its index
+    pub fn add_breakpoint(&self, breakpoint: Breakpoint) -> usize {
+        let mut state = self.0.borrow_mut();
+        state.breakpoints.push(breakpoint);
+        state.breakpoints.len() - 1
+    }
+
+    pub fn breakpoints(&self) -> Vec<Breakpoint> {
+        self.0.borrow().breakpoints.clone()
+    }
+
+    pub fn clear_breakpoints(&self) {
+        self.0.borrow_mut().breakpoints.clear();
+    }
+
+    /// Returns the state of the last pause
+    pub fn last_pause(&self) -> Option<DebugPause> {
+        self.0.borrow().last_pause.clone()
+    }
+
+    /// Prepares the debugger for the next execution with the given source map,
+    /// which is used to resolve span breakpoints
+    pub(crate) fn set_source_map(&self, source_map: Option<Rc<SourceMap>>) {
+        let mut state = self.0.borrow_mut();
+        state.source_map = source_map;
+        state.step = None;
+    }
+
+    /// Called by the execution loop before an instruction is executed.
+    /// Calls the debug handler if the execution has to be paused.
+    pub(crate) fn before_instruction(
+        &self,
+        offset: usize,
+        depth: usize,
+        is_debugger_statement: bool,
+        slots: &RuntimeExecutionSlots,
+    ) {
+        let Some(reason) =
+            self.pause_reason(offset, depth, is_debugger_statement)
+        else {
+            return;
+        };
+
+        let pause = {
+            let mut state = self.0.borrow_mut();
+            let pause = DebugPause {
+                reason,
+                offset,
+                depth,
+                location: state
+                    .source_map
+                    .as_ref()
+                    .and_then(|source_map| source_map.location(offset)),
+                memory_dump: slots.memory_dump(),
+            };
+            state.last_pause = Some(pause.clone());
+            pause
+        };
+
+        // the state is not borrowed while the handler is called
+        let handler = self.0.borrow_mut().handler.take();
+        let Some(mut handler) = handler else {
+            return;
+        };
+        let action = handler.on_pause(&pause);
+
+        let mut state = self.0.borrow_mut();
+        state.handler = Some(handler);
+        state.step = match action {
+            DebugAction::Continue => None,
+            DebugAction::StepInto => Some(StepMode::Into),
+            DebugAction::StepOver => Some(StepMode::Over(depth)),
+            DebugAction::StepOut => Some(StepMode::Out(depth)),
+        };
+    }
+
+    fn pause_reason(
+        &self,
+        offset: usize,
+        depth: usize,
+        is_debugger_statement: bool,
+    ) -> Option<PauseReason> {
+        let state = self.0.borrow();
+        if is_debugger_statement {
+            return Some(PauseReason::DebuggerStatement);
+        }
+        if let Some(index) = state.breakpoints.iter().position(|breakpoint| {
+            state.breakpoint_offset(breakpoint) == Some(offset)
+        }) {
+            return Some(PauseReason::Breakpoint(index));
+        }
+        match state.step {
+            Some(StepMode::Into) => Some(PauseReason::Step),
+            Some(StepMode::Over(step_depth)) if depth <= step_depth => {
+                Some(PauseReason::Step)
+            }
+            Some(StepMode::Out(step_depth)) if depth < step_depth => {
+                Some(PauseReason::Step)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl DebuggerState {
+    fn breakpoint_offset(&self, breakpoint: &Breakpoint) -> Option<usize> {
+        match breakpoint {
+            Breakpoint::Offset(offset) => Some(*offset),
+            Breakpoint::Span(span) => self
+                .source_map
+                .as_ref()?
+                .entries()
+                .iter()
+                .filter(|entry| {
+                    span.start <= entry.span.start && entry.span.end <= span.end
+                })
+                .map(|entry| entry.bytecode.start)
+                .min(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::execution::context::ExecutionContext;
+    use crate::values::core_values::integer::Integer;
+    use crate::values::value_container::ValueContainer;
+
+    fn execute_with_debugger(
+        script: &str,
+        breakpoints: Vec<Breakpoint>,
+        mut actions: Vec<DebugAction>,
+    ) -> (Option<ValueContainer>, Vec<DebugPause>) {
+        let pauses = Rc::new(RefCell::new(Vec::new()));
+        let recorded_pauses = pauses.clone();
+        let debugger = Debugger::new(move |pause: &DebugPause| {
+            recorded_pauses.borrow_mut().push(pause.clone());
+            if actions.is_empty() {
+                DebugAction::Continue
+            } else {
+                actions.remove(0)
+            }
+        });
+        for breakpoint in breakpoints {
+            debugger.add_breakpoint(breakpoint);
+        }
+        let mut context = ExecutionContext::local();
+        context.set_debugger(debugger);
+        let result = context.execute_sync(script, &[]).unwrap();
+        (result, pauses.take())
+    }
+
+    fn location<'a>(script: &'a str, pause: &DebugPause) -> &'a str {
+        &script[pause.location.as_ref().unwrap().span.clone()]
+    }
+
+    #[test]
+    fn debugger_statement() {
+        let (result, pauses) =
+            execute_with_debugger("const a = 42; debugger; a", vec![], vec![]);
+        assert_eq!(result, Some(Integer::from(42).into()));
+        assert_eq!(pauses.len(), 1);
+        assert_eq!(pauses[0].reason, PauseReason::DebuggerStatement);
+        assert_eq!(
+            pauses[0].memory_dump.slots,
+            vec![(0, Some(Integer::from(42).into()))]
+        );
+    }
+
+    #[test]
+    fn offset_breakpoint() {
+        let (_, pauses) =
+            execute_with_debugger("1 + 2", vec![Breakpoint::Offset(0)], vec![]);
+        assert_eq!(pauses.len(), 1);
+        assert_eq!(pauses[0].reason, PauseReason::Breakpoint(0));
+        assert_eq!(pauses[0].offset, 0);
+    }
+
+    #[test]
+    fn span_breakpoint_and_steps() {
+        let script = "const a = 1 + 2;\nconst b = a + 3;\nb";
+        let (result, pauses) = execute_with_debugger(
+            script,
+            vec![Breakpoint::Span(17..33)],
+            vec![DebugAction::StepInto, DebugAction::StepOver],
+        );
+        assert_eq!(result, Some(Integer::from(6).into()));
+        assert_eq!(pauses[0].reason, PauseReason::Breakpoint(0));
+        assert_eq!(location(script, &pauses[0]), "const b = a + 3");
+        assert_eq!(pauses[1].reason, PauseReason::Step);
+        assert_eq!(location(script, &pauses[1]), "a + 3");
+        assert_eq!(pauses[1].depth, pauses[0].depth + 1);
+        // step over skips the operands of the addition
+        assert_eq!(location(script, &pauses[2]), "b");
+        assert_eq!(pauses.len(), 3);
+    }
+}
@@ -1,11 +1,16 @@
 use crate::dxb_parser::body::DXBParserError;
 use crate::network::com_hub::ResponseError;
 use crate::references::reference::{
-    AccessError, AssignmentError, ReferenceCreationError,
+    AccessError, AssignmentError, ReferenceCreationError, TypeError,
 };
 use crate::runtime::execution::execution_loop::state::ExecutionLoopState;
+use crate::runtime::execution::limits::ExecutionLimit;
+use crate::runtime::permissions::PermissionError;
+use crate::stdlib::boxed::Box;
 use crate::stdlib::string::String;
+use crate::stdlib::string::ToString;
 use crate::types::error::IllegalTypeError;
+use crate::values::value::Value;
 use crate::values::value_container::{ValueContainer, ValueError};
 use core::fmt::Display;
 
@@ -55,6 +60,7 @@ pub enum ExecutionError {
     ValueError(ValueError),
     InvalidProgram(InvalidProgramError),
     AccessError(AccessError),
+    PermissionError(PermissionError),
     Unknown,
     NotImplemented(String),
     SlotNotAllocated(u32),
@@ -65,15 +71,57 @@ pub enum ExecutionError {
     IllegalTypeError(IllegalTypeError),
     ReferenceNotFound,
     DerefOfNonReference,
-    InvalidTypeCast,
+    InvalidTypeCast {
+        value: String,
+        target: String,
+    },
+    TypeError(Box<TypeError>),
     ExpectedTypeValue,
     AssignmentError(AssignmentError),
     ReferenceFromValueContainerError(ReferenceCreationError),
     IntermediateResultWithState(
         Option<ValueContainer>,
-        Option<ExecutionLoopState>,
+        Option<Box<ExecutionLoopState>>,
     ),
     InvalidApply,
+    ExecutionContextDisposed,
+    Cancelled,
+    LimitExceeded(ExecutionLimit),
+    /// An error value raised with yeet that was not recovered with try
+    Yeeted(ValueContainer),
+}
+
+impl ExecutionError {
+    /// Returns true if the error can be recovered from with try.
+    /// Errors caused by invalid programs, exceeded limits, missing permissions
+    /// or the execution environment always abort the execution.
+    pub fn is_recoverable(&self) -> bool {
+        !matches!(
+            self,
+            ExecutionError::DXBParserError(_)
+                | ExecutionError::InvalidProgram(_)
+                | ExecutionError::PermissionError(_)
+                | ExecutionError::RequiresAsyncExecution
+                | ExecutionError::RequiresRuntime
+                | ExecutionError::IntermediateResultWithState(..)
+                | ExecutionError::ExecutionContextDisposed
+                | ExecutionError::Cancelled
+                | ExecutionError::LimitExceeded(_)
+        )
+    }
+
+    /// Converts the error into an error value that can be handled by the program.
+    /// Yeeted errors keep their original value, all other errors are converted
+    /// to an error value with the error description as message.
+    pub fn into_error_value(self) -> ValueContainer {
+        match self {
+            ExecutionError::Yeeted(value) => value,
+            error => ValueContainer::from(Value::error(
+                error.to_string(),
+                Value::null().into(),
+            )),
+        }
+    }
 }
 impl From<ReferenceCreationError> for ExecutionError {
     fn from(error: ReferenceCreationError) -> Self {
@@ -87,6 +135,12 @@ impl From<AccessError> for ExecutionError {
     }
 }
 
+impl From<PermissionError> for ExecutionError {
+    fn from(error: PermissionError) -> Self {
+        ExecutionError::PermissionError(error)
+    }
+}
+
 impl From<DXBParserError> for ExecutionError {
     fn from(error: DXBParserError) -> Self {
         ExecutionError::DXBParserError(error)
@@ -117,6 +171,12 @@ impl From<ResponseError> for ExecutionError {
     }
 }
 
+impl From<TypeError> for ExecutionError {
+    fn from(error: TypeError) -> Self {
+        ExecutionError::TypeError(Box::new(error))
+    }
+}
+
 impl From<AssignmentError> for ExecutionError {
     fn from(error: AssignmentError) -> Self {
         ExecutionError::AssignmentError(error)
@@ -177,8 +237,11 @@ impl Display for ExecutionError {
             ExecutionError::AssignmentError(err) => {
                 core::write!(f, "Assignment error: {err}")
             }
-            ExecutionError::InvalidTypeCast => {
-                core::write!(f, "Invalid type cast")
+            ExecutionError::TypeError(err) => {
+                core::write!(f, "Type error: {err}")
+            }
+            ExecutionError::InvalidTypeCast { value, target } => {
+                core::write!(f, "Cannot cast {value} to {target}")
             }
             ExecutionError::ExpectedTypeValue => {
                 core::write!(f, "Expected a type value")
@@ -186,6 +249,9 @@ impl Display for ExecutionError {
             ExecutionError::AccessError(err) => {
                 core::write!(f, "Access error: {err}")
             }
+            ExecutionError::PermissionError(err) => {
+                core::write!(f, "Permission error: {err}")
+            }
             ExecutionError::IntermediateResultWithState(
                 value_opt,
                 state_opt,
@@ -200,6 +266,23 @@ impl Display for ExecutionError {
             ExecutionError::InvalidApply => {
                 core::write!(f, "Invalid apply operation")
             }
+            ExecutionError::ExecutionContextDisposed => {
+                core::write!(f, "Execution context was already disposed")
+            }
+            ExecutionError::Cancelled => {
+                core::write!(f, "Execution was cancelled")
+            }
+            ExecutionError::LimitExceeded(limit) => {
+                core::write!(f, "Execution exceeded the {limit}")
+            }
+            ExecutionError::Yeeted(value) => {
+                match value.to_value().borrow().error_message() {
+                    Some(message) => {
+                        core::write!(f, "Uncaught error: {message}")
+                    }
+                    None => core::write!(f, "Uncaught error: {value}"),
+                }
+            }
         }
     }
 }
@@ -1,5 +1,4 @@
 use crate::runtime::RuntimeInternal;
-use crate::runtime::execution::ExecutionError;
 use crate::runtime::execution::execution_loop::execution_loop;
 use crate::runtime::execution::execution_loop::interrupts::{
     ExternalExecutionInterrupt, InterruptProvider,
@@ -7,13 +6,22 @@ use crate::runtime::execution::execution_loop::interrupts::{
 use crate::runtime::execution::execution_loop::state::{
     ExecutionLoopState, RuntimeExecutionState,
 };
+use crate::runtime::execution::limits::{
+    CancellationHandle, ExecutionBudget, ExecutionLimits,
+};
+use crate::runtime::execution::source_map::{SourceLocation, SourceMap};
+use crate::runtime::execution::{Debugger, ExecutionError};
 use crate::stdlib::boxed::Box;
 use crate::stdlib::rc::Rc;
-use core::cell::RefCell;
+use crate::values::core_values::endpoint::Endpoint;
+use core::cell::{Cell, RefCell};
+use core::time::Duration;
 
 #[derive(Debug, Clone, Default)]
 pub struct ExecutionOptions {
     pub verbose: bool,
+    /// Resource limits for all executions in the same execution context.
+    pub limits: ExecutionLimits,
 }
 
 /// Input required to execute a DXB program.
@@ -26,6 +34,17 @@ pub struct ExecutionInput<'a> {
     /// For persisting execution state across multiple executions (e.g., for REPL scenarios).
     pub loop_state: Option<ExecutionLoopState>,
     pub runtime: Option<Rc<RuntimeInternal>>,
+    /// The endpoint that sent the DXB program, used to resolve local pointer addresses.
+    pub sender: Option<Endpoint>,
+    /// Handle to cancel the execution.
+    pub cancellation: CancellationHandle,
+    /// Source map of the DXB body, used to report the source location of execution errors.
+    pub source_map: Option<Rc<SourceMap>>,
+    /// Receives the source location of the instruction that caused an execution error.
+    /// Only set if a source map is provided.
+    pub error_location: Rc<RefCell<Option<SourceLocation>>>,
+    /// Debugger that can pause the execution at breakpoints and debugger statements.
+    pub debugger: Option<Debugger>,
 }
 
 impl<'a> ExecutionInput<'a> {
@@ -39,6 +58,20 @@ impl<'a> ExecutionInput<'a> {
             dxb_body,
             loop_state: None,
             runtime,
+            sender: None,
+            cancellation: CancellationHandle::default(),
+            source_map: None,
+            error_location: Rc::default(),
+            debugger: None,
+        }
+    }
+
+    /// Returns the wall time that is left for the execution, if a maximum
+    /// duration is set. Continued executions share the time of the context.
+    pub(crate) fn remaining_duration(&self) -> Option<Duration> {
+        match &self.loop_state {
+            Some(loop_state) => loop_state.budget.borrow().remaining_duration(),
+            None => self.options.limits.max_duration,
         }
     }
 
@@ -48,18 +81,34 @@ impl<'a> ExecutionInput<'a> {
         InterruptProvider,
         impl Iterator<Item = Result<ExternalExecutionInterrupt, ExecutionError>>,
     ) {
+        if let Some(debugger) = &self.debugger {
+            debugger.set_source_map(self.source_map.clone());
+        }
         // use execution iterator if one already exists from previous execution
         let mut loop_state = if let Some(existing_loop_state) =
             self.loop_state.take()
         {
             // update dxb so that instruction iterator can continue with next instructions
+            // the budget of the previous executions is kept, so that the
+            // limits apply to all executions of the context together
             *existing_loop_state.dxb_body.borrow_mut() = self.dxb_body.to_vec();
+            *existing_loop_state.debugger.borrow_mut() = self.debugger.clone();
             existing_loop_state
         }
         // otherwise start a new execution loop
         else {
+            let budget = Rc::new(RefCell::new(ExecutionBudget::new(
+                self.options.limits,
+                self.cancellation.clone(),
+            )));
+            let instruction_offset = Rc::new(Cell::new(0));
+            let debugger = Rc::new(RefCell::new(self.debugger.clone()));
             let state = RuntimeExecutionState {
                 runtime_internal: self.runtime.clone(),
+                sender: self.sender.clone(),
+                budget: budget.clone(),
+                instruction_offset: instruction_offset.clone(),
+                debugger: debugger.clone(),
                 source_id: 0, // TODO #640: set proper source ID
                 ..Default::default()
             };
@@ -68,6 +117,9 @@ impl<'a> ExecutionInput<'a> {
             let interrupt_provider = InterruptProvider::new();
             ExecutionLoopState {
                 dxb_body: dxb_rc.clone(),
+                budget,
+                instruction_offset,
+                debugger,
                 iterator: Box::new(execution_loop(
                     state,
                     dxb_rc,
@@ -77,6 +129,8 @@ impl<'a> ExecutionInput<'a> {
             }
         };
         let interrupt_provider = loop_state.interrupt_provider.clone();
+        let source_map = self.source_map.take();
+        let error_location = self.error_location.clone();
 
         // proxy the iterator, storing it back into state if interrupted to await more instructions
         let iterator = gen move {
@@ -95,10 +149,17 @@ impl<'a> ExecutionInput<'a> {
                         return yield Err(
                             ExecutionError::IntermediateResultWithState(
                                 intermediate_result,
-                                Some(loop_state),
+                                Some(Box::new(loop_state)),
                             ),
                         );
                     }
+                    Err(error) => {
+                        if let Some(source_map) = &source_map {
+                            *error_location.borrow_mut() = source_map
+                                .location(loop_state.instruction_offset.get());
+                        }
+                        yield Err(error)
+                    }
                     _ => yield item,
                 }
             }
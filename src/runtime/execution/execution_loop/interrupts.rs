@@ -1,6 +1,7 @@
 use crate::global::protocol_structures::instructions::{
     RawFullPointerAddress, RawInternalPointerAddress, RawLocalPointerAddress,
 };
+use crate::runtime::execution::ExecutionError;
 use crate::stdlib::cell::RefCell;
 use crate::stdlib::rc::Rc;
 use crate::stdlib::vec::Vec;
@@ -28,6 +29,8 @@ pub enum ExternalExecutionInterrupt {
 #[derive(Debug)]
 pub enum InterruptResult {
     ResolvedValue(Option<ValueContainer>),
+    /// a recoverable error that occurred while handling the interrupt
+    Error(ExecutionError),
 }
 
 #[derive(Debug, Clone)]
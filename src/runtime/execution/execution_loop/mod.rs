@@ -1,11 +1,13 @@
 pub mod interrupts;
-mod operations;
+pub(crate) mod operations;
 mod runtime_value;
 mod slots;
 pub mod state;
 
 use crate::core_compiler::value_compiler::compile_value_container;
-use crate::dxb_parser::body::{DXBParserError, iterate_instructions};
+use crate::dxb_parser::body::{
+    DXBParserError, iterate_instructions_with_offsets,
+};
 use crate::dxb_parser::instruction_collector::{
     CollectedResults, CollectionResultsPopper, FullOrPartialResult,
     InstructionCollector, LastUnboundedResultCollector, ResultCollector,
@@ -13,14 +15,18 @@ use crate::dxb_parser::instruction_collector::{
 };
 use crate::global::instruction_codes::InstructionCode;
 use crate::global::operators::{
-    AssignmentOperator, BinaryOperator, ComparisonOperator, UnaryOperator,
+    AssignmentOperator, BinaryOperator, CastMode, ComparisonOperator,
+    UnaryOperator,
 };
 use crate::global::protocol_structures::instructions::{
-    ApplyData, DecimalData, Float32Data, Float64Data, FloatAsInt16Data,
-    FloatAsInt32Data, Instruction, IntegerData, RawPointerAddress,
-    RegularInstruction, ShortTextData, SlotAddress, TextData, TypeInstruction,
+    ApplyData, CollectionTypeKind, DecimalData, Float32Data, Float64Data,
+    FloatAsInt16Data, FloatAsInt32Data, Instruction, IntegerData,
+    RawPointerAddress, RegularInstruction, ShortTextData, SlotAddress,
+    StructuralTypeKind, TextData, TypeInstruction,
 };
+use crate::libs::core::{CoreLibPointerId, get_core_lib_type_reference};
 use crate::references::reference::{Reference, ReferenceMutability};
+use crate::references::type_reference::TypeReference;
 use crate::runtime::execution::execution_loop::interrupts::{
     ExecutionInterrupt, ExternalExecutionInterrupt, InterruptProvider,
     InterruptResult,
@@ -33,22 +39,25 @@ use crate::runtime::execution::execution_loop::runtime_value::RuntimeValue;
 use crate::runtime::execution::execution_loop::slots::get_internal_slot_value;
 use crate::runtime::execution::execution_loop::state::RuntimeExecutionState;
 use crate::runtime::execution::macros::{
-    interrupt, interrupt_with_maybe_value, interrupt_with_value, yield_unwrap,
+    interrupt, interrupt_with_maybe_value, interrupt_with_result,
+    interrupt_with_value, yield_unwrap,
 };
 use crate::runtime::execution::{ExecutionError, InvalidProgramError};
 use crate::stdlib::boxed::Box;
 use crate::stdlib::rc::Rc;
 use crate::stdlib::vec::Vec;
+use crate::types::collection_type_definition::CollectionTypeDefinition;
 use crate::types::definition::TypeDefinition;
+use crate::types::structural_type_definition::StructuralTypeDefinition;
 use crate::utils::buffers::append_u32;
 use crate::values::core_value::CoreValue;
+use crate::values::core_values::callable::CallableSignature;
 use crate::values::core_values::decimal::Decimal;
 use crate::values::core_values::decimal::typed_decimal::TypedDecimal;
 use crate::values::core_values::integer::typed_integer::TypedInteger;
 use crate::values::core_values::list::List;
 use crate::values::core_values::map::{Map, MapKey};
 use crate::values::core_values::r#type::Type;
-use crate::values::pointer::PointerAddress;
 use crate::values::value::Value;
 use crate::values::value_container::{OwnedValueKey, ValueContainer};
 use core::cell::RefCell;
@@ -222,6 +231,27 @@ pub fn execution_loop(
     }
 }
 
+/// Finds the innermost try instruction that can recover from the error.
+/// Returns the depth of the try instruction together with the error value,
+/// or the original error if the error is not handled.
+fn recover_from_error(
+    collector: &InstructionCollector<CollectedExecutionResult>,
+    error: ExecutionError,
+) -> Result<(usize, ValueContainer), ExecutionError> {
+    if !error.is_recoverable() {
+        return Err(error);
+    }
+    match collector.find_innermost(|instruction| {
+        matches!(
+            instruction,
+            Instruction::RegularInstruction(RegularInstruction::Try)
+        )
+    }) {
+        Some(try_depth) => Ok((try_depth, error.into_error_value())),
+        None => Err(error),
+    }
+}
+
 pub fn inner_execution_loop(
     dxb_body: Rc<RefCell<Vec<u8>>>,
     interrupt_provider: InterruptProvider,
@@ -231,8 +261,14 @@ pub fn inner_execution_loop(
         let mut collector =
             InstructionCollector::<CollectedExecutionResult>::default();
 
-        for instruction_result in iterate_instructions(dxb_body) {
-            let instruction = match instruction_result {
+        // offsets of the instructions that are currently collecting results
+        let mut collection_offsets: Vec<usize> = Vec::new();
+
+        // depth of the try instruction and error value while recovering from an error
+        let mut recovering: Option<(usize, ValueContainer)> = None;
+
+        for instruction_result in iterate_instructions_with_offsets(dxb_body) {
+            let (offset, instruction) = match instruction_result {
                 Ok(instruction) => instruction,
                 Err(DXBParserError::ExpectingMoreInstructions) => {
                     yield Err(DXBParserError::ExpectingMoreInstructions.into());
@@ -245,21 +281,75 @@ pub fn inner_execution_loop(
                 }
             };
 
-            let result = match instruction {
-                // handle regular instructions
-                Instruction::RegularInstruction(regular_instruction) => {
-                    let regular_instruction = collector
-                        .default_regular_instruction_collection(
+            state.instruction_offset.set(offset);
+
+            if let Some(debugger) = state.debugger.borrow().as_ref() {
+                debugger.before_instruction(
+                    offset,
+                    collector.depth(),
+                    matches!(
+                        instruction,
+                        Instruction::RegularInstruction(
+                            RegularInstruction::Debugger
+                        )
+                    ),
+                    &state.slots,
+                );
+            }
+
+            yield_unwrap!(
+                state
+                    .budget
+                    .borrow_mut()
+                    .next_instruction(collector.depth())
+            );
+
+            let result = if recovering.is_some() {
+                // while recovering from an error, the remaining instructions of the
+                // try body are only collected, but not evaluated
+                match instruction {
+                    Instruction::RegularInstruction(regular_instruction) => {
+                        collector
+                            .default_regular_instruction_collection(
+                                regular_instruction,
+                                StatementResultCollectionStrategy::Last,
+                            )
+                            .map(|_| CollectedExecutionResult::Value(None))
+                    }
+                    Instruction::TypeInstruction(type_instruction) => collector
+                        .default_type_instruction_collection(type_instruction)
+                        .map(|_| CollectedExecutionResult::Value(None)),
+                }
+            } else {
+                let result: Result<
+                    Option<CollectedExecutionResult>,
+                    ExecutionError,
+                > = 'evaluation: {
+                    // errors are passed to the error handling below instead of aborting the execution
+                    macro_rules! yield_unwrap {
+                        ($e:expr) => {{
+                            match $e {
+                                Ok(res) => res,
+                                Err(err) => break 'evaluation Err(err.into()),
+                            }
+                        }};
+                    }
+                    Ok(match instruction {
+                        // handle regular instructions
+                        Instruction::RegularInstruction(
                             regular_instruction,
-                            StatementResultCollectionStrategy::Last,
-                        );
+                        ) => {
+                            let regular_instruction = collector
+                                .default_regular_instruction_collection(
+                                    regular_instruction,
+                                    StatementResultCollectionStrategy::Last,
+                                );
 
-                    let expr: Option<Option<RuntimeValue>> = if let Some(
-                        regular_instruction,
-                    ) =
-                        regular_instruction
-                    {
-                        Some(match regular_instruction {
+                            let expr: Option<Option<RuntimeValue>> =
+                                if let Some(regular_instruction) =
+                                    regular_instruction
+                                {
+                                    Some(match regular_instruction {
                             // boolean
                             RegularInstruction::True => Some(ValueContainer::from(true).into()),
                             RegularInstruction::False => Some(ValueContainer::from(false).into()),
@@ -389,6 +479,9 @@ pub fn inner_execution_loop(
                                 None
                             }
 
+                            // the debugger is already called before the instruction
+                            RegularInstruction::Debugger => None,
+
                             // NOTE: make sure that each possible match case is either implemented in the default collection or here
                             // If an instruction is implemented in the default collection, it should be marked as unreachable!() here
                             RegularInstruction::Statements(_) |
@@ -405,6 +498,7 @@ pub fn inner_execution_loop(
                             RegularInstruction::Subtract |
                             RegularInstruction::Multiply |
                             RegularInstruction::Divide |
+                            RegularInstruction::Default |
                             RegularInstruction::UnaryMinus |
                             RegularInstruction::UnaryPlus |
                             RegularInstruction::BitwiseNot |
@@ -412,10 +506,12 @@ pub fn inner_execution_loop(
                             RegularInstruction::GetPropertyText(_) |
                             RegularInstruction::GetPropertyIndex(_) |
                             RegularInstruction::GetPropertyDynamic |
+                            RegularInstruction::GetPropertyOptional |
                             RegularInstruction::SetPropertyText(_) |
                             RegularInstruction::SetPropertyIndex(_) |
                             RegularInstruction::SetPropertyDynamic |
                             RegularInstruction::Is |
+                            RegularInstruction::Implements |
                             RegularInstruction::Matches |
                             RegularInstruction::StructuralEqual |
                             RegularInstruction::Equal |
@@ -433,112 +529,229 @@ pub fn inner_execution_loop(
                             RegularInstruction::SetSlot(_) |
                             RegularInstruction::SetReferenceValue(_) |
                             RegularInstruction::Deref |
+                            RegularInstruction::Yeet |
+                            RegularInstruction::Try |
                             RegularInstruction::TypedValue |
+                            RegularInstruction::Transform(_) |
                             RegularInstruction::RemoteExecution(_) |
                             RegularInstruction::TypeExpression => unreachable!()
                         })
-                    } else {
-                        None
-                    };
+                                } else {
+                                    None
+                                };
 
-                    expr.map(CollectedExecutionResult::from)
-                }
-                Instruction::TypeInstruction(type_instruction) => {
-                    let type_instruction = collector
-                        .default_type_instruction_collection(type_instruction);
+                            expr.map(CollectedExecutionResult::from)
+                        }
+                        Instruction::TypeInstruction(type_instruction) => {
+                            let type_instruction = collector
+                                .default_type_instruction_collection(
+                                    type_instruction,
+                                );
 
-                    let type_expression: Option<Type> = if let Some(
-                        type_instruction,
-                    ) = type_instruction
-                    {
-                        Some(match type_instruction {
-                            TypeInstruction::LiteralInteger(integer) => {
-                                Type::structural(integer.0)
-                            }
-                            TypeInstruction::LiteralText(text_data) => {
-                                Type::structural(text_data.0)
-                            }
+                            let type_expression: Option<Type> = if let Some(
+                                type_instruction,
+                            ) =
+                                type_instruction
+                            {
+                                Some(match type_instruction {
+                                    TypeInstruction::LiteralInteger(
+                                        integer,
+                                    ) => Type::structural(integer.0),
+                                    TypeInstruction::LiteralText(text_data) => {
+                                        Type::structural(text_data.0)
+                                    }
+                                    TypeInstruction::Unit(metadata) => {
+                                        Type::new(
+                                            TypeDefinition::Unit,
+                                            metadata.mutability.into(),
+                                        )
+                                    }
+                                    TypeInstruction::Unknown(metadata) => {
+                                        Type::new(
+                                            TypeDefinition::Unknown,
+                                            metadata.mutability.into(),
+                                        )
+                                    }
+                                    TypeInstruction::Never(metadata) => {
+                                        Type::new(
+                                            TypeDefinition::Never,
+                                            metadata.mutability.into(),
+                                        )
+                                    }
+                                    TypeInstruction::Parameter(parameter) => {
+                                        Type::new(
+                                            TypeDefinition::Parameter(
+                                                parameter.name,
+                                            ),
+                                            parameter
+                                                .metadata
+                                                .mutability
+                                                .into(),
+                                        )
+                                    }
 
-                            TypeInstruction::TypeReference(type_ref) => {
-                                let metadata = type_ref.metadata;
-                                let val = interrupt_with_maybe_value!(
-                                    interrupt_provider,
-                                    match type_ref.address {
-                                        RawPointerAddress::Local(address) => {
-                                            ExecutionInterrupt::External(
+                                    TypeInstruction::TypeReference(
+                                        type_ref,
+                                    ) => {
+                                        let metadata = type_ref.metadata;
+                                        let val = interrupt_with_maybe_value!(
+                                            interrupt_provider,
+                                            match type_ref.address {
+                                                RawPointerAddress::Local(
+                                                    address,
+                                                ) => {
+                                                    ExecutionInterrupt::External(
                                                 ExternalExecutionInterrupt::ResolveLocalPointer(
                                                     address,
                                                 ),
                                             )
-                                        }
-                                        RawPointerAddress::Internal(
-                                            address,
-                                        ) => {
-                                            ExecutionInterrupt::External(ExternalExecutionInterrupt::ResolveInternalPointer(address))
-                                        }
-                                        RawPointerAddress::Full(address) => {
-                                            ExecutionInterrupt::External(
+                                                }
+                                                RawPointerAddress::Internal(
+                                                    address,
+                                                ) => {
+                                                    ExecutionInterrupt::External(ExternalExecutionInterrupt::ResolveInternalPointer(address))
+                                                }
+                                                RawPointerAddress::Full(
+                                                    address,
+                                                ) => {
+                                                    ExecutionInterrupt::External(
                                                 ExternalExecutionInterrupt::ResolvePointer(
                                                     address,
                                                 ),
                                             )
-                                        }
-                                    }
-                                );
+                                                }
+                                            }
+                                        );
 
-                                match val {
-                                    // simple Type value
-                                    Some(ValueContainer::Value(Value {
-                                        inner: CoreValue::Type(ty),
-                                        ..
-                                    })) => ty,
-                                    // Type Reference
-                                    Some(ValueContainer::Reference(
-                                        Reference::TypeReference(type_ref),
-                                    )) => Type::new(
-                                        TypeDefinition::Reference(type_ref),
-                                        metadata.mutability.into(),
-                                    ),
-                                    _ => {
-                                        return yield Err(
+                                        match val {
+                                            // simple Type value
+                                            Some(ValueContainer::Value(
+                                                Value {
+                                                    inner: CoreValue::Type(ty),
+                                                    ..
+                                                },
+                                            )) => ty,
+                                            // Type Reference
+                                            Some(
+                                                ValueContainer::Reference(
+                                                    Reference::TypeReference(
+                                                        type_ref,
+                                                    ),
+                                                ),
+                                            ) => Type::new(
+                                                TypeDefinition::Reference(
+                                                    type_ref,
+                                                ),
+                                                metadata.mutability.into(),
+                                            ),
+                                            _ => {
+                                                break 'evaluation Err(
                                             ExecutionError::ExpectedTypeValue,
                                         );
+                                            }
+                                        }
                                     }
-                                }
-                            }
 
-                            // NOTE: make sure that each possible match case is either implemented in the default collection or here
-                            // If an instruction is implemented in the default collection, it should be marked as unreachable!() here
-                            TypeInstruction::List(_)
-                            | TypeInstruction::ImplType(_) => unreachable!(),
-                        })
-                    } else {
-                        None
-                    };
+                                    // NOTE: make sure that each possible match case is either implemented in the default collection or here
+                                    // If an instruction is implemented in the default collection, it should be marked as unreachable!() here
+                                    TypeInstruction::List(_)
+                                    | TypeInstruction::ImplType(_)
+                                    | TypeInstruction::Collection(_)
+                                    | TypeInstruction::Structural(_)
+                                    | TypeInstruction::Nominal(_)
+                                    | TypeInstruction::Callable(_)
+                                    | TypeInstruction::Type(_)
+                                    | TypeInstruction::Union(_)
+                                    | TypeInstruction::Intersection(_) => {
+                                        unreachable!()
+                                    }
+                                })
+                            } else {
+                                None
+                            };
 
-                    type_expression
-                        .map(|ty_expr| CollectedExecutionResult::from(ty_expr))
+                            type_expression.map(|ty_expr| {
+                                CollectedExecutionResult::from(ty_expr)
+                            })
+                        }
+                    })
+                };
+                match result {
+                    Ok(result) => result,
+                    Err(err) => {
+                        recovering = Some(yield_unwrap!(recover_from_error(
+                            &collector, err
+                        )));
+                        // placeholder for the result of the failed instruction
+                        Some(CollectedExecutionResult::Value(None))
+                    }
                 }
             };
 
+            collection_offsets.resize(collector.depth(), offset);
+
             if let Some(result) = result {
                 collector.push_result(result);
             }
 
             // handle collecting nested expressions
             while let Some(result) = collector.try_pop_collected() {
-                let expr: CollectedExecutionResult = match result {
-                    FullOrPartialResult::Full(
-                        instruction,
-                        mut collected_results,
-                    ) => {
-                        match instruction {
+                // errors of collected instructions are located at the collecting instruction
+                collection_offsets.truncate(collector.depth() + 1);
+                if let Some(offset) = collection_offsets.pop() {
+                    state.instruction_offset.set(offset);
+                }
+                let expr: CollectedExecutionResult = if let Some(try_depth) =
+                    recovering.as_ref().map(|(depth, _)| *depth)
+                {
+                    // unbounded statements must still be closed while recovering
+                    if let FullOrPartialResult::Full(
+                        Instruction::RegularInstruction(
+                            RegularInstruction::UnboundedStatementsEnd(_),
+                        ),
+                        _,
+                    ) = result
+                    {
+                        collector.try_pop_unbounded();
+                    }
+                    if collector.depth() == try_depth {
+                        // the try instruction is completed, the error value is its result
+                        let (_, error) = recovering.take().unwrap();
+                        RuntimeValue::ValueContainer(error).into()
+                    } else {
+                        CollectedExecutionResult::Value(None)
+                    }
+                } else {
+                    let expr: Result<CollectedExecutionResult, ExecutionError> = 'collected: {
+                        // errors are passed to the error handling below instead of aborting the execution
+                        macro_rules! yield_unwrap {
+                            ($e:expr) => {{
+                                match $e {
+                                    Ok(res) => res,
+                                    Err(err) => {
+                                        break 'collected Err(err.into());
+                                    }
+                                }
+                            }};
+                        }
+                        Ok(match result {
+                            FullOrPartialResult::Full(
+                                instruction,
+                                mut collected_results,
+                            ) => {
+                                match instruction {
                             Instruction::RegularInstruction(
                                 regular_instruction,
                             ) => match regular_instruction {
                                 RegularInstruction::List(_)
                                 | RegularInstruction::ShortList(_) => {
                                     let elements = yield_unwrap!(collected_results.collect_value_container_results_assert_existing(&state));
+                                    yield_unwrap!(
+                                        state
+                                            .budget
+                                            .borrow_mut()
+                                            .allocate_values(elements.len())
+                                    );
                                     RuntimeValue::ValueContainer(
                                         ValueContainer::from(List::new(
                                             elements,
@@ -549,6 +762,12 @@ pub fn inner_execution_loop(
                                 RegularInstruction::Map(_)
                                 | RegularInstruction::ShortMap(_) => {
                                     let entries = yield_unwrap!(collected_results.collect_key_value_pair_results_assert_existing());
+                                    yield_unwrap!(
+                                        state
+                                            .budget
+                                            .borrow_mut()
+                                            .allocate_values(entries.len())
+                                    );
                                     RuntimeValue::ValueContainer(
                                         ValueContainer::from(Map::from(
                                             entries,
@@ -586,7 +805,8 @@ pub fn inner_execution_loop(
                                 RegularInstruction::Add
                                 | RegularInstruction::Subtract
                                 | RegularInstruction::Multiply
-                                | RegularInstruction::Divide => {
+                                | RegularInstruction::Divide
+                                | RegularInstruction::Default => {
                                     let right = yield_unwrap!(
                                         collected_results
                                             .pop_cloned_value_container_result_assert_existing(&state)
@@ -610,6 +830,7 @@ pub fn inner_execution_loop(
                                 }
 
                                 RegularInstruction::Is
+                                | RegularInstruction::Implements
                                 | RegularInstruction::StructuralEqual
                                 | RegularInstruction::Equal
                                 | RegularInstruction::NotStructuralEqual
@@ -698,6 +919,19 @@ pub fn inner_execution_loop(
                                     .into()
                                 }
 
+                                RegularInstruction::Transform(mode) => {
+                                    let value_container = yield_unwrap!(
+                                        collected_results
+                                            .pop_cloned_value_container_result_assert_existing(&state)
+                                    );
+                                    let ty =
+                                        collected_results.pop_type_result();
+                                    RuntimeValue::ValueContainer(yield_unwrap!(
+                                        ty.cast(&value_container, mode)
+                                    ))
+                                    .into()
+                                }
+
                                 // type(...)
                                 RegularInstruction::TypeExpression => {
                                     let ty =
@@ -766,6 +1000,17 @@ pub fn inner_execution_loop(
                                     if let Some(reference) =
                                         ref_value_container.maybe_reference()
                                     {
+                                        if let Some(runtime) =
+                                            &state.runtime_internal
+                                        {
+                                            yield_unwrap!(
+                                                runtime
+                                                    .check_reference_mutation(
+                                                        &state.sender,
+                                                        reference
+                                                    )
+                                            );
+                                        }
                                         let lhs = reference.value_container();
                                         let res = yield_unwrap!(
                                             handle_assignment_operation(
@@ -782,7 +1027,7 @@ pub fn inner_execution_loop(
                                         )
                                         .into()
                                     } else {
-                                        return yield Err(
+                                        break 'collected Err(
                                             ExecutionError::DerefOfNonReference,
                                         );
                                     }
@@ -810,6 +1055,12 @@ pub fn inner_execution_loop(
                                         collected_results
                                             .pop_cloned_value_container_result_assert_existing(&state)
                                     );
+                                    yield_unwrap!(
+                                        state
+                                            .budget
+                                            .borrow_mut()
+                                            .allocate_values(1)
+                                    );
                                     state
                                         .slots
                                         .allocate_slot(address, Some(value));
@@ -883,6 +1134,39 @@ pub fn inner_execution_loop(
                                     .into()
                                 }
 
+                                RegularInstruction::GetPropertyOptional => {
+                                    let mut target = yield_unwrap!(
+                                        collected_results
+                                            .pop_runtime_value_result_assert_existing()
+                                    );
+                                    let key = yield_unwrap!(
+                                        collected_results
+                                            .pop_cloned_value_container_result_assert_existing(&state)
+                                    );
+
+                                    // optional chaining: a null target results in null
+                                    let res = target.with_mut_value_container(
+                                        &mut state,
+                                        |target| {
+                                            if target
+                                                .to_value()
+                                                .borrow()
+                                                .is_null()
+                                            {
+                                                Ok(ValueContainer::from(
+                                                    CoreValue::Null,
+                                                ))
+                                            } else {
+                                                target.try_get_property(&key)
+                                            }
+                                        },
+                                    );
+                                    RuntimeValue::ValueContainer(yield_unwrap!(
+                                        yield_unwrap!(res)
+                                    ))
+                                    .into()
+                                }
+
                                 RegularInstruction::SetPropertyText(
                                     property_data,
                                 ) => {
@@ -896,11 +1180,13 @@ pub fn inner_execution_loop(
                                     );
                                     let runtime_internal =
                                         state.runtime_internal.clone();
+                                    let sender = state.sender.clone();
                                     let res = target.with_mut_value_container(
                                         &mut state,
                                         |target| {
                                             set_property(
                                                 &runtime_internal,
+                                                &sender,
                                                 target,
                                                 OwnedValueKey::Text(
                                                     property_data.0,
@@ -927,11 +1213,13 @@ pub fn inner_execution_loop(
 
                                     let runtime_internal =
                                         state.runtime_internal.clone();
+                                    let sender = state.sender.clone();
                                     let res = target.with_mut_value_container(
                                         &mut state,
                                         |target| {
                                             set_property(
                                                 &runtime_internal,
+                                                &sender,
                                                 target,
                                                 OwnedValueKey::Index(
                                                     property_data.0 as i64,
@@ -960,11 +1248,13 @@ pub fn inner_execution_loop(
 
                                     let runtime_internal =
                                         state.runtime_internal.clone();
+                                    let sender = state.sender.clone();
                                     let res = target.with_mut_value_container(
                                         &mut state,
                                         |target| {
                                             set_property(
                                                 &runtime_internal,
+                                                &sender,
                                                 target,
                                                 OwnedValueKey::Value(key),
                                                 value,
@@ -1009,14 +1299,14 @@ pub fn inner_execution_loop(
                                             .pop_cloned_value_container_result_assert_existing(&state)
                                     );
 
-                                    interrupt_with_maybe_value!(
+                                    yield_unwrap!(interrupt_with_result!(
                                         interrupt_provider,
                                         ExecutionInterrupt::External(
                                             ExternalExecutionInterrupt::RemoteExecution(
                                                 receivers, buffer
                                             )
                                         )
-                                    )
+                                    ))
                                         .map(|val| RuntimeValue::ValueContainer(val))
                                         .into()
                                 }
@@ -1027,20 +1317,45 @@ pub fn inner_execution_loop(
                                     let mut args = yield_unwrap!(collected_results.collect_value_container_results_assert_existing(&state));
                                     // last argument is the callee
                                     let callee = args.remove(args.len() - 1);
-                                    interrupt_with_maybe_value!(
+                                    yield_unwrap!(interrupt_with_result!(
                                         interrupt_provider,
                                         ExecutionInterrupt::External(
                                             ExternalExecutionInterrupt::Apply(
                                                 callee, args
                                             )
                                         )
-                                    )
+                                    ))
                                     .map(|val| {
                                         RuntimeValue::ValueContainer(val)
                                     })
                                     .into()
                                 }
 
+                                RegularInstruction::Yeet => {
+                                    let value = yield_unwrap!(
+                                        collected_results
+                                            .pop_cloned_value_container_result_assert_existing(&state)
+                                    );
+                                    // text values are converted to error values with the text as message
+                                    let error = yield_unwrap!(
+                                        TypeReference::cast(
+                                            &get_core_lib_type_reference(
+                                                CoreLibPointerId::Error
+                                            ),
+                                            &value,
+                                            CastMode::Checked,
+                                        )
+                                    );
+                                    break 'collected Err(
+                                        ExecutionError::Yeeted(error),
+                                    );
+                                }
+
+                                // the result of the try body is passed through if no error occurred
+                                RegularInstruction::Try => {
+                                    collected_results.pop_value_result().into()
+                                }
+
                                 RegularInstruction::UnboundedStatementsEnd(
                                     terminated,
                                 ) => {
@@ -1085,38 +1400,256 @@ pub fn inner_execution_loop(
                                             .metadata
                                             .mutability
                                             .into();
-                                        let base_type =
-                                            collected_results.pop_type_result();
+                                        // impl markers come first, the base type last
+                                        let mut types = collected_results
+                                            .collect_type_results();
+                                        let base_type = types.pop().unwrap();
+                                        let markers = types
+                                            .iter()
+                                            .filter_map(Type::inner_reference)
+                                            .collect();
                                         Type::new(
                                             TypeDefinition::ImplType(
                                                 Box::new(base_type),
-                                                impl_type_data
-                                                    .impls
-                                                    .iter()
-                                                    .map(PointerAddress::from)
-                                                    .collect(),
+                                                markers,
                                             ),
                                             mutability.clone(),
                                         )
                                         .into()
                                     }
+                                    TypeInstruction::Collection(
+                                        collection_data,
+                                    ) => {
+                                        let mutability: Option<
+                                            ReferenceMutability,
+                                        > = collection_data
+                                            .metadata
+                                            .mutability
+                                            .into();
+                                        let collection = match collection_data
+                                            .kind
+                                        {
+                                            CollectionTypeKind::List => {
+                                                CollectionTypeDefinition::List(
+                                                    Box::new(
+                                                        collected_results
+                                                            .pop_type_result(),
+                                                    ),
+                                                )
+                                            }
+                                            CollectionTypeKind::FixedSizeList {
+                                                size,
+                                            } => CollectionTypeDefinition::ListSlice(
+                                                Box::new(
+                                                    collected_results
+                                                        .pop_type_result(),
+                                                ),
+                                                size as usize,
+                                            ),
+                                            CollectionTypeKind::Map => {
+                                                // value type was collected last
+                                                let value = collected_results
+                                                    .pop_type_result();
+                                                let key = collected_results
+                                                    .pop_type_result();
+                                                CollectionTypeDefinition::Map {
+                                                    key: Box::new(key),
+                                                    value: Box::new(value),
+                                                }
+                                            }
+                                            CollectionTypeKind::Instance {
+                                                ..
+                                            } => {
+                                                // base type comes first, the arguments last
+                                                let mut types = collected_results
+                                                    .collect_type_results();
+                                                let base = types.remove(0);
+                                                CollectionTypeDefinition::Instance {
+                                                    base: Box::new(base),
+                                                    arguments: types,
+                                                }
+                                            }
+                                        };
+                                        Type::new(
+                                            TypeDefinition::Collection(
+                                                collection,
+                                            ),
+                                            mutability,
+                                        )
+                                        .into()
+                                    }
+                                    TypeInstruction::Structural(
+                                        structural_data,
+                                    ) => {
+                                        let mutability: Option<
+                                            ReferenceMutability,
+                                        > = structural_data
+                                            .metadata
+                                            .mutability
+                                            .into();
+                                        let definition = match structural_data
+                                            .kind
+                                        {
+                                            StructuralTypeKind::Literal => {
+                                                let literal = yield_unwrap!(
+                                                    collected_results
+                                                        .pop_cloned_value_container_result_assert_existing(&state)
+                                                );
+                                                let inner = literal
+                                                    .to_value()
+                                                    .borrow()
+                                                    .inner
+                                                    .clone();
+                                                Type::from(inner)
+                                                    .type_definition
+                                            }
+                                            StructuralTypeKind::List {
+                                                ..
+                                            } => TypeDefinition::Structural(
+                                                StructuralTypeDefinition::List(
+                                                    collected_results
+                                                        .collect_type_results(),
+                                                ),
+                                            ),
+                                            StructuralTypeKind::Map {
+                                                count,
+                                            } => {
+                                                let mut fields = (0..count)
+                                                    .map(|_| {
+                                                        // value type was collected last
+                                                        let value =
+                                                            collected_results
+                                                                .pop_type_result();
+                                                        let key =
+                                                            collected_results
+                                                                .pop_type_result();
+                                                        (key, value)
+                                                    })
+                                                    .collect::<Vec<_>>();
+                                                fields.reverse();
+                                                TypeDefinition::Structural(
+                                                    StructuralTypeDefinition::Map(
+                                                        fields,
+                                                    ),
+                                                )
+                                            }
+                                        };
+                                        Type::new(definition, mutability).into()
+                                    }
+                                    TypeInstruction::Nominal(nominal_data) => {
+                                        let mutability: Option<
+                                            ReferenceMutability,
+                                        > = nominal_data
+                                            .metadata
+                                            .mutability
+                                            .into();
+                                        let type_value =
+                                            collected_results.pop_type_result();
+                                        let mut type_reference =
+                                            TypeReference::nominal(
+                                                type_value,
+                                                nominal_data.name.as_str(),
+                                                None,
+                                            );
+                                        type_reference.generic_parameters =
+                                            nominal_data.generic_parameters;
+                                        Type::new(
+                                            TypeDefinition::Reference(
+                                                state.declare_nominal_type(
+                                                    type_reference,
+                                                ),
+                                            ),
+                                            mutability,
+                                        )
+                                        .into()
+                                    }
+                                    TypeInstruction::Callable(callable_data) => {
+                                        let mutability: Option<
+                                            ReferenceMutability,
+                                        > = callable_data
+                                            .metadata
+                                            .mutability
+                                            .clone()
+                                            .into();
+                                        let mut types = collected_results
+                                            .collect_type_results()
+                                            .into_iter();
+                                        let parameter_types = callable_data
+                                            .parameter_names
+                                            .into_iter()
+                                            .zip(types.by_ref())
+                                            .collect();
+                                        let rest_parameter_type = callable_data
+                                            .rest_parameter
+                                            .map(|name| {
+                                                (name, Box::new(types.next().unwrap()))
+                                            });
+                                        let return_type = callable_data
+                                            .has_return_type
+                                            .then(|| Box::new(types.next().unwrap()));
+                                        let yeet_type = callable_data
+                                            .has_yeet_type
+                                            .then(|| Box::new(types.next().unwrap()));
+                                        Type::new(
+                                            TypeDefinition::Callable(
+                                                CallableSignature {
+                                                    kind: callable_data.kind,
+                                                    generic_parameters:
+                                                        callable_data
+                                                            .generic_parameters,
+                                                    parameter_types,
+                                                    rest_parameter_type,
+                                                    return_type,
+                                                    yeet_type,
+                                                },
+                                            ),
+                                            mutability,
+                                        )
+                                        .into()
+                                    }
+                                    TypeInstruction::Type(metadata) => {
+                                        Type::new(
+                                            TypeDefinition::Type(Box::new(
+                                                collected_results
+                                                    .pop_type_result(),
+                                            )),
+                                            metadata.mutability.into(),
+                                        )
+                                        .into()
+                                    }
+                                    TypeInstruction::Union(_) => Type::union(
+                                        collected_results
+                                            .collect_type_results(),
+                                    )
+                                    .into(),
+                                    TypeInstruction::Intersection(_) => {
+                                        Type::intersection(
+                                            collected_results
+                                                .collect_type_results(),
+                                        )
+                                        .into()
+                                    }
                                     _ => todo!("#649 Undescribed by author."),
                                 }
                             }
                         }
-                    }
-                    FullOrPartialResult::Partial(
-                        instruction,
-                        collected_result,
-                    ) => match instruction {
-                        Instruction::RegularInstruction(
-                            regular_instruction,
-                        ) => match regular_instruction {
-                            RegularInstruction::Statements(statements_data) => {
-                                if statements_data.terminated {
-                                    CollectedExecutionResult::Value(None)
-                                } else {
-                                    match collected_result {
+                            }
+                            FullOrPartialResult::Partial(
+                                instruction,
+                                collected_result,
+                            ) => match instruction {
+                                Instruction::RegularInstruction(
+                                    regular_instruction,
+                                ) => match regular_instruction {
+                                    RegularInstruction::Statements(
+                                        statements_data,
+                                    ) => {
+                                        if statements_data.terminated {
+                                            CollectedExecutionResult::Value(
+                                                None,
+                                            )
+                                        } else {
+                                            match collected_result {
                                         Some(
                                             CollectedExecutionResult::Value(
                                                 val,
@@ -1129,13 +1662,27 @@ pub fn inner_execution_loop(
                                         }
                                         _ => unreachable!(),
                                     }
-                                }
-                            }
-                            _ => unreachable!(),
-                        },
+                                        }
+                                    }
+                                    _ => unreachable!(),
+                                },
 
-                        Instruction::TypeInstruction(data) => unreachable!(),
-                    },
+                                Instruction::TypeInstruction(data) => {
+                                    unreachable!()
+                                }
+                            },
+                        })
+                    };
+                    match expr {
+                        Ok(expr) => expr,
+                        Err(err) => {
+                            recovering = Some(yield_unwrap!(
+                                recover_from_error(&collector, err)
+                            ));
+                            // placeholder for the result of the failed instruction
+                            CollectedExecutionResult::Value(None)
+                        }
+                    }
                 };
 
                 collector.push_result(expr);
@@ -12,16 +12,23 @@ use crate::stdlib::rc::Rc;
 use crate::traits::identity::Identity;
 use crate::traits::structural_eq::StructuralEq;
 use crate::traits::value_eq::ValueEq;
+use crate::values::core_value::CoreValue;
+use crate::values::core_values::endpoint::Endpoint;
+use crate::values::value::Value;
 use crate::values::value_container::{OwnedValueKey, ValueContainer};
 use datex_core::runtime::RuntimeInternal;
 
 pub fn set_property(
     runtime_internal: &Option<Rc<RuntimeInternal>>,
+    sender: &Option<Endpoint>,
     target: &mut ValueContainer,
     key: OwnedValueKey,
     value: ValueContainer,
 ) -> Result<(), ExecutionError> {
     if let Some(runtime) = runtime_internal {
+        if let ValueContainer::Reference(reference) = target {
+            runtime.check_reference_mutation(sender, reference)?;
+        }
         target.try_set_property(
             0, // TODO #644: set correct source id
             &runtime.memory,
@@ -132,6 +139,21 @@ pub fn handle_comparison_operation(
             let val = v_type.value_matches(lhs);
             Ok(ValueContainer::from(val))
         }
+        ComparisonOperator::Implements => {
+            let interface = match rhs {
+                ValueContainer::Value(Value {
+                    inner: CoreValue::Type(ty),
+                    ..
+                }) => ty.inner_reference(),
+                ValueContainer::Reference(Reference::TypeReference(
+                    reference,
+                )) => Some(reference.clone()),
+                _ => None,
+            }
+            .ok_or(ExecutionError::ExpectedTypeValue)?;
+            let val = lhs.actual_container_type().implements(&interface);
+            Ok(ValueContainer::from(val))
+        }
         _ => {
             unreachable!("Instruction {:?} is not a valid operation", operator);
         }
@@ -193,9 +215,18 @@ pub fn handle_logical_operation(
     lhs: &ValueContainer,
     rhs: &ValueContainer,
 ) -> Result<ValueContainer, ExecutionError> {
-    // apply operation to active value
-    {
-        core::todo!("#410 Implement logical operation for {:?}", operator);
+    match operator {
+        // null coalescing: rhs if lhs is null, lhs otherwise
+        LogicalOperator::NullCoalescing => {
+            if lhs.to_value().borrow().is_null() {
+                Ok(rhs.clone())
+            } else {
+                Ok(lhs.clone())
+            }
+        }
+        _ => {
+            core::todo!("#410 Implement logical operation for {:?}", operator);
+        }
     }
 }
 
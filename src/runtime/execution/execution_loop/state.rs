@@ -1,14 +1,17 @@
 use crate::collections::HashMap;
 use crate::references::observers::TransceiverId;
+use crate::references::type_reference::TypeReference;
 use crate::runtime::RuntimeInternal;
-use crate::runtime::execution::ExecutionError;
 use crate::runtime::execution::execution_loop::ExternalExecutionInterrupt;
 use crate::runtime::execution::execution_loop::interrupts::InterruptProvider;
+use crate::runtime::execution::limits::ExecutionBudget;
+use crate::runtime::execution::{Debugger, ExecutionError};
 use crate::stdlib::boxed::Box;
 use crate::stdlib::rc::Rc;
 use crate::stdlib::vec::Vec;
+use crate::values::core_values::endpoint::Endpoint;
 use crate::values::value_container::ValueContainer;
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 use core::fmt::Debug;
 
 pub struct ExecutionLoopState {
@@ -16,6 +19,11 @@ pub struct ExecutionLoopState {
         dyn Iterator<Item = Result<ExternalExecutionInterrupt, ExecutionError>>,
     >,
     pub dxb_body: Rc<RefCell<Vec<u8>>>,
+    pub(crate) budget: Rc<RefCell<ExecutionBudget>>,
+    /// Byte offset of the current instruction, shared with the RuntimeExecutionState.
+    pub(crate) instruction_offset: Rc<Cell<usize>>,
+    /// Debugger attached to the current execution, shared with the RuntimeExecutionState.
+    pub(crate) debugger: Rc<RefCell<Option<Debugger>>>,
     pub(crate) interrupt_provider: InterruptProvider,
 }
 
@@ -34,6 +42,33 @@ pub struct RuntimeExecutionState {
     pub(crate) slots: RuntimeExecutionSlots,
     pub(crate) runtime_internal: Option<Rc<RuntimeInternal>>,
     pub(crate) source_id: TransceiverId,
+    /// The endpoint that sent the executed DXB, used for permission checks.
+    pub(crate) sender: Option<Endpoint>,
+    /// Resources used by the current execution, shared with the ExecutionLoopState.
+    pub(crate) budget: Rc<RefCell<ExecutionBudget>>,
+    /// Byte offset of the instruction in the DXB body that is currently executed,
+    /// used to locate execution errors in the source code.
+    pub(crate) instruction_offset: Rc<Cell<usize>>,
+    /// Debugger that is called before each instruction, if attached.
+    pub(crate) debugger: Rc<RefCell<Option<Debugger>>>,
+    /// Nominal types declared in this execution context, so that a type
+    /// declaration that is executed again yields the same type reference.
+    pub(crate) nominal_types:
+        HashMap<TypeReference, Rc<RefCell<TypeReference>>>,
+}
+
+impl RuntimeExecutionState {
+    /// Returns the shared reference for a declared nominal type, creating
+    /// it on the first declaration.
+    pub(crate) fn declare_nominal_type(
+        &mut self,
+        type_reference: TypeReference,
+    ) -> Rc<RefCell<TypeReference>> {
+        self.nominal_types
+            .entry(type_reference.clone())
+            .or_insert_with(|| type_reference.as_ref_cell())
+            .clone()
+    }
 }
 
 #[derive(Debug, Default)]
@@ -0,0 +1,193 @@
+use crate::runtime::execution::ExecutionError;
+use crate::stdlib::rc::Rc;
+use crate::stdlib::vec::Vec;
+use crate::utils::time::Time;
+use core::cell::{Cell, RefCell};
+use core::fmt::Display;
+use core::future::{self, Future};
+use core::prelude::rust_2024::*;
+use core::result::Result;
+use core::task::{Poll, Waker};
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+
+/// Resource limits for an execution context.
+/// Limits that are not set are not enforced.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub struct ExecutionLimits {
+    /// maximum number of instructions that are executed
+    pub max_instructions: Option<u64>,
+    /// maximum wall time of the execution
+    pub max_duration: Option<Duration>,
+    /// maximum number of values that are allocated (collection elements and slots)
+    pub max_allocated_values: Option<u64>,
+    /// maximum nesting depth of expressions (e.g. nested collections and statement blocks)
+    pub max_nesting_depth: Option<usize>,
+}
+
+/// A limit of ExecutionLimits that was exceeded during execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionLimit {
+    Instructions(u64),
+    Duration(Duration),
+    AllocatedValues(u64),
+    NestingDepth(usize),
+}
+
+impl Display for ExecutionLimit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ExecutionLimit::Instructions(max) => {
+                core::write!(f, "maximum of {max} instructions")
+            }
+            ExecutionLimit::Duration(max) => {
+                core::write!(f, "maximum duration of {} ms", max.as_millis())
+            }
+            ExecutionLimit::AllocatedValues(max) => {
+                core::write!(f, "maximum of {max} allocated values")
+            }
+            ExecutionLimit::NestingDepth(max) => {
+                core::write!(f, "maximum nesting depth of {max}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct CancellationState {
+    cancelled: Cell<bool>,
+    /// wakers of pending futures returned by CancellationHandle::cancelled
+    wakers: RefCell<Vec<Waker>>,
+}
+
+/// A handle that can be used to cancel a running execution.
+/// All clones of the handle share the same cancellation state.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationHandle(Rc<CancellationState>);
+
+impl CancellationHandle {
+    /// Cancels the execution. The execution is aborted before the next instruction
+    /// or while it is waiting for a remote execution with an ExecutionError::Cancelled.
+    pub fn cancel(&self) {
+        self.0.cancelled.set(true);
+        for waker in self.0.wakers.borrow_mut().drain(..) {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.get()
+    }
+
+    /// Resets the cancellation state so that the handle can be used for following executions.
+    pub fn reset(&self) {
+        self.0.cancelled.set(false);
+    }
+
+    /// Returns a future that resolves as soon as the execution is cancelled.
+    pub fn cancelled(&self) -> impl Future<Output = ()> + '_ {
+        future::poll_fn(|cx| {
+            if self.is_cancelled() {
+                return Poll::Ready(());
+            }
+            let mut wakers = self.0.wakers.borrow_mut();
+            if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+            Poll::Pending
+        })
+    }
+}
+
+/// Tracks the resources used by an execution.
+/// The budget is shared by all executions of an execution context that reuse
+/// the execution loop state, so that the limits cannot be bypassed by
+/// splitting the work across multiple executions.
+#[derive(Debug, Default)]
+pub(crate) struct ExecutionBudget {
+    limits: ExecutionLimits,
+    cancellation: CancellationHandle,
+    instruction_count: u64,
+    allocated_values: u64,
+    /// timestamp in milliseconds after which the execution is aborted
+    deadline: Option<u64>,
+}
+
+impl ExecutionBudget {
+    pub(crate) fn new(
+        limits: ExecutionLimits,
+        cancellation: CancellationHandle,
+    ) -> Self {
+        ExecutionBudget {
+            limits,
+            cancellation,
+            deadline: limits
+                .max_duration
+                .map(|duration| Time::now() + duration.as_millis() as u64),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the remaining wall time until the deadline, if a maximum duration is set.
+    pub(crate) fn remaining_duration(&self) -> Option<Duration> {
+        self.deadline.map(|deadline| {
+            Duration::from_millis(deadline.saturating_sub(Time::now()))
+        })
+    }
+
+    /// Checks the cancellation state and the limits before the next instruction is executed.
+    // the error is returned to the execution loop as is, like all other execution errors
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn next_instruction(
+        &mut self,
+        depth: usize,
+    ) -> Result<(), ExecutionError> {
+        if self.cancellation.is_cancelled() {
+            return Err(ExecutionError::Cancelled);
+        }
+        self.instruction_count += 1;
+        if let Some(max) = self.limits.max_instructions
+            && self.instruction_count > max
+        {
+            return Err(ExecutionError::LimitExceeded(
+                ExecutionLimit::Instructions(max),
+            ));
+        }
+        if let Some(max) = self.limits.max_nesting_depth
+            && depth > max
+        {
+            return Err(ExecutionError::LimitExceeded(
+                ExecutionLimit::NestingDepth(max),
+            ));
+        }
+        if let Some(deadline) = self.deadline
+            && Time::now() > deadline
+        {
+            return Err(ExecutionError::LimitExceeded(
+                ExecutionLimit::Duration(
+                    self.limits.max_duration.unwrap_or_default(),
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Registers newly allocated values.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn allocate_values(
+        &mut self,
+        count: usize,
+    ) -> Result<(), ExecutionError> {
+        self.allocated_values += count as u64;
+        if let Some(max) = self.limits.max_allocated_values
+            && self.allocated_values > max
+        {
+            return Err(ExecutionError::LimitExceeded(
+                ExecutionLimit::AllocatedValues(max),
+            ));
+        }
+        Ok(())
+    }
+}
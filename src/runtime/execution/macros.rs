@@ -22,6 +22,23 @@ macro_rules! interrupt_with_maybe_value {
 }
 pub(crate) use interrupt_with_maybe_value;
 
+/// Yield an interrupt and get the next resolved value or None, or the error
+/// that occurred while handling the interrupt
+/// expecting the next input to be a ResolvedValue or Error variant
+macro_rules! interrupt_with_result {
+    ($input:expr, $arg:expr) => {{
+        use crate::runtime::execution::execution_loop::InterruptResult;
+        use crate::runtime::execution::macros::interrupt;
+
+        let res = interrupt!($input, $arg).unwrap();
+        match res {
+            InterruptResult::ResolvedValue(value) => Ok(value),
+            InterruptResult::Error(err) => Err(err),
+        }
+    }};
+}
+pub(crate) use interrupt_with_result;
+
 /// Yield an interrupt and get the next resolved value
 /// expecting the next input to be a ResolvedValue variant with Some value
 macro_rules! interrupt_with_value {
@@ -4,6 +4,7 @@ use crate::values::value_container::ValueContainer;
 use core::fmt::Display;
 use itertools::Itertools;
 
+#[derive(Debug, Clone)]
 pub struct MemoryDump {
     pub slots: Vec<(u32, Option<ValueContainer>)>,
 }
@@ -9,30 +9,43 @@ use crate::runtime::execution::context::RemoteExecutionContext;
 use crate::runtime::execution::execution_loop::interrupts::{
     ExternalExecutionInterrupt, InterruptResult,
 };
+use crate::runtime::permissions::Capability;
 use crate::stdlib::rc::Rc;
+use crate::task;
 use crate::traits::apply::Apply;
+use crate::values::core_values::endpoint::Endpoint;
 use crate::values::pointer::PointerAddress;
 use crate::values::value_container::ValueContainer;
 use core::prelude::rust_2024::*;
 use core::result::Result;
 use core::unreachable;
+pub use debugger::{
+    Breakpoint, DebugAction, DebugHandler, DebugPause, Debugger, PauseReason,
+};
 pub use errors::*;
 pub use execution_input::ExecutionInput;
 pub use execution_input::ExecutionOptions;
+use futures::future::{self, Either};
+pub use limits::{CancellationHandle, ExecutionLimit, ExecutionLimits};
 pub use memory_dump::*;
 use num_enum::TryFromPrimitive;
+pub use source_map::{SourceLocation, SourceMap, SourceMapEntry};
 
 pub mod context;
+mod debugger;
 mod errors;
 mod execution_input;
 pub mod execution_loop;
+mod limits;
 pub mod macros;
 mod memory_dump;
+mod source_map;
 
 pub fn execute_dxb_sync(
     input: ExecutionInput,
 ) -> Result<Option<ValueContainer>, ExecutionError> {
     let runtime_internal = input.runtime.clone();
+    let sender = input.sender.clone();
     let (interrupt_provider, execution_loop) = input.execution_loop();
 
     for output in execution_loop {
@@ -42,15 +55,16 @@ pub fn execute_dxb_sync(
                 interrupt_provider.provide_result(
                     InterruptResult::ResolvedValue(get_pointer_value(
                         &runtime_internal,
+                        &sender,
                         address,
                     )?),
                 )
             }
             ExternalExecutionInterrupt::ResolveLocalPointer(address) => {
-                // TODO #401: in the future, local pointer addresses should be relative to the block sender, not the local runtime
                 interrupt_provider.provide_result(
                     InterruptResult::ResolvedValue(get_local_pointer_value(
                         &runtime_internal,
+                        &sender,
                         address,
                     )?),
                 );
@@ -63,9 +77,12 @@ pub fn execute_dxb_sync(
                 );
             }
             ExternalExecutionInterrupt::Apply(callee, args) => {
-                let res = handle_apply(&callee, &args)?;
-                interrupt_provider
-                    .provide_result(InterruptResult::ResolvedValue(res));
+                if let Some(runtime) = &runtime_internal {
+                    runtime
+                        .check_capability(&sender, Capability::CallProcedure)?;
+                }
+                let res = handle_apply(&callee, &args);
+                interrupt_provider.provide_result(into_interrupt_result(res)?);
             }
             _ => return Err(ExecutionError::RequiresAsyncExecution),
         }
@@ -76,8 +93,43 @@ pub fn execute_dxb_sync(
 
 pub async fn execute_dxb(
     input: ExecutionInput<'_>,
+) -> Result<Option<ValueContainer>, ExecutionError> {
+    // cancellations are also handled while waiting for remote executions
+    let cancellation = input.cancellation.clone();
+    let execution = execute_dxb_with_duration_limit(input);
+    let cancelled = cancellation.cancelled();
+    futures::pin_mut!(execution);
+    futures::pin_mut!(cancelled);
+    match future::select(execution, cancelled).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(ExecutionError::Cancelled),
+    }
+}
+
+async fn execute_dxb_with_duration_limit(
+    input: ExecutionInput<'_>,
+) -> Result<Option<ValueContainer>, ExecutionError> {
+    // the wall time limit is also enforced while waiting for remote executions
+    if let Some(max_duration) = input.options.limits.max_duration {
+        let remaining_duration =
+            input.remaining_duration().unwrap_or(max_duration);
+        task::timeout(remaining_duration, execute_dxb_without_timeout(input))
+            .await
+            .map_err(|_| {
+                ExecutionError::LimitExceeded(ExecutionLimit::Duration(
+                    max_duration,
+                ))
+            })?
+    } else {
+        execute_dxb_without_timeout(input).await
+    }
+}
+
+async fn execute_dxb_without_timeout(
+    input: ExecutionInput<'_>,
 ) -> Result<Option<ValueContainer>, ExecutionError> {
     let runtime_internal = input.runtime.clone();
+    let sender = input.sender.clone();
     let (interrupt_provider, execution_loop) = input.execution_loop();
 
     for output in execution_loop {
@@ -87,15 +139,16 @@ pub async fn execute_dxb(
                 interrupt_provider.provide_result(
                     InterruptResult::ResolvedValue(get_pointer_value(
                         &runtime_internal,
+                        &sender,
                         address,
                     )?),
                 );
             }
             ExternalExecutionInterrupt::ResolveLocalPointer(address) => {
-                // TODO #402: in the future, local pointer addresses should be relative to the block sender, not the local runtime
                 interrupt_provider.provide_result(
                     InterruptResult::ResolvedValue(get_local_pointer_value(
                         &runtime_internal,
+                        &sender,
                         address,
                     )?),
                 );
@@ -109,6 +162,10 @@ pub async fn execute_dxb(
             }
             ExternalExecutionInterrupt::RemoteExecution(receivers, body) => {
                 if let Some(runtime) = &runtime_internal {
+                    runtime.check_capability(
+                        &sender,
+                        Capability::RemoteExecution,
+                    )?;
                     // assert that receivers is a single endpoint
                     // TODO #230: support advanced receivers
                     let receiver_endpoint = receivers
@@ -126,17 +183,20 @@ pub async fn execute_dxb(
                         &mut remote_execution_context,
                         body,
                     )
-                    .await?;
+                    .await;
                     interrupt_provider
-                        .provide_result(InterruptResult::ResolvedValue(res));
+                        .provide_result(into_interrupt_result(res)?);
                 } else {
                     return Err(ExecutionError::RequiresRuntime);
                 }
             }
             ExternalExecutionInterrupt::Apply(callee, args) => {
-                let res = handle_apply(&callee, &args)?;
-                interrupt_provider
-                    .provide_result(InterruptResult::ResolvedValue(res));
+                if let Some(runtime) = &runtime_internal {
+                    runtime
+                        .check_capability(&sender, Capability::CallProcedure)?;
+                }
+                let res = handle_apply(&callee, &args);
+                interrupt_provider.provide_result(into_interrupt_result(res)?);
             }
         }
     }
@@ -144,6 +204,19 @@ pub async fn execute_dxb(
     unreachable!("Execution loop should always return a result");
 }
 
+/// Converts the result of a handled interrupt into an interrupt result.
+/// Recoverable errors are passed back to the execution loop so that they
+/// can be handled with try, all other errors abort the execution.
+fn into_interrupt_result(
+    result: Result<Option<ValueContainer>, ExecutionError>,
+) -> Result<InterruptResult, ExecutionError> {
+    match result {
+        Ok(value) => Ok(InterruptResult::ResolvedValue(value)),
+        Err(err) if err.is_recoverable() => Ok(InterruptResult::Error(err)),
+        Err(err) => Err(err),
+    }
+}
+
 fn handle_apply(
     callee: &ValueContainer,
     args: &[ValueContainer],
@@ -159,12 +232,14 @@ fn handle_apply(
 
 fn get_pointer_value(
     runtime_internal: &Option<Rc<RuntimeInternal>>,
+    sender: &Option<Endpoint>,
     address: RawFullPointerAddress,
 ) -> Result<Option<ValueContainer>, ExecutionError> {
     if let Some(runtime) = &runtime_internal {
         let memory = runtime.memory.borrow();
         let resolved_address =
             memory.get_pointer_address_from_raw_full_address(address);
+        runtime.check_pointer_read(sender, &resolved_address)?;
         // convert slot to InternalSlot enum
         Ok(memory
             .get_reference(&resolved_address)
@@ -208,16 +283,25 @@ fn get_internal_pointer_value_from_memory(
     }
 }
 
+/// Resolves a local pointer address.
+/// Local addresses are relative to the sender of the executed DXB - if a sender is set,
+/// the address is resolved as a pointer with the sender as origin, otherwise
+/// as a pointer of the local runtime.
 fn get_local_pointer_value(
     runtime_internal: &Option<Rc<RuntimeInternal>>,
+    sender: &Option<Endpoint>,
     address: RawLocalPointerAddress,
 ) -> Result<Option<ValueContainer>, ExecutionError> {
     if let Some(runtime) = &runtime_internal {
-        // convert slot to InternalSlot enum
-        Ok(runtime
-            .memory
-            .borrow()
-            .get_reference(&PointerAddress::Local(address.id))
+        let memory = runtime.memory.borrow();
+        let resolved_address = match sender {
+            Some(sender) => memory
+                .get_pointer_address_from_raw_local_address(address, sender),
+            None => PointerAddress::Local(address.id),
+        };
+        runtime.check_pointer_read(sender, &resolved_address)?;
+        Ok(memory
+            .get_reference(&resolved_address)
             .map(|r| ValueContainer::Reference(r.clone())))
     } else {
         Err(ExecutionError::RequiresRuntime)
@@ -231,11 +315,14 @@ mod tests {
 
     use super::*;
     use crate::compiler::scope::CompilationScope;
-    use crate::compiler::{CompileOptions, compile_script};
+    use crate::compiler::{
+        CompileOptions, compile_script, compile_template_with_source_map,
+    };
     use crate::global::instruction_codes::InstructionCode;
     use crate::logger::init_logger_debug;
     use crate::runtime::execution::context::ExecutionContext;
     use crate::runtime::execution::context::LocalExecutionContext;
+    use crate::runtime::execution::context::ScriptExecutionError;
     use crate::runtime::execution::execution_input::ExecutionOptions;
     use crate::stdlib::string::ToString;
     use crate::stdlib::vec::Vec;
@@ -243,6 +330,7 @@ mod tests {
     use crate::traits::value_eq::ValueEq;
     use crate::values::core_value::CoreValue;
     use crate::values::core_values::decimal::Decimal;
+    use crate::values::core_values::endpoint::Endpoint;
     use crate::values::core_values::integer::Integer;
     use crate::values::core_values::integer::typed_integer::TypedInteger;
     use crate::values::core_values::list::List;
@@ -255,8 +343,14 @@ mod tests {
     ) -> Option<ValueContainer> {
         let (dxb, _) =
             compile_script(datex_script, CompileOptions::default()).unwrap();
-        let context =
-            ExecutionInput::new(&dxb, ExecutionOptions { verbose: true }, None);
+        let context = ExecutionInput::new(
+            &dxb,
+            ExecutionOptions {
+                verbose: true,
+                ..Default::default()
+            },
+            None,
+        );
         execute_dxb_sync(context).unwrap_or_else(|err| {
             core::panic!("Execution failed: {err}");
         })
@@ -314,8 +408,14 @@ mod tests {
     ) -> Result<Option<ValueContainer>, ExecutionError> {
         let (dxb, _) =
             compile_script(datex_script, CompileOptions::default()).unwrap();
-        let context =
-            ExecutionInput::new(&dxb, ExecutionOptions { verbose: true }, None);
+        let context = ExecutionInput::new(
+            &dxb,
+            ExecutionOptions {
+                verbose: true,
+                ..Default::default()
+            },
+            None,
+        );
         execute_dxb_sync(context)
     }
 
@@ -330,7 +430,10 @@ mod tests {
     ) -> Result<Option<ValueContainer>, ExecutionError> {
         let context = ExecutionInput::new(
             dxb_body,
-            ExecutionOptions { verbose: true },
+            ExecutionOptions {
+                verbose: true,
+                ..Default::default()
+            },
             None,
         );
         execute_dxb_sync(context)
@@ -749,4 +852,579 @@ mod tests {
             ],
         )
     }
+
+    #[test]
+    fn local_pointer_relative_to_sender() {
+        use crate::runtime::{Runtime, RuntimeConfig};
+        use crate::values::core_values::endpoint::Endpoint;
+
+        let local_endpoint = Endpoint::new("@local");
+        let sender = Endpoint::new("@sender");
+        let runtime = Runtime::init_native(RuntimeConfig::new_with_endpoint(
+            local_endpoint.clone(),
+        ));
+
+        let local_id = [1, 2, 3, 4, 5];
+        let local_reference = Reference::from(ValueContainer::from(1));
+        local_reference.set_pointer_address(PointerAddress::Local(local_id));
+        let remote_reference = Reference::from(ValueContainer::from(2));
+        remote_reference.set_pointer_address(
+            PointerAddress::Local(local_id)
+                .to_raw_full_address(&sender)
+                .map(|address| PointerAddress::from(&address))
+                .unwrap(),
+        );
+        {
+            let mut memory = runtime.memory().borrow_mut();
+            memory.register_reference(&local_reference);
+            memory.register_reference(&remote_reference);
+        }
+
+        let mut dxb = vec![InstructionCode::GET_LOCAL_REF as u8];
+        dxb.extend_from_slice(&local_id);
+
+        // without sender, the local runtime memory is used
+        let mut context = LocalExecutionContext::new_with_runtime_internal(
+            runtime.internal(),
+            ExecutionMode::Static,
+        );
+        let result = ExecutionContext::Local(context)
+            .execute_dxb_sync(&dxb)
+            .unwrap()
+            .unwrap();
+        assert_eq!(result, ValueContainer::Reference(local_reference.clone()));
+
+        // with a remote sender, the address is resolved relative to the sender
+        context = LocalExecutionContext::new_with_runtime_internal(
+            runtime.internal(),
+            ExecutionMode::Static,
+        );
+        context.set_sender(sender);
+        let result = ExecutionContext::Local(context)
+            .execute_dxb_sync(&dxb)
+            .unwrap()
+            .unwrap();
+        assert_eq!(result, ValueContainer::Reference(remote_reference));
+
+        // with the local endpoint as sender, the local runtime memory is used
+        context = LocalExecutionContext::new_with_runtime_internal(
+            runtime.internal(),
+            ExecutionMode::Static,
+        );
+        context.set_sender(local_endpoint);
+        let result = ExecutionContext::Local(context)
+            .execute_dxb_sync(&dxb)
+            .unwrap()
+            .unwrap();
+        assert_eq!(result, ValueContainer::Reference(local_reference));
+    }
+
+    fn execute_datex_script_with_limits(
+        datex_script: &str,
+        limits: ExecutionLimits,
+    ) -> Result<Option<ValueContainer>, ExecutionError> {
+        let (dxb, _) =
+            compile_script(datex_script, CompileOptions::default()).unwrap();
+        let context = ExecutionInput::new(
+            &dxb,
+            ExecutionOptions {
+                limits,
+                ..Default::default()
+            },
+            None,
+        );
+        execute_dxb_sync(context)
+    }
+
+    #[test]
+    fn instruction_limit() {
+        let limits = ExecutionLimits {
+            max_instructions: Some(3),
+            ..Default::default()
+        };
+        assert!(execute_datex_script_with_limits("1 + 2", limits).is_ok());
+        assert_matches!(
+            execute_datex_script_with_limits("1 + 2 + 3 + 4", limits),
+            Err(ExecutionError::LimitExceeded(ExecutionLimit::Instructions(
+                3
+            )))
+        );
+    }
+
+    #[test]
+    fn nesting_depth_limit() {
+        let limits = ExecutionLimits {
+            max_nesting_depth: Some(2),
+            ..Default::default()
+        };
+        assert!(execute_datex_script_with_limits("[[1]]", limits).is_ok());
+        assert_matches!(
+            execute_datex_script_with_limits("[[[[1]]]]", limits),
+            Err(ExecutionError::LimitExceeded(ExecutionLimit::NestingDepth(
+                2
+            )))
+        );
+    }
+
+    #[test]
+    fn allocated_values_limit() {
+        let limits = ExecutionLimits {
+            max_allocated_values: Some(4),
+            ..Default::default()
+        };
+        assert!(
+            execute_datex_script_with_limits("[1, 2, 3, 4]", limits).is_ok()
+        );
+        assert_matches!(
+            execute_datex_script_with_limits("[1, 2, 3, 4, 5]", limits),
+            Err(ExecutionError::LimitExceeded(
+                ExecutionLimit::AllocatedValues(4)
+            ))
+        );
+        assert_matches!(
+            execute_datex_script_with_limits(
+                "const a = [1, 2]; const b = [3, 4]; a",
+                limits
+            ),
+            Err(ExecutionError::LimitExceeded(
+                ExecutionLimit::AllocatedValues(4)
+            ))
+        );
+    }
+
+    #[test]
+    fn limits_apply_to_all_executions_of_a_context() {
+        use crate::runtime::execution::context::LocalExecutionContext;
+
+        let mut local_context =
+            LocalExecutionContext::new(ExecutionMode::unbounded());
+        local_context.execution_options.limits = ExecutionLimits {
+            max_allocated_values: Some(6),
+            ..Default::default()
+        };
+        let mut context = ExecutionContext::Local(local_context);
+
+        // each execution is within the limit, but both together exceed it
+        let dxb = context.compile("const a = [1, 2, 3, 4]; a", &[]).unwrap();
+        assert!(context.execute_dxb_sync(&dxb).is_ok());
+        let dxb = context.compile("const b = [5, 6, 7, 8]; b", &[]).unwrap();
+        assert_matches!(
+            context.execute_dxb_sync(&dxb),
+            Err(ExecutionError::LimitExceeded(
+                ExecutionLimit::AllocatedValues(6)
+            ))
+        );
+    }
+
+    #[test]
+    fn cancelled_execution() {
+        let (dxb, _) =
+            compile_script("1 + 2", CompileOptions::default()).unwrap();
+        let mut context = ExecutionContext::local();
+        let cancellation = context.cancellation_handle().unwrap();
+        cancellation.cancel();
+        assert_matches!(
+            context.execute_dxb_sync(&dxb),
+            Err(ExecutionError::Cancelled)
+        );
+
+        // the context can be used again after resetting the handle
+        cancellation.reset();
+        assert_eq!(
+            context.execute_dxb_sync(&dxb).unwrap(),
+            Some(Integer::from(3).into())
+        );
+    }
+
+    #[test]
+    fn error_source_location() {
+        let script = "const a = 1; a + true";
+        let (dxb, source_map, _) = compile_template_with_source_map(
+            script,
+            &[],
+            None,
+            CompileOptions::default(),
+        )
+        .unwrap();
+        let mut input =
+            ExecutionInput::new(&dxb, ExecutionOptions::default(), None);
+        input.source_map = Some(Rc::new(source_map));
+        let error_location = input.error_location.clone();
+        let error = execute_dxb_sync(input).unwrap_err();
+        assert_matches!(error, ExecutionError::ValueError(_));
+        assert_eq!(
+            &script[error_location.borrow().as_ref().unwrap().span.clone()],
+            "a + true"
+        );
+
+        // without source map, errors are not located
+        let input =
+            ExecutionInput::new(&dxb, ExecutionOptions::default(), None);
+        let error_location = input.error_location.clone();
+        assert!(execute_dxb_sync(input).is_err());
+        assert_eq!(*error_location.borrow(), None);
+    }
+
+    #[test]
+    fn script_error_report() {
+        let script = "const a = 1;\na + true";
+        let error = ExecutionContext::local()
+            .execute_sync(script, &[])
+            .unwrap_err();
+        // the location is reported next to the original execution error
+        assert_matches!(
+            error,
+            ScriptExecutionError::ExecutionError(
+                ExecutionError::ValueError(_),
+                Some(_)
+            )
+        );
+        let report = error.render_report("main.dx", script);
+        assert!(report.contains("main.dx:2:1"), "{report}");
+        assert!(report.contains("a + true"), "{report}");
+    }
+
+    #[test]
+    fn typed_fixed_size_list_round_trip() {
+        use crate::core_compiler::value_compiler::compile_value_container;
+        use crate::types::definition::TypeDefinition;
+        use crate::values::value::Value;
+
+        let item_type = TypeDefinition::Reference(get_core_lib_type_reference(
+            CoreLibPointerId::Integer(None),
+        ));
+        let mut value = Value::from(datex_list![1, 2]);
+        value.actual_type =
+            Box::new(TypeDefinition::fixed_size_list(item_type, 2));
+        let dxb = compile_value_container(&ValueContainer::from(value.clone()));
+
+        let result = execute_dxb_sync(ExecutionInput::new(
+            &dxb,
+            ExecutionOptions::default(),
+            None,
+        ))
+        .unwrap()
+        .unwrap();
+        let ValueContainer::Value(result) = result else {
+            core::panic!("Expected a value");
+        };
+        assert_eq!(result.actual_type, value.actual_type);
+    }
+
+    #[test]
+    fn nominal_type_constructor() {
+        use crate::types::definition::TypeDefinition;
+
+        let result = execute_datex_script_debug_with_result(
+            "type User = {x: integer}; User({x: 1})",
+        );
+        let ValueContainer::Value(value) = &result else {
+            core::panic!("Expected a value");
+        };
+        let TypeDefinition::Reference(reference) = value.actual_type.as_ref()
+        else {
+            core::panic!("Expected a nominal type");
+        };
+        assert_eq!(reference.borrow().to_string(), "User");
+
+        // nominal values are only structurally equal to their plain value
+        let result = execute_datex_script_debug_with_result(
+            "type User = {x: integer}; [User({x: 1}) === {x: 1}, User({x: 1}) == {x: 1}]",
+        );
+        assert_eq!(result, ValueContainer::from(datex_list![false, true]));
+    }
+
+    #[test]
+    fn nominal_type_declared_again() {
+        use crate::references::type_reference::TypeReference;
+        use crate::runtime::execution::execution_loop::state::RuntimeExecutionState;
+        use crate::stdlib::rc::Rc;
+        use crate::values::core_values::r#type::Type;
+
+        let mut state = RuntimeExecutionState::default();
+        let user = TypeReference::nominal(Type::integer(), "User", None);
+        let first = state.declare_nominal_type(user.clone());
+        let second = state.declare_nominal_type(user);
+        assert!(Rc::ptr_eq(&first, &second));
+
+        let other = state.declare_nominal_type(TypeReference::nominal(
+            Type::integer(),
+            "Other",
+            None,
+        ));
+        assert!(!Rc::ptr_eq(&first, &other));
+    }
+
+    #[test]
+    fn nominal_type_constructor_invalid_argument() {
+        let result = execute_datex_script_debug_with_error(
+            "type User = {x: integer}; User({x: \"text\"})",
+        );
+        assert_matches!(result, Err(ExecutionError::TypeError(_)));
+    }
+
+    #[test]
+    fn generic_type_instance() {
+        use crate::types::collection_type_definition::CollectionTypeDefinition;
+        use crate::types::definition::TypeDefinition;
+
+        let result = execute_datex_script_debug_with_result(
+            "type Box<T> = {value: T}; type IntBox = Box<integer>; 1",
+        );
+        assert_eq!(result, Integer::from(1i8).into());
+
+        for script in [
+            "type Box<T> = {value: T}; {value: 1} as Box<integer>",
+            "type Box<T> = {value: T}; Box<integer>({value: 1})",
+        ] {
+            let result = execute_datex_script_debug_with_result(script);
+            let ValueContainer::Value(value) = &result else {
+                core::panic!("Expected a value");
+            };
+            let TypeDefinition::Collection(
+                CollectionTypeDefinition::Instance { base, arguments },
+            ) = value.actual_type.as_ref()
+            else {
+                core::panic!("Expected an instance type");
+            };
+            assert_eq!(base.to_string(), "Box");
+            assert_eq!(arguments.len(), 1);
+            assert_eq!(arguments[0].to_string(), "integer");
+        }
+    }
+
+    #[test]
+    fn generic_type_instance_invalid_argument() {
+        let result = execute_datex_script_debug_with_error(
+            r#"type Box<T> = {value: T}; {value: "x"} as Box<integer>"#,
+        );
+        assert_matches!(result, Err(ExecutionError::InvalidTypeCast { .. }));
+        let result = execute_datex_script_debug_with_error(
+            r#"type Box<T> = {value: T}; Box<integer>({value: "x"})"#,
+        );
+        assert_matches!(result, Err(ExecutionError::InvalidTypeCast { .. }));
+    }
+
+    #[test]
+    fn interface_implements() {
+        let result = execute_datex_script_debug_with_result(
+            r#"
+            interface Named = {name: text};
+            type User = {name: text, age: integer} + Named;
+            type Pet = {name: text};
+            [
+                User({name: "a", age: 1}) implements Named,
+                Pet({name: "b"}) implements Named,
+                {name: "c"} implements Named
+            ]
+            "#,
+        );
+        assert_eq!(
+            result,
+            ValueContainer::from(datex_list![true, false, false])
+        );
+    }
+
+    #[test]
+    fn type_cast() {
+        let result = execute_datex_script_debug_with_result("42 as text");
+        assert_eq!(result, ValueContainer::from("42"));
+
+        let result =
+            execute_datex_script_debug_with_result("\"42\" as integer/u8");
+        assert_eq!(result, ValueContainer::from(TypedInteger::U8(42)));
+
+        let result = execute_datex_script_debug_with_result("3.7 as integer");
+        assert_eq!(result, ValueContainer::from(Integer::from(3)));
+
+        let result = execute_datex_script_debug_with_result("0 as boolean");
+        assert_eq!(result, ValueContainer::from(false));
+
+        let result =
+            execute_datex_script_debug_with_result("\"@example\" as endpoint");
+        assert_eq!(result, ValueContainer::from(Endpoint::new("@example")));
+    }
+
+    #[test]
+    fn optional_chaining() {
+        let result = execute_datex_script_debug_with_result("null?.a");
+        assert_eq!(result, ValueContainer::from(CoreValue::Null));
+
+        let result = execute_datex_script_debug_with_result("{a: 1}?.a");
+        assert_eq!(result, Integer::from(1i8).into());
+
+        let result =
+            execute_datex_script_debug_with_result("{a: null}.a?.b?.c");
+        assert_eq!(result, ValueContainer::from(CoreValue::Null));
+
+        // a null base short-circuits the rest of the chain
+        let result = execute_datex_script_debug_with_result("null?.a.b");
+        assert_eq!(result, ValueContainer::from(CoreValue::Null));
+    }
+
+    #[test]
+    fn null_coalescing() {
+        let result = execute_datex_script_debug_with_result("null ?? 2");
+        assert_eq!(result, ValueContainer::from(Integer::from(2)));
+
+        let result = execute_datex_script_debug_with_result("1 ?? 2");
+        assert_eq!(result, Integer::from(1i8).into());
+
+        let result =
+            execute_datex_script_debug_with_result("null ?? null ?? 3");
+        assert_eq!(result, ValueContainer::from(Integer::from(3)));
+    }
+
+    #[test]
+    fn saturating_integer_cast() {
+        let result = execute_datex_script_debug_with_result(
+            "300 as saturating integer/u8",
+        );
+        assert_eq!(result, ValueContainer::from(TypedInteger::U8(255)));
+
+        let result = execute_datex_script_debug_with_result(
+            "-300 as saturating integer/i8",
+        );
+        assert_eq!(result, ValueContainer::from(TypedInteger::I8(-128)));
+    }
+
+    #[test]
+    fn failed_narrowing_cast() {
+        let result = execute_datex_script_debug_with_error("300 as integer/u8");
+        assert_matches!(result, Err(ExecutionError::InvalidTypeCast { .. }));
+    }
+
+    #[test]
+    fn error_value() {
+        let result =
+            execute_datex_script_debug_with_result(r#"error("Not found")"#);
+        assert!(result.to_value().borrow().is_error());
+        assert_eq!(
+            result.to_value().borrow().error_message(),
+            Some("Not found".to_string())
+        );
+
+        let result = execute_datex_script_debug_with_result(
+            r#"error({message: "Not found", payload: 404}).payload"#,
+        );
+        assert_eq!(result, ValueContainer::from(Integer::from(404)));
+    }
+
+    #[test]
+    fn try_recovers_yeet() {
+        let result = execute_datex_script_debug_with_result(
+            r#"(try yeet "Not found").message"#,
+        );
+        assert_eq!(result, ValueContainer::from("Not found"));
+
+        // the remaining instructions of the try body are skipped
+        let result = execute_datex_script_debug_with_result(
+            r#"[1, try (yeet error({message: "a", payload: 2}); [3, 4]), 5]"#,
+        );
+        let list = result.to_value().borrow().inner.clone();
+        let CoreValue::List(list) = list else {
+            core::panic!("Expected list");
+        };
+        assert_eq!(
+            list.get(0).unwrap(),
+            &ValueContainer::from(Integer::from(1))
+        );
+        assert!(list.get(1).unwrap().to_value().borrow().is_error());
+        assert_eq!(
+            list.get(2).unwrap(),
+            &ValueContainer::from(Integer::from(5))
+        );
+
+        // values are passed through if no error occurs
+        let result = execute_datex_script_debug_with_result("try (1 + 2)");
+        assert_eq!(result, ValueContainer::from(Integer::from(3)));
+    }
+
+    #[test]
+    fn try_recovers_execution_errors() {
+        let result = execute_datex_script_debug_with_result(
+            "(try 300 as integer/u8).message",
+        );
+        assert_eq!(
+            result,
+            ValueContainer::from("Cannot cast 300 to integer/u8")
+        );
+
+        // errors of applied callables are recovered as well
+        let result = execute_datex_script_debug_with_result(
+            r#"type User = {x: integer}; try User({x: "text"})"#,
+        );
+        assert!(result.to_value().borrow().is_error());
+    }
+
+    #[test]
+    fn nested_try() {
+        let result = execute_datex_script_debug_with_result(
+            r#"(try [try yeet "inner", yeet "outer"]).message"#,
+        );
+        assert_eq!(result, ValueContainer::from("outer"));
+    }
+
+    #[test]
+    fn try_recovers_yeet_from_callable() {
+        use crate::runtime::{Runtime, RuntimeConfig};
+        use crate::values::core_values::callable::{
+            CallableBody, CallableKind, CallableSignature,
+        };
+        use crate::values::core_values::endpoint::Endpoint;
+        use crate::values::core_values::r#type::Type;
+        use crate::values::value::Value;
+
+        let runtime = Runtime::init_native(RuntimeConfig::new_with_endpoint(
+            Endpoint::new("@local"),
+        ));
+        // a native function that yeets its argument as the error payload
+        let callable = Reference::from(ValueContainer::from(Value::callable(
+            Some("fail".to_string()),
+            CallableSignature {
+                kind: CallableKind::Function,
+                generic_parameters: vec![],
+                parameter_types: vec![(
+                    Some("payload".to_string()),
+                    Type::unknown(),
+                )],
+                rest_parameter_type: None,
+                return_type: None,
+                yeet_type: None,
+            },
+            CallableBody::Native(|args| {
+                Err(ExecutionError::Yeeted(ValueContainer::from(Value::error(
+                    "Failed",
+                    args[0].clone(),
+                ))))
+            }),
+        )));
+        callable.set_pointer_address(PointerAddress::Local([1, 2, 3, 4, 5]));
+        runtime.memory().borrow_mut().register_reference(&callable);
+
+        let result = runtime
+            .execute_sync("(try $0102030405(42)).payload", &[], None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(result, ValueContainer::from(Integer::from(42)));
+
+        // without try, the yeeted error is returned by the execution
+        let result = runtime.execute_sync("$0102030405(42); 1", &[], None);
+        assert_matches!(
+            result,
+            Err(ScriptExecutionError::ExecutionError(
+                ExecutionError::Yeeted(_),
+                _
+            ))
+        );
+    }
+
+    #[test]
+    fn uncaught_yeet() {
+        let result =
+            execute_datex_script_debug_with_error(r#"yeet "Not found""#);
+        let err = result.unwrap_err();
+        assert_matches!(err, ExecutionError::Yeeted(_));
+        assert_eq!(err.to_string(), "Uncaught error: Not found");
+    }
 }
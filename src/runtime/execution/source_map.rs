@@ -0,0 +1,103 @@
+use crate::stdlib::vec::Vec;
+use core::fmt::Display;
+use core::ops::Range;
+use url::Url;
+
+/// A bytecode range in a compiled DXB body and the source span it was compiled from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceMapEntry {
+    pub bytecode: Range<usize>,
+    pub span: Range<usize>,
+}
+
+/// Side table that maps byte offsets in a compiled DXB body to spans in the source code.
+/// Source maps are optionally emitted by the compiler and used to report the source
+/// location of execution errors.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    /// URL of the compiled source file, if known
+    pub source: Option<Url>,
+    entries: Vec<SourceMapEntry>,
+}
+
+impl SourceMap {
+    pub fn new(source: Option<Url>) -> Self {
+        SourceMap {
+            source,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Adds a mapping for the given bytecode range. Empty bytecode ranges are ignored.
+    pub fn add(&mut self, bytecode: Range<usize>, span: Range<usize>) {
+        if !bytecode.is_empty() {
+            self.entries.push(SourceMapEntry { bytecode, span });
+        }
+    }
+
+    pub fn entries(&self) -> &[SourceMapEntry] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the source location of the innermost expression whose bytecode
+    /// contains the given byte offset
+    pub fn location(&self, offset: usize) -> Option<SourceLocation> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.bytecode.contains(&offset))
+            .min_by_key(|entry| entry.bytecode.len())
+            .map(|entry| SourceLocation {
+                source: self.source.clone(),
+                span: entry.span.clone(),
+            })
+    }
+}
+
+/// A location in the source code of an executed DXB program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub source: Option<Url>,
+    pub span: Range<usize>,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(source) = &self.source {
+            core::write!(f, "{source}:")?;
+        }
+        core::write!(f, "{}..{}", self.span.start, self.span.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn innermost_location() {
+        let mut source_map = SourceMap::new(None);
+        source_map.add(0..10, 0..20);
+        source_map.add(2..6, 5..10);
+        source_map.add(6..6, 11..12);
+
+        assert_eq!(source_map.entries().len(), 2);
+        assert_eq!(source_map.location(3).unwrap().span, 5..10);
+        assert_eq!(source_map.location(7).unwrap().span, 0..20);
+        assert_eq!(source_map.location(10), None);
+    }
+
+    #[test]
+    fn location_display() {
+        let mut source_map =
+            SourceMap::new(Some(Url::parse("file:///main.dx").unwrap()));
+        source_map.add(0..4, 3..8);
+        assert_eq!(
+            source_map.location(0).unwrap().to_string(),
+            "file:///main.dx:3..8"
+        );
+    }
+}
@@ -1,16 +1,16 @@
 use crate::collections::HashMap;
-use crate::global::protocol_structures::instructions::RawFullPointerAddress;
+use crate::global::protocol_structures::instructions::{
+    RawFullPointerAddress, RawLocalPointerAddress,
+};
 use crate::libs::core::{CoreLibPointerId, load_core_lib};
 use crate::references::reference::Reference;
 use crate::references::type_reference::TypeReference;
 use crate::references::value_reference::ValueReference;
 use crate::stdlib::rc::Rc;
-use crate::stdlib::vec::Vec;
 use crate::types::error::IllegalTypeError;
 use crate::utils::time::Time;
 use crate::values::core_values::endpoint::Endpoint;
 use crate::values::pointer::PointerAddress;
-use binrw::io::Cursor;
 use core::cell::RefCell;
 use core::prelude::rust_2024::*;
 use core::result::Result;
@@ -133,19 +133,27 @@ impl Memory {
         &self,
         raw_address: RawFullPointerAddress,
     ) -> PointerAddress {
-        if let Ok(endpoint) = raw_address.endpoint()
-            && endpoint == self.local_endpoint
-        {
-            // TODO #639: check if it makes sense to take the last 5 bytes only here
-            let last_bytes = &raw_address.id[raw_address.id.len() - 5..];
-            PointerAddress::Local(last_bytes.try_into().unwrap())
-        } else {
-            // combine raw_address.endpoint and raw_address.id to [u8; 26]
-            let writer = Cursor::new(Vec::new());
-            let mut bytes = writer.into_inner();
-            bytes.extend_from_slice(&raw_address.id);
-            PointerAddress::Remote(<[u8; 26]>::try_from(bytes).unwrap())
-        }
+        PointerAddress::from_raw_full_address(
+            &raw_address,
+            &self.local_endpoint,
+        )
+    }
+
+    /// Takes a RawLocalPointerAddress that is relative to the given origin endpoint
+    /// (e.g. the sender of a block) and converts it to a PointerAddress.
+    /// If the origin is the local endpoint, a PointerAddress::Local is returned,
+    /// otherwise a PointerAddress::Remote with the origin endpoint as prefix.
+    pub fn get_pointer_address_from_raw_local_address(
+        &self,
+        raw_address: RawLocalPointerAddress,
+        origin: &Endpoint,
+    ) -> PointerAddress {
+        let raw_full_address = PointerAddress::Local(raw_address.id)
+            .to_raw_full_address(origin)
+            .expect(
+                "local addresses can always be converted to full addresses",
+            );
+        self.get_pointer_address_from_raw_full_address(raw_full_address)
     }
 
     /// Creates a new unique local PointerAddress.
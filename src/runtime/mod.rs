@@ -2,17 +2,21 @@ use crate::collections::HashMap;
 #[cfg(all(feature = "native_crypto", feature = "std"))]
 use crate::crypto::crypto_native::CryptoNative;
 use crate::global::dxb_block::{
-    DXBBlock, IncomingEndpointContextSectionId, IncomingSection,
-    OutgoingContextId,
+    DXBBlock, IncomingEndpointContextId, IncomingSection, OutgoingContextId,
+};
+use crate::global::protocol_structures::block_header::{
+    BlockHeader, BlockType, FlagsAndTimestamp,
 };
-use crate::global::protocol_structures::block_header::BlockHeader;
 use crate::global::protocol_structures::encrypted_header::EncryptedHeader;
+use crate::global::protocol_structures::instructions::RawFullPointerAddress;
 use crate::global::protocol_structures::routing_header::RoutingHeader;
 use crate::logger::{init_logger, init_logger_debug};
 use crate::network::com_hub::{ComHub, InterfacePriority, ResponseOptions};
 use crate::network::com_interfaces::com_interface::ComInterfaceFactory;
-use crate::runtime::execution::ExecutionError;
 use crate::runtime::execution::context::ExecutionMode;
+use crate::runtime::execution::{
+    CancellationHandle, ExecutionError, ExecutionLimits,
+};
 use crate::serde::error::SerializationError;
 use crate::serde::serializer::to_value_container;
 use crate::stdlib::borrow::ToOwned;
@@ -26,6 +30,7 @@ use crate::stdlib::vec::Vec;
 use crate::stdlib::{cell::RefCell, rc::Rc};
 use crate::time::Instant;
 use crate::utils::time::Time;
+use crate::values::core_value::CoreValue;
 use crate::values::core_values::endpoint::Endpoint;
 use crate::values::value_container::ValueContainer;
 use core::fmt::Debug;
@@ -34,7 +39,8 @@ use core::result::Result;
 use core::slice;
 use core::unreachable;
 use execution::context::{
-    ExecutionContext, RemoteExecutionContext, ScriptExecutionError,
+    ExecutionContext, LocalExecutionContext, RemoteExecutionContext,
+    ScriptExecutionError,
 };
 use futures::channel::oneshot::Sender;
 use global_context::{GlobalContext, set_global_context};
@@ -45,12 +51,17 @@ pub mod dif_interface;
 pub mod execution;
 pub mod global_context;
 pub mod memory;
+pub mod permissions;
 mod update_loop;
 
 use self::memory::Memory;
+use self::permissions::{AccessPolicy, check_data_only_dxb};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// default time in milliseconds after which an idle incoming execution context is disposed
+pub const DEFAULT_EXECUTION_CONTEXT_TIMEOUT: u64 = 60_000;
+
 #[derive(Clone)]
 pub struct Runtime {
     pub version: String,
@@ -105,12 +116,26 @@ pub struct RuntimeInternal {
     update_loop_running: RefCell<bool>,
     update_loop_stop_sender: RefCell<Option<Sender<()>>>,
 
-    /// active execution contexts, stored by context_id
+    /// active execution contexts for incoming sections, stored by endpoint context id
     pub execution_contexts:
-        RefCell<HashMap<IncomingEndpointContextSectionId, ExecutionContext>>,
+        RefCell<HashMap<IncomingEndpointContextId, IncomingExecutionContext>>,
+    /// access policy for executions triggered by remote endpoints
+    pub access_policy: RefCell<AccessPolicy>,
+    /// cancellation handles of incoming sections that are currently executed
+    pub running_executions:
+        RefCell<HashMap<IncomingEndpointContextId, CancellationHandle>>,
     pub async_context: AsyncContext,
 }
 
+/// An execution context that is used for incoming sections from a remote endpoint
+#[derive(Debug)]
+pub struct IncomingExecutionContext {
+    pub context: ExecutionContext,
+    /// timestamp of the last execution in this context
+    /// when the configured timeout has passed since the timestamp, the context is disposed
+    pub last_used_timestamp: u64,
+}
+
 macro_rules! get_execution_context {
     // take context and self_rc as parameters
     ($self_rc:expr, $execution_context:expr) => {
@@ -139,6 +164,8 @@ impl RuntimeInternal {
             update_loop_running: RefCell::new(false),
             update_loop_stop_sender: RefCell::new(None),
             execution_contexts: RefCell::new(HashMap::new()),
+            access_policy: RefCell::new(AccessPolicy::default()),
+            running_executions: RefCell::new(HashMap::new()),
             async_context,
         }
     }
@@ -252,19 +279,141 @@ impl RuntimeInternal {
     /// To reuse the context later, the caller must store it back in the map after use.
     fn take_execution_context(
         self_rc: Rc<RuntimeInternal>,
-        context_id: &IncomingEndpointContextSectionId,
+        context_id: &IncomingEndpointContextId,
     ) -> ExecutionContext {
         let mut execution_contexts = self_rc.execution_contexts.borrow_mut();
         // get execution context by context_id or create a new one if it doesn't exist
         let execution_context = execution_contexts.remove(context_id);
         if let Some(context) = execution_context {
-            context
+            context.context
         } else {
-            ExecutionContext::local_with_runtime_internal(
-                self_rc.clone(),
+            Self::new_incoming_execution_context(self_rc.clone(), context_id)
+        }
+    }
+
+    /// Creates a new execution context for incoming sections from the given context
+    fn new_incoming_execution_context(
+        self_rc: Rc<RuntimeInternal>,
+        context_id: &IncomingEndpointContextId,
+    ) -> ExecutionContext {
+        let limits = self_rc.config.execution_limits.unwrap_or_default();
+        // local pointer addresses in the incoming blocks are relative to the sender
+        let mut local_context =
+            LocalExecutionContext::new_with_runtime_internal(
+                self_rc,
                 ExecutionMode::unbounded(),
-            )
+            );
+        local_context.set_sender(context_id.sender.clone());
+        local_context.execution_options.limits = limits;
+        ExecutionContext::Local(local_context)
+    }
+
+    /// Stores an execution context for incoming sections so that it can be
+    /// reused by following sections of the same context.
+    fn store_execution_context(
+        &self,
+        context_id: IncomingEndpointContextId,
+        context: ExecutionContext,
+    ) {
+        self.execution_contexts.borrow_mut().insert(
+            context_id,
+            IncomingExecutionContext {
+                context,
+                last_used_timestamp: Time::now(),
+            },
+        );
+    }
+
+    /// Disposes the execution context with the given id, releasing all slots
+    /// and values held by the context.
+    /// Late blocks for the disposed context are rejected.
+    /// Returns true if an active execution context was disposed.
+    pub fn dispose_execution_context(
+        &self,
+        context_id: &IncomingEndpointContextId,
+    ) -> bool {
+        let context = self.execution_contexts.borrow_mut().remove(context_id);
+        self.com_hub.block_handler.dispose_scope_context(context_id);
+        info!("Disposed execution context {context_id:?}");
+        context.is_some()
+    }
+
+    /// Cancels the running execution of the execution context with the given id
+    /// and disposes the context.
+    /// Returns true if a running execution was cancelled or an active execution context was disposed.
+    pub fn cancel_execution_context(
+        &self,
+        context_id: &IncomingEndpointContextId,
+    ) -> bool {
+        let cancelled = if let Some(cancellation) =
+            self.running_executions.borrow().get(context_id)
+        {
+            cancellation.cancel();
+            true
+        } else {
+            false
+        };
+        info!("Cancelled execution context {context_id:?}");
+        self.dispose_execution_context(context_id) || cancelled
+    }
+
+    /// Requests the remote endpoint to cancel the execution of the given remote execution context.
+    /// The pending remote execution returns with an error as soon as the remote endpoint has cancelled
+    /// the execution.
+    pub async fn cancel_remote_execution(
+        self_rc: Rc<RuntimeInternal>,
+        remote_execution_context: &RemoteExecutionContext,
+    ) -> Result<(), Vec<Endpoint>> {
+        // nothing was executed in the context yet
+        let Some(context_id) = remote_execution_context.context_id else {
+            return Ok(());
+        };
+        let routing_header: RoutingHeader = RoutingHeader::default()
+            .with_sender(self_rc.endpoint.clone())
+            .to_owned();
+        let block_header = BlockHeader {
+            context_id,
+            section_index: remote_execution_context.next_section_index,
+            block_number: remote_execution_context.next_block_number,
+            flags_and_timestamp: FlagsAndTimestamp::default()
+                .with_block_type(BlockType::Cancel),
+            ..BlockHeader::default()
+        };
+        let mut block = DXBBlock::new(
+            routing_header,
+            block_header,
+            EncryptedHeader::default(),
+            vec![],
+        );
+        block
+            .set_receivers(slice::from_ref(&remote_execution_context.endpoint));
+        self_rc.com_hub.send_own_block(block).await
+    }
+
+    /// Disposes all execution contexts that have not been used
+    /// within the configured execution context timeout.
+    fn dispose_idle_execution_contexts(&self) {
+        let timeout = self
+            .config
+            .execution_context_timeout
+            .unwrap_or(DEFAULT_EXECUTION_CONTEXT_TIMEOUT);
+        let now = Time::now();
+        let idle_contexts = self
+            .execution_contexts
+            .borrow()
+            .iter()
+            .filter(|(_, context)| {
+                now.saturating_sub(context.last_used_timestamp) > timeout
+            })
+            .map(|(context_id, _)| context_id.clone())
+            .collect::<Vec<_>>();
+        for context_id in idle_contexts {
+            self.dispose_execution_context(&context_id);
         }
+        // also dispose the corresponding block scopes in the block handler
+        self.com_hub
+            .block_handler
+            .dispose_idle_scope_contexts(timeout);
     }
 
     pub async fn execute_remote(
@@ -285,10 +434,24 @@ impl RuntimeInternal {
                 remote_execution_context.context_id.unwrap()
             });
 
+        // an unbounded context is kept alive on the remote endpoint, following
+        // executions are sent as new sections in the same context
+        let is_unbounded =
+            remote_execution_context.execution_mode.is_unbounded();
         let block_header = BlockHeader {
             context_id,
+            section_index: remote_execution_context.next_section_index,
+            block_number: remote_execution_context.next_block_number,
+            flags_and_timestamp: FlagsAndTimestamp::default()
+                .with_is_end_of_context(!is_unbounded),
             ..BlockHeader::default()
         };
+        if is_unbounded {
+            remote_execution_context.next_section_index =
+                remote_execution_context.next_section_index.wrapping_add(1);
+            remote_execution_context.next_block_number =
+                remote_execution_context.next_block_number.wrapping_add(1);
+        }
         let encrypted_header = EncryptedHeader::default();
 
         let mut block =
@@ -303,9 +466,31 @@ impl RuntimeInternal {
             .await
             .remove(0)?;
         let incoming_section = response.take_incoming_section();
-        RuntimeInternal::execute_incoming_section(self_rc, incoming_section)
+        RuntimeInternal::execute_response_section(self_rc, incoming_section)
             .await
-            .0
+    }
+
+    /// Executes a response section in a new execution context that is dropped after the execution
+    async fn execute_response_section(
+        self_rc: Rc<RuntimeInternal>,
+        mut incoming_section: IncomingSection,
+    ) -> Result<Option<ValueContainer>, ExecutionError> {
+        let mut context = Self::new_incoming_execution_context(
+            self_rc.clone(),
+            &incoming_section
+                .get_section_context_id()
+                .endpoint_context_id,
+        );
+        let mut result = None;
+        while let Some(block) = incoming_section.next().await {
+            result = RuntimeInternal::execute_dxb_block_local(
+                self_rc.clone(),
+                block,
+                Some(&mut context),
+            )
+            .await?;
+        }
+        Ok(result)
     }
 
     async fn execute_incoming_section(
@@ -318,13 +503,45 @@ impl RuntimeInternal {
     ) {
         let section_context_id =
             incoming_section.get_section_context_id().clone();
+        let endpoint_context_id = section_context_id.endpoint_context_id;
+
+        // a section that does not start a new context belongs to an already disposed context
+        if section_context_id.section_index != 0
+            && self_rc
+                .com_hub
+                .block_handler
+                .is_scope_context_disposed(&endpoint_context_id)
+            && !self_rc
+                .execution_contexts
+                .borrow()
+                .contains_key(&endpoint_context_id)
+        {
+            error!(
+                "Received late section for disposed execution context {endpoint_context_id:?}"
+            );
+            return (
+                Err(ExecutionError::ExecutionContextDisposed),
+                endpoint_context_id.sender,
+                endpoint_context_id.context_id,
+            );
+        }
+
         let mut context =
-            Self::take_execution_context(self_rc.clone(), &section_context_id);
+            Self::take_execution_context(self_rc.clone(), &endpoint_context_id);
         info!(
             "Executing incoming section with index: {}",
             incoming_section.get_section_index()
         );
 
+        // register the execution so that it can be cancelled by the sender
+        let cancellation = context
+            .cancellation_handle()
+            .expect("Incoming execution context must be local");
+        self_rc
+            .running_executions
+            .borrow_mut()
+            .insert(endpoint_context_id.clone(), cancellation.clone());
+
         let mut result = None;
         let mut last_block = None;
 
@@ -339,6 +556,11 @@ impl RuntimeInternal {
                 )
                 .await;
                 if let Err(err) = res {
+                    self_rc
+                        .running_executions
+                        .borrow_mut()
+                        .remove(&endpoint_context_id);
+                    self_rc.dispose_execution_context(&endpoint_context_id);
                     return (
                         Err(err),
                         block.get_sender().clone(),
@@ -352,6 +574,11 @@ impl RuntimeInternal {
             }
         }
 
+        self_rc
+            .running_executions
+            .borrow_mut()
+            .remove(&endpoint_context_id);
+
         if last_block.is_none() {
             unreachable!("Incoming section must contain at least one block");
         }
@@ -359,16 +586,58 @@ impl RuntimeInternal {
         let sender_endpoint = last_block.get_sender().clone();
         let context_id = last_block.block_header.context_id;
 
-        // insert the context back into the map for future use
-        // TODO #638: is this needed or can we drop the context after execution here?
-        self_rc
-            .execution_contexts
-            .borrow_mut()
-            .insert(section_context_id, context);
+        // the section queue is closed when the context is cancelled while waiting for more blocks
+        if cancellation.is_cancelled() {
+            self_rc.dispose_execution_context(&endpoint_context_id);
+            return (
+                Err(ExecutionError::Cancelled),
+                sender_endpoint,
+                context_id,
+            );
+        }
+
+        // dispose the context if the sender ended it, otherwise
+        // insert the context back into the map for following sections
+        if last_block
+            .block_header
+            .flags_and_timestamp
+            .is_end_of_context()
+        {
+            self_rc.dispose_execution_context(&endpoint_context_id);
+        } else {
+            self_rc.store_execution_context(endpoint_context_id, context);
+        }
 
         (Ok(result), sender_endpoint, context_id)
     }
 
+    /// Resolves the endpoints stored in a pointer that is used as a block receiver.
+    /// The pointer value must either be a single endpoint or a list of endpoints.
+    fn resolve_pointer_receivers(
+        &self,
+        raw_address: &RawFullPointerAddress,
+    ) -> Option<Vec<Endpoint>> {
+        let memory = self.memory.borrow();
+        let pointer_address = memory
+            .get_pointer_address_from_raw_full_address(raw_address.clone());
+        let value = memory
+            .get_value_reference(&pointer_address)?
+            .borrow()
+            .resolve_current_value();
+        let value = value.borrow();
+        match &value.inner {
+            CoreValue::Endpoint(endpoint) => Some(vec![endpoint.clone()]),
+            CoreValue::List(list) => list
+                .iter()
+                .map(|item| match &item.to_value().borrow().inner {
+                    CoreValue::Endpoint(endpoint) => Some(endpoint.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+
     async fn execute_dxb_block_local(
         self_rc: Rc<RuntimeInternal>,
         block: DXBBlock,
@@ -382,6 +651,10 @@ impl RuntimeInternal {
                 "Execution context must be local for executing a DXB block"
             );
         }
+        // blocks that are marked as data-only must not contain executable instructions
+        if block.block_header.flags_and_timestamp.has_only_data() {
+            check_data_only_dxb(&block.body)?;
+        }
         let dxb = block.body;
         let end_execution =
             block.block_header.flags_and_timestamp.is_end_of_section();
@@ -431,6 +704,11 @@ pub struct RuntimeConfig {
     pub env: Option<HashMap<String, String>>,
     /// if set to true, the runtime will log debug messages
     pub debug: Option<bool>,
+    /// time in milliseconds after which an idle execution context for incoming sections is disposed
+    /// defaults to DEFAULT_EXECUTION_CONTEXT_TIMEOUT
+    pub execution_context_timeout: Option<u64>,
+    /// resource limits for executions of incoming sections
+    pub execution_limits: Option<ExecutionLimits>,
 }
 
 impl RuntimeConfig {
@@ -440,6 +718,8 @@ impl RuntimeConfig {
             interfaces: None,
             env: None,
             debug: None,
+            execution_context_timeout: None,
+            execution_limits: None,
         }
     }
 
@@ -474,15 +754,27 @@ impl Runtime {
         let endpoint = config.endpoint.clone().unwrap_or_else(Endpoint::random);
         let com_hub = ComHub::new(endpoint.clone(), async_context.clone());
         let memory = RefCell::new(Memory::new(endpoint.clone()));
+        let internal = Rc::new(RuntimeInternal {
+            endpoint,
+            memory,
+            config,
+            com_hub,
+            ..RuntimeInternal::new(async_context)
+        });
+
+        // resolve blocks addressed to pointers with endpoint values from memory
+        let weak_internal = Rc::downgrade(&internal);
+        internal.com_hub.register_pointer_receiver_resolver(
+            move |pointer_address| {
+                weak_internal.upgrade().and_then(|internal| {
+                    internal.resolve_pointer_receivers(pointer_address)
+                })
+            },
+        );
+
         Runtime {
             version: VERSION.to_string(),
-            internal: Rc::new(RuntimeInternal {
-                endpoint,
-                memory,
-                config,
-                com_hub,
-                ..RuntimeInternal::new(async_context)
-            }),
+            internal,
         }
     }
 
@@ -523,6 +815,10 @@ impl Runtime {
         &self.internal.memory
     }
 
+    pub fn access_policy(&self) -> &RefCell<AccessPolicy> {
+        &self.internal.access_policy
+    }
+
     #[cfg(all(
         feature = "native_crypto",
         feature = "std",
@@ -684,6 +980,27 @@ impl Runtime {
         )
     }
 
+    /// Explicitly closes the execution context for incoming sections with the given id.
+    /// Returns true if an active execution context was closed.
+    pub fn close_execution_context(
+        &self,
+        context_id: &IncomingEndpointContextId,
+    ) -> bool {
+        self.internal.dispose_execution_context(context_id)
+    }
+
+    /// Requests the remote endpoint to cancel the running execution of the given remote execution context.
+    pub async fn cancel_remote_execution(
+        &self,
+        remote_execution_context: &RemoteExecutionContext,
+    ) -> Result<(), Vec<Endpoint>> {
+        RuntimeInternal::cancel_remote_execution(
+            self.internal(),
+            remote_execution_context,
+        )
+        .await
+    }
+
     async fn execute_remote(
         &self,
         remote_execution_context: &mut RemoteExecutionContext,
@@ -0,0 +1,542 @@
+use crate::collections::{HashMap, HashSet};
+use crate::global::protocol_structures::instructions::{
+    Instruction, RegularInstruction,
+};
+use crate::references::reference::Reference;
+use crate::runtime::RuntimeInternal;
+use crate::stdlib::rc::Rc;
+use crate::stdlib::vec::Vec;
+use crate::values::core_values::endpoint::Endpoint;
+use crate::values::pointer::PointerAddress;
+use core::cell::RefCell;
+use core::fmt::Display;
+use core::prelude::rust_2024::*;
+use core::result::Result;
+
+/// A capability that can be granted to remote endpoints executing DXB on this runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// resolve and read the values of pointers
+    ReadPointer,
+    /// update the values of pointers
+    MutatePointer,
+    /// call functions and procedures
+    CallProcedure,
+    /// trigger further remote executions on other endpoints
+    RemoteExecution,
+}
+
+impl Capability {
+    pub const ALL: [Capability; 4] = [
+        Capability::ReadPointer,
+        Capability::MutatePointer,
+        Capability::CallProcedure,
+        Capability::RemoteExecution,
+    ];
+}
+
+/// Permission of an endpoint for a specific pointer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerPermission {
+    /// the pointer can not be accessed
+    Denied,
+    /// the pointer value can be read, but not updated
+    ReadOnly,
+    /// the pointer value can be read and updated
+    Mutable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermissionError {
+    MissingCapability(Endpoint, Capability),
+    PointerAccessDenied(Endpoint, PointerAddress),
+    PointerMutationDenied(Endpoint, PointerAddress),
+    /// a block that is marked with has_only_data contains executable instructions
+    ExecutableInstructionInDataBlock,
+    /// the body of a block that is marked with has_only_data cannot be parsed
+    InvalidDataBlock,
+}
+
+impl Display for PermissionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PermissionError::MissingCapability(endpoint, capability) => {
+                core::write!(
+                    f,
+                    "Endpoint {endpoint} is missing the {capability:?} capability"
+                )
+            }
+            PermissionError::PointerAccessDenied(endpoint, address) => {
+                core::write!(
+                    f,
+                    "Endpoint {endpoint} is not allowed to access pointer {address}"
+                )
+            }
+            PermissionError::PointerMutationDenied(endpoint, address) => {
+                core::write!(
+                    f,
+                    "Endpoint {endpoint} is not allowed to mutate pointer {address}"
+                )
+            }
+            PermissionError::ExecutableInstructionInDataBlock => {
+                core::write!(
+                    f,
+                    "Data-only block contains executable instructions"
+                )
+            }
+            PermissionError::InvalidDataBlock => {
+                core::write!(f, "Data-only block cannot be parsed")
+            }
+        }
+    }
+}
+
+/// Access policy for DXB executions triggered by remote endpoints.
+/// Executions of the local endpoint are never restricted.
+#[derive(Debug, Clone)]
+pub struct AccessPolicy {
+    /// capabilities of all endpoints that have no explicitly configured capabilities
+    pub default_capabilities: HashSet<Capability>,
+    endpoint_capabilities: HashMap<Endpoint, HashSet<Capability>>,
+    /// permissions for pointers that apply to all endpoints
+    pointer_permissions: HashMap<PointerAddress, PointerPermission>,
+    /// permissions for pointers that apply to a specific endpoint,
+    /// overriding the general pointer permissions
+    endpoint_pointer_permissions:
+        HashMap<(Endpoint, PointerAddress), PointerPermission>,
+}
+
+/// By default, all capabilities are granted to all endpoints
+impl Default for AccessPolicy {
+    fn default() -> Self {
+        AccessPolicy::permissive()
+    }
+}
+
+impl AccessPolicy {
+    /// Creates a policy that grants all capabilities to all endpoints
+    pub fn permissive() -> Self {
+        AccessPolicy::with_default_capabilities(&Capability::ALL)
+    }
+
+    /// Creates a policy that grants no capabilities to endpoints
+    /// unless they are explicitly allowed
+    pub fn restrictive() -> Self {
+        AccessPolicy::with_default_capabilities(&[])
+    }
+
+    pub fn with_default_capabilities(capabilities: &[Capability]) -> Self {
+        AccessPolicy {
+            default_capabilities: capabilities.iter().copied().collect(),
+            endpoint_capabilities: HashMap::new(),
+            pointer_permissions: HashMap::new(),
+            endpoint_pointer_permissions: HashMap::new(),
+        }
+    }
+
+    /// Returns the capabilities of the given endpoint, initialized with the
+    /// default capabilities if the endpoint has no explicit capabilities yet
+    fn endpoint_capabilities_mut(
+        &mut self,
+        endpoint: &Endpoint,
+    ) -> &mut HashSet<Capability> {
+        let default_capabilities = &self.default_capabilities;
+        self.endpoint_capabilities
+            .entry(endpoint.clone())
+            .or_insert_with(|| default_capabilities.clone())
+    }
+
+    /// Grants a capability to the given endpoint
+    pub fn allow(&mut self, endpoint: &Endpoint, capability: Capability) {
+        self.endpoint_capabilities_mut(endpoint).insert(capability);
+    }
+
+    /// Revokes a capability from the given endpoint
+    pub fn deny(&mut self, endpoint: &Endpoint, capability: Capability) {
+        self.endpoint_capabilities_mut(endpoint).remove(&capability);
+    }
+
+    pub fn has_capability(
+        &self,
+        endpoint: &Endpoint,
+        capability: Capability,
+    ) -> bool {
+        self.endpoint_capabilities
+            .get(endpoint)
+            .unwrap_or(&self.default_capabilities)
+            .contains(&capability)
+    }
+
+    /// Sets the permission for a pointer for all endpoints
+    pub fn set_pointer_permission(
+        &mut self,
+        address: PointerAddress,
+        permission: PointerPermission,
+    ) {
+        self.pointer_permissions.insert(address, permission);
+    }
+
+    /// Sets the permission for a pointer for a specific endpoint
+    pub fn set_endpoint_pointer_permission(
+        &mut self,
+        endpoint: Endpoint,
+        address: PointerAddress,
+        permission: PointerPermission,
+    ) {
+        self.endpoint_pointer_permissions
+            .insert((endpoint, address), permission);
+    }
+
+    /// Returns the explicitly configured permission of the endpoint for the pointer, if any
+    pub fn get_pointer_permission(
+        &self,
+        endpoint: &Endpoint,
+        address: &PointerAddress,
+    ) -> Option<PointerPermission> {
+        self.endpoint_pointer_permissions
+            .get(&(endpoint.clone(), address.clone()))
+            .or_else(|| self.pointer_permissions.get(address))
+            .copied()
+    }
+
+    pub fn check_capability(
+        &self,
+        endpoint: &Endpoint,
+        capability: Capability,
+    ) -> Result<(), PermissionError> {
+        if self.has_capability(endpoint, capability) {
+            Ok(())
+        } else {
+            Err(PermissionError::MissingCapability(
+                endpoint.clone(),
+                capability,
+            ))
+        }
+    }
+
+    /// Checks if the endpoint is allowed to read the value of the pointer
+    pub fn check_pointer_read(
+        &self,
+        endpoint: &Endpoint,
+        address: &PointerAddress,
+    ) -> Result<(), PermissionError> {
+        self.check_capability(endpoint, Capability::ReadPointer)?;
+        match self.get_pointer_permission(endpoint, address) {
+            Some(PointerPermission::Denied) => {
+                Err(PermissionError::PointerAccessDenied(
+                    endpoint.clone(),
+                    address.clone(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks if the endpoint is allowed to update the value of the pointer
+    pub fn check_pointer_mutation(
+        &self,
+        endpoint: &Endpoint,
+        address: &PointerAddress,
+    ) -> Result<(), PermissionError> {
+        self.check_pointer_read(endpoint, address)?;
+        self.check_capability(endpoint, Capability::MutatePointer)?;
+        match self.get_pointer_permission(endpoint, address) {
+            Some(PointerPermission::ReadOnly) => {
+                Err(PermissionError::PointerMutationDenied(
+                    endpoint.clone(),
+                    address.clone(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl RuntimeInternal {
+    /// Returns the remote endpoint whose executions must be checked against the access policy.
+    /// Executions without a sender or from the local endpoint are not restricted.
+    /// The ComHub only accepts blocks with a local sender over the local loopback interface,
+    /// so remote endpoints cannot use a local sender to bypass the access policy.
+    fn restricted_sender<'a>(
+        &self,
+        sender: &'a Option<Endpoint>,
+    ) -> Option<&'a Endpoint> {
+        sender.as_ref().filter(|sender| {
+            **sender != self.endpoint && **sender != Endpoint::LOCAL
+        })
+    }
+
+    /// Checks if the sender of an execution has the given capability
+    pub fn check_capability(
+        &self,
+        sender: &Option<Endpoint>,
+        capability: Capability,
+    ) -> Result<(), PermissionError> {
+        match self.restricted_sender(sender) {
+            Some(sender) => self
+                .access_policy
+                .borrow()
+                .check_capability(sender, capability),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks if the sender of an execution is allowed to read the value of the pointer
+    pub fn check_pointer_read(
+        &self,
+        sender: &Option<Endpoint>,
+        address: &PointerAddress,
+    ) -> Result<(), PermissionError> {
+        match self.restricted_sender(sender) {
+            Some(sender) => self
+                .access_policy
+                .borrow()
+                .check_pointer_read(sender, address),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks if the sender of an execution is allowed to update the value of the reference.
+    /// References without a pointer address were created in the execution and can always be updated.
+    pub fn check_reference_mutation(
+        &self,
+        sender: &Option<Endpoint>,
+        reference: &Reference,
+    ) -> Result<(), PermissionError> {
+        match (self.restricted_sender(sender), reference.pointer_address()) {
+            (Some(sender), Some(address)) => self
+                .access_policy
+                .borrow()
+                .check_pointer_mutation(sender, &address),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns true if the instruction only describes data and has no side effects
+fn is_data_instruction(instruction: &Instruction) -> bool {
+    match instruction {
+        Instruction::TypeInstruction(_) => true,
+        Instruction::RegularInstruction(instruction) => core::matches!(
+            instruction,
+            RegularInstruction::Int8(_)
+                | RegularInstruction::Int16(_)
+                | RegularInstruction::Int32(_)
+                | RegularInstruction::Int64(_)
+                | RegularInstruction::Int128(_)
+                | RegularInstruction::UInt8(_)
+                | RegularInstruction::UInt16(_)
+                | RegularInstruction::UInt32(_)
+                | RegularInstruction::UInt64(_)
+                | RegularInstruction::UInt128(_)
+                | RegularInstruction::BigInteger(_)
+                | RegularInstruction::Integer(_)
+                | RegularInstruction::Endpoint(_)
+                | RegularInstruction::DecimalF32(_)
+                | RegularInstruction::DecimalF64(_)
+                | RegularInstruction::DecimalAsInt16(_)
+                | RegularInstruction::DecimalAsInt32(_)
+                | RegularInstruction::BigDecimal(_)
+                | RegularInstruction::Decimal(_)
+                | RegularInstruction::ShortText(_)
+                | RegularInstruction::Text(_)
+                | RegularInstruction::True
+                | RegularInstruction::False
+                | RegularInstruction::Null
+                | RegularInstruction::Statements(_)
+                | RegularInstruction::ShortStatements(_)
+                | RegularInstruction::UnboundedStatements
+                | RegularInstruction::UnboundedStatementsEnd(_)
+                | RegularInstruction::List(_)
+                | RegularInstruction::ShortList(_)
+                | RegularInstruction::Map(_)
+                | RegularInstruction::ShortMap(_)
+                | RegularInstruction::KeyValueDynamic
+                | RegularInstruction::KeyValueShortText(_)
+                | RegularInstruction::GetRef(_)
+                | RegularInstruction::GetLocalRef(_)
+                | RegularInstruction::GetInternalRef(_)
+                | RegularInstruction::TypedValue
+                | RegularInstruction::TypeExpression
+        ),
+    }
+}
+
+/// Checks that a DXB body only contains data instructions, as required for blocks
+/// with the has_only_data flag.
+/// Bodies that cannot be parsed are rejected as well.
+pub fn check_data_only_dxb(dxb_body: &[u8]) -> Result<(), PermissionError> {
+    let dxb_body = Rc::new(RefCell::new(Vec::from(dxb_body)));
+    for instruction in crate::dxb_parser::body::iterate_instructions(dxb_body) {
+        match instruction {
+            Ok(instruction) if !is_data_instruction(&instruction) => {
+                return Err(PermissionError::ExecutableInstructionInDataBlock);
+            }
+            Ok(_) => {}
+            Err(_) => return Err(PermissionError::InvalidDataBlock),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::global::instruction_codes::InstructionCode;
+    use crate::references::reference::Reference;
+    use crate::runtime::execution::ExecutionError;
+    use crate::runtime::execution::context::{
+        ExecutionContext, ExecutionMode, LocalExecutionContext,
+    };
+    use crate::runtime::{Runtime, RuntimeConfig};
+    use crate::stdlib::vec;
+    use crate::values::value_container::ValueContainer;
+
+    #[test]
+    fn endpoint_capabilities() {
+        let endpoint_a = Endpoint::new("@test_a");
+        let endpoint_b = Endpoint::new("@test_b");
+
+        let mut policy = AccessPolicy::restrictive();
+        assert!(!policy.has_capability(&endpoint_a, Capability::ReadPointer));
+
+        policy.allow(&endpoint_a, Capability::ReadPointer);
+        assert!(policy.has_capability(&endpoint_a, Capability::ReadPointer));
+        assert!(!policy.has_capability(&endpoint_b, Capability::ReadPointer));
+
+        let mut policy = AccessPolicy::permissive();
+        policy.deny(&endpoint_a, Capability::RemoteExecution);
+        assert_eq!(
+            policy.check_capability(&endpoint_a, Capability::RemoteExecution),
+            Err(PermissionError::MissingCapability(
+                endpoint_a.clone(),
+                Capability::RemoteExecution
+            ))
+        );
+        assert!(policy.has_capability(&endpoint_a, Capability::CallProcedure));
+        assert!(
+            policy.has_capability(&endpoint_b, Capability::RemoteExecution)
+        );
+    }
+
+    #[test]
+    fn pointer_permissions() {
+        let endpoint_a = Endpoint::new("@test_a");
+        let endpoint_b = Endpoint::new("@test_b");
+        let address = PointerAddress::Local([1, 2, 3, 4, 5]);
+
+        let mut policy = AccessPolicy::permissive();
+        policy.set_pointer_permission(
+            address.clone(),
+            PointerPermission::ReadOnly,
+        );
+        policy.set_endpoint_pointer_permission(
+            endpoint_b.clone(),
+            address.clone(),
+            PointerPermission::Mutable,
+        );
+
+        assert!(policy.check_pointer_read(&endpoint_a, &address).is_ok());
+        assert_eq!(
+            policy.check_pointer_mutation(&endpoint_a, &address),
+            Err(PermissionError::PointerMutationDenied(
+                endpoint_a.clone(),
+                address.clone()
+            ))
+        );
+        assert!(policy.check_pointer_mutation(&endpoint_b, &address).is_ok());
+
+        // pointer permissions can not grant more than the endpoint capabilities
+        policy.deny(&endpoint_b, Capability::MutatePointer);
+        assert!(
+            policy
+                .check_pointer_mutation(&endpoint_b, &address)
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "compiler")]
+    #[test]
+    fn data_only_dxb() {
+        use crate::compiler::{CompileOptions, compile_script};
+
+        let (dxb, _) = compile_script(
+            "[1, \"text\", {a: true}]",
+            CompileOptions::default(),
+        )
+        .unwrap();
+        assert!(check_data_only_dxb(&dxb).is_ok());
+
+        let (dxb, _) =
+            compile_script("const x = 1; x", CompileOptions::default())
+                .unwrap();
+        assert_eq!(
+            check_data_only_dxb(&dxb),
+            Err(PermissionError::ExecutableInstructionInDataBlock)
+        );
+    }
+
+    #[test]
+    fn invalid_data_only_dxb() {
+        use crate::compiler::{CompileOptions, compile_script};
+
+        let (dxb, _) =
+            compile_script("[1, \"text\"]", CompileOptions::default()).unwrap();
+        assert_eq!(
+            check_data_only_dxb(&dxb[..dxb.len() - 2]),
+            Err(PermissionError::InvalidDataBlock)
+        );
+        assert_eq!(
+            check_data_only_dxb(&[0xff, 0xfe, 0xfd]),
+            Err(PermissionError::InvalidDataBlock)
+        );
+    }
+
+    #[test]
+    fn denied_pointer_read_for_remote_sender() {
+        let local_endpoint = Endpoint::new("@local");
+        let sender = Endpoint::new("@sender");
+        let runtime = Runtime::init_native(RuntimeConfig::new_with_endpoint(
+            local_endpoint.clone(),
+        ));
+
+        let address = PointerAddress::Local([1, 2, 3, 4, 5]);
+        let reference = Reference::from(ValueContainer::from(1));
+        reference.set_pointer_address(address.clone());
+        runtime.memory().borrow_mut().register_reference(&reference);
+        runtime
+            .access_policy()
+            .borrow_mut()
+            .set_pointer_permission(address.clone(), PointerPermission::Denied);
+
+        let mut dxb = vec![InstructionCode::GET_REF as u8];
+        dxb.extend_from_slice(
+            &address.to_raw_full_address(&local_endpoint).unwrap().id,
+        );
+
+        // remote senders are restricted by the access policy
+        let mut context = LocalExecutionContext::new_with_runtime_internal(
+            runtime.internal(),
+            ExecutionMode::Static,
+        );
+        context.set_sender(sender.clone());
+        let result = ExecutionContext::Local(context).execute_dxb_sync(&dxb);
+        assert!(core::matches!(
+            result,
+            Err(ExecutionError::PermissionError(
+                PermissionError::PointerAccessDenied(endpoint, _)
+            )) if endpoint == sender
+        ));
+
+        // local executions are never restricted
+        let context = LocalExecutionContext::new_with_runtime_internal(
+            runtime.internal(),
+            ExecutionMode::Static,
+        );
+        let result = ExecutionContext::Local(context)
+            .execute_dxb_sync(&dxb)
+            .unwrap()
+            .unwrap();
+        assert_eq!(result, ValueContainer::Reference(reference));
+    }
+}
@@ -1,5 +1,8 @@
 use crate::core_compiler::value_compiler::compile_value_container;
-use crate::global::dxb_block::{DXBBlock, IncomingSection, OutgoingContextId};
+use crate::global::dxb_block::{
+    DXBBlock, IncomingSection, OutgoingContextId, OutgoingSectionIndex,
+};
+use crate::global::instruction_codes::InstructionCode;
 use crate::global::protocol_structures::block_header::FlagsAndTimestamp;
 use crate::global::protocol_structures::block_header::{
     BlockHeader, BlockType,
@@ -19,13 +22,14 @@ use core::prelude::rust_2024::*;
 use core::result::Result;
 use core::time::Duration;
 use futures::channel::oneshot;
-use log::info;
+use log::{error, info};
 
 #[cfg_attr(feature = "embassy_runtime", embassy_executor::task)]
 async fn handle_incoming_section_task(
     runtime_rc: Rc<RuntimeInternal>,
     section: IncomingSection,
 ) {
+    let section_index = section.get_section_index();
     let (result, endpoint, context_id) =
         RuntimeInternal::execute_incoming_section(runtime_rc.clone(), section)
             .await;
@@ -39,6 +43,7 @@ async fn handle_incoming_section_task(
         result,
         endpoint,
         context_id,
+        section_index,
     )
     .await;
     // TODO #231: handle errors in sending response
@@ -91,10 +96,28 @@ impl RuntimeInternal {
     async fn update(self_rc: Rc<RuntimeInternal>) {
         // update the ComHub
         self_rc.com_hub.update().await;
+        // dispose execution contexts that have not been used for a while
+        self_rc.dispose_idle_execution_contexts();
+        // cancel executions that were cancelled by the sender
+        self_rc.handle_incoming_cancellations();
         // handle incoming sections
         RuntimeInternal::handle_incoming_sections(self_rc);
     }
 
+    /// pops incoming cancellations from the ComHub and cancels the corresponding executions
+    fn handle_incoming_cancellations(&self) {
+        let cancellations = self
+            .com_hub
+            .block_handler
+            .incoming_cancellations_queue
+            .borrow_mut()
+            .drain(..)
+            .collect::<Vec<_>>();
+        for context_id in cancellations {
+            self.cancel_execution_context(&context_id);
+        }
+    }
+
     /// pops incoming sections from the ComHub and executes them in separate tasks
     fn handle_incoming_sections(self_rc: Rc<RuntimeInternal>) {
         let mut sections = self_rc
@@ -119,12 +142,14 @@ impl RuntimeInternal {
         result: Result<Option<ValueContainer>, ExecutionError>,
         receiver_endpoint: Endpoint,
         context_id: OutgoingContextId,
+        section_index: OutgoingSectionIndex,
     ) -> Result<(), Vec<Endpoint>> {
         let routing_header: RoutingHeader = RoutingHeader::default()
             .with_sender(self_rc.endpoint.clone())
             .to_owned();
         let block_header = BlockHeader {
             context_id,
+            section_index,
             flags_and_timestamp: FlagsAndTimestamp::new()
                 .with_block_type(BlockType::Response)
                 .with_is_end_of_section(true)
@@ -137,24 +162,23 @@ impl RuntimeInternal {
             "send response, context_id: {context_id:?}, receiver: {receiver_endpoint}"
         );
 
-        if let Ok(value) = result {
-            let dxb = if let Some(value) = &value {
-                compile_value_container(value)
-            } else {
-                vec![]
-            };
-
-            let mut block = DXBBlock::new(
-                routing_header,
-                block_header,
-                encrypted_header,
-                dxb,
-            );
-            block.set_receivers(core::slice::from_ref(&receiver_endpoint));
+        let dxb = match result {
+            Ok(Some(value)) => compile_value_container(&value),
+            Ok(None) => vec![],
+            // report the error back to the sender (e.g. for late blocks of a disposed context),
+            // the error value is yeeted so that it can be handled with try by the sender
+            Err(err) => {
+                error!("Execution error in context {context_id}: {err}");
+                let mut dxb = vec![InstructionCode::YEET as u8];
+                dxb.extend(compile_value_container(&err.into_error_value()));
+                dxb
+            }
+        };
 
-            self_rc.com_hub.send_own_block(block).await
-        } else {
-            core::todo!("#233 Handle returning error response block");
-        }
+        let mut block =
+            DXBBlock::new(routing_header, block_header, encrypted_header, dxb);
+        block.set_receivers(core::slice::from_ref(&receiver_endpoint));
+
+        self_rc.com_hub.send_own_block(block).await
     }
 }
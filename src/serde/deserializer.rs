@@ -54,7 +54,10 @@ impl<'de> DatexDeserializer {
     pub fn from_bytes(input: &'de [u8]) -> Result<Self, DeserializationError> {
         let context = ExecutionInput::new(
             input,
-            ExecutionOptions { verbose: true },
+            ExecutionOptions {
+                verbose: true,
+                ..Default::default()
+            },
             None,
         );
         let value = execute_dxb_sync(context)
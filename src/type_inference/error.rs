@@ -3,8 +3,7 @@ use core::{fmt::Display, ops::Range};
 
 use crate::values::core_values::r#type::Type;
 use crate::{
-    compiler::error::ErrorCollector,
-    global::operators::binary::ArithmeticOperator,
+    compiler::error::ErrorCollector, global::operators::BinaryOperator,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -13,7 +12,7 @@ pub enum TypeError {
     // only for debugging purposes
     InvalidDerefType(Type),
     Unimplemented(String),
-    MismatchedOperands(ArithmeticOperator, Type, Type),
+    MismatchedOperands(BinaryOperator, Type, Type),
     AssignmentToImmutableReference(String),
     AssignmentToImmutableValue(String),
     AssignmentToConstant(String),
@@ -23,6 +22,55 @@ pub enum TypeError {
         annotated_type: Type,
         assigned_type: Type,
     },
+
+    // callee of an apply expression is not callable
+    NotCallable(Type),
+    WrongArgumentCount {
+        expected: usize,
+        found: usize,
+        // true if the callable has a rest parameter
+        variadic: bool,
+    },
+    ArgumentTypeMismatch {
+        parameter: String,
+        expected: Type,
+        found: Type,
+    },
+    // value passed to a nominal type constructor does not match its definition
+    InvalidConstructorArgument {
+        type_name: String,
+        expected: Type,
+        found: Type,
+    },
+    PropertyNotFound(Type, String),
+    IndexOutOfBounds(Type, usize),
+
+    // value can never be converted to the target type of a type cast
+    InvalidCast {
+        from: Type,
+        to: Type,
+    },
+
+    // type used as an interface is not a nominal type with a map of members
+    InvalidInterface(Type),
+    // type marked as implementing an interface does not provide its members
+    MissingInterfaceMembers {
+        interface: String,
+        expected: Type,
+        found: Type,
+    },
+
+    // value passed to yeet is neither an error nor a text
+    InvalidYeetValue(Type),
+    // errors yeeted in a callable are neither handled with try nor declared with yeets
+    UnhandledYeet(Type),
+
+    // type arguments were passed to a type or callable without generic parameters
+    NotGeneric(Type),
+    WrongTypeArgumentCount {
+        expected: usize,
+        found: usize,
+    },
 }
 
 impl Display for TypeError {
@@ -71,6 +119,94 @@ impl Display for TypeError {
                     assigned_type, annotated_type
                 )
             }
+            TypeError::NotCallable(ty) => {
+                write!(f, "Value of type {} is not callable", ty)
+            }
+            TypeError::WrongArgumentCount {
+                expected,
+                found,
+                variadic,
+            } => {
+                write!(
+                    f,
+                    "Expected {}{} argument{}, but got {}",
+                    if *variadic { "at least " } else { "" },
+                    expected,
+                    if *expected == 1 { "" } else { "s" },
+                    found
+                )
+            }
+            TypeError::ArgumentTypeMismatch {
+                parameter,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "Cannot pass {} as parameter '{}' of type {}",
+                    found, parameter, expected
+                )
+            }
+            TypeError::InvalidConstructorArgument {
+                type_name,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "Cannot construct {} from {}, expected {}",
+                    type_name, found, expected
+                )
+            }
+            TypeError::PropertyNotFound(ty, property) => {
+                write!(f, "Property {} does not exist on type {}", property, ty)
+            }
+            TypeError::IndexOutOfBounds(ty, index) => {
+                write!(f, "Index {} is out of bounds for type {}", index, ty)
+            }
+            TypeError::InvalidCast { from, to } => {
+                write!(f, "Cannot cast {} to {}", from, to)
+            }
+            TypeError::InvalidInterface(ty) => {
+                write!(f, "Type {} can not be used as an interface", ty)
+            }
+            TypeError::MissingInterfaceMembers {
+                interface,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "Type {} does not implement {}, expected {}",
+                    found, interface, expected
+                )
+            }
+            TypeError::InvalidYeetValue(ty) => {
+                write!(
+                    f,
+                    "Value of type {} can not be yeeted, expected an error or text",
+                    ty
+                )
+            }
+            TypeError::UnhandledYeet(ty) => {
+                write!(
+                    f,
+                    "Errors of type {} must be handled with try or declared with yeets",
+                    ty
+                )
+            }
+            TypeError::NotGeneric(ty) => {
+                write!(f, "Type {} does not take type arguments", ty)
+            }
+            TypeError::WrongTypeArgumentCount { expected, found } => {
+                write!(
+                    f,
+                    "Expected {} type argument{}, but got {}",
+                    expected,
+                    if *expected == 1 { "" } else { "s" },
+                    found
+                )
+            }
         }
     }
 }
@@ -1,7 +1,9 @@
 use crate::{
     ast::resolved_variable::ResolvedVariable,
     global::operators::{
-        AssignmentOperator, BinaryOperator, LogicalUnaryOperator, UnaryOperator,
+        AssignmentOperator, BinaryOperator, ComparisonOperator,
+        LogicalUnaryOperator, ReferenceUnaryOperator, UnaryOperator,
+        binary::LogicalOperator,
     },
     libs::core::get_core_lib_type_reference,
     references::{
@@ -19,22 +21,26 @@ use crate::ast::expressions::{
 };
 use crate::ast::expressions::{
     CallableDeclaration, ComparisonOperation, Conditional, CreateRef,
-    DatexExpressionData, Deref, DerefAssignment, List, Map, PropertyAssignment,
-    RemoteExecution, Slot, SlotAssignment, UnaryOperation, VariableAssignment,
-    VariantAccess,
+    DatexExpressionData, Deref, DerefAssignment, Export, Import, List, Map,
+    PropertyAssignment, RemoteExecution, Slot, SlotAssignment, Try, TypeCast,
+    UnaryOperation, VariableAssignment, VariantAccess, Yeet,
 };
 use crate::ast::expressions::{GenericInstantiation, PropertyAccess};
 use crate::ast::type_expressions::{
-    CallableTypeExpression, FixedSizeList, GenericAccess, SliceList,
+    CallableTypeExpression, FixedSizeList, GenericAccess, ImplType, SliceList,
     TypeVariantAccess,
 };
 use crate::ast::type_expressions::{
     Intersection, StructuralList, StructuralMap, TypeExpression, Union,
 };
+use crate::collections::HashMap;
+use crate::types::collection_type_definition::CollectionTypeDefinition;
 use crate::values::core_values::callable::CallableSignature;
 use crate::{
-    compiler::precompiler::precompiled_ast::{AstMetadata, RichAst},
-    libs::core::{CoreLibPointerId, get_core_lib_type},
+    compiler::precompiler::precompiled_ast::{
+        AstMetadata, RichAst, VariableShape,
+    },
+    libs::core::{CoreLibPointerId, get_core_lib_type, get_core_lib_value},
     type_inference::{
         error::{
             DetailedTypeErrors, SimpleOrDetailedTypeError, SpannedTypeError,
@@ -61,7 +67,7 @@ use crate::{
         },
     },
 };
-use core::{cell::RefCell, ops::Range, panic, str::FromStr};
+use core::{cell::RefCell, iter::once, ops::Range, panic, str::FromStr};
 
 pub mod error;
 pub mod options;
@@ -129,6 +135,14 @@ pub fn infer_expression_type_with_errors(
     )
 }
 
+/// Infers the type described by a type expression, e.g. the target type of a type cast.
+pub fn infer_type_expression_type(
+    type_expression: &mut TypeExpression,
+    metadata: Rc<RefCell<AstMetadata>>,
+) -> Result<Type, SpannedTypeError> {
+    TypeInference::new(metadata).infer_type_expression(type_expression)
+}
+
 /// Infers the type of an expression as precisely as possible.
 /// Uses cached type information if available.
 fn infer_expression_type(
@@ -141,6 +155,11 @@ fn infer_expression_type(
 pub struct TypeInference {
     errors: Option<DetailedTypeErrors>,
     metadata: Rc<RefCell<AstMetadata>>,
+    /// types of the errors yeeted in the enclosing callable bodies and try expressions
+    yeet_scopes: Vec<Vec<Type>>,
+    /// narrowed types of variables inside null checks, which only apply when
+    /// the variable is accessed and never replace the declared type
+    narrowed_types: HashMap<usize, Type>,
 }
 
 impl TypeInference {
@@ -148,6 +167,8 @@ impl TypeInference {
         TypeInference {
             metadata,
             errors: None,
+            yeet_scopes: vec![],
+            narrowed_types: HashMap::new(),
         }
     }
 
@@ -205,6 +226,15 @@ impl TypeInference {
         &mut self,
         expr: &mut DatexExpression,
     ) -> Result<Type, SpannedTypeError> {
+        // types of data literals reused from a previous build are already known
+        if let DatexExpressionData::Map(_) | DatexExpressionData::List(_) =
+            expr.data
+            && let Some(ty) =
+                self.metadata.borrow().restored_types.get(&expr.span)
+        {
+            expr.ty = Some(ty.clone());
+            return Ok(ty.clone());
+        }
         self.visit_datex_expression(expr)?;
         Ok(expr.ty.clone().unwrap_or(Type::never()))
     }
@@ -217,6 +247,25 @@ impl TypeInference {
         Ok(type_expr.ty.clone().unwrap_or(Type::never()))
     }
 
+    /// Returns true if the expression refers to a type instead of a value
+    fn is_type_expression(&self, expression: &DatexExpression) -> bool {
+        match &expression.data {
+            DatexExpressionData::GetReference(address) => {
+                CoreLibPointerId::try_from(address)
+                    .ok()
+                    .and_then(get_core_lib_value)
+                    .is_some_and(|value| value.is_type())
+            }
+            DatexExpressionData::VariableAccess(var_access) => self
+                .metadata
+                .borrow()
+                .variable_metadata(var_access.id)
+                .is_some_and(|meta| meta.shape == VariableShape::Type),
+            DatexExpressionData::TypeExpression(_) => true,
+            _ => false,
+        }
+    }
+
     fn variable_type(&self, id: usize) -> Option<Type> {
         self.metadata
             .borrow()
@@ -232,6 +281,108 @@ impl TypeInference {
             panic!("Variable metadata not found for id {}", id);
         }
     }
+
+    /// Infers the type of an expression, with null removed from the type of
+    /// the given variable while inferring, e.g. inside if (x != null)
+    fn infer_narrowed(
+        &mut self,
+        expr: &mut DatexExpression,
+        narrowing: Option<(usize, bool)>,
+    ) -> Result<Type, SpannedTypeError> {
+        let Some((id, _)) = narrowing else {
+            return self.infer_expression(expr);
+        };
+        let Some(var_type) = self.variable_type(id) else {
+            return self.infer_expression(expr);
+        };
+        let narrowed_type =
+            without_null(self.narrowed_types.get(&id).unwrap_or(&var_type));
+        let previous = self.narrowed_types.insert(id, narrowed_type);
+        let result = self.infer_expression(expr);
+        match previous {
+            Some(previous) => self.narrowed_types.insert(id, previous),
+            None => self.narrowed_types.remove(&id),
+        };
+        result
+    }
+
+    /// Infers the type of a property of the base type
+    fn infer_property_type(
+        &mut self,
+        base_type: Type,
+        property_type: Type,
+        property_span: Range<usize>,
+    ) -> ExpressionVisitResult<SpannedTypeError> {
+        let key = structural_definition(&property_type).filter(is_literal_key);
+        match structural_definition(&base_type) {
+            Some(StructuralTypeDefinition::Map(fields)) => match key {
+                Some(key) => {
+                    match fields.iter().find(|(field_key, _)| {
+                        structural_definition(field_key).as_ref() == Some(&key)
+                    }) {
+                        Some((_, value_type)) => mark_type(value_type.clone()),
+                        None => {
+                            self.record_error(SpannedTypeError::new_with_span(
+                                TypeError::PropertyNotFound(
+                                    base_type,
+                                    property_type.to_string(),
+                                ),
+                                property_span,
+                            ))
+                        }
+                    }
+                }
+                // any of the fields could be accessed
+                None => mark_type(union_of(
+                    fields.into_iter().map(|(_, value)| value).collect(),
+                )),
+            },
+            Some(StructuralTypeDefinition::List(items)) => {
+                match list_index(&key) {
+                    Some(index) => match items.get(index) {
+                        Some(item_type) => mark_type(item_type.clone()),
+                        None => {
+                            self.record_error(SpannedTypeError::new_with_span(
+                                TypeError::IndexOutOfBounds(base_type, index),
+                                property_span,
+                            ))
+                        }
+                    },
+                    None => mark_type(union_of(items)),
+                }
+            }
+            _ => match collection_definition(&base_type) {
+                Some(CollectionTypeDefinition::ListSlice(item_type, size)) => {
+                    match list_index(&key) {
+                        Some(index) if index >= size => {
+                            self.record_error(SpannedTypeError::new_with_span(
+                                TypeError::IndexOutOfBounds(base_type, index),
+                                property_span,
+                            ))
+                        }
+                        _ => mark_type(*item_type),
+                    }
+                }
+                // the length of the list is only known at runtime
+                Some(CollectionTypeDefinition::List(item_type)) => {
+                    mark_type(*item_type)
+                }
+                Some(CollectionTypeDefinition::Map { value, .. }) => {
+                    mark_type(*value)
+                }
+                // properties of other types are only known at runtime
+                _ => mark_type(Type::unknown()),
+            },
+        }
+    }
+    /// Records a yeeted error type in the innermost callable body or try expression.
+    /// Errors yeeted at the top level are not tracked.
+    fn record_yeet(&mut self, ty: Type) {
+        if let Some(scope) = self.yeet_scopes.last_mut() {
+            scope.push(ty);
+        }
+    }
+
     fn record_error(
         &mut self,
         error: SpannedTypeError,
@@ -260,6 +411,629 @@ fn mark_type<E>(ty: Type) -> Result<VisitAction<E>, SpannedTypeError> {
     Ok(VisitAction::SetTypeSkipChildren(ty))
 }
 
+/// Creates the union of the given types, omitting duplicates and never types.
+/// Returns the single member if only one type remains.
+fn union_of(types: Vec<Type>) -> Type {
+    let mut members: Vec<Type> = vec![];
+    for ty in types {
+        let flattened = match ty.type_definition {
+            TypeDefinition::Union(inner)
+                if ty.reference_mutability.is_none() =>
+            {
+                inner
+            }
+            _ => vec![ty],
+        };
+        for member in flattened {
+            if !core::matches!(member.type_definition, TypeDefinition::Never)
+                && !members.contains(&member)
+            {
+                members.push(member);
+            }
+        }
+    }
+    match members.len() {
+        0 => Type::never(),
+        1 => members.remove(0),
+        _ => Type::union(members),
+    }
+}
+
+/// Returns the base type of a type if it can be determined at compile time,
+/// e.g. integer for 42 or integer/u8
+fn known_base_type(ty: &Type) -> Option<Rc<RefCell<TypeReference>>> {
+    match &ty.type_definition {
+        TypeDefinition::Structural(_) => ty.base_type_reference(),
+        // only core lib references have a known base type
+        TypeDefinition::Reference(reference)
+            if reference.borrow().pointer_address.as_ref().is_some_and(
+                |address| CoreLibPointerId::try_from(address).is_ok(),
+            ) =>
+        {
+            ty.base_type_reference()
+        }
+        _ => None,
+    }
+}
+
+/// Returns the core type of a type if it can be determined at compile time,
+/// with integer and decimal variants collapsed to their base type
+fn known_core_type(ty: &Type) -> Option<CoreLibPointerId> {
+    let base = known_base_type(ty)?;
+    let core_lib_id =
+        CoreLibPointerId::try_from(base.borrow().pointer_address.as_ref()?)
+            .ok()?;
+    Some(match core_lib_id {
+        CoreLibPointerId::Integer(_) => CoreLibPointerId::Integer(None),
+        CoreLibPointerId::Decimal(_) => CoreLibPointerId::Decimal(None),
+        core_lib_id => core_lib_id,
+    })
+}
+
+/// Returns true if the type is the null type
+fn is_null_type(ty: &Type) -> bool {
+    known_core_type(ty) == Some(CoreLibPointerId::Null)
+}
+
+/// Returns true if a value of the type could be null, e.g. for integer | null
+fn is_nullable(ty: &Type) -> bool {
+    match &ty.type_definition {
+        TypeDefinition::Union(members) => members.iter().any(is_nullable),
+        _ => is_null_type(ty),
+    }
+}
+
+/// Removes null from a type, e.g. integer | null results in integer
+fn without_null(ty: &Type) -> Type {
+    match &ty.type_definition {
+        TypeDefinition::Union(members) if ty.reference_mutability.is_none() => {
+            union_of(
+                members
+                    .iter()
+                    .filter(|member| !is_null_type(member))
+                    .cloned()
+                    .collect(),
+            )
+        }
+        _ if is_null_type(ty) => Type::never(),
+        _ => ty.clone(),
+    }
+}
+
+/// Returns the variable id checked against null in a condition such as x != null or
+/// null == x, together with whether the variable is known to be non-null if the
+/// condition is true
+fn null_check(condition: &DatexExpression) -> Option<(usize, bool)> {
+    let DatexExpressionData::ComparisonOperation(ComparisonOperation {
+        left,
+        operator,
+        right,
+    }) = &condition.data
+    else {
+        return None;
+    };
+    let is_not_null = match operator {
+        ComparisonOperator::NotEqual
+        | ComparisonOperator::NotStructuralEqual => true,
+        ComparisonOperator::Equal | ComparisonOperator::StructuralEqual => {
+            false
+        }
+        _ => return None,
+    };
+    match (&left.data, &right.data) {
+        (
+            DatexExpressionData::VariableAccess(VariableAccess { id, .. }),
+            DatexExpressionData::Null,
+        )
+        | (
+            DatexExpressionData::Null,
+            DatexExpressionData::VariableAccess(VariableAccess { id, .. }),
+        ) => Some((*id, is_not_null)),
+        _ => None,
+    }
+}
+
+/// Returns true if a value of the given type can be cast to the target type.
+/// Numbers, text, booleans and endpoints are converted between each other,
+/// nominal types accept values matching their definition and all other casts
+/// require the value to match the target type at runtime.
+/// Casts from types that are only known at runtime are always accepted.
+fn is_castable(ty: &Type, target: &Type) -> bool {
+    if is_assignable(ty, target) {
+        return true;
+    }
+    if let TypeDefinition::Union(members) = &ty.type_definition {
+        return members.iter().all(|member| is_castable(member, target));
+    }
+    if let Some(reference) = target.inner_reference()
+        && reference.borrow().pointer_address.is_none()
+        && reference.borrow().nominal_type_declaration.is_some()
+    {
+        return is_castable(ty, &reference.borrow().type_value);
+    }
+
+    let Some(from) = known_core_type(ty) else {
+        return true;
+    };
+    match known_core_type(target) {
+        Some(CoreLibPointerId::Text) => true,
+        Some(
+            CoreLibPointerId::Integer(None) | CoreLibPointerId::Decimal(None),
+        ) => core::matches!(
+            from,
+            CoreLibPointerId::Integer(None)
+                | CoreLibPointerId::Decimal(None)
+                | CoreLibPointerId::Text
+                | CoreLibPointerId::Boolean
+        ),
+        Some(CoreLibPointerId::Boolean) => core::matches!(
+            from,
+            CoreLibPointerId::Integer(None)
+                | CoreLibPointerId::Text
+                | CoreLibPointerId::Boolean
+                | CoreLibPointerId::Null
+        ),
+        Some(CoreLibPointerId::Endpoint) => core::matches!(
+            from,
+            CoreLibPointerId::Text | CoreLibPointerId::Endpoint
+        ),
+        Some(to) => from == to,
+        None => true,
+    }
+}
+
+/// Returns true if a value of the given type can be used where the expected type is required.
+/// Unknown and never types, as well as types that can not be matched yet, are accepted
+/// to prevent follow-up errors.
+fn is_assignable(ty: &Type, expected: &Type) -> bool {
+    match (&ty.type_definition, &expected.type_definition) {
+        (_, TypeDefinition::Unknown)
+        | (TypeDefinition::Unknown | TypeDefinition::Never, _) => true,
+        (TypeDefinition::Union(members), _) => {
+            members.iter().all(|member| is_assignable(member, expected))
+        }
+        // unbound type parameters can not be checked
+        (_, TypeDefinition::Parameter(_))
+        | (TypeDefinition::Parameter(_), _) => true,
+        (
+            TypeDefinition::Structural(StructuralTypeDefinition::List(items)),
+            TypeDefinition::Structural(StructuralTypeDefinition::List(
+                expected_items,
+            )),
+        ) => {
+            if items.len() == expected_items.len() {
+                items
+                    .iter()
+                    .zip(expected_items)
+                    .all(|(item, expected)| is_assignable(item, expected))
+            }
+            // a list type with a single item type, e.g. [T], accepts lists of any length
+            else if let [expected_item] = expected_items.as_slice() {
+                items.iter().all(|item| is_assignable(item, expected_item))
+            } else {
+                false
+            }
+        }
+        (
+            TypeDefinition::Structural(StructuralTypeDefinition::Map(fields)),
+            TypeDefinition::Structural(StructuralTypeDefinition::Map(
+                expected_fields,
+            )),
+        ) => expected_fields
+            .iter()
+            .all(|(expected_key, expected_value)| {
+                find_field(fields, expected_key)
+                    .is_some_and(|value| is_assignable(value, expected_value))
+            }),
+        (
+            _,
+            TypeDefinition::Collection(CollectionTypeDefinition::Instance {
+                ..
+            }),
+        ) => {
+            ty == expected
+                || expected
+                    .instantiate()
+                    .is_some_and(|expected| is_assignable(ty, &expected))
+        }
+        (
+            TypeDefinition::Collection(CollectionTypeDefinition::Instance {
+                ..
+            }),
+            _,
+        ) => ty
+            .instantiate()
+            .is_some_and(|ty| is_assignable(&ty, expected)),
+        (
+            TypeDefinition::Collection(collection),
+            TypeDefinition::Collection(expected_collection),
+        ) => is_collection_assignable(collection, expected_collection),
+        (
+            TypeDefinition::Structural(StructuralTypeDefinition::List(items)),
+            TypeDefinition::Collection(CollectionTypeDefinition::List(
+                expected_item,
+            )),
+        ) => items.iter().all(|item| is_assignable(item, expected_item)),
+        (
+            TypeDefinition::Structural(StructuralTypeDefinition::List(items)),
+            TypeDefinition::Collection(CollectionTypeDefinition::ListSlice(
+                expected_item,
+                size,
+            )),
+        ) => {
+            items.len() == *size
+                && items.iter().all(|item| is_assignable(item, expected_item))
+        }
+        (
+            TypeDefinition::Structural(StructuralTypeDefinition::Map(fields)),
+            TypeDefinition::Collection(CollectionTypeDefinition::Map {
+                key,
+                value,
+            }),
+        ) => fields.iter().all(|(field_key, field_value)| {
+            is_assignable(field_key, key) && is_assignable(field_value, value)
+        }),
+        (
+            TypeDefinition::Collection(CollectionTypeDefinition::ListSlice(
+                item,
+                size,
+            )),
+            TypeDefinition::Structural(StructuralTypeDefinition::List(
+                expected_items,
+            )),
+        ) => {
+            if expected_items.len() == *size {
+                expected_items
+                    .iter()
+                    .all(|expected| is_assignable(item, expected))
+            } else if let [expected_item] = expected_items.as_slice() {
+                is_assignable(item, expected_item)
+            } else {
+                false
+            }
+        }
+        (
+            TypeDefinition::Collection(CollectionTypeDefinition::List(item)),
+            TypeDefinition::Structural(StructuralTypeDefinition::List(
+                expected_items,
+            )),
+        ) => match expected_items.as_slice() {
+            [expected_item] => is_assignable(item, expected_item),
+            // the length of the list is not known
+            _ => false,
+        },
+        // local nominal types are only assignable to themselves and to the
+        // interfaces they are marked as implementing
+        (_, TypeDefinition::Reference(expected_reference))
+            if is_local_nominal(expected_reference)
+                && ty
+                    .inner_reference()
+                    .is_some_and(|reference| is_local_nominal(&reference)) =>
+        {
+            ty.implements(expected_reference)
+        }
+        (TypeDefinition::ImplType(base, _), _) => {
+            match &expected.type_definition {
+                TypeDefinition::Reference(expected_reference)
+                    if is_local_nominal(expected_reference) =>
+                {
+                    ty.implements(expected_reference)
+                }
+                _ => is_assignable(base, expected),
+            }
+        }
+        (_, TypeDefinition::ImplType(expected_base, _)) => {
+            is_assignable(ty, expected_base)
+        }
+        (TypeDefinition::Unit, _) => ty.matches_type(expected),
+        _ if known_base_type(ty).is_some() => ty.matches_type(expected),
+        _ => true,
+    }
+}
+
+/// Returns true if the reference is a nominal type declared in the script
+fn is_local_nominal(reference: &Rc<RefCell<TypeReference>>) -> bool {
+    let reference = reference.borrow();
+    reference.pointer_address.is_none()
+        && reference.nominal_type_declaration.is_some()
+}
+
+/// Returns true if a value of the collection type can be used where
+/// the expected collection type is required
+fn is_collection_assignable(
+    collection: &CollectionTypeDefinition,
+    expected: &CollectionTypeDefinition,
+) -> bool {
+    match (collection, expected) {
+        (
+            CollectionTypeDefinition::List(item)
+            | CollectionTypeDefinition::ListSlice(item, _),
+            CollectionTypeDefinition::List(expected_item),
+        ) => is_assignable(item, expected_item),
+        (
+            CollectionTypeDefinition::ListSlice(item, size),
+            CollectionTypeDefinition::ListSlice(expected_item, expected_size),
+        ) => size == expected_size && is_assignable(item, expected_item),
+        (
+            CollectionTypeDefinition::Map { key, value },
+            CollectionTypeDefinition::Map {
+                key: expected_key,
+                value: expected_value,
+            },
+        ) => {
+            is_assignable(key, expected_key)
+                && is_assignable(value, expected_value)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the value type of the map field with the given key
+fn find_field<'a>(fields: &'a [(Type, Type)], key: &Type) -> Option<&'a Type> {
+    let key = structural_definition(key)?;
+    fields
+        .iter()
+        .find(|(field_key, _)| {
+            structural_definition(field_key).as_ref() == Some(&key)
+        })
+        .map(|(_, value)| value)
+}
+
+/// Collects the bindings of the generic type parameters in the expected type
+/// by matching it against the actual type,
+/// e.g. [T] matched against [1, 2] binds T to 1 | 2
+fn bind_type_parameters(
+    expected: &Type,
+    actual: &Type,
+    bindings: &mut HashMap<String, Type>,
+) {
+    if !expected.has_parameters() {
+        return;
+    }
+    match (&expected.type_definition, &actual.type_definition) {
+        (TypeDefinition::Parameter(name), _) => {
+            let bound = match bindings.remove(name) {
+                Some(existing) => union_of(vec![existing, actual.clone()]),
+                None => actual.clone(),
+            };
+            bindings.insert(name.clone(), bound);
+        }
+        (
+            TypeDefinition::Type(inner) | TypeDefinition::ImplType(inner, _),
+            _,
+        ) => bind_type_parameters(inner, actual, bindings),
+        (
+            _,
+            TypeDefinition::Type(inner) | TypeDefinition::ImplType(inner, _),
+        ) => bind_type_parameters(expected, inner, bindings),
+        (
+            TypeDefinition::Collection(CollectionTypeDefinition::Instance {
+                base,
+                arguments,
+            }),
+            TypeDefinition::Collection(CollectionTypeDefinition::Instance {
+                base: actual_base,
+                arguments: actual_arguments,
+            }),
+        ) if base == actual_base => {
+            for (argument, actual_argument) in
+                arguments.iter().zip(actual_arguments)
+            {
+                bind_type_parameters(argument, actual_argument, bindings);
+            }
+        }
+        (
+            TypeDefinition::Callable(signature),
+            TypeDefinition::Callable(actual_signature),
+        ) => {
+            for ((_, parameter), (_, actual_parameter)) in signature
+                .parameter_types
+                .iter()
+                .zip(&actual_signature.parameter_types)
+            {
+                bind_type_parameters(parameter, actual_parameter, bindings);
+            }
+            if let (Some(return_type), Some(actual_return_type)) =
+                (&signature.return_type, &actual_signature.return_type)
+            {
+                bind_type_parameters(return_type, actual_return_type, bindings);
+            }
+        }
+        (
+            TypeDefinition::Collection(
+                CollectionTypeDefinition::List(item)
+                | CollectionTypeDefinition::ListSlice(item, _),
+            ),
+            _,
+        ) => {
+            let actual_items = match (
+                structural_definition(actual),
+                collection_definition(actual),
+            ) {
+                (Some(StructuralTypeDefinition::List(actual_items)), _) => {
+                    actual_items
+                }
+                (
+                    _,
+                    Some(
+                        CollectionTypeDefinition::List(actual_item)
+                        | CollectionTypeDefinition::ListSlice(actual_item, _),
+                    ),
+                ) => vec![*actual_item],
+                _ => vec![],
+            };
+            for actual_item in &actual_items {
+                bind_type_parameters(item, actual_item, bindings);
+            }
+        }
+        _ => match (
+            structural_definition(expected),
+            structural_definition(actual),
+        ) {
+            (
+                Some(StructuralTypeDefinition::List(items)),
+                Some(StructuralTypeDefinition::List(actual_items)),
+            ) => {
+                if items.len() == actual_items.len() {
+                    for (item, actual_item) in items.iter().zip(&actual_items) {
+                        bind_type_parameters(item, actual_item, bindings);
+                    }
+                } else if let [item] = items.as_slice() {
+                    for actual_item in &actual_items {
+                        bind_type_parameters(item, actual_item, bindings);
+                    }
+                }
+            }
+            (
+                Some(StructuralTypeDefinition::Map(fields)),
+                Some(StructuralTypeDefinition::Map(actual_fields)),
+            ) => {
+                for (key, value) in &fields {
+                    if let Some(actual_value) = find_field(&actual_fields, key)
+                    {
+                        bind_type_parameters(value, actual_value, bindings);
+                    }
+                }
+            }
+            _ => {}
+        },
+    }
+}
+
+/// Replaces the generic type parameters of a callable signature with the bound types
+fn specialize_signature(
+    signature: &CallableSignature,
+    bindings: &HashMap<String, Type>,
+) -> CallableSignature {
+    let specialized =
+        Type::callable(signature.clone()).substitute_parameters(bindings);
+    let TypeDefinition::Callable(mut specialized) = specialized.type_definition
+    else {
+        unreachable!(
+            "Substituting a callable type always returns a callable type"
+        )
+    };
+    specialized.generic_parameters.clear();
+    specialized
+}
+
+/// Returns the name and type of the parameter at the given argument position,
+/// remaining arguments belong to the rest parameter
+fn parameter_at(
+    signature: &CallableSignature,
+    index: usize,
+) -> Option<(Option<String>, Type)> {
+    signature.parameter_types.get(index).cloned().or_else(|| {
+        signature
+            .rest_parameter_type
+            .clone()
+            .map(|(name, ty)| (name, *ty))
+    })
+}
+
+/// Instantiates a generic type or callable with the given type arguments,
+/// e.g. Box<integer> for type Box<T> = { value: T }
+fn instantiate_generic(
+    base: Type,
+    arguments: Vec<Type>,
+) -> Result<Type, TypeError> {
+    let parameters = match &base.type_definition {
+        TypeDefinition::Reference(reference) => {
+            reference.borrow().generic_parameters.clone()
+        }
+        _ => callable_signature(&base)
+            .map(|signature| signature.generic_parameters.clone())
+            .unwrap_or_default(),
+    };
+    if parameters.is_empty() {
+        return Err(TypeError::NotGeneric(base));
+    }
+    if parameters.len() != arguments.len() {
+        return Err(TypeError::WrongTypeArgumentCount {
+            expected: parameters.len(),
+            found: arguments.len(),
+        });
+    }
+    Ok(match callable_signature(&base) {
+        // generic callables are specialized directly
+        Some(signature) => Type::callable(specialize_signature(
+            signature,
+            &parameters.into_iter().zip(arguments).collect(),
+        )),
+        None => Type::instance(base, arguments),
+    })
+}
+
+/// Returns the signature of a callable type
+fn callable_signature(ty: &Type) -> Option<&CallableSignature> {
+    match &ty.type_definition {
+        TypeDefinition::Callable(signature) => Some(signature),
+        TypeDefinition::Type(inner) | TypeDefinition::ImplType(inner, _) => {
+            callable_signature(inner)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the structural definition of a type, looking through
+/// references and nested reference types
+fn structural_definition(ty: &Type) -> Option<StructuralTypeDefinition> {
+    match &ty.type_definition {
+        TypeDefinition::Structural(definition) => Some(definition.clone()),
+        TypeDefinition::Type(inner) | TypeDefinition::ImplType(inner, _) => {
+            structural_definition(inner)
+        }
+        TypeDefinition::Reference(reference) => {
+            structural_definition(&reference.borrow().type_value)
+        }
+        TypeDefinition::Collection(CollectionTypeDefinition::Instance {
+            ..
+        }) => structural_definition(&ty.instantiate()?),
+        _ => None,
+    }
+}
+
+/// Returns the collection definition of a type, looking through
+/// references and nested reference types
+fn collection_definition(ty: &Type) -> Option<CollectionTypeDefinition> {
+    match &ty.type_definition {
+        TypeDefinition::Collection(CollectionTypeDefinition::Instance {
+            ..
+        }) => collection_definition(&ty.instantiate()?),
+        TypeDefinition::Collection(definition) => Some(definition.clone()),
+        TypeDefinition::Type(inner) | TypeDefinition::ImplType(inner, _) => {
+            collection_definition(inner)
+        }
+        TypeDefinition::Reference(reference) => {
+            collection_definition(&reference.borrow().type_value)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the list index of a literal property key, e.g. 2 for list.2
+fn list_index(key: &Option<StructuralTypeDefinition>) -> Option<usize> {
+    match key {
+        Some(StructuralTypeDefinition::Integer(index)) => index.as_usize(),
+        Some(StructuralTypeDefinition::TypedInteger(index)) => index.as_usize(),
+        _ => None,
+    }
+}
+
+/// Returns true if the structural definition is a single literal value
+/// that can be used as a known property key
+fn is_literal_key(definition: &StructuralTypeDefinition) -> bool {
+    core::matches!(
+        definition,
+        StructuralTypeDefinition::Text(_)
+            | StructuralTypeDefinition::Integer(_)
+            | StructuralTypeDefinition::TypedInteger(_)
+            | StructuralTypeDefinition::Boolean(_)
+            | StructuralTypeDefinition::Endpoint(_)
+            | StructuralTypeDefinition::Null
+    )
+}
+
 impl TypeExpressionVisitor<SpannedTypeError> for TypeInference {
     fn visit_integer_type(
         &mut self,
@@ -403,14 +1177,10 @@ impl TypeExpressionVisitor<SpannedTypeError> for TypeInference {
     fn visit_fixed_size_list_type(
         &mut self,
         fixed_size_list: &mut FixedSizeList,
-        span: &Range<usize>,
+        _: &Range<usize>,
     ) -> TypeExpressionVisitResult<SpannedTypeError> {
-        Err(SpannedTypeError {
-            error: TypeError::Unimplemented(
-                "FixedSizeList type inference not implemented".into(),
-            ),
-            span: Some(span.clone()),
-        })
+        let item_type = self.infer_type_expression(&mut fixed_size_list.ty)?;
+        mark_type(Type::fixed_size_list(item_type, fixed_size_list.size))
     }
     fn visit_callable_type(
         &mut self,
@@ -447,6 +1217,7 @@ impl TypeExpressionVisitor<SpannedTypeError> for TypeInference {
 
         mark_type(Type::callable(CallableSignature {
             kind: callable_type.kind.clone(),
+            generic_parameters: vec![],
             parameter_types,
             rest_parameter_type,
             return_type: return_type.map(Box::new),
@@ -458,12 +1229,50 @@ impl TypeExpressionVisitor<SpannedTypeError> for TypeInference {
         generic_access: &mut GenericAccess,
         span: &Range<usize>,
     ) -> TypeExpressionVisitResult<SpannedTypeError> {
-        Err(SpannedTypeError {
-            error: TypeError::Unimplemented(
-                "GenericAccess type inference not implemented".into(),
-            ),
-            span: Some(span.clone()),
-        })
+        let base_type = self.infer_type_expression(&mut generic_access.base)?;
+        let arguments = generic_access
+            .access
+            .iter_mut()
+            .map(|argument| self.infer_type_expression(argument))
+            .collect::<Result<Vec<_>, _>>()?;
+        let instance =
+            instantiate_generic(base_type, arguments).map_err(|error| {
+                SpannedTypeError::new_with_span(error, span.clone())
+            })?;
+        mark_type(instance)
+    }
+    fn visit_impl_type(
+        &mut self,
+        impl_type: &mut ImplType,
+        _: &Range<usize>,
+    ) -> TypeExpressionVisitResult<SpannedTypeError> {
+        let base_type = self.infer_type_expression(&mut impl_type.base)?;
+        let mut markers = vec![];
+        for interface in impl_type.interfaces.iter_mut() {
+            let interface_type = self.infer_type_expression(interface)?;
+            let Some(reference) =
+                interface_type.inner_reference().filter(is_local_nominal)
+            else {
+                self.record_error(SpannedTypeError::new_with_span(
+                    TypeError::InvalidInterface(interface_type),
+                    interface.span.clone(),
+                ))?;
+                continue;
+            };
+            let required = reference.borrow().type_value.clone();
+            if !is_assignable(&base_type, &required) {
+                self.record_error(SpannedTypeError::new_with_span(
+                    TypeError::MissingInterfaceMembers {
+                        interface: reference.borrow().to_string(),
+                        expected: required,
+                        found: base_type.clone(),
+                    },
+                    interface.span.clone(),
+                ))?;
+            }
+            markers.push(reference);
+        }
+        mark_type(Type::impl_type(base_type, markers))
     }
     fn visit_literal_type(
         &mut self,
@@ -493,14 +1302,10 @@ impl TypeExpressionVisitor<SpannedTypeError> for TypeInference {
     fn visit_slice_list_type(
         &mut self,
         slice_list: &mut SliceList,
-        span: &Range<usize>,
+        _: &Range<usize>,
     ) -> TypeExpressionVisitResult<SpannedTypeError> {
-        Err(SpannedTypeError {
-            error: TypeError::Unimplemented(
-                "SliceList type inference not implemented".into(),
-            ),
-            span: Some(span.clone()),
-        })
+        let item_type = self.infer_type_expression(&mut slice_list.0)?;
+        mark_type(Type::slice_list(item_type))
     }
     fn visit_variant_access_type(
         &mut self,
@@ -597,7 +1402,13 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
         var_access: &mut VariableAccess,
         _: &Range<usize>,
     ) -> ExpressionVisitResult<SpannedTypeError> {
-        mark_type(self.variable_type(var_access.id).unwrap_or(Type::never()))
+        mark_type(
+            self.narrowed_types
+                .get(&var_access.id)
+                .cloned()
+                .or_else(|| self.variable_type(var_access.id))
+                .unwrap_or(Type::never()),
+        )
     }
 
     fn visit_property_assignment(
@@ -640,7 +1451,7 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
 
         match variable_assignment.operator {
             AssignmentOperator::Assign => {
-                if !assigned_type.matches_type(&annotated_type) {
+                if !is_assignable(&assigned_type, &annotated_type) {
                     return Err(SpannedTypeError {
                         error: TypeError::AssignmentTypeMismatch {
                             annotated_type,
@@ -654,6 +1465,8 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
                 panic!("Unsupported assignment operator");
             }
         }
+        // the assigned value is not covered by a null check anymore
+        self.narrowed_types.remove(&id);
         mark_type(annotated_type)
     }
 
@@ -734,7 +1547,7 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
             if let Some(specific) = &mut variable_declaration.type_annotation {
                 // FIXME #619 check if matches
                 let annotated_type = self.infer_type_expression(specific)?;
-                if !init_type.matches_type(&annotated_type) {
+                if !is_assignable(&init_type, &annotated_type) {
                     self.record_error(SpannedTypeError::new_with_span(
                         TypeError::AssignmentTypeMismatch {
                             annotated_type: annotated_type.clone(),
@@ -754,6 +1567,30 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
         mark_type(actual_type)
     }
 
+    fn visit_export(
+        &mut self,
+        export: &mut Export,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<SpannedTypeError> {
+        mark_type(self.infer_expression(&mut export.declaration)?)
+    }
+
+    fn visit_import(
+        &mut self,
+        _: &mut Import,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<SpannedTypeError> {
+        // types of imported variables are set during precompilation
+        mark_type(Type::unit())
+    }
+
+    fn visit_debugger(
+        &mut self,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<SpannedTypeError> {
+        mark_type(Type::unit())
+    }
+
     fn visit_binary_operation(
         &mut self,
         binary_operation: &mut BinaryOperation,
@@ -768,33 +1605,41 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
         //     right_type.base_type_reference()
         // );
 
-        match binary_operation.operator {
-            BinaryOperator::Arithmetic(op) => {
-                // if base types are the same, use that as result type
-                if let Some(left) = left_type.base_type_reference()
-                    && let Some(right) = right_type.base_type_reference()
-                    && left == right
-                {
-                    mark_type(Type::new(TypeDefinition::Reference(left), None))
-                } else {
-                    Err(SpannedTypeError {
+        let operator = binary_operation.operator;
+        match operator {
+            // the right operand is only used if the left operand is null
+            BinaryOperator::Logical(LogicalOperator::NullCoalescing) => {
+                mark_type(union_of(vec![without_null(&left_type), right_type]))
+            }
+            BinaryOperator::Logical(_) => mark_type(Type::boolean()),
+            BinaryOperator::Arithmetic(_) | BinaryOperator::Bitwise(_) => {
+                if core::matches!(
+                    left_type.type_definition,
+                    TypeDefinition::Never
+                ) || core::matches!(
+                    right_type.type_definition,
+                    TypeDefinition::Never
+                ) {
+                    return mark_type(Type::never());
+                }
+                match (
+                    known_base_type(&left_type),
+                    known_base_type(&right_type),
+                ) {
+                    // if base types are the same, use that as result type
+                    (Some(left), Some(right)) if left == right => mark_type(
+                        Type::new(TypeDefinition::Reference(left), None),
+                    ),
+                    (Some(_), Some(_)) => Err(SpannedTypeError {
                         error: TypeError::MismatchedOperands(
-                            op, left_type, right_type,
+                            operator, left_type, right_type,
                         ),
                         span: Some(span.clone()),
-                    })
+                    }),
+                    // the result depends on operand types only known at runtime
+                    _ => mark_type(Type::unknown()),
                 }
             }
-            _ => {
-                //  otherwise, use never type
-                self.record_error(SpannedTypeError {
-                    error: TypeError::Unimplemented(
-                        "Binary operation not implemented".into(),
-                    ),
-                    span: Some(span.clone()),
-                })?;
-                mark_type(Type::never())
-            }
         }
     }
 
@@ -818,7 +1663,24 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
         let inferred_type_def =
             self.infer_type_expression(&mut type_declaration.definition)?;
 
-        if type_declaration.kind.is_nominal() {
+        // interfaces list the required members as a map
+        if type_declaration.kind.is_interface()
+            && !core::matches!(
+                inferred_type_def.type_definition,
+                TypeDefinition::Structural(StructuralTypeDefinition::Map(_))
+            )
+        {
+            self.record_error(SpannedTypeError::new_with_span(
+                TypeError::InvalidInterface(inferred_type_def.clone()),
+                type_declaration.definition.span.clone(),
+            ))?;
+        }
+
+        // generic type aliases keep their reference, so that they can be
+        // instantiated with type arguments
+        if type_declaration.kind.is_nominal()
+            || !type_declaration.generic_parameters.is_empty()
+        {
             match &inferred_type_def.inner_reference() {
                 None => {
                     reference.borrow_mut().type_value = inferred_type_def;
@@ -865,30 +1727,168 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
 
     fn visit_apply(
         &mut self,
-        apply_chain: &mut Apply,
+        apply: &mut Apply,
         span: &Range<usize>,
     ) -> ExpressionVisitResult<SpannedTypeError> {
-        Err(SpannedTypeError {
-            error: TypeError::Unimplemented(
-                "ApplyChain type inference not implemented".into(),
-            ),
-            span: Some(span.clone()),
-        })
-    }
+        let callee_type = self.infer_expression(&mut apply.base)?;
+        let argument_types = apply
+            .arguments
+            .iter_mut()
+            .map(|argument| self.infer_expression(argument))
+            .collect::<Result<Vec<_>, _>>()?;
 
-    // FIXME #621 for property access we need to implement
-    // apply chain access on type container level for structural types
-    fn visit_property_access(
-        &mut self,
-        property_access: &mut PropertyAccess,
-        span: &Range<usize>,
-    ) -> ExpressionVisitResult<SpannedTypeError> {
-        Err(SpannedTypeError {
-            error: TypeError::Unimplemented(
-                "PropertyAccess type inference not implemented".into(),
-            ),
-            span: Some(span.clone()),
-        })
+        // applying a type to values creates a value of that type
+        if self.is_type_expression(&apply.base) {
+            if let Some(reference) = callee_type.inner_reference()
+                && reference.borrow().pointer_address.is_none()
+                && let Some(declaration) =
+                    reference.borrow().nominal_type_declaration.clone()
+            {
+                // local nominal types are constructed from a single value
+                // matching their definition
+                if argument_types.len() != 1 {
+                    self.record_error(SpannedTypeError::new_with_span(
+                        TypeError::WrongArgumentCount {
+                            expected: 1,
+                            found: argument_types.len(),
+                            variadic: false,
+                        },
+                        span.clone(),
+                    ))?;
+                } else {
+                    let definition = reference.borrow().type_value.clone();
+                    if !is_assignable(&argument_types[0], &definition) {
+                        self.record_error(SpannedTypeError::new_with_span(
+                            TypeError::InvalidConstructorArgument {
+                                type_name: declaration.to_string(),
+                                expected: definition,
+                                found: argument_types[0].clone(),
+                            },
+                            apply.arguments[0].span.clone(),
+                        ))?;
+                    }
+                }
+            }
+            return mark_type(callee_type);
+        }
+        if core::matches!(
+            callee_type.type_definition,
+            TypeDefinition::Never | TypeDefinition::Unknown
+        ) {
+            return mark_type(callee_type);
+        }
+        let Some(signature) = callable_signature(&callee_type).cloned() else {
+            return self.record_error(SpannedTypeError::new_with_span(
+                TypeError::NotCallable(callee_type),
+                apply.base.span.clone(),
+            ));
+        };
+
+        // bind generic type parameters to the argument types
+        let signature = if signature.generic_parameters.is_empty() {
+            signature
+        } else {
+            let mut bindings = HashMap::new();
+            for (index, argument_type) in argument_types.iter().enumerate() {
+                if let Some((_, parameter_type)) =
+                    parameter_at(&signature, index)
+                {
+                    bind_type_parameters(
+                        &parameter_type,
+                        argument_type,
+                        &mut bindings,
+                    );
+                }
+            }
+            // parameters that can not be inferred from the arguments are unknown
+            for parameter in &signature.generic_parameters {
+                bindings
+                    .entry(parameter.clone())
+                    .or_insert_with(Type::unknown);
+            }
+            specialize_signature(&signature, &bindings)
+        };
+
+        let parameter_count = signature.parameter_types.len();
+        let variadic = signature.rest_parameter_type.is_some();
+        if argument_types.len() < parameter_count
+            || (!variadic && argument_types.len() > parameter_count)
+        {
+            self.record_error(SpannedTypeError::new_with_span(
+                TypeError::WrongArgumentCount {
+                    expected: parameter_count,
+                    found: argument_types.len(),
+                    variadic,
+                },
+                span.clone(),
+            ))?;
+        }
+
+        for (index, (argument, argument_type)) in
+            apply.arguments.iter().zip(argument_types).enumerate()
+        {
+            let Some((name, expected_type)) = parameter_at(&signature, index)
+            else {
+                break;
+            };
+            if !is_assignable(&argument_type, &expected_type) {
+                self.record_error(SpannedTypeError::new_with_span(
+                    TypeError::ArgumentTypeMismatch {
+                        parameter: name.unwrap_or_else(|| index.to_string()),
+                        expected: expected_type,
+                        found: argument_type,
+                    },
+                    argument.span.clone(),
+                ))?;
+            }
+        }
+
+        if let Some(yeet_type) = &signature.yeet_type {
+            self.record_yeet(*yeet_type.clone());
+        }
+
+        mark_type(
+            signature
+                .return_type
+                .map(|return_type| *return_type)
+                .unwrap_or_else(Type::unknown),
+        )
+    }
+
+    fn visit_property_access(
+        &mut self,
+        property_access: &mut PropertyAccess,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<SpannedTypeError> {
+        let base_type = self.infer_expression(&mut property_access.base)?;
+        let property_type =
+            self.infer_expression(&mut property_access.property)?;
+        let property_span = property_access.property.span.clone();
+
+        // optional chaining on a nullable base results in null if the base is null
+        if property_access.is_optional_chain() && is_nullable(&base_type) {
+            let base_type = without_null(&base_type);
+            if core::matches!(base_type.type_definition, TypeDefinition::Never)
+            {
+                return mark_type(Type::null());
+            }
+            return Ok(
+                match self.infer_property_type(
+                    base_type,
+                    property_type,
+                    property_span,
+                )? {
+                    VisitAction::SetTypeSkipChildren(ty) => {
+                        VisitAction::SetTypeSkipChildren(union_of(vec![
+                            ty,
+                            Type::null(),
+                        ]))
+                    }
+                    action => action,
+                },
+            );
+        }
+        self.infer_property_type(base_type, property_type, property_span)
     }
 
     fn visit_generic_instantiation(
@@ -896,12 +1896,20 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
         generic_instantiation: &mut GenericInstantiation,
         span: &Range<usize>,
     ) -> ExpressionVisitResult<SpannedTypeError> {
-        Err(SpannedTypeError {
-            error: TypeError::Unimplemented(
-                "GenericInstantiation type inference not implemented".into(),
-            ),
-            span: Some(span.clone()),
-        })
+        let base_type =
+            self.infer_expression(&mut generic_instantiation.base)?;
+        let arguments = generic_instantiation
+            .generic_arguments
+            .iter_mut()
+            .map(|argument| self.infer_type_expression(argument))
+            .collect::<Result<Vec<_>, _>>()?;
+        match instantiate_generic(base_type, arguments) {
+            Ok(instance) => mark_type(instance),
+            Err(error) => self.record_error(SpannedTypeError::new_with_span(
+                error,
+                span.clone(),
+            )),
+        }
     }
 
     fn visit_comparison_operation(
@@ -911,17 +1919,45 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
     ) -> ExpressionVisitResult<SpannedTypeError> {
         mark_type(Type::boolean())
     }
+    fn visit_type_cast(
+        &mut self,
+        type_cast: &mut TypeCast,
+        span: &Range<usize>,
+    ) -> ExpressionVisitResult<SpannedTypeError> {
+        let value_type = self.infer_expression(&mut type_cast.expression)?;
+        let target_type = self.infer_type_expression(&mut type_cast.target)?;
+        if !is_castable(&value_type, &target_type) {
+            self.record_error(SpannedTypeError::new_with_span(
+                TypeError::InvalidCast {
+                    from: value_type,
+                    to: target_type.clone(),
+                },
+                span.clone(),
+            ))?;
+        }
+        mark_type(target_type)
+    }
     fn visit_conditional(
         &mut self,
         conditional: &mut Conditional,
-        span: &Range<usize>,
+        _: &Range<usize>,
     ) -> ExpressionVisitResult<SpannedTypeError> {
-        Err(SpannedTypeError {
-            error: TypeError::Unimplemented(
-                "Conditional type inference not implemented".into(),
-            ),
-            span: Some(span.clone()),
-        })
+        self.infer_expression(&mut conditional.condition)?;
+        let narrowing = null_check(&conditional.condition);
+
+        let then_type = self.infer_narrowed(
+            &mut conditional.then_branch,
+            narrowing.filter(|(_, is_not_null)| *is_not_null),
+        )?;
+        // without an else branch, the conditional evaluates to null
+        let else_type = match &mut conditional.else_branch {
+            Some(else_branch) => self.infer_narrowed(
+                else_branch,
+                narrowing.filter(|(_, is_not_null)| !*is_not_null),
+            )?,
+            None => Type::null(),
+        };
+        mark_type(union_of(vec![then_type, else_type]))
     }
     fn visit_create_mut(
         &mut self,
@@ -963,6 +1999,44 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
         mark_type(deref_type)
     }
 
+    fn visit_yeet(
+        &mut self,
+        yeet: &mut Yeet,
+        span: &Range<usize>,
+    ) -> ExpressionVisitResult<SpannedTypeError> {
+        let value_type = self.infer_expression(&mut yeet.expression)?;
+        if is_assignable(&value_type, &Type::error()) {
+            self.record_yeet(value_type);
+        } else if is_assignable(&value_type, &Type::text()) {
+            // text values are converted to errors with the text as message
+            self.record_yeet(Type::error());
+        } else {
+            self.record_error(SpannedTypeError::new_with_span(
+                TypeError::InvalidYeetValue(value_type),
+                span.clone(),
+            ))?;
+        }
+        // yeet never produces a value and is assignable to any type
+        mark_type(Type::new(TypeDefinition::Never, None))
+    }
+
+    fn visit_try(
+        &mut self,
+        r#try: &mut Try,
+        _: &Range<usize>,
+    ) -> ExpressionVisitResult<SpannedTypeError> {
+        self.yeet_scopes.push(vec![]);
+        let value_type = self.infer_expression(&mut r#try.expression);
+        let yeeted_types = self.yeet_scopes.pop().unwrap_or_default();
+        // any runtime error inside the try expression is recovered as an error value
+        mark_type(union_of(
+            once(value_type?)
+                .chain(yeeted_types)
+                .chain(once(Type::error()))
+                .collect(),
+        ))
+    }
+
     fn visit_callable_declaration(
         &mut self,
         callable_declaration: &mut CallableDeclaration,
@@ -982,9 +2056,11 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
                 None
             };
 
+        self.yeet_scopes.push(vec![]);
         let inferred_return_type = self
             .infer_expression(&mut callable_declaration.body)
             .unwrap_or(Type::never());
+        let yeeted_types = self.yeet_scopes.pop().unwrap_or_default();
 
         let rest_parameter_type = if let Some((name, rest_param)) =
             &mut callable_declaration.rest_parameter
@@ -1010,6 +2086,7 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
 
         let signature = CallableSignature {
             kind: callable_declaration.kind.clone(),
+            generic_parameters: callable_declaration.generic_parameters.clone(),
             parameter_types: parameters,
             rest_parameter_type,
             return_type: annotated_return_type,
@@ -1021,7 +2098,7 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
         // If they don't match, record an error
         // TODO #622: improve
         if let Some(annotated_return_type) = &signature.return_type
-            && !inferred_return_type.matches_type(annotated_return_type)
+            && !is_assignable(&inferred_return_type, annotated_return_type)
         {
             self.record_error(SpannedTypeError {
                 error: TypeError::AssignmentTypeMismatch {
@@ -1032,6 +2109,22 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
             })?;
         }
 
+        // errors yeeted in the body must be declared with yeets
+        let unhandled_yeets = yeeted_types
+            .into_iter()
+            .filter(|yeeted_type| {
+                !signature.yeet_type.as_ref().is_some_and(|yeet_type| {
+                    is_assignable(yeeted_type, yeet_type)
+                })
+            })
+            .collect::<Vec<_>>();
+        if !unhandled_yeets.is_empty() {
+            self.record_error(SpannedTypeError {
+                error: TypeError::UnhandledYeet(union_of(unhandled_yeets)),
+                span: Some(span.clone()),
+            })?;
+        }
+
         // Use the annotated type despite the mismatch
         mark_type(Type::callable(signature))
     }
@@ -1048,15 +2141,50 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
                 LogicalUnaryOperator::Not => Type::boolean(),
             },
             UnaryOperator::Arithmetic(_) | UnaryOperator::Bitwise(_) => {
-                inner.base_type().unwrap_or(Type::never())
+                match &inner.type_definition {
+                    TypeDefinition::Never => Type::never(),
+                    _ => known_base_type(&inner)
+                        .map(|base| {
+                            Type::reference(
+                                base,
+                                ReferenceMutability::Immutable,
+                            )
+                        })
+                        .unwrap_or_else(Type::unknown),
+                }
             }
-            UnaryOperator::Reference(_) => return Err(SpannedTypeError {
-                error: TypeError::Unimplemented(
-                    "Unary reference operator type inference not implemented"
-                        .into(),
-                ),
-                span: Some(span.clone()),
-            }),
+            UnaryOperator::Reference(op) => match op {
+                ReferenceUnaryOperator::CreateRef
+                | ReferenceUnaryOperator::CreateRefMut => {
+                    let reference = match inner.type_definition {
+                        TypeDefinition::Reference(reference) => reference,
+                        _ => Rc::new(RefCell::new(TypeReference::anonymous(
+                            inner, None,
+                        ))),
+                    };
+                    Type::reference(
+                        reference,
+                        if op == ReferenceUnaryOperator::CreateRefMut {
+                            ReferenceMutability::Mutable
+                        } else {
+                            ReferenceMutability::Immutable
+                        },
+                    )
+                }
+                ReferenceUnaryOperator::Deref => {
+                    match inner.inner_reference() {
+                        Some(reference) => {
+                            reference.borrow().type_value.clone()
+                        }
+                        None => {
+                            return self.record_error(SpannedTypeError {
+                                error: TypeError::InvalidDerefType(inner),
+                                span: Some(span.clone()),
+                            });
+                        }
+                    }
+                }
+            },
         })
     }
     fn visit_variant_access(
@@ -1182,10 +2310,18 @@ impl ExpressionVisitor<SpannedTypeError> for TypeInference {
         span: &Range<usize>,
     ) -> ExpressionVisitResult<SpannedTypeError> {
         match pointer_address {
-            PointerAddress::Internal(_) => mark_type(get_core_lib_type(
-                CoreLibPointerId::try_from(&pointer_address.to_owned())
-                    .unwrap(),
-            )),
+            PointerAddress::Internal(_) => {
+                let id =
+                    CoreLibPointerId::try_from(&pointer_address.to_owned())
+                        .unwrap();
+                match get_core_lib_value(id.clone()) {
+                    // core lib values such as functions have their actual type
+                    Some(value) if !value.is_type() => {
+                        mark_type(value.actual_container_type())
+                    }
+                    _ => mark_type(get_core_lib_type(id)),
+                }
+            }
             _ => Err(SpannedTypeError {
                 error: TypeError::Unimplemented(
                     "GetReference type inference not implemented".into(),
@@ -1481,6 +2617,7 @@ mod tests {
             res,
             Type::callable(CallableSignature {
                 kind: CallableKind::Function,
+                generic_parameters: vec![],
                 parameter_types: vec![
                     (
                         Some("a".to_string()),
@@ -1510,6 +2647,7 @@ mod tests {
             res,
             Type::callable(CallableSignature {
                 kind: CallableKind::Function,
+                generic_parameters: vec![],
                 parameter_types: vec![
                     (
                         Some("a".to_string()),
@@ -1527,6 +2665,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn infer_core_lib_function_type() {
+        let res = infer_from_script("print");
+        assert_eq!(
+            res,
+            Type::callable(CallableSignature {
+                kind: CallableKind::Function,
+                generic_parameters: vec![],
+                parameter_types: vec![],
+                rest_parameter_type: Some((
+                    Some("values".to_string()),
+                    Box::new(Type::unknown())
+                )),
+                return_type: None,
+                yeet_type: None,
+            })
+        );
+    }
+
     #[test]
     fn infer_literal_types() {
         assert_eq!(
@@ -2141,4 +3298,558 @@ mod tests {
             TypeError::AssignmentToImmutableValue { .. }
         );
     }
+
+    #[test]
+    fn infer_call_result_from_signature() {
+        let inferred = infer_from_script(
+            "const add = function add(x: integer, y: integer) -> integer (42); add(1, 2)",
+        );
+        assert_eq!(
+            inferred,
+            get_core_lib_type(CoreLibPointerId::Integer(None))
+        );
+
+        // no return type annotation
+        let inferred =
+            infer_from_script("const f = function f(x: integer) (42); f(1)");
+        assert_eq!(inferred, Type::unknown());
+    }
+
+    #[test]
+    fn call_with_wrong_argument_count() {
+        let errors = errors_for_script(
+            "const f = function f(x: integer) -> integer (42); f(1, 2)",
+        );
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::WrongArgumentCount {
+                expected: 1,
+                found: 2,
+                variadic: false
+            }
+        );
+
+        // rest parameters accept any number of additional arguments
+        assert_eq!(infer_from_script("print(1, 2, 3)"), Type::unknown());
+    }
+
+    #[test]
+    fn call_with_argument_type_mismatch() {
+        let src = r#"const f = function f(x: integer) -> integer (42); f("a")"#;
+        let errors = errors_for_script(src);
+        let error = errors.first().unwrap();
+        assert_matches!(
+            &error.error,
+            TypeError::ArgumentTypeMismatch { parameter, .. } if parameter == "x"
+        );
+        assert_eq!(error.span, Some(52..55));
+    }
+
+    #[test]
+    fn nominal_type_constructor() {
+        let script = "type User = {x: integer}; User({x: 1})";
+        let result = infer_from_script(script);
+        assert_eq!(result.to_string(), "User");
+
+        let src = r#"type User = {x: integer}; User({x: "a"})"#;
+        let errors = errors_for_script(src);
+        let error = errors.first().unwrap();
+        assert_matches!(
+            &error.error,
+            TypeError::InvalidConstructorArgument { type_name, .. } if type_name == "User"
+        );
+        assert_eq!(error.span, Some(31..39));
+    }
+
+    #[test]
+    fn interface_implementation() {
+        let src = r#"
+        interface Named = {name: text};
+        type User = {name: text, age: integer} + Named;
+        const user: Named = User({name: "a", age: 1});
+        user
+        "#;
+        assert_eq!(infer_from_script(src).to_string(), "Named");
+
+        // nominal types without the marker are not assignable to the interface
+        let src = r#"
+        interface Named = {name: text};
+        type Pet = {name: text};
+        const pet: Named = Pet({name: "b"});
+        "#;
+        assert_matches!(
+            errors_for_script(src).first().unwrap().error,
+            TypeError::AssignmentTypeMismatch { .. }
+        );
+
+        let src = r#"
+        interface Named = {name: text};
+        type User = {age: integer} + Named;
+        "#;
+        assert_matches!(
+            &errors_for_script(src).first().unwrap().error,
+            TypeError::MissingInterfaceMembers { interface, .. } if interface == "Named"
+        );
+
+        let src = "type User = {age: integer} + integer;";
+        assert_matches!(
+            errors_for_script(src).first().unwrap().error,
+            TypeError::InvalidInterface(_)
+        );
+    }
+
+    #[test]
+    fn type_cast() {
+        assert_eq!(infer_from_script("42 as text"), Type::text());
+        assert_eq!(
+            infer_from_script("300 as integer/u8"),
+            get_core_lib_type(CoreLibPointerId::Integer(Some(
+                IntegerTypeVariant::U8
+            )))
+        );
+
+        let errors = errors_for_script("true as endpoint");
+        let error = errors.first().unwrap();
+        assert_matches!(error.error, TypeError::InvalidCast { .. });
+        assert_eq!(error.span, Some(0..16));
+
+        let errors = errors_for_script("[1, 2] as integer");
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::InvalidCast { .. }
+        );
+    }
+
+    #[test]
+    fn call_of_non_callable() {
+        let errors = errors_for_script("const a = 1; a(2)");
+        let error = errors.first().unwrap();
+        assert_matches!(error.error, TypeError::NotCallable(_));
+        assert_eq!(error.span, Some(13..14));
+    }
+
+    #[test]
+    fn infer_property_access() {
+        let inferred = infer_from_script(r#"const a = {b: 42, c: "x"}; a.b"#);
+        assert_eq!(
+            inferred,
+            Type::structural(StructuralTypeDefinition::Integer(Integer::from(
+                42
+            )))
+        );
+
+        let inferred =
+            infer_from_script(r#"const a = {b: {c: [1, "x"]}}; a.b.c.(1)"#);
+        assert_eq!(
+            inferred,
+            Type::structural(StructuralTypeDefinition::Text("x".into()))
+        );
+    }
+
+    #[test]
+    fn missing_property() {
+        let errors = errors_for_script("const a = {b: 42}; a.c");
+        let error = errors.first().unwrap();
+        assert_matches!(
+            &error.error,
+            TypeError::PropertyNotFound(_, property) if property == "\"c\""
+        );
+        assert_eq!(error.span, Some(21..22));
+
+        let errors = errors_for_script("const a = [1, 2]; a.(2)");
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::IndexOutOfBounds(_, 2)
+        );
+    }
+
+    #[test]
+    fn infer_conditional() {
+        let inferred = infer_from_script(r#"if (true) (1) else ("a")"#);
+        assert_eq!(
+            inferred,
+            Type::union(vec![
+                Type::structural(StructuralTypeDefinition::Integer(
+                    Integer::from(1)
+                )),
+                Type::structural(StructuralTypeDefinition::Text("a".into())),
+            ])
+        );
+
+        // without else branch
+        let inferred = infer_from_script("if (true) (1)");
+        assert_eq!(
+            inferred,
+            Type::union(vec![
+                Type::structural(StructuralTypeDefinition::Integer(
+                    Integer::from(1)
+                )),
+                Type::null(),
+            ])
+        );
+
+        // same type in both branches
+        let inferred = infer_from_script("if (false) (1) else (1)");
+        assert_eq!(
+            inferred,
+            Type::structural(StructuralTypeDefinition::Integer(Integer::from(
+                1
+            )))
+        );
+    }
+
+    #[test]
+    fn infer_optional_types() {
+        let nullable_integer = Type::union(vec![
+            Type::integer(),
+            Type::structural(StructuralTypeDefinition::Null),
+        ]);
+
+        // null is removed from the left operand of null coalescing
+        let inferred =
+            infer_from_script(r#"const x: integer? = null; x ?? "none""#);
+        assert_eq!(
+            inferred,
+            Type::union(vec![
+                Type::integer(),
+                Type::structural(StructuralTypeDefinition::Text("none".into())),
+            ])
+        );
+
+        // optional chaining on a nullable base may result in null
+        let inferred = infer_from_script("const x: {a: integer}? = null; x?.a");
+        assert_eq!(inferred, Type::union(vec![Type::integer(), Type::null()]));
+
+        // the following accesses of an optional chain are short-circuited
+        let inferred =
+            infer_from_script("const x: {a: {b: integer}}? = null; x?.a.b");
+        assert_eq!(inferred, Type::union(vec![Type::integer(), Type::null()]));
+
+        // x is narrowed to integer inside the then branch
+        let inferred = infer_from_script(
+            r#"const x: integer? = null; if (x != null) (x) else ("none")"#,
+        );
+        assert_eq!(
+            inferred,
+            Type::union(vec![
+                Type::integer(),
+                Type::structural(StructuralTypeDefinition::Text("none".into())),
+            ])
+        );
+
+        // x is narrowed to integer inside the else branch
+        let inferred = infer_from_script(
+            "const x: integer? = null; if (null == x) (0) else (x)",
+        );
+        assert_eq!(
+            inferred,
+            Type::union(vec![
+                Type::structural(StructuralTypeDefinition::Integer(
+                    Integer::from(0)
+                )),
+                Type::integer(),
+            ])
+        );
+
+        // the narrowing does not leak out of the branch
+        let inferred = infer_from_script(
+            "const x: integer? = null; if (x != null) (x); x",
+        );
+        assert_eq!(inferred, nullable_integer);
+
+        // the declared type is still used for assignments inside the branch
+        ast_for_script("var x: integer? = 1; if (x != null) (x = null)");
+        let inferred = infer_from_script(
+            "var x: integer? = 1; if (x != null) (x = null; x) else (1)",
+        );
+        assert_eq!(
+            inferred,
+            Type::union(vec![
+                Type::integer(),
+                Type::structural(StructuralTypeDefinition::Null),
+                Type::structural(StructuralTypeDefinition::Integer(
+                    Integer::from(1)
+                )),
+            ])
+        );
+    }
+
+    #[test]
+    fn infer_logical_and_bitwise_operations() {
+        assert_eq!(infer_from_script("true and false"), Type::boolean());
+        assert_eq!(infer_from_script("1 & 2"), Type::integer());
+    }
+
+    #[test]
+    fn infer_generic_type_instance() {
+        let src = r#"
+        type Box<T> = { value: T };
+        const b: Box<integer> = { value: 42 };
+        b
+        "#;
+        let inferred = infer_from_script(src);
+        assert_eq!(inferred.to_string(), "Box<integer>");
+        ast_for_script(src);
+        assert_eq!(
+            inferred.instantiate().unwrap(),
+            Type::structural(StructuralTypeDefinition::Map(vec![(
+                Type::structural(StructuralTypeDefinition::Text(
+                    "value".to_string().into()
+                )),
+                Type::integer(),
+            )]))
+        );
+
+        // properties of instances are substituted
+        let src = r#"
+        type Pair<A, B> = { first: A, second: B };
+        const p: Pair<integer, text> = { first: 1, second: "a" };
+        p.second
+        "#;
+        assert_eq!(infer_from_script(src), Type::text());
+    }
+
+    #[test]
+    fn generic_type_instance_mismatch() {
+        let src = r#"
+        type Box<T> = { value: T };
+        const b: Box<integer> = { value: "x" };
+        "#;
+        let errors = errors_for_script(src);
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::AssignmentTypeMismatch { .. }
+        );
+    }
+
+    #[test]
+    fn generic_type_argument_errors() {
+        let src = r#"
+        type Box<T> = { value: T };
+        const b: Box<integer, text> = { value: 1 };
+        "#;
+        let errors = errors_for_script(src);
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::WrongTypeArgumentCount {
+                expected: 1,
+                found: 2
+            }
+        );
+
+        let src = r#"
+        type A = integer;
+        const b: A<integer> = 1;
+        "#;
+        let errors = errors_for_script(src);
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::NotGeneric(_)
+        );
+    }
+
+    #[test]
+    fn infer_type_arguments_of_generic_call() {
+        let src = r#"
+        const first = function first<T>(list: [T]) -> T (list.(0));
+        first([1, 2])
+        "#;
+        assert_eq!(
+            infer_from_script(src),
+            Type::union(vec![
+                Type::structural(StructuralTypeDefinition::Integer(
+                    Integer::from(1)
+                )),
+                Type::structural(StructuralTypeDefinition::Integer(
+                    Integer::from(2)
+                )),
+            ])
+        );
+
+        // type arguments are inferred from instances of generic types
+        let src = r#"
+        type Box<T> = { value: T };
+        const unbox = function unbox<T>(box: Box<T>) -> T (box.value);
+        const b: Box<text> = { value: "a" };
+        unbox(b)
+        "#;
+        assert_eq!(infer_from_script(src), Type::text());
+
+        // type parameters that can not be inferred are unknown
+        let src = r#"
+        const make = function make<T>() -> T (42);
+        make()
+        "#;
+        assert_eq!(infer_from_script(src), Type::unknown());
+    }
+
+    #[test]
+    fn explicit_type_arguments_of_generic_call() {
+        let src = r#"
+        const first = function first<T>(list: [T]) -> T (list.(0));
+        first<integer>([1, 2])
+        "#;
+        assert_eq!(infer_from_script(src), Type::integer());
+
+        let src = r#"
+        const first = function first<T>(list: [T]) -> T (list.(0));
+        first<text>([1, 2])
+        "#;
+        let errors = errors_for_script(src);
+        assert_matches!(
+            &errors.first().unwrap().error,
+            TypeError::ArgumentTypeMismatch { parameter, .. } if parameter == "list"
+        );
+    }
+
+    #[test]
+    fn infer_list_types() {
+        let src = r#"
+        const a: [integer; 2] = [1, 2];
+        a
+        "#;
+        assert_eq!(
+            infer_from_script(src),
+            Type::fixed_size_list(Type::integer(), 2)
+        );
+
+        let src = r#"
+        const a: text[] = ["a", "b", "c"];
+        a.(1)
+        "#;
+        assert_eq!(infer_from_script(src), Type::text());
+
+        // fixed size lists are assignable to slice lists of the same item type
+        let src = r#"
+        const a: [integer; 2] = [1, 2];
+        const b: integer[] = a;
+        b
+        "#;
+        assert_eq!(infer_from_script(src), Type::slice_list(Type::integer()));
+        // valid list assignments do not produce type errors
+        ast_for_script(src);
+
+        // item types of list parameters are inferred
+        let src = r#"
+        const first = function first<T>(list: T[]) -> T (list.(0));
+        first(["a", "a"])
+        "#;
+        assert_eq!(
+            infer_from_script(src),
+            Type::structural(StructuralTypeDefinition::Text("a".into()))
+        );
+    }
+
+    #[test]
+    fn restored_literal_types() {
+        let ast = Parser::parse_with_default_options("[1, 2]; 3").unwrap();
+        let mut scope_stack = PrecompilerScopeStack::default();
+        let ast_metadata = Rc::new(RefCell::new(AstMetadata::default()));
+        let mut res =
+            precompile_ast_simple_error(ast, &mut scope_stack, ast_metadata)
+                .expect("Precompilation failed");
+        let DatexExpressionData::Statements(statements) = &mut res.ast.data
+        else {
+            panic!("Expected statements");
+        };
+        // types that are not restored by the incremental cache are inferred again
+        statements.statements[1].ty = Some(Type::text());
+        // restored types of data literals are not inferred again
+        res.metadata
+            .borrow_mut()
+            .restored_types
+            .insert(0..6, Type::slice_list(Type::text()));
+
+        assert_eq!(
+            infer_expression_type_simple_error(&mut res).unwrap(),
+            Type::structural(StructuralTypeDefinition::Integer(Integer::from(
+                3
+            )))
+        );
+        let DatexExpressionData::Statements(statements) = &res.ast.data else {
+            panic!("Expected statements");
+        };
+        assert_eq!(
+            statements.statements[0].ty,
+            Some(Type::slice_list(Type::text()))
+        );
+    }
+
+    #[test]
+    fn list_type_errors() {
+        // wrong length
+        let errors = errors_for_script("const a: [integer; 3] = [1, 2];");
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::AssignmentTypeMismatch { .. }
+        );
+
+        // wrong item type
+        let errors = errors_for_script(r#"const a: integer[] = [1, "x"];"#);
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::AssignmentTypeMismatch { .. }
+        );
+
+        // slice lists have no known length
+        let errors = errors_for_script(
+            "const a: integer[] = [1, 2]; const b: [integer; 2] = a;",
+        );
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::AssignmentTypeMismatch { .. }
+        );
+
+        let errors = errors_for_script("const a: [integer; 2] = [1, 2]; a.(2)");
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::IndexOutOfBounds(_, 2)
+        );
+    }
+
+    #[test]
+    fn infer_try_types() {
+        // try results in the value type or an error value
+        let inferred = infer_from_script("try 1");
+        assert_eq!(
+            inferred,
+            Type::union(vec![
+                Type::structural(StructuralTypeDefinition::Integer(
+                    Integer::from(1)
+                )),
+                Type::error(),
+            ])
+        );
+
+        // yeeted text is converted to an error value
+        assert_eq!(infer_from_script(r#"try yeet "a""#), Type::error());
+
+        // declared yeet types do not need to be handled
+        ast_for_script(
+            r#"const load = function load() -> integer yeets error (yeet "a"); load()"#,
+        );
+    }
+
+    #[test]
+    fn yeet_type_errors() {
+        let errors = errors_for_script("yeet 42");
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::InvalidYeetValue(_)
+        );
+
+        // function yeets without declaring a yeet type
+        let errors = errors_for_script(
+            r#"const load = function load() -> integer (yeet "a");"#,
+        );
+        assert_matches!(
+            errors.first().unwrap().error,
+            TypeError::UnhandledYeet(_)
+        );
+
+        // handled yeets do not need to be declared
+        ast_for_script(
+            r#"const load = function load() -> integer ((try yeet "a"); 1);"#,
+        );
+    }
 }
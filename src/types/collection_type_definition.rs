@@ -1,4 +1,6 @@
 use crate::stdlib::boxed::Box;
+use crate::stdlib::string::{String, ToString};
+use crate::stdlib::vec::Vec;
 use crate::values::core_values::r#type::Type;
 use core::fmt::Display;
 use core::prelude::rust_2024::*;
@@ -13,7 +15,16 @@ pub enum CollectionTypeDefinition {
     ListSlice(Box<Type>, usize),
 
     // e.g. {string: integer}
-    Map { key: Box<Type>, value: Box<Type> },
+    Map {
+        key: Box<Type>,
+        value: Box<Type>,
+    },
+
+    // e.g. Box<integer> for type Box<T> = { value: T }
+    Instance {
+        base: Box<Type>,
+        arguments: Vec<Type>,
+    },
 }
 
 impl Display for CollectionTypeDefinition {
@@ -26,6 +37,11 @@ impl Display for CollectionTypeDefinition {
             CollectionTypeDefinition::Map { key, value } => {
                 core::write!(f, "Map<{}, {}>", key, value)
             }
+            CollectionTypeDefinition::Instance { base, arguments } => {
+                let arguments: Vec<String> =
+                    arguments.iter().map(|t| t.to_string()).collect();
+                core::write!(f, "{}<{}>", base, arguments.join(", "))
+            }
         }
     }
 }
@@ -8,7 +8,6 @@ use crate::stdlib::vec::Vec;
 use crate::stdlib::{cell::RefCell, hash::Hash, rc::Rc};
 use crate::values::core_values::callable::CallableSignature;
 use crate::values::core_values::r#type::Type;
-use crate::values::pointer::PointerAddress;
 use crate::{
     traits::structural_eq::StructuralEq,
     types::{
@@ -39,11 +38,12 @@ pub enum TypeDefinition {
 
     /// innerType + Marker1 + Marker2
     /// A special type that behaves like `innerType` but is marked with additional
-    /// pointer addresses that represent meta information about the type.
+    /// interface type references that represent meta information about the type.
     /// The type is treated as equivalent to `innerType` for most operations,
-    /// but the impl markers can be used to enforce additional constraints during
-    /// type checking or runtime behavior.
-    ImplType(Box<Type>, Vec<PointerAddress>),
+    /// but the impl markers are used to enforce additional constraints during
+    /// type checking (the inner type must provide all members required by the
+    /// interfaces) and for runtime `implements` checks.
+    ImplType(Box<Type>, Vec<Rc<RefCell<TypeReference>>>),
 
     /// NOTE: all the types below can never exist as actual types of a runtime value - they are only
     /// relevant for type space definitions and type checking.
@@ -62,6 +62,11 @@ pub enum TypeDefinition {
 
     /// unknown type
     Unknown,
+
+    /// generic type parameter, e.g. T in type Box<T> = { value: T }
+    /// Type parameters are substituted with the actual type arguments when
+    /// a generic type is instantiated or a generic callable is applied.
+    Parameter(String),
 }
 
 impl Hash for TypeDefinition {
@@ -83,6 +88,7 @@ impl Hash for TypeDefinition {
             TypeDefinition::Unit => 0_u8.hash(state),
             TypeDefinition::Unknown => 1_u8.hash(state),
             TypeDefinition::Never => 2_u8.hash(state),
+            TypeDefinition::Parameter(name) => name.hash(state),
 
             TypeDefinition::Union(types) => {
                 for ty in types {
@@ -96,6 +102,7 @@ impl Hash for TypeDefinition {
             }
             TypeDefinition::Callable(callable) => {
                 callable.kind.hash(state);
+                callable.generic_parameters.hash(state);
                 for (name, ty) in callable.parameter_types.iter() {
                     name.hash(state);
                     ty.hash(state);
@@ -107,7 +114,7 @@ impl Hash for TypeDefinition {
             TypeDefinition::ImplType(ty, impls) => {
                 ty.hash(state);
                 for marker in impls {
-                    marker.hash(state);
+                    marker.borrow().hash(state);
                 }
             }
         }
@@ -126,10 +133,11 @@ impl Display for TypeDefinition {
             TypeDefinition::Unit => core::write!(f, "()"),
             TypeDefinition::Unknown => core::write!(f, "unknown"),
             TypeDefinition::Never => core::write!(f, "never"),
+            TypeDefinition::Parameter(name) => core::write!(f, "{}", name),
             TypeDefinition::ImplType(ty, impls) => {
                 core::write!(f, "{}", ty)?;
                 for marker in impls {
-                    core::write!(f, " + {}", marker)?;
+                    core::write!(f, " + {}", marker.borrow())?;
                 }
                 Ok(())
             }
@@ -184,10 +192,18 @@ impl Display for TypeDefinition {
                     None => "".to_string(),
                 };
 
+                let generic_parameters_code =
+                    if callable.generic_parameters.is_empty() {
+                        "".to_string()
+                    } else {
+                        format!("<{}>", callable.generic_parameters.join(", "))
+                    };
+
                 core::write!(
                     f,
-                    "{} ({}){}{}",
+                    "{}{} ({}){}{}",
                     callable.kind,
+                    generic_parameters_code,
                     params_code.join(", "),
                     return_type_code,
                     yeet_type_code
@@ -262,8 +278,39 @@ impl TypeDefinition {
         TypeDefinition::Callable(signature)
     }
 
+    /// Creates a new generic type parameter.
+    pub fn parameter(name: impl Into<String>) -> Self {
+        TypeDefinition::Parameter(name.into())
+    }
+
+    /// Creates a new list type of arbitrary length, e.g. integer[]
+    pub fn slice_list(item_type: impl Into<Type>) -> Self {
+        TypeDefinition::Collection(CollectionTypeDefinition::List(Box::new(
+            item_type.into(),
+        )))
+    }
+
+    /// Creates a new list type with a fixed size, e.g. [integer; 5]
+    pub fn fixed_size_list(item_type: impl Into<Type>, size: usize) -> Self {
+        TypeDefinition::Collection(CollectionTypeDefinition::ListSlice(
+            Box::new(item_type.into()),
+            size,
+        ))
+    }
+
+    /// Creates a new instance of a generic type with the given type arguments.
+    pub fn instance(base: impl Into<Type>, arguments: Vec<Type>) -> Self {
+        TypeDefinition::Collection(CollectionTypeDefinition::Instance {
+            base: Box::new(base.into()),
+            arguments,
+        })
+    }
+
     /// Creates a new type with impls.
-    pub fn impl_type(ty: impl Into<Type>, impls: Vec<PointerAddress>) -> Self {
+    pub fn impl_type(
+        ty: impl Into<Type>,
+        impls: Vec<Rc<RefCell<TypeReference>>>,
+    ) -> Self {
         TypeDefinition::ImplType(Box::new(ty.into()), impls)
     }
 
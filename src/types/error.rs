@@ -20,3 +20,35 @@ impl Display for IllegalTypeError {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeExportError {
+    /// The type has no representation in the export format,
+    /// e.g. callables in JSON Schema
+    UnsupportedType(String),
+    /// Map keys that are neither text literals nor text,
+    /// e.g. { 1: integer }
+    UnsupportedMapKey(String),
+    /// A generic type parameter that is not bound to a type argument
+    UnboundTypeParameter(String),
+}
+
+impl Display for TypeExportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TypeExportError::UnsupportedType(ty) => {
+                core::write!(f, "Type {} can not be exported", ty)
+            }
+            TypeExportError::UnsupportedMapKey(key) => {
+                core::write!(f, "Map keys of type {} can not be exported", key)
+            }
+            TypeExportError::UnboundTypeParameter(name) => {
+                core::write!(
+                    f,
+                    "Type parameter {} is not bound to a type",
+                    name
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,600 @@
+use crate::libs::core::CoreLibPointerId;
+use crate::references::type_reference::TypeReference;
+use crate::stdlib::format;
+use crate::stdlib::string::String;
+use crate::stdlib::string::ToString;
+use crate::stdlib::vec::Vec;
+use crate::types::collection_type_definition::CollectionTypeDefinition;
+use crate::types::definition::TypeDefinition;
+use crate::types::error::TypeExportError;
+use crate::types::export::{
+    ExportedReference, is_number_decimal, is_number_integer, is_text,
+    literal_key,
+};
+use crate::types::structural_type_definition::StructuralTypeDefinition;
+use crate::values::core_values::integer::typed_integer::IntegerTypeVariant;
+use crate::values::core_values::r#type::Type;
+use core::prelude::rust_2024::*;
+use core::result::Result;
+use serde_json::{Map, Value, json};
+
+/// The JSON Schema dialect of the exported schemas
+pub const JSON_SCHEMA_DIALECT: &str =
+    "https://json-schema.org/draft/2020-12/schema";
+
+/// Name of the definition for pointer addresses in DIF value containers
+pub const DIF_POINTER_ADDRESS: &str = "DIFPointerAddress";
+
+/// Exports DATEX types as JSON Schemas that validate their DIF representation.
+/// Value positions are DIF value containers, i.e. objects with a `value` and
+/// an optional `type`, or pointer address strings.
+/// Nominal types are collected in `$defs` as schemas of their representation
+/// and referenced via `$ref`, so that `type User = { name: text }` is exported
+/// as `{"$ref": "#/$defs/User"}`.
+#[derive(Debug, Default)]
+pub struct JsonSchemaExporter {
+    /// schemas of all exported nominal types
+    definitions: Map<String, Value>,
+}
+
+impl JsonSchemaExporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the schema for the DIF representation of values of the given
+    /// type, i.e. the `value` of their DIF value containers.
+    /// Schemas for all referenced nominal types are added to the exporter.
+    pub fn export_type(&mut self, ty: &Type) -> Result<Value, TypeExportError> {
+        self.export_type_definition(&ty.type_definition)
+    }
+
+    /// Returns the schema for the DIF representation of values of the given
+    /// type definition.
+    /// Schemas for all referenced nominal types are added to the exporter.
+    pub fn export_type_definition(
+        &mut self,
+        type_definition: &TypeDefinition,
+    ) -> Result<Value, TypeExportError> {
+        Ok(match type_definition {
+            TypeDefinition::Structural(structural) => {
+                self.export_structural(structural)?
+            }
+            TypeDefinition::Collection(collection) => {
+                self.export_collection(collection)?
+            }
+            TypeDefinition::Reference(reference) => {
+                self.export_reference(&reference.borrow())?
+            }
+            // references and impl markers have no representation in DIF
+            TypeDefinition::Type(ty) | TypeDefinition::ImplType(ty, _) => {
+                self.export_type(ty)?
+            }
+            TypeDefinition::Union(members) => {
+                json!({ "anyOf": self.export_all(members)? })
+            }
+            TypeDefinition::Intersection(members) => {
+                json!({ "allOf": self.export_all(members)? })
+            }
+            TypeDefinition::Never => Value::Bool(false),
+            TypeDefinition::Unknown => Value::Bool(true),
+            TypeDefinition::Parameter(name) => {
+                return Err(TypeExportError::UnboundTypeParameter(
+                    name.clone(),
+                ));
+            }
+            TypeDefinition::Callable(_) | TypeDefinition::Unit => {
+                return Err(TypeExportError::UnsupportedType(
+                    type_definition.to_string(),
+                ));
+            }
+        })
+    }
+
+    /// Returns the schema for the DIF value containers holding values of
+    /// the given type
+    pub fn export_container(
+        &mut self,
+        ty: &Type,
+    ) -> Result<Value, TypeExportError> {
+        let value = self.export_type(ty)?;
+        Ok(self.container(value))
+    }
+
+    /// Returns a schema document with the given root schema and the schemas
+    /// of all referenced nominal types in `$defs`
+    pub fn finish(self, root: Value) -> Value {
+        let mut document = Map::new();
+        document.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+        match root {
+            Value::Object(root) => document.extend(root),
+            // boolean schemas can not be merged into the document
+            root => {
+                document.insert("allOf".to_string(), json!([root]));
+            }
+        }
+        if !self.definitions.is_empty() {
+            document
+                .insert("$defs".to_string(), Value::Object(self.definitions));
+        }
+        Value::Object(document)
+    }
+
+    fn container(&mut self, value: Value) -> Value {
+        self.definitions
+            .entry(DIF_POINTER_ADDRESS.to_string())
+            .or_insert_with(|| {
+                json!({
+                    "type": "string",
+                    "pattern": "^([0-9a-f]{6}|[0-9a-f]{10}|[0-9a-f]{52})$"
+                })
+            });
+        json!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": { "value": value, "type": true },
+                    "required": ["value"],
+                    "additionalProperties": false
+                },
+                { "$ref": format!("#/$defs/{DIF_POINTER_ADDRESS}") }
+            ]
+        })
+    }
+
+    fn export_structural(
+        &mut self,
+        structural: &StructuralTypeDefinition,
+    ) -> Result<Value, TypeExportError> {
+        Ok(match structural {
+            // integers and decimals that are not represented as numbers
+            // are represented as strings in DIF
+            StructuralTypeDefinition::Integer(integer) => {
+                json!({ "const": integer.to_string() })
+            }
+            StructuralTypeDefinition::TypedInteger(integer) => {
+                if is_number_integer(Some(integer.variant())) {
+                    json!({ "const": integer.as_i64() })
+                } else {
+                    json!({ "const": integer.to_string() })
+                }
+            }
+            StructuralTypeDefinition::Decimal(decimal) => {
+                json!({ "const": decimal.to_string() })
+            }
+            StructuralTypeDefinition::TypedDecimal(decimal) => {
+                if !is_number_decimal(Some(decimal.variant())) {
+                    json!({ "const": decimal.to_string() })
+                } else if decimal.is_finite() {
+                    json!({ "const": decimal.as_f64() })
+                } else {
+                    // NaN and infinity can not be represented in JSON
+                    Value::Bool(false)
+                }
+            }
+            StructuralTypeDefinition::Text(text) => json!({ "const": text.0 }),
+            StructuralTypeDefinition::Boolean(boolean) => {
+                json!({ "const": boolean.0 })
+            }
+            StructuralTypeDefinition::Endpoint(endpoint) => {
+                json!({ "const": endpoint.to_string() })
+            }
+            StructuralTypeDefinition::Null => json!({ "type": "null" }),
+            StructuralTypeDefinition::List(items) => {
+                let items = items
+                    .iter()
+                    .map(|item| self.export_container(item))
+                    .collect::<Result<Vec<_>, _>>()?;
+                json!({
+                    "type": "array",
+                    "minItems": items.len(),
+                    "prefixItems": items,
+                    "items": false,
+                })
+            }
+            StructuralTypeDefinition::Map(entries) => {
+                let mut schema = Map::new();
+                let mut properties = Map::new();
+                let mut required = Vec::new();
+                for (key, value) in entries {
+                    let value = self.export_container(value)?;
+                    if let Some(key) = literal_key(key) {
+                        properties.insert(key.to_string(), value);
+                        required.push(Value::from(key));
+                    } else if is_text(key) {
+                        schema
+                            .insert("additionalProperties".to_string(), value);
+                    } else {
+                        return Err(TypeExportError::UnsupportedMapKey(
+                            key.to_string(),
+                        ));
+                    }
+                }
+                // structural maps match values with additional fields,
+                // so additional properties are only restricted for
+                // maps with text keys, e.g. { (text): integer }
+                schema.insert("type".to_string(), json!("object"));
+                schema.insert(
+                    "properties".to_string(),
+                    Value::Object(properties),
+                );
+                schema.insert("required".to_string(), Value::Array(required));
+                Value::Object(schema)
+            }
+        })
+    }
+
+    fn export_collection(
+        &mut self,
+        collection: &CollectionTypeDefinition,
+    ) -> Result<Value, TypeExportError> {
+        Ok(match collection {
+            CollectionTypeDefinition::List(item) => {
+                json!({ "type": "array", "items": self.export_container(item)? })
+            }
+            CollectionTypeDefinition::ListSlice(item, size) => {
+                json!({
+                    "type": "array",
+                    "items": self.export_container(item)?,
+                    "minItems": size,
+                    "maxItems": size,
+                })
+            }
+            // maps are represented as lists of key-value pairs in DIF
+            CollectionTypeDefinition::Map { key, value } => {
+                json!({
+                    "type": "array",
+                    "items": {
+                        "type": "array",
+                        "prefixItems": [
+                            self.export_container(key)?,
+                            self.export_container(value)?
+                        ],
+                        "items": false,
+                        "minItems": 2,
+                    },
+                })
+            }
+            // JSON Schema has no generics, so instances are exported
+            // with all type parameters substituted
+            CollectionTypeDefinition::Instance { .. } => {
+                let instance =
+                    Type::from(TypeDefinition::Collection(collection.clone()));
+                match instance.instantiate() {
+                    Some(ty) => self.export_type(&ty)?,
+                    None => {
+                        return Err(TypeExportError::UnsupportedType(
+                            instance.to_string(),
+                        ));
+                    }
+                }
+            }
+        })
+    }
+
+    fn export_reference(
+        &mut self,
+        reference: &TypeReference,
+    ) -> Result<Value, TypeExportError> {
+        match ExportedReference::from(reference) {
+            ExportedReference::Core(core_lib_id) => {
+                self.export_core(core_lib_id)
+            }
+            ExportedReference::Nominal(name) => {
+                if !self.definitions.contains_key(&name) {
+                    // reserve the name first, so that recursive types
+                    // only refer to the definition
+                    self.definitions.insert(name.clone(), Value::Bool(true));
+                    match self.export_type(&reference.type_value) {
+                        Ok(schema) => {
+                            self.definitions.insert(name.clone(), schema);
+                        }
+                        Err(err) => {
+                            self.definitions.remove(&name);
+                            return Err(err);
+                        }
+                    }
+                }
+                Ok(json!({ "$ref": format!("#/$defs/{name}") }))
+            }
+            ExportedReference::Anonymous => {
+                self.export_type(&reference.type_value)
+            }
+        }
+    }
+
+    fn export_all(
+        &mut self,
+        types: &[Type],
+    ) -> Result<Vec<Value>, TypeExportError> {
+        types.iter().map(|ty| self.export_type(ty)).collect()
+    }
+
+    /// Maps core library types to JSON Schemas
+    fn export_core(
+        &mut self,
+        core_lib_id: CoreLibPointerId,
+    ) -> Result<Value, TypeExportError> {
+        Ok(match core_lib_id {
+            CoreLibPointerId::Null => json!({ "type": "null" }),
+            CoreLibPointerId::Boolean => json!({ "type": "boolean" }),
+            CoreLibPointerId::Integer(Some(variant))
+                if is_number_integer(Some(variant)) =>
+            {
+                let (minimum, maximum) = integer_range(variant);
+                json!({ "type": "integer", "minimum": minimum, "maximum": maximum })
+            }
+            CoreLibPointerId::Integer(_) => {
+                json!({ "type": "string", "pattern": "^-?[0-9]+$" })
+            }
+            CoreLibPointerId::Decimal(variant) => {
+                if is_number_decimal(variant) {
+                    json!({ "type": "number" })
+                } else {
+                    json!({ "type": "string" })
+                }
+            }
+            CoreLibPointerId::Text | CoreLibPointerId::Endpoint => {
+                json!({ "type": "string" })
+            }
+            CoreLibPointerId::List => {
+                json!({ "type": "array", "items": self.container(Value::Bool(true)) })
+            }
+            // structural maps are represented as objects,
+            // all other maps as lists of key-value pairs
+            CoreLibPointerId::Map => {
+                let item = self.container(Value::Bool(true));
+                json!({
+                    "anyOf": [
+                        { "type": "object", "additionalProperties": item },
+                        {
+                            "type": "array",
+                            "items": {
+                                "type": "array",
+                                "prefixItems": [item, item],
+                                "items": false,
+                                "minItems": 2,
+                            },
+                        }
+                    ]
+                })
+            }
+            CoreLibPointerId::Never => Value::Bool(false),
+            CoreLibPointerId::Unknown => Value::Bool(true),
+            CoreLibPointerId::Core
+            | CoreLibPointerId::Type
+            | CoreLibPointerId::Callable
+            | CoreLibPointerId::Unit
+            | CoreLibPointerId::Error
+            | CoreLibPointerId::Print => {
+                return Err(TypeExportError::UnsupportedType(
+                    core_lib_id.to_string(),
+                ));
+            }
+        })
+    }
+}
+
+/// Returns the range of integers of the given variant that are
+/// represented as numbers in DIF
+fn integer_range(variant: IntegerTypeVariant) -> (i64, i64) {
+    match variant {
+        IntegerTypeVariant::U8 => (0, u8::MAX as i64),
+        IntegerTypeVariant::U16 => (0, u16::MAX as i64),
+        IntegerTypeVariant::U32 => (0, u32::MAX as i64),
+        IntegerTypeVariant::I8 => (i8::MIN as i64, i8::MAX as i64),
+        IntegerTypeVariant::I16 => (i16::MIN as i64, i16::MAX as i64),
+        IntegerTypeVariant::I32 => (i32::MIN as i64, i32::MAX as i64),
+        _ => core::unreachable!("{variant:?} is represented as string"),
+    }
+}
+
+/// Exports the given type as JSON Schema document for the DIF value
+/// containers holding values of the type, including the schemas of all
+/// referenced nominal types
+pub fn to_json_schema(ty: &Type) -> Result<Value, TypeExportError> {
+    let mut exporter = JsonSchemaExporter::new();
+    let root = exporter.export_container(ty)?;
+    Ok(exporter.finish(root))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DIF_POINTER_ADDRESS, JSON_SCHEMA_DIALECT, to_json_schema};
+    use crate::references::type_reference::TypeReference;
+    use crate::stdlib::boxed::Box;
+    use crate::stdlib::rc::Rc;
+    use crate::types::collection_type_definition::CollectionTypeDefinition;
+    use crate::types::definition::TypeDefinition;
+    use crate::types::error::TypeExportError;
+    use crate::types::structural_type_definition::StructuralTypeDefinition;
+    use crate::values::core_values::integer::typed_integer::IntegerTypeVariant;
+    use crate::values::core_values::r#type::Type;
+    use core::cell::RefCell;
+    use serde_json::{Value, json};
+
+    fn nominal(name: &str, ty: Type, generic_parameters: Vec<&str>) -> Type {
+        let mut reference = TypeReference::nominal(ty, name, None);
+        reference.generic_parameters =
+            generic_parameters.into_iter().map(String::from).collect();
+        TypeDefinition::reference(Rc::new(RefCell::new(reference))).into()
+    }
+
+    /// Schema of a DIF value container with the given value schema
+    fn container(value: Value) -> Value {
+        json!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": { "value": value, "type": true },
+                    "required": ["value"],
+                    "additionalProperties": false
+                },
+                { "$ref": "#/$defs/DIFPointerAddress" }
+            ]
+        })
+    }
+
+    fn pointer_address() -> Value {
+        json!({
+            "type": "string",
+            "pattern": "^([0-9a-f]{6}|[0-9a-f]{10}|[0-9a-f]{52})$"
+        })
+    }
+
+    /// Returns a schema document for the given root container schema
+    fn document(root: Value, mut definitions: Value) -> Value {
+        definitions[DIF_POINTER_ADDRESS] = pointer_address();
+        let mut document = json!({ "$schema": JSON_SCHEMA_DIALECT });
+        document
+            .as_object_mut()
+            .unwrap()
+            .extend(root.as_object().unwrap().clone());
+        document["$defs"] = definitions;
+        document
+    }
+
+    #[test]
+    fn export_structural_types() {
+        let ty = Type::structural(StructuralTypeDefinition::Map(vec![
+            (Type::structural("name"), Type::text()),
+            (
+                Type::structural("age"),
+                Type::typed_integer(IntegerTypeVariant::U8),
+            ),
+            (Type::structural("id"), Type::integer()),
+            (
+                Type::structural("tags"),
+                Type::slice_list(Type::union(vec![
+                    Type::structural("a"),
+                    Type::null(),
+                ])),
+            ),
+        ]));
+        assert_eq!(
+            to_json_schema(&ty).unwrap(),
+            document(
+                container(json!({
+                    "type": "object",
+                    "properties": {
+                        "name": container(json!({ "type": "string" })),
+                        "age": container(json!({
+                            "type": "integer",
+                            "minimum": 0,
+                            "maximum": 255
+                        })),
+                        "id": container(json!({
+                            "type": "string",
+                            "pattern": "^-?[0-9]+$"
+                        })),
+                        "tags": container(json!({
+                            "type": "array",
+                            "items": container(json!({
+                                "anyOf": [{ "const": "a" }, { "type": "null" }]
+                            }))
+                        }))
+                    },
+                    "required": ["name", "age", "id", "tags"]
+                })),
+                json!({})
+            )
+        );
+    }
+
+    #[test]
+    fn export_maps() {
+        let ty = Type::structural(StructuralTypeDefinition::Map(vec![(
+            Type::text(),
+            Type::fixed_size_list(Type::boolean(), 2),
+        )]));
+        assert_eq!(
+            to_json_schema(&ty).unwrap(),
+            document(
+                container(json!({
+                    "type": "object",
+                    "properties": {},
+                    "required": [],
+                    "additionalProperties": container(json!({
+                        "type": "array",
+                        "items": container(json!({ "type": "boolean" })),
+                        "minItems": 2,
+                        "maxItems": 2
+                    }))
+                })),
+                json!({})
+            )
+        );
+
+        // maps are represented as lists of key-value pairs
+        let ty = Type::from(TypeDefinition::Collection(
+            CollectionTypeDefinition::Map {
+                key: Box::new(Type::boolean()),
+                value: Box::new(Type::text()),
+            },
+        ));
+        assert_eq!(
+            to_json_schema(&ty).unwrap(),
+            document(
+                container(json!({
+                    "type": "array",
+                    "items": {
+                        "type": "array",
+                        "prefixItems": [
+                            container(json!({ "type": "boolean" })),
+                            container(json!({ "type": "string" }))
+                        ],
+                        "items": false,
+                        "minItems": 2
+                    }
+                })),
+                json!({})
+            )
+        );
+    }
+
+    #[test]
+    fn export_nominal_types() {
+        let user = nominal(
+            "User",
+            Type::structural(StructuralTypeDefinition::Map(vec![(
+                Type::structural("name"),
+                Type::text(),
+            )])),
+            vec![],
+        );
+        let boxed = nominal(
+            "Box",
+            Type::structural(StructuralTypeDefinition::Map(vec![(
+                Type::structural("value"),
+                Type::parameter("T"),
+            )])),
+            vec!["T"],
+        );
+        assert_eq!(
+            to_json_schema(&Type::instance(boxed.clone(), vec![user])).unwrap(),
+            document(
+                container(json!({
+                    "type": "object",
+                    "properties": {
+                        "value": container(json!({ "$ref": "#/$defs/User" }))
+                    },
+                    "required": ["value"]
+                })),
+                json!({
+                    "User": {
+                        "type": "object",
+                        "properties": {
+                            "name": container(json!({ "type": "string" }))
+                        },
+                        "required": ["name"]
+                    }
+                })
+            )
+        );
+        // generic types can only be exported as instances
+        assert_eq!(
+            to_json_schema(&boxed),
+            Err(TypeExportError::UnboundTypeParameter("T".to_string()))
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Export of DATEX types as TypeScript declarations and JSON Schema documents,
+//! e.g. to validate DIF payloads without reimplementing the DATEX type system.
+//!
+//! The exports describe the DIF representation of values: every value
+//! position (the exported value itself, fields, list items, map keys and
+//! values) holds a DIF value container, which is either a value with an
+//! optional type (`{"value": ..., "type": ...}`) or a pointer address
+//! string. For example, a value of `{name: text, age: integer/u8}` is
+//! represented as
+//! `{"value": {"name": {"value": "a"}, "age": {"value": 1, "type": "..."}}}`.
+
+use crate::libs::core::CoreLibPointerId;
+use crate::references::type_reference::TypeReference;
+use crate::stdlib::string::String;
+use crate::stdlib::string::ToString;
+use crate::types::definition::TypeDefinition;
+use crate::types::structural_type_definition::StructuralTypeDefinition;
+use crate::values::core_values::decimal::typed_decimal::DecimalTypeVariant;
+use crate::values::core_values::integer::typed_integer::IntegerTypeVariant;
+use crate::values::core_values::r#type::Type;
+use core::prelude::rust_2024::*;
+
+pub mod json_schema;
+pub mod typescript;
+
+/// Describes how a referenced type is exported
+enum ExportedReference {
+    /// core types without a type definition are mapped to builtin types
+    Core(CoreLibPointerId),
+    /// nominal types are exported as named declarations,
+    /// e.g. `User/admin` as `User_admin`
+    Nominal(String),
+    /// anonymous references are exported as their type value
+    Anonymous,
+}
+
+impl From<&TypeReference> for ExportedReference {
+    fn from(reference: &TypeReference) -> Self {
+        if reference.type_value.is_unit()
+            && let Some(core_lib_id) = reference
+                .pointer_address
+                .as_ref()
+                .and_then(|address| CoreLibPointerId::try_from(address).ok())
+        {
+            return ExportedReference::Core(core_lib_id);
+        }
+        match &reference.nominal_type_declaration {
+            Some(declaration) => ExportedReference::Nominal(
+                declaration.to_string().replace('/', "_"),
+            ),
+            None => ExportedReference::Anonymous,
+        }
+    }
+}
+
+/// Returns true if integers of the given variant are represented as numbers
+/// in DIF. Integers of 64 bits and more are represented as strings.
+fn is_number_integer(variant: Option<IntegerTypeVariant>) -> bool {
+    core::matches!(
+        variant,
+        Some(
+            IntegerTypeVariant::U8
+                | IntegerTypeVariant::U16
+                | IntegerTypeVariant::U32
+                | IntegerTypeVariant::I8
+                | IntegerTypeVariant::I16
+                | IntegerTypeVariant::I32
+        )
+    )
+}
+
+/// Returns true if decimals of the given variant are represented as numbers
+/// in DIF. Big decimals are represented as strings.
+fn is_number_decimal(variant: Option<DecimalTypeVariant>) -> bool {
+    core::matches!(
+        variant,
+        Some(DecimalTypeVariant::F32 | DecimalTypeVariant::F64)
+    )
+}
+
+/// Returns the property name if the type is a text literal, e.g. "name"
+fn literal_key(ty: &Type) -> Option<&str> {
+    match &ty.type_definition {
+        TypeDefinition::Structural(StructuralTypeDefinition::Text(text)) => {
+            Some(&text.0)
+        }
+        _ => None,
+    }
+}
+
+/// Returns true if the type is the text type, e.g. for { (text): integer }
+fn is_text(ty: &Type) -> bool {
+    match &ty.type_definition {
+        TypeDefinition::Reference(reference) => core::matches!(
+            ExportedReference::from(&*reference.borrow()),
+            ExportedReference::Core(CoreLibPointerId::Text)
+        ),
+        _ => false,
+    }
+}
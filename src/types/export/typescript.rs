@@ -0,0 +1,536 @@
+use crate::libs::core::CoreLibPointerId;
+use crate::references::type_reference::TypeReference;
+use crate::stdlib::format;
+use crate::stdlib::string::String;
+use crate::stdlib::string::ToString;
+use crate::stdlib::vec;
+use crate::stdlib::vec::Vec;
+use crate::types::collection_type_definition::CollectionTypeDefinition;
+use crate::types::definition::TypeDefinition;
+use crate::types::error::TypeExportError;
+use crate::types::export::{
+    ExportedReference, is_number_decimal, is_number_integer, is_text,
+    literal_key,
+};
+use crate::types::structural_type_definition::StructuralTypeDefinition;
+use crate::values::core_values::r#type::Type;
+use core::prelude::rust_2024::*;
+use core::result::Result;
+use indexmap::IndexMap;
+
+/// Name of the generic TypeScript type for DIF value containers
+pub const DIF_VALUE_CONTAINER: &str = "DIFValueContainer";
+
+/// Exports DATEX types as TypeScript types describing their DIF representation.
+/// Nominal types are collected as named declarations of their representation,
+/// so that `type User = { name: text }` is exported as
+/// `export type User = { name: DIFValueContainer<string> };`
+/// Value positions are wrapped in `DIFValueContainer`, which is declared
+/// as `{ value: T; type?: unknown } | string` when it is used.
+#[derive(Debug, Default)]
+pub struct TypeScriptExporter {
+    /// declarations of all exported nominal types, in order of their export
+    declarations: IndexMap<String, String>,
+    /// generic type parameters of the declaration that is currently exported
+    parameters: Vec<String>,
+    /// true if a DIF value container was exported
+    uses_container: bool,
+}
+
+impl TypeScriptExporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a named declaration for the given type, e.g.
+    /// `export type Point = { x: number; y: number };`
+    pub fn declare(
+        &mut self,
+        name: &str,
+        ty: &Type,
+    ) -> Result<(), TypeExportError> {
+        let ty = self.export_type(ty)?;
+        self.declarations
+            .insert(name.to_string(), format!("export type {name} = {ty};"));
+        Ok(())
+    }
+
+    /// Returns the TypeScript type of the DIF representation of values of
+    /// the given type, i.e. the `value` of their DIF value containers.
+    /// Declarations for all referenced nominal types are added to the exporter.
+    pub fn export_type(
+        &mut self,
+        ty: &Type,
+    ) -> Result<String, TypeExportError> {
+        self.export_type_definition(&ty.type_definition)
+    }
+
+    /// Returns the TypeScript type of the DIF representation of values of
+    /// the given type definition.
+    /// Declarations for all referenced nominal types are added to the exporter.
+    pub fn export_type_definition(
+        &mut self,
+        type_definition: &TypeDefinition,
+    ) -> Result<String, TypeExportError> {
+        Ok(match type_definition {
+            TypeDefinition::Structural(structural) => {
+                self.export_structural(structural)?
+            }
+            TypeDefinition::Collection(collection) => {
+                self.export_collection(collection)?
+            }
+            TypeDefinition::Reference(reference) => {
+                self.export_reference(&reference.borrow())?
+            }
+            // references and impl markers have no representation in DIF
+            TypeDefinition::Type(ty) | TypeDefinition::ImplType(ty, _) => {
+                self.export_type(ty)?
+            }
+            TypeDefinition::Union(members) => {
+                self.export_members(members, " | ")?
+            }
+            TypeDefinition::Intersection(members) => {
+                self.export_members(members, " & ")?
+            }
+            TypeDefinition::Never => "never".to_string(),
+            TypeDefinition::Unknown => "unknown".to_string(),
+            TypeDefinition::Parameter(name) => {
+                if !self.parameters.contains(name) {
+                    return Err(TypeExportError::UnboundTypeParameter(
+                        name.clone(),
+                    ));
+                }
+                name.clone()
+            }
+            // callables and unit values have no DIF representation
+            TypeDefinition::Callable(_) | TypeDefinition::Unit => {
+                return Err(TypeExportError::UnsupportedType(
+                    type_definition.to_string(),
+                ));
+            }
+        })
+    }
+
+    /// Returns the TypeScript type of the DIF value containers holding
+    /// values of the given type, e.g. `DIFValueContainer<string>`
+    pub fn export_container(
+        &mut self,
+        ty: &Type,
+    ) -> Result<String, TypeExportError> {
+        let ty = self.export_type(ty)?;
+        Ok(self.container(&ty))
+    }
+
+    /// Returns all collected declarations, separated by newlines
+    pub fn finish(self) -> String {
+        let container = self.uses_container.then(|| {
+            format!(
+                "export type {DIF_VALUE_CONTAINER}<T> = {{ value: T; type?: unknown }} | string;"
+            )
+        });
+        container
+            .into_iter()
+            .chain(self.declarations.into_values())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn container(&mut self, ty: &str) -> String {
+        self.uses_container = true;
+        format!("{DIF_VALUE_CONTAINER}<{ty}>")
+    }
+
+    fn export_structural(
+        &mut self,
+        structural: &StructuralTypeDefinition,
+    ) -> Result<String, TypeExportError> {
+        Ok(match structural {
+            // integers and decimals that are not represented as numbers
+            // are represented as strings in DIF
+            StructuralTypeDefinition::Integer(integer) => {
+                string_literal(&integer.to_string())
+            }
+            StructuralTypeDefinition::TypedInteger(integer) => {
+                if is_number_integer(Some(integer.variant())) {
+                    integer.to_string()
+                } else {
+                    string_literal(&integer.to_string())
+                }
+            }
+            StructuralTypeDefinition::Decimal(decimal) => {
+                string_literal(&decimal.to_string())
+            }
+            StructuralTypeDefinition::TypedDecimal(decimal) => {
+                if !is_number_decimal(Some(decimal.variant())) {
+                    string_literal(&decimal.to_string())
+                } else if decimal.is_finite() {
+                    decimal.as_f64().to_string()
+                } else {
+                    // NaN and infinity have no literal type in TypeScript
+                    "number".to_string()
+                }
+            }
+            StructuralTypeDefinition::Text(text) => string_literal(&text.0),
+            StructuralTypeDefinition::Boolean(boolean) => boolean.to_string(),
+            StructuralTypeDefinition::Endpoint(endpoint) => {
+                string_literal(&endpoint.to_string())
+            }
+            StructuralTypeDefinition::Null => "null".to_string(),
+            StructuralTypeDefinition::List(items) => {
+                let items = items
+                    .iter()
+                    .map(|item| self.export_container(item))
+                    .collect::<Result<Vec<_>, _>>()?;
+                format!("[{}]", items.join(", "))
+            }
+            StructuralTypeDefinition::Map(entries) => {
+                let mut fields = Vec::new();
+                for (key, value) in entries {
+                    let value = self.export_container(value)?;
+                    if let Some(key) = literal_key(key) {
+                        fields.push(format!(
+                            "{}: {}",
+                            property_name(key),
+                            value
+                        ));
+                    } else if is_text(key) {
+                        fields.push(format!("[key: string]: {}", value));
+                    } else {
+                        return Err(TypeExportError::UnsupportedMapKey(
+                            key.to_string(),
+                        ));
+                    }
+                }
+                if fields.is_empty() {
+                    "Record<string, never>".to_string()
+                } else {
+                    format!("{{ {} }}", fields.join("; "))
+                }
+            }
+        })
+    }
+
+    fn export_collection(
+        &mut self,
+        collection: &CollectionTypeDefinition,
+    ) -> Result<String, TypeExportError> {
+        Ok(match collection {
+            CollectionTypeDefinition::List(item) => {
+                format!("{}[]", self.export_container(item)?)
+            }
+            CollectionTypeDefinition::ListSlice(item, size) => {
+                let item = self.export_container(item)?;
+                format!("[{}]", vec![item; *size].join(", "))
+            }
+            // maps are represented as lists of key-value pairs in DIF
+            CollectionTypeDefinition::Map { key, value } => {
+                format!(
+                    "[{}, {}][]",
+                    self.export_container(key)?,
+                    self.export_container(value)?
+                )
+            }
+            CollectionTypeDefinition::Instance { base, arguments } => {
+                let base = self.export_type(base)?;
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| self.export_type(argument))
+                    .collect::<Result<Vec<_>, _>>()?;
+                format!("{}<{}>", base, arguments.join(", "))
+            }
+        })
+    }
+
+    fn export_reference(
+        &mut self,
+        reference: &TypeReference,
+    ) -> Result<String, TypeExportError> {
+        match ExportedReference::from(reference) {
+            ExportedReference::Core(core_lib_id) => {
+                self.export_core(core_lib_id)
+            }
+            ExportedReference::Nominal(name) => {
+                if !self.declarations.contains_key(&name) {
+                    // reserve the name first, so that recursive types
+                    // only refer to the declaration
+                    self.declarations.insert(name.clone(), String::new());
+                    let parameters = core::mem::replace(
+                        &mut self.parameters,
+                        reference.generic_parameters.clone(),
+                    );
+                    let ty = self.export_type(&reference.type_value);
+                    self.parameters = parameters;
+                    let ty = match ty {
+                        Ok(ty) => ty,
+                        Err(err) => {
+                            self.declarations.shift_remove(&name);
+                            return Err(err);
+                        }
+                    };
+                    let generic_parameters = if reference
+                        .generic_parameters
+                        .is_empty()
+                    {
+                        "".to_string()
+                    } else {
+                        format!("<{}>", reference.generic_parameters.join(", "))
+                    };
+                    self.declarations.insert(
+                        name.clone(),
+                        format!(
+                            "export type {name}{generic_parameters} = {ty};"
+                        ),
+                    );
+                }
+                Ok(name)
+            }
+            ExportedReference::Anonymous => {
+                self.export_type(&reference.type_value)
+            }
+        }
+    }
+
+    fn export_members(
+        &mut self,
+        members: &[Type],
+        separator: &str,
+    ) -> Result<String, TypeExportError> {
+        let members = members
+            .iter()
+            .map(|member| self.export_operand(member))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(members.join(separator))
+    }
+
+    /// Exports a type that is used as an operand of a union or
+    /// intersection. Nested unions and intersections are wrapped in
+    /// parentheses.
+    fn export_operand(&mut self, ty: &Type) -> Result<String, TypeExportError> {
+        let exported = self.export_type(ty)?;
+        Ok(match ty.type_definition {
+            TypeDefinition::Union(_) | TypeDefinition::Intersection(_) => {
+                format!("({exported})")
+            }
+            _ => exported,
+        })
+    }
+
+    /// Maps core library types to builtin TypeScript types
+    fn export_core(
+        &mut self,
+        core_lib_id: CoreLibPointerId,
+    ) -> Result<String, TypeExportError> {
+        Ok(match core_lib_id {
+            CoreLibPointerId::Null => "null".to_string(),
+            CoreLibPointerId::Boolean => "boolean".to_string(),
+            CoreLibPointerId::Integer(variant) => {
+                if is_number_integer(variant) {
+                    "number".to_string()
+                } else {
+                    "string".to_string()
+                }
+            }
+            CoreLibPointerId::Decimal(variant) => {
+                if is_number_decimal(variant) {
+                    "number".to_string()
+                } else {
+                    "string".to_string()
+                }
+            }
+            CoreLibPointerId::Text | CoreLibPointerId::Endpoint => {
+                "string".to_string()
+            }
+            CoreLibPointerId::List => {
+                format!("{}[]", self.container("unknown"))
+            }
+            // structural maps are represented as objects,
+            // all other maps as lists of key-value pairs
+            CoreLibPointerId::Map => {
+                let item = self.container("unknown");
+                format!("Record<string, {item}> | [{item}, {item}][]")
+            }
+            CoreLibPointerId::Never => "never".to_string(),
+            CoreLibPointerId::Unknown => "unknown".to_string(),
+            CoreLibPointerId::Core
+            | CoreLibPointerId::Type
+            | CoreLibPointerId::Callable
+            | CoreLibPointerId::Unit
+            | CoreLibPointerId::Error
+            | CoreLibPointerId::Print => {
+                return Err(TypeExportError::UnsupportedType(
+                    core_lib_id.to_string(),
+                ));
+            }
+        })
+    }
+}
+
+/// Returns a TypeScript string literal, e.g. "name"
+fn string_literal(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+/// Returns the property name, quoted if it is not a valid identifier
+fn property_name(key: &str) -> String {
+    let mut chars = key.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        key.to_string()
+    } else {
+        string_literal(key)
+    }
+}
+
+/// Exports the DIF representation of the given type as TypeScript
+/// declaration with the given name, including declarations for all
+/// referenced nominal types and the DIF value container type
+pub fn to_typescript_declarations(
+    name: &str,
+    ty: &Type,
+) -> Result<String, TypeExportError> {
+    let mut exporter = TypeScriptExporter::new();
+    exporter.declare(name, ty)?;
+    Ok(exporter.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TypeScriptExporter, to_typescript_declarations};
+    use crate::references::type_reference::TypeReference;
+    use crate::stdlib::boxed::Box;
+    use crate::stdlib::rc::Rc;
+    use crate::types::collection_type_definition::CollectionTypeDefinition;
+    use crate::types::definition::TypeDefinition;
+    use crate::types::error::TypeExportError;
+    use crate::types::structural_type_definition::StructuralTypeDefinition;
+    use crate::values::core_values::integer::typed_integer::IntegerTypeVariant;
+    use crate::values::core_values::r#type::Type;
+    use core::cell::RefCell;
+    use indoc::indoc;
+
+    fn nominal(name: &str, ty: Type, generic_parameters: Vec<&str>) -> Type {
+        let mut reference = TypeReference::nominal(ty, name, None);
+        reference.generic_parameters =
+            generic_parameters.into_iter().map(String::from).collect();
+        TypeDefinition::reference(Rc::new(RefCell::new(reference))).into()
+    }
+
+    #[test]
+    fn export_core_types() {
+        let mut exporter = TypeScriptExporter::new();
+        let export = |exporter: &mut TypeScriptExporter, ty: Type| {
+            exporter.export_type(&ty).unwrap()
+        };
+        assert_eq!(export(&mut exporter, Type::text()), "string");
+        assert_eq!(export(&mut exporter, Type::boolean()), "boolean");
+        assert_eq!(export(&mut exporter, Type::null()), "null");
+        assert_eq!(
+            export(&mut exporter, Type::typed_integer(IntegerTypeVariant::U8)),
+            "number"
+        );
+        // big integers are represented as strings in DIF
+        assert_eq!(
+            export(&mut exporter, Type::typed_integer(IntegerTypeVariant::I64)),
+            "string"
+        );
+        assert_eq!(export(&mut exporter, Type::integer()), "string");
+        assert_eq!(exporter.finish(), "");
+    }
+
+    #[test]
+    fn export_structural_types() {
+        let ty = Type::structural(StructuralTypeDefinition::Map(vec![
+            (Type::structural("name"), Type::text()),
+            (
+                Type::structural("first-name"),
+                Type::union(vec![Type::text(), Type::null()]),
+            ),
+            (
+                Type::structural("tags"),
+                Type::slice_list(Type::union(vec![
+                    Type::structural("a"),
+                    Type::structural("b"),
+                ])),
+            ),
+            (
+                Type::structural("position"),
+                Type::fixed_size_list(
+                    Type::typed_integer(IntegerTypeVariant::I32),
+                    2,
+                ),
+            ),
+        ]));
+        assert_eq!(
+            to_typescript_declarations("Person", &ty).unwrap(),
+            indoc! {r#"
+                export type DIFValueContainer<T> = { value: T; type?: unknown } | string;
+                export type Person = { name: DIFValueContainer<string>; "first-name": DIFValueContainer<string | null>; tags: DIFValueContainer<DIFValueContainer<"a" | "b">[]>; position: DIFValueContainer<[DIFValueContainer<number>, DIFValueContainer<number>]> };"#
+            }
+        );
+    }
+
+    #[test]
+    fn export_nominal_types() {
+        let user = nominal(
+            "User",
+            Type::structural(StructuralTypeDefinition::Map(vec![(
+                Type::structural("name"),
+                Type::text(),
+            )])),
+            vec![],
+        );
+        let boxed = nominal(
+            "Box",
+            Type::structural(StructuralTypeDefinition::Map(vec![(
+                Type::structural("value"),
+                Type::parameter("T"),
+            )])),
+            vec!["T"],
+        );
+        let ty = Type::instance(boxed, vec![user]);
+        assert_eq!(
+            to_typescript_declarations("BoxedUser", &ty).unwrap(),
+            indoc! {"
+                export type DIFValueContainer<T> = { value: T; type?: unknown } | string;
+                export type Box<T> = { value: DIFValueContainer<T> };
+                export type User = { name: DIFValueContainer<string> };
+                export type BoxedUser = Box<User>;"
+            }
+        );
+    }
+
+    #[test]
+    fn export_maps() {
+        // maps are represented as lists of key-value pairs
+        let ty = Type::from(TypeDefinition::Collection(
+            CollectionTypeDefinition::Map {
+                key: Box::new(Type::integer()),
+                value: Box::new(Type::boolean()),
+            },
+        ));
+        let mut exporter = TypeScriptExporter::new();
+        assert_eq!(
+            exporter.export_container(&ty).unwrap(),
+            "DIFValueContainer<[DIFValueContainer<string>, DIFValueContainer<boolean>][]>"
+        );
+    }
+
+    #[test]
+    fn export_unsupported_types() {
+        let ty = Type::structural(StructuralTypeDefinition::Map(vec![(
+            Type::integer(),
+            Type::text(),
+        )]));
+        assert_eq!(
+            to_typescript_declarations("Invalid", &ty),
+            Err(TypeExportError::UnsupportedMapKey("integer".to_string()))
+        );
+        assert_eq!(
+            to_typescript_declarations("Invalid", &Type::parameter("T")),
+            Err(TypeExportError::UnboundTypeParameter("T".to_string()))
+        );
+    }
+}
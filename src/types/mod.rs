@@ -1,5 +1,6 @@
 pub mod collection_type_definition;
 pub mod definition;
 pub mod error;
+pub mod export;
 pub mod structural_type_definition;
 pub mod type_alias;
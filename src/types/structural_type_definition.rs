@@ -17,7 +17,6 @@ use crate::values::value_container::ValueContainer;
 use core::fmt::Display;
 use core::hash::Hash;
 use core::prelude::rust_2024::*;
-use core::unimplemented;
 
 #[derive(Debug, Clone, PartialEq, Hash, Eq)]
 pub enum StructuralTypeDefinition {
@@ -150,10 +149,10 @@ impl StructuralTypeDefinition {
                 StructuralTypeDefinition::Map(field_types),
                 CoreValue::Map(map),
             ) => field_types.iter().all(|(field_name, field_type)| {
-                core::todo!("#375 handle key matching")
-                // map.get(&field_name_value).is_some_and(|field_value| {
-                //     field_type.value_matches(field_value)
-                // })
+                map.into_iter().any(|(key, value)| {
+                    field_name.value_matches(&ValueContainer::from(key))
+                        && field_type.value_matches(value)
+                })
             }),
 
             // list
@@ -169,7 +168,8 @@ impl StructuralTypeDefinition {
                     .zip(list.iter())
                     .all(|(t, v)| t.value_matches(v))
             }
-            _ => unimplemented!("handle complex structural type matching"),
+            // values of a different kind never match
+            _ => false,
         }
     }
 
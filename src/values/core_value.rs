@@ -2,6 +2,7 @@ use core::prelude::rust_2024::*;
 use core::result::Result;
 use datex_macros::FromCoreValue;
 
+use crate::global::operators::CastMode;
 use crate::libs::core::{CoreLibPointerId, get_core_lib_type_reference};
 use crate::stdlib::string::String;
 use crate::stdlib::string::ToString;
@@ -274,7 +275,10 @@ impl CoreValue {
         match self {
             CoreValue::Text(text) => Some(Boolean(!text.0.is_empty())),
             CoreValue::Boolean(bool) => Some(bool.clone()),
-            CoreValue::TypedInteger(int) => Some(Boolean(int.as_i128()? != 0)),
+            CoreValue::TypedInteger(int) => {
+                Some(Boolean(!int.as_integer().is_zero()))
+            }
+            CoreValue::Integer(int) => Some(Boolean(!int.is_zero())),
             CoreValue::Null => Some(Boolean(false)),
             _ => None,
         }
@@ -283,7 +287,7 @@ impl CoreValue {
     pub fn cast_to_decimal(&self) -> Option<Decimal> {
         match self {
             CoreValue::Text(text) => {
-                text.to_string().parse::<f64>().ok().map(Decimal::from)
+                text.as_str().parse::<f64>().ok().map(Decimal::from)
             }
             CoreValue::TypedInteger(int) => {
                 Some(Decimal::from(int.as_i128()? as f64))
@@ -295,6 +299,9 @@ impl CoreValue {
                 Some(Decimal::from(int.as_i128()? as f64))
             }
             CoreValue::Decimal(decimal) => Some(decimal.clone()),
+            CoreValue::Boolean(bool) => {
+                Some(Decimal::from(if bool.0 { 1.0 } else { 0.0 }))
+            }
             _ => None,
         }
     }
@@ -339,6 +346,13 @@ impl CoreValue {
                 )
                 .ok()?,
             ),
+            CoreValue::Boolean(bool) => {
+                TypedDecimal::from_string_and_variant_in_range(
+                    if bool.0 { "1" } else { "0" },
+                    variant,
+                )
+                .ok()
+            }
             _ => None,
         }
     }
@@ -367,54 +381,52 @@ impl CoreValue {
         }
     }
 
-    // TODO #315 improve conversion logic
+    /// Casts the value to an integer.
+    /// Decimals are truncated towards zero, NaN and infinite values can
+    /// not be cast to an integer.
     pub fn cast_to_integer(&self) -> Option<Integer> {
         match self {
-            CoreValue::Text(text) => {
-                Integer::from_string(&text.to_string()).ok()
-            }
+            CoreValue::Text(text) => Integer::from_string(text.as_str()).ok(),
             CoreValue::TypedInteger(int) => Some(int.as_integer()),
             CoreValue::Integer(int) => Some(int.clone()),
             CoreValue::Decimal(decimal) => {
-                // FIXME #316 currently bad as f64 can be infinity or nan
-                // convert decimal directly to integer into_f64 is wrong here
-                Some(Integer::from(decimal.into_f64() as i128))
-            }
-            CoreValue::TypedDecimal(decimal) => {
-                decimal.as_integer().map(Integer::from)
+                decimal.trunc_to_big_int().map(Integer::from)
             }
+            CoreValue::TypedDecimal(decimal) => Decimal::from(decimal.clone())
+                .trunc_to_big_int()
+                .map(Integer::from),
+            CoreValue::Boolean(bool) => Some(Integer::from(bool.0 as u8)),
             _ => None,
         }
     }
 
+    /// Casts the value to the given integer variant.
+    /// Fails if the value is out of the range of the variant.
     pub fn cast_to_typed_integer(
         &self,
         variant: IntegerTypeVariant,
     ) -> Option<TypedInteger> {
-        match self {
-            CoreValue::Text(text) => {
-                TypedInteger::from_string_with_variant(text.as_str(), variant)
-                    .ok()
-            }
-            CoreValue::TypedInteger(int) => {
-                TypedInteger::from_string_with_variant(
-                    &int.to_string(),
-                    variant,
-                )
-                .ok()
-            }
-            CoreValue::Integer(int) => TypedInteger::from_string_with_variant(
-                int.to_string().as_str(),
-                variant,
-            )
-            .ok(),
-            CoreValue::Decimal(decimal) => {
-                Some(TypedInteger::from(decimal.into_f64() as i128))
+        self.cast_to_typed_integer_with_mode(variant, CastMode::Checked)
+    }
+
+    /// Casts the value to the given integer variant.
+    /// If the value is out of the range of the variant, checked casts fail,
+    /// while saturating casts clamp the value to the bounds of the variant.
+    pub fn cast_to_typed_integer_with_mode(
+        &self,
+        variant: IntegerTypeVariant,
+        mode: CastMode,
+    ) -> Option<TypedInteger> {
+        let integer = self.cast_to_integer()?;
+        match mode {
+            CastMode::Checked => {
+                TypedInteger::from_integer_with_variant(&integer, variant)
             }
-            CoreValue::TypedDecimal(decimal) => {
-                decimal.as_integer().map(TypedInteger::from)
+            CastMode::Saturating => {
+                Some(TypedInteger::from_integer_with_variant_saturating(
+                    &integer, variant,
+                ))
             }
-            _ => None,
         }
     }
 
@@ -38,6 +38,8 @@ pub enum CallableBody {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CallableSignature {
     pub kind: CallableKind,
+    /// names of the generic type parameters, e.g. T for `function first<T>(list: [T]) -> T`
+    pub generic_parameters: Vec<String>,
     pub parameter_types: Vec<(Option<String>, Type)>,
     pub rest_parameter_type: Option<(Option<String>, Box<Type>)>,
     pub return_type: Option<Box<Type>>,
@@ -91,6 +91,16 @@ impl Decimal {
         }
     }
 
+    /// Returns the integral part of the value, truncating towards zero.
+    /// Returns None if the value is NaN or infinite.
+    pub fn trunc_to_big_int(&self) -> Option<BigInt> {
+        match self {
+            Decimal::Finite(value) => Some(value.trunc_to_big_int()),
+            Decimal::Zero | Decimal::NegZero => Some(BigInt::zero()),
+            Decimal::Nan | Decimal::Infinity | Decimal::NegInfinity => None,
+        }
+    }
+
     /// Returns true if the value is finite (not NaN or Infinity).
     pub fn is_finite(&self) -> bool {
         core::matches!(
@@ -56,6 +56,10 @@ impl Rational {
     pub(crate) fn to_i64(&self) -> Option<i64> {
         self.big_rational.to_i64()
     }
+    /// Returns the integral part, truncating towards zero
+    pub(crate) fn trunc_to_big_int(&self) -> BigInt {
+        self.big_rational.to_integer()
+    }
     pub(crate) fn to_f32(&self) -> f32 {
         self.big_rational.to_f32().unwrap_or(f32::NAN)
     }
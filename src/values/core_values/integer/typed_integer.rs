@@ -153,7 +153,73 @@ impl Hash for TypedInteger {
 }
 
 impl TypedInteger {
-    // TODO #342: add from_integer_with_variant
+    /// Converts an integer into a TypedInteger with the given variant.
+    /// Returns None if the integer is out of the range of the variant.
+    pub fn from_integer_with_variant(
+        integer: &Integer,
+        variant: IntegerTypeVariant,
+    ) -> Option<TypedInteger> {
+        Some(match variant {
+            IntegerTypeVariant::U8 => TypedInteger::U8(integer.as_u8()?),
+            IntegerTypeVariant::U16 => TypedInteger::U16(integer.as_u16()?),
+            IntegerTypeVariant::U32 => TypedInteger::U32(integer.as_u32()?),
+            IntegerTypeVariant::U64 => TypedInteger::U64(integer.as_u64()?),
+            IntegerTypeVariant::U128 => TypedInteger::U128(integer.as_u128()?),
+            IntegerTypeVariant::I8 => TypedInteger::I8(integer.as_i8()?),
+            IntegerTypeVariant::I16 => TypedInteger::I16(integer.as_i16()?),
+            IntegerTypeVariant::I32 => TypedInteger::I32(integer.as_i32()?),
+            IntegerTypeVariant::I64 => TypedInteger::I64(integer.as_i64()?),
+            IntegerTypeVariant::I128 => TypedInteger::I128(integer.as_i128()?),
+            IntegerTypeVariant::IBig => TypedInteger::IBig(integer.clone()),
+        })
+    }
+
+    /// Converts an integer into a TypedInteger with the given variant.
+    /// If the integer is out of the range of the variant, it is clamped
+    /// to the minimum or maximum value of the variant.
+    pub fn from_integer_with_variant_saturating(
+        integer: &Integer,
+        variant: IntegerTypeVariant,
+    ) -> TypedInteger {
+        macro_rules! saturate {
+            ($as_fn:ident, $ty:ty) => {
+                integer.$as_fn().unwrap_or(if integer.is_negative() {
+                    <$ty>::MIN
+                } else {
+                    <$ty>::MAX
+                })
+            };
+        }
+        match variant {
+            IntegerTypeVariant::U8 => TypedInteger::U8(saturate!(as_u8, u8)),
+            IntegerTypeVariant::U16 => {
+                TypedInteger::U16(saturate!(as_u16, u16))
+            }
+            IntegerTypeVariant::U32 => {
+                TypedInteger::U32(saturate!(as_u32, u32))
+            }
+            IntegerTypeVariant::U64 => {
+                TypedInteger::U64(saturate!(as_u64, u64))
+            }
+            IntegerTypeVariant::U128 => {
+                TypedInteger::U128(saturate!(as_u128, u128))
+            }
+            IntegerTypeVariant::I8 => TypedInteger::I8(saturate!(as_i8, i8)),
+            IntegerTypeVariant::I16 => {
+                TypedInteger::I16(saturate!(as_i16, i16))
+            }
+            IntegerTypeVariant::I32 => {
+                TypedInteger::I32(saturate!(as_i32, i32))
+            }
+            IntegerTypeVariant::I64 => {
+                TypedInteger::I64(saturate!(as_i64, i64))
+            }
+            IntegerTypeVariant::I128 => {
+                TypedInteger::I128(saturate!(as_i128, i128))
+            }
+            IntegerTypeVariant::IBig => TypedInteger::IBig(integer.clone()),
+        }
+    }
 
     /// Parses a string into a TypedInteger with the given variant.
     /// If the string is not a valid integer, returns an error.
@@ -1,16 +1,21 @@
 #[cfg(feature = "compiler")]
 use crate::ast::expressions::DatexExpressionData;
+use crate::collections::HashMap;
+use crate::global::operators::CastMode;
 use crate::libs::core::CoreLibPointerId;
 use crate::libs::core::get_core_lib_type;
 use crate::libs::core::get_core_lib_type_reference;
 use crate::references::reference::ReferenceMutability;
 use crate::references::type_reference::TypeReference;
+use crate::runtime::execution::ExecutionError;
+use crate::stdlib::boxed::Box;
 use crate::stdlib::format;
 use crate::stdlib::rc::Rc;
 use crate::stdlib::string::String;
 use crate::stdlib::string::ToString;
 use crate::stdlib::vec::Vec;
 use crate::traits::structural_eq::StructuralEq;
+use crate::types::collection_type_definition::CollectionTypeDefinition;
 use crate::types::definition::TypeDefinition;
 use crate::types::structural_type_definition::StructuralTypeDefinition;
 use crate::values::core_value::CoreValue;
@@ -19,14 +24,12 @@ use crate::values::core_values::callable::CallableSignature;
 use crate::values::core_values::decimal::typed_decimal::DecimalTypeVariant;
 use crate::values::core_values::integer::typed_integer::IntegerTypeVariant;
 use crate::values::core_values::text::Text;
-use crate::values::pointer::PointerAddress;
 use crate::values::value_container::ValueContainer;
 use core::cell::RefCell;
 use core::fmt::Display;
 use core::hash::{Hash, Hasher};
 use core::prelude::rust_2024::*;
 use core::result::Result;
-use core::unimplemented;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Type {
@@ -85,6 +88,9 @@ impl Type {
     pub fn ty() -> Self {
         get_core_lib_type(CoreLibPointerId::Type)
     }
+    pub fn error() -> Self {
+        get_core_lib_type(CoreLibPointerId::Error)
+    }
 }
 
 impl Type {
@@ -201,7 +207,7 @@ impl Type {
 
     pub fn impl_type(
         base_type: impl Into<Type>,
-        impl_types: Vec<PointerAddress>,
+        impl_types: Vec<Rc<RefCell<TypeReference>>>,
     ) -> Self {
         Type {
             type_definition: TypeDefinition::impl_type(base_type, impl_types),
@@ -209,6 +215,240 @@ impl Type {
             reference_mutability: None,
         }
     }
+
+    /// Creates a generic type parameter, e.g. T
+    pub fn parameter(name: impl Into<String>) -> Self {
+        Type {
+            type_definition: TypeDefinition::parameter(name),
+            base_type: None,
+            reference_mutability: None,
+        }
+    }
+
+    /// Creates a list type of arbitrary length, e.g. integer[]
+    pub fn slice_list(item_type: impl Into<Type>) -> Self {
+        Type {
+            type_definition: TypeDefinition::slice_list(item_type),
+            base_type: None,
+            reference_mutability: None,
+        }
+    }
+
+    /// Creates a list type with a fixed size, e.g. [integer; 5]
+    pub fn fixed_size_list(item_type: impl Into<Type>, size: usize) -> Self {
+        Type {
+            type_definition: TypeDefinition::fixed_size_list(item_type, size),
+            base_type: None,
+            reference_mutability: None,
+        }
+    }
+
+    /// Creates an instance of a generic type, e.g. Box<integer>
+    pub fn instance(base: impl Into<Type>, arguments: Vec<Type>) -> Self {
+        Type {
+            type_definition: TypeDefinition::instance(base, arguments),
+            base_type: None,
+            reference_mutability: None,
+        }
+    }
+}
+
+impl Type {
+    /// Returns true if the type contains generic type parameters that are not
+    /// yet substituted, e.g. [T] or function (x: T) -> T
+    pub fn has_parameters(&self) -> bool {
+        match &self.type_definition {
+            TypeDefinition::Parameter(_) => true,
+            TypeDefinition::Structural(StructuralTypeDefinition::List(
+                items,
+            )) => items.iter().any(Type::has_parameters),
+            TypeDefinition::Structural(StructuralTypeDefinition::Map(
+                entries,
+            )) => entries.iter().any(|(key, value)| {
+                key.has_parameters() || value.has_parameters()
+            }),
+            TypeDefinition::Collection(collection) => match collection {
+                CollectionTypeDefinition::List(ty)
+                | CollectionTypeDefinition::ListSlice(ty, _) => {
+                    ty.has_parameters()
+                }
+                CollectionTypeDefinition::Map { key, value } => {
+                    key.has_parameters() || value.has_parameters()
+                }
+                CollectionTypeDefinition::Instance { arguments, .. } => {
+                    arguments.iter().any(Type::has_parameters)
+                }
+            },
+            TypeDefinition::Type(ty) | TypeDefinition::ImplType(ty, _) => {
+                ty.has_parameters()
+            }
+            TypeDefinition::Union(members)
+            | TypeDefinition::Intersection(members) => {
+                members.iter().any(Type::has_parameters)
+            }
+            TypeDefinition::Callable(signature) => {
+                signature
+                    .parameter_types
+                    .iter()
+                    .any(|(_, ty)| ty.has_parameters())
+                    || signature
+                        .rest_parameter_type
+                        .as_ref()
+                        .is_some_and(|(_, ty)| ty.has_parameters())
+                    || signature
+                        .return_type
+                        .as_ref()
+                        .is_some_and(|ty| ty.has_parameters())
+                    || signature
+                        .yeet_type
+                        .as_ref()
+                        .is_some_and(|ty| ty.has_parameters())
+            }
+            // referenced types are substituted when they are instantiated
+            TypeDefinition::Reference(_)
+            | TypeDefinition::Structural(_)
+            | TypeDefinition::Unit
+            | TypeDefinition::Never
+            | TypeDefinition::Unknown => false,
+        }
+    }
+
+    /// Replaces all generic type parameters with the types bound in `bindings`.
+    /// Parameters without a binding are kept as they are.
+    /// e.g. { value: T } with T = integer -> { value: integer }
+    pub fn substitute_parameters(
+        &self,
+        bindings: &HashMap<String, Type>,
+    ) -> Type {
+        let substitute_all = |types: &[Type]| -> Vec<Type> {
+            types
+                .iter()
+                .map(|ty| ty.substitute_parameters(bindings))
+                .collect()
+        };
+        let substitute_boxed =
+            |ty: &Type| Box::new(ty.substitute_parameters(bindings));
+
+        let type_definition = match &self.type_definition {
+            TypeDefinition::Parameter(name) => match bindings.get(name) {
+                Some(bound) => return bound.clone(),
+                None => self.type_definition.clone(),
+            },
+            TypeDefinition::Structural(StructuralTypeDefinition::List(
+                items,
+            )) => TypeDefinition::Structural(StructuralTypeDefinition::List(
+                substitute_all(items),
+            )),
+            TypeDefinition::Structural(StructuralTypeDefinition::Map(
+                entries,
+            )) => TypeDefinition::Structural(StructuralTypeDefinition::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| {
+                        (
+                            key.substitute_parameters(bindings),
+                            value.substitute_parameters(bindings),
+                        )
+                    })
+                    .collect(),
+            )),
+            TypeDefinition::Collection(collection) => {
+                TypeDefinition::Collection(match collection {
+                    CollectionTypeDefinition::List(ty) => {
+                        CollectionTypeDefinition::List(substitute_boxed(ty))
+                    }
+                    CollectionTypeDefinition::ListSlice(ty, size) => {
+                        CollectionTypeDefinition::ListSlice(
+                            substitute_boxed(ty),
+                            *size,
+                        )
+                    }
+                    CollectionTypeDefinition::Map { key, value } => {
+                        CollectionTypeDefinition::Map {
+                            key: substitute_boxed(key),
+                            value: substitute_boxed(value),
+                        }
+                    }
+                    CollectionTypeDefinition::Instance { base, arguments } => {
+                        CollectionTypeDefinition::Instance {
+                            base: base.clone(),
+                            arguments: substitute_all(arguments),
+                        }
+                    }
+                })
+            }
+            TypeDefinition::Type(ty) => {
+                TypeDefinition::Type(substitute_boxed(ty))
+            }
+            TypeDefinition::ImplType(ty, impls) => {
+                TypeDefinition::ImplType(substitute_boxed(ty), impls.clone())
+            }
+            TypeDefinition::Union(members) => {
+                TypeDefinition::Union(substitute_all(members))
+            }
+            TypeDefinition::Intersection(members) => {
+                TypeDefinition::Intersection(substitute_all(members))
+            }
+            TypeDefinition::Callable(signature) => {
+                TypeDefinition::Callable(CallableSignature {
+                    kind: signature.kind.clone(),
+                    generic_parameters: signature.generic_parameters.clone(),
+                    parameter_types: signature
+                        .parameter_types
+                        .iter()
+                        .map(|(name, ty)| {
+                            (name.clone(), ty.substitute_parameters(bindings))
+                        })
+                        .collect(),
+                    rest_parameter_type: signature
+                        .rest_parameter_type
+                        .as_ref()
+                        .map(|(name, ty)| (name.clone(), substitute_boxed(ty))),
+                    return_type: signature
+                        .return_type
+                        .as_deref()
+                        .map(substitute_boxed),
+                    yeet_type: signature
+                        .yeet_type
+                        .as_deref()
+                        .map(substitute_boxed),
+                })
+            }
+            TypeDefinition::Reference(_)
+            | TypeDefinition::Structural(_)
+            | TypeDefinition::Unit
+            | TypeDefinition::Never
+            | TypeDefinition::Unknown => return self.clone(),
+        };
+        Type {
+            type_definition,
+            base_type: self.base_type.clone(),
+            reference_mutability: self.reference_mutability.clone(),
+        }
+    }
+
+    /// Returns the definition of an instantiated generic type with all type
+    /// parameters replaced by the type arguments,
+    /// e.g. Box<integer> -> { value: integer } for type Box<T> = { value: T }
+    /// Returns None if the type is not an instance of a generic type.
+    pub fn instantiate(&self) -> Option<Type> {
+        let TypeDefinition::Collection(CollectionTypeDefinition::Instance {
+            base,
+            arguments,
+        }) = &self.type_definition
+        else {
+            return None;
+        };
+        let reference = base.inner_reference()?;
+        let reference = reference.borrow();
+        let bindings = reference
+            .generic_parameters
+            .iter()
+            .cloned()
+            .zip(arguments.iter().cloned())
+            .collect::<HashMap<_, _>>();
+        Some(reference.type_value.substitute_parameters(&bindings))
+    }
 }
 
 impl Type {
@@ -234,6 +474,13 @@ impl Type {
             TypeDefinition::Union(_) => {
                 core::todo!("#322 handle union base type"); // generic type base type / type
             }
+            TypeDefinition::Collection(
+                CollectionTypeDefinition::List(_)
+                | CollectionTypeDefinition::ListSlice(_, _),
+            ) => get_core_lib_type_reference(CoreLibPointerId::List),
+            TypeDefinition::Collection(CollectionTypeDefinition::Map {
+                ..
+            }) => get_core_lib_type_reference(CoreLibPointerId::Map),
             TypeDefinition::Reference(reference) => {
                 let type_ref = reference.borrow();
                 if let Some(pointer_address) = &type_ref.pointer_address {
@@ -280,6 +527,60 @@ impl Type {
         Type::value_matches_type(value, self)
     }
 
+    /// Returns true if this type is marked as implementing the given
+    /// interface, either directly (e.g. `{ name: text } + Named`) or
+    /// through a referenced nominal type that carries the marker.
+    pub fn implements(&self, interface: &Rc<RefCell<TypeReference>>) -> bool {
+        match &self.type_definition {
+            TypeDefinition::ImplType(ty, markers) => {
+                markers.iter().any(|marker| {
+                    TypeReference::is_same_declaration(marker, interface)
+                }) || ty.implements(interface)
+            }
+            TypeDefinition::Reference(reference) => {
+                TypeReference::is_same_declaration(reference, interface)
+                    || reference.borrow().type_value.implements(interface)
+            }
+            TypeDefinition::Type(ty) => ty.implements(interface),
+            _ => false,
+        }
+    }
+
+    /// Casts a value to this type, e.g. `42 as text`.
+    /// Referenced types convert the value (see [TypeReference::cast]),
+    /// instances of nominal generic types create a new value of the
+    /// instance type, e.g. `{value: 1} as Box<integer>`.
+    /// All other types only accept values that already match the type.
+    pub fn cast(
+        &self,
+        value: &ValueContainer,
+        mode: CastMode,
+    ) -> Result<ValueContainer, ExecutionError> {
+        match &self.type_definition {
+            TypeDefinition::Reference(reference) => {
+                TypeReference::cast(reference, value, mode)
+            }
+            TypeDefinition::Collection(
+                CollectionTypeDefinition::Instance { base, .. },
+            ) if self.value_matches(value) => {
+                let is_nominal = base.inner_reference().is_some_and(|r| {
+                    r.borrow().nominal_type_declaration.is_some()
+                });
+                if !is_nominal {
+                    return Ok(value.clone());
+                }
+                let mut value = value.to_value().borrow().clone();
+                value.actual_type = Box::new(self.type_definition.clone());
+                Ok(ValueContainer::from(value))
+            }
+            _ if self.value_matches(value) => Ok(value.clone()),
+            _ => Err(ExecutionError::InvalidTypeCast {
+                value: value.to_value().borrow().inner.to_string(),
+                target: self.to_string(),
+            }),
+        }
+    }
+
     /// 1 matches integer -> true
     /// integer matches 1 -> false
     /// integer matches integer -> true
@@ -378,8 +679,44 @@ impl Type {
                 structural_type.value_matches(value)
             }
             TypeDefinition::Reference(reference) => {
-                core::todo!("#327 handle reference type matching");
-                //reference.value_matches(value)
+                // every value matches unknown
+                if *reference.borrow()
+                    == *get_core_lib_type_reference(CoreLibPointerId::Unknown)
+                        .borrow()
+                {
+                    return true;
+                }
+                // nominal match against the actual type of the value,
+                // e.g. error("x") matches error
+                if let TypeDefinition::Reference(actual_type) =
+                    value.to_value().borrow().actual_type()
+                    && *actual_type.borrow() == *reference.borrow()
+                {
+                    return true;
+                }
+                // nominal match against the core type chain of the value,
+                // e.g. 1u8 matches integer/u8 and integer
+                let value_type = Type::from(&value.to_value().borrow().inner);
+                let mut current = value_type.base_type_reference();
+                while let Some(base) = current {
+                    if *base.borrow() == *reference.borrow() {
+                        return true;
+                    }
+                    let next = Type::new(
+                        TypeDefinition::Reference(base.clone()),
+                        None,
+                    )
+                    .base_type_reference();
+                    // core base types are their own base type
+                    if next
+                        .as_ref()
+                        .is_some_and(|next| *next.borrow() == *base.borrow())
+                    {
+                        break;
+                    }
+                    current = next;
+                }
+                false
             }
             TypeDefinition::Type(inner_type) => {
                 // TODO #464: also check mutability of current type?
@@ -388,12 +725,47 @@ impl Type {
             TypeDefinition::Callable(signature) => {
                 core::todo!("#328 handle function type matching");
             }
+            TypeDefinition::Collection(
+                CollectionTypeDefinition::Instance { .. },
+            ) => match_type
+                .instantiate()
+                .is_some_and(|ty| Type::value_matches_type(value, &ty)),
             TypeDefinition::Collection(collection_type) => {
-                core::todo!("#329 handle collection type matching");
+                let value = value.to_value();
+                let value = value.borrow();
+                match (collection_type, &value.inner) {
+                    // e.g. [1, 2, 3] matches integer[]
+                    (
+                        CollectionTypeDefinition::List(item_type),
+                        CoreValue::List(list),
+                    ) => list.iter().all(|item| item_type.value_matches(item)),
+                    // e.g. [1, 2, 3] matches [integer; 3]
+                    (
+                        CollectionTypeDefinition::ListSlice(item_type, size),
+                        CoreValue::List(list),
+                    ) => {
+                        list.len() as usize == *size
+                            && list
+                                .iter()
+                                .all(|item| item_type.value_matches(item))
+                    }
+                    (
+                        CollectionTypeDefinition::Map {
+                            key: key_type,
+                            value: value_type,
+                        },
+                        CoreValue::Map(map),
+                    ) => map.into_iter().all(|(key, value)| {
+                        key_type.value_matches(&ValueContainer::from(key))
+                            && value_type.value_matches(value)
+                    }),
+                    _ => false,
+                }
             }
             TypeDefinition::Unit => false, // unit type does not match any value
             TypeDefinition::Never => false,
             TypeDefinition::Unknown => false,
+            TypeDefinition::Parameter(_) => false,
             TypeDefinition::ImplType(ty, _) => {
                 Type::value_matches_type(value, ty)
             }
@@ -473,7 +845,11 @@ impl From<&CoreValue> for Type {
                     .collect::<Vec<_>>();
                 Type::structural(StructuralTypeDefinition::Map(struct_types))
             }
-            e => unimplemented!("Type conversion not implemented for {}", e),
+            // a type value is an instance of the core type `type`
+            CoreValue::Type(_) => Type::ty(),
+            CoreValue::Callable(callable) => {
+                Type::callable(callable.signature.clone())
+            }
         }
     }
 }
@@ -525,6 +901,7 @@ mod tests {
     use crate::values::{
         core_values::{
             integer::{Integer, typed_integer::TypedInteger},
+            list::List,
             text::Text,
             r#type::Type,
         },
@@ -594,38 +971,63 @@ mod tests {
         );
     }
 
-    // TODO #330
-    // #[test]
-    // fn test_match_combined_type() {
-    //     // [1, 1] matches List<1>
-    //     assert!(Type::value_matches_type(
-    //         &ValueContainer::from(List::from(vec![1, 1])),
-    //         &Type::list(Type::structural(1))
-    //     ));
-    //
-    //     // [1, 2] matches List<(1 | 2)>
-    //     assert!(Type::value_matches_type(
-    //         &ValueContainer::from(List::from(vec![1, 2])),
-    //         &Type::list(Type::union(vec![
-    //             Type::structural(1).as_type_container(),
-    //             Type::structural(2).as_type_container(),
-    //         ])),
-    //     ));
-    //
-    //     // [1, 2] does not match List<1>
-    //     assert!(!Type::value_matches_type(
-    //         &ValueContainer::from(List::from(vec![1, 2])),
-    //         &Type::list(Type::structural(1))
-    //     ));
-    //
-    //     // ["test", "jonas"] matches List<("jonas" | "test" | 3)>
-    //     assert!(Type::value_matches_type(
-    //         &ValueContainer::from(List::from(vec!["test", "jonas"])),
-    //         &Type::list(Type::union(vec![
-    //             Type::structural("jonas"),
-    //             Type::structural("test"),
-    //             Type::structural(3),
-    //         ])),
-    //     ));
-    // }
+    #[test]
+    fn test_match_combined_type() {
+        // [1, 1] matches 1[]
+        assert!(Type::value_matches_type(
+            &ValueContainer::from(List::from(vec![1, 1])),
+            &Type::slice_list(Type::structural(1))
+        ));
+
+        // [1, 2] matches (1 | 2)[]
+        assert!(Type::value_matches_type(
+            &ValueContainer::from(List::from(vec![1, 2])),
+            &Type::slice_list(Type::union(vec![
+                Type::structural(1),
+                Type::structural(2),
+            ])),
+        ));
+
+        // [1, 2] does not match 1[]
+        assert!(!Type::value_matches_type(
+            &ValueContainer::from(List::from(vec![1, 2])),
+            &Type::slice_list(Type::structural(1))
+        ));
+
+        // ["test", "jonas"] matches ("jonas" | "test" | 3)[]
+        assert!(Type::value_matches_type(
+            &ValueContainer::from(List::from(vec!["test", "jonas"])),
+            &Type::slice_list(Type::union(vec![
+                Type::structural("jonas"),
+                Type::structural("test"),
+                Type::structural(3),
+            ])),
+        ));
+    }
+
+    #[test]
+    fn test_match_fixed_size_list() {
+        let integer = get_core_lib_type(CoreLibPointerId::Integer(None));
+
+        // [1, 2u8] matches [integer; 2]
+        assert!(Type::value_matches_type(
+            &ValueContainer::from(List::from(vec![
+                ValueContainer::from(Integer::from(1)),
+                ValueContainer::from(TypedInteger::from(2u8)),
+            ])),
+            &Type::fixed_size_list(integer.clone(), 2)
+        ));
+
+        // [1, 2, 3] does not match [integer; 2]
+        assert!(!Type::value_matches_type(
+            &ValueContainer::from(List::from(vec![1, 2, 3])),
+            &Type::fixed_size_list(integer.clone(), 2)
+        ));
+
+        // ["a", "b"] does not match [integer; 2]
+        assert!(!Type::value_matches_type(
+            &ValueContainer::from(List::from(vec!["a", "b"])),
+            &Type::fixed_size_list(integer, 2)
+        ));
+    }
 }
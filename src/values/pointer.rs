@@ -4,6 +4,7 @@ use crate::global::protocol_structures::instructions::{
 use crate::stdlib::format;
 use crate::stdlib::string::String;
 use crate::stdlib::vec::Vec;
+use crate::values::core_values::endpoint::Endpoint;
 use binrw::BinWrite;
 use binrw::io::Cursor;
 use core::fmt::Display;
@@ -98,6 +99,48 @@ impl From<&RawPointerAddress> for PointerAddress {
 }
 
 impl PointerAddress {
+    /// Creates a PointerAddress from a RawFullPointerAddress.
+    /// If the origin endpoint of the address is the given local endpoint
+    /// (or @@local), a PointerAddress::Local is returned, otherwise a
+    /// PointerAddress::Remote containing the full address.
+    pub fn from_raw_full_address(
+        raw_address: &RawFullPointerAddress,
+        local_endpoint: &Endpoint,
+    ) -> PointerAddress {
+        match raw_address.endpoint() {
+            Ok(origin)
+                if &origin == local_endpoint || origin == Endpoint::LOCAL =>
+            {
+                let mut id = [0u8; 5];
+                id.copy_from_slice(&raw_address.id[21..]);
+                PointerAddress::Local(id)
+            }
+            _ => PointerAddress::Remote(raw_address.id),
+        }
+    }
+
+    /// Converts the address to a RawFullPointerAddress.
+    /// Local addresses are prefixed with the given origin endpoint,
+    /// remote addresses are returned as is.
+    /// Internal addresses have no full representation and return None.
+    pub fn to_raw_full_address(
+        &self,
+        origin: &Endpoint,
+    ) -> Option<RawFullPointerAddress> {
+        match self {
+            PointerAddress::Local(bytes) => {
+                let mut id = [0u8; 26];
+                id[0..21].copy_from_slice(&origin.to_slice());
+                id[21..].copy_from_slice(bytes);
+                Some(RawFullPointerAddress { id })
+            }
+            PointerAddress::Remote(bytes) => {
+                Some(RawFullPointerAddress { id: *bytes })
+            }
+            PointerAddress::Internal(_) => None,
+        }
+    }
+
     pub fn to_address_string(&self) -> String {
         match self {
             PointerAddress::Local(bytes) => hex::encode(bytes),
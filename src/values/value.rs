@@ -1,6 +1,7 @@
 use crate::dif::update::{DIFKey, DIFUpdateData};
 use crate::dif::value::DIFValueContainer;
-use crate::libs::core::CoreLibPointerId;
+use crate::global::operators::CastMode;
+use crate::libs::core::{CoreLibPointerId, get_core_lib_type_definition};
 use crate::references::mutations::DIFUpdateDataOrMemory;
 use crate::references::observers::TransceiverId;
 use crate::references::reference::AccessError;
@@ -10,15 +11,18 @@ use crate::stdlib::boxed::Box;
 use crate::stdlib::format;
 use crate::stdlib::string::String;
 use crate::stdlib::string::ToString;
+use crate::stdlib::vec;
 use crate::traits::apply::Apply;
 use crate::traits::structural_eq::StructuralEq;
 use crate::traits::value_eq::ValueEq;
+use crate::types::collection_type_definition::CollectionTypeDefinition;
 use crate::types::definition::TypeDefinition;
 use crate::values::core_value::CoreValue;
 use crate::values::core_values::callable::{
     Callable, CallableBody, CallableSignature,
 };
 use crate::values::core_values::integer::typed_integer::TypedInteger;
+use crate::values::core_values::map::Map;
 use crate::values::value_container::{ValueContainer, ValueError, ValueKey};
 use core::fmt::{Display, Formatter};
 use core::ops::{Add, AddAssign, Deref, Neg, Not, Sub};
@@ -72,6 +76,16 @@ impl Apply for Value {
     ) -> Result<Option<ValueContainer>, ExecutionError> {
         match self.inner {
             CoreValue::Callable(ref callable) => callable.apply_single(arg),
+            CoreValue::Type(ref ty) => match &ty.type_definition {
+                TypeDefinition::Reference(reference) => {
+                    TypeReference::construct(reference, arg)
+                }
+                // instances of generic types, e.g. `Box<integer>({value: 1})`
+                TypeDefinition::Collection(
+                    CollectionTypeDefinition::Instance { .. },
+                ) => ty.cast(arg, CastMode::Checked).map(Some),
+                _ => Err(ExecutionError::InvalidApply),
+            },
             _ => Err(ExecutionError::InvalidApply),
         }
     }
@@ -109,6 +123,53 @@ impl Value {
         }
     }
 
+    /// Creates a new error value with the given message and payload,
+    /// e.g. error({message: "Not found", payload: 404})
+    pub fn error(message: impl Into<String>, payload: ValueContainer) -> Self {
+        Value {
+            inner: CoreValue::Map(Map::from_iter(vec![
+                ("message".to_string(), ValueContainer::from(message.into())),
+                ("payload".to_string(), payload),
+            ])),
+            actual_type: Box::new(get_core_lib_type_definition(
+                CoreLibPointerId::Error,
+            )),
+        }
+    }
+
+    /// Returns true if the value is an error value (actual type is #core.error)
+    pub fn is_error(&self) -> bool {
+        if let TypeDefinition::Reference(type_reference) =
+            self.actual_type.as_ref()
+            && let Some(pointer_address) =
+                &type_reference.borrow().pointer_address
+        {
+            CoreLibPointerId::try_from(pointer_address)
+                == Ok(CoreLibPointerId::Error)
+        } else {
+            false
+        }
+    }
+
+    /// Returns the message of an error value
+    pub fn error_message(&self) -> Option<String> {
+        if !self.is_error() {
+            return None;
+        }
+        match &self.inner {
+            CoreValue::Map(map) => {
+                map.get("message").ok().map(|message| match message {
+                    ValueContainer::Value(Value {
+                        inner: CoreValue::Text(text),
+                        ..
+                    }) => text.0.clone(),
+                    message => message.to_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+
     pub fn is_type(&self) -> bool {
         core::matches!(self.inner, CoreValue::Type(_))
     }
@@ -4,10 +4,11 @@ use crate::ast::expressions::{
     Apply, BinaryOperation, CallableDeclaration, ComparisonOperation,
     Conditional, CreateRef, DatexExpression, DatexExpressionData, Deref,
     DerefAssignment, List, Map, PropertyAccess, PropertyAssignment,
-    RemoteExecution, Slot, SlotAssignment, Statements, TypeDeclaration,
-    UnaryOperation, VariableAccess, VariableAssignment, VariableDeclaration,
-    VariantAccess,
+    RemoteExecution, Slot, SlotAssignment, Statements, Try, TypeCast,
+    TypeDeclaration, UnaryOperation, VariableAccess, VariableAssignment,
+    VariableDeclaration, VariantAccess, Yeet,
 };
+use crate::ast::expressions::{Export, Import};
 use crate::values::core_values::decimal::Decimal;
 use crate::values::core_values::decimal::typed_decimal::TypedDecimal;
 use crate::values::core_values::endpoint::Endpoint;
@@ -141,9 +142,18 @@ pub trait ExpressionVisitor<E>: TypeExpressionVisitor<E> {
                     &expr.span,
                 )
             }
+            DatexExpressionData::TypeCast(type_cast) => {
+                self.visit_type_cast(type_cast, &expr.span)
+            }
             DatexExpressionData::DerefAssignment(deref_assignment) => {
                 self.visit_deref_assignment(deref_assignment, &expr.span)
             }
+            DatexExpressionData::Yeet(yeet) => {
+                self.visit_yeet(yeet, &expr.span)
+            }
+            DatexExpressionData::Try(r#try) => {
+                self.visit_try(r#try, &expr.span)
+            }
             DatexExpressionData::Apply(apply_chain) => {
                 self.visit_apply(apply_chain, &expr.span)
             }
@@ -157,18 +167,23 @@ pub trait ExpressionVisitor<E>: TypeExpressionVisitor<E> {
             DatexExpressionData::RemoteExecution(remote_execution) => {
                 self.visit_remote_execution(remote_execution, &expr.span)
             }
+            DatexExpressionData::Export(export) => {
+                self.visit_export(export, &expr.span)
+            }
+            DatexExpressionData::Import(import) => {
+                self.visit_import(import, &expr.span)
+            }
+            DatexExpressionData::Debugger => self.visit_debugger(&expr.span),
             DatexExpressionData::Identifier(identifier) => {
                 self.visit_identifier(identifier, &expr.span)
             }
             DatexExpressionData::Placeholder => {
                 self.visit_placeholder(&expr.span)
             }
-            DatexExpressionData::Recover => {
-                unreachable!(
-                    "Placeholder and Recover expressions should not be visited"
-                )
+            // recovered parser errors are reported by the parser
+            DatexExpressionData::Recover | DatexExpressionData::Noop => {
+                Ok(VisitAction::SkipChildren)
             }
-            DatexExpressionData::Noop => Ok(VisitAction::SkipChildren),
             DatexExpressionData::NativeImplementationIndicator => {
                 Ok(VisitAction::SkipChildren)
             }
@@ -304,6 +319,17 @@ pub trait ExpressionVisitor<E>: TypeExpressionVisitor<E> {
         Ok(VisitAction::VisitChildren)
     }
 
+    /// Visit type cast
+    fn visit_type_cast(
+        &mut self,
+        type_cast: &mut TypeCast,
+        span: &Range<usize>,
+    ) -> ExpressionVisitResult<E> {
+        let _ = span;
+        let _ = type_cast;
+        Ok(VisitAction::VisitChildren)
+    }
+
     /// Visit dereference assignment
     fn visit_deref_assignment(
         &mut self,
@@ -315,6 +341,28 @@ pub trait ExpressionVisitor<E>: TypeExpressionVisitor<E> {
         Ok(VisitAction::VisitChildren)
     }
 
+    /// Visit yeet expression
+    fn visit_yeet(
+        &mut self,
+        yeet: &mut Yeet,
+        span: &Range<usize>,
+    ) -> ExpressionVisitResult<E> {
+        let _ = span;
+        let _ = yeet;
+        Ok(VisitAction::VisitChildren)
+    }
+
+    /// Visit try expression
+    fn visit_try(
+        &mut self,
+        r#try: &mut Try,
+        span: &Range<usize>,
+    ) -> ExpressionVisitResult<E> {
+        let _ = span;
+        let _ = r#try;
+        Ok(VisitAction::VisitChildren)
+    }
+
     /// Visit apply chain
     fn visit_apply(
         &mut self,
@@ -359,6 +407,37 @@ pub trait ExpressionVisitor<E>: TypeExpressionVisitor<E> {
         Ok(VisitAction::VisitChildren)
     }
 
+    /// Visit exported declaration
+    fn visit_export(
+        &mut self,
+        export: &mut Export,
+        span: &Range<usize>,
+    ) -> ExpressionVisitResult<E> {
+        let _ = span;
+        let _ = export;
+        Ok(VisitAction::VisitChildren)
+    }
+
+    /// Visit import
+    fn visit_import(
+        &mut self,
+        import: &mut Import,
+        span: &Range<usize>,
+    ) -> ExpressionVisitResult<E> {
+        let _ = span;
+        let _ = import;
+        Ok(VisitAction::SkipChildren)
+    }
+
+    /// Visit debugger statement
+    fn visit_debugger(
+        &mut self,
+        span: &Range<usize>,
+    ) -> ExpressionVisitResult<E> {
+        let _ = span;
+        Ok(VisitAction::SkipChildren)
+    }
+
     /// Visit callable declaration
     fn visit_callable_declaration(
         &mut self,
@@ -1,10 +1,12 @@
 use crate::ast::expressions::Apply;
+use crate::ast::expressions::Export;
 use crate::ast::expressions::{
     BinaryOperation, CallableDeclaration, ComparisonOperation, Conditional,
     CreateRef, DatexExpression, DatexExpressionData, Deref, DerefAssignment,
     GenericInstantiation, List, Map, PropertyAccess, PropertyAssignment,
-    RemoteExecution, SlotAssignment, Statements, TypeDeclaration,
-    UnaryOperation, VariableAssignment, VariableDeclaration,
+    RemoteExecution, SlotAssignment, Statements, Try, TypeCast,
+    TypeDeclaration, UnaryOperation, VariableAssignment, VariableDeclaration,
+    Yeet,
 };
 use crate::visitor::VisitAction;
 use crate::visitor::expression::ExpressionVisitor;
@@ -126,6 +128,16 @@ impl<E> VisitableExpression<E> for ComparisonOperation {
         Ok(())
     }
 }
+impl<E> VisitableExpression<E> for TypeCast {
+    fn walk_children(
+        &mut self,
+        visitor: &mut impl ExpressionVisitor<E>,
+    ) -> Result<(), E> {
+        visitor.visit_datex_expression(&mut self.expression)?;
+        visitor.visit_type_expression(&mut self.target)?;
+        Ok(())
+    }
+}
 impl<E> VisitableExpression<E> for DerefAssignment {
     fn walk_children(
         &mut self,
@@ -136,6 +148,24 @@ impl<E> VisitableExpression<E> for DerefAssignment {
         Ok(())
     }
 }
+impl<E> VisitableExpression<E> for Yeet {
+    fn walk_children(
+        &mut self,
+        visitor: &mut impl ExpressionVisitor<E>,
+    ) -> Result<(), E> {
+        visitor.visit_datex_expression(&mut self.expression)?;
+        Ok(())
+    }
+}
+impl<E> VisitableExpression<E> for Try {
+    fn walk_children(
+        &mut self,
+        visitor: &mut impl ExpressionVisitor<E>,
+    ) -> Result<(), E> {
+        visitor.visit_datex_expression(&mut self.expression)?;
+        Ok(())
+    }
+}
 impl<E> VisitableExpression<E> for Apply {
     fn walk_children(
         &mut self,
@@ -240,6 +270,16 @@ impl<E> VisitableExpression<E> for PropertyAssignment {
     }
 }
 
+impl<E> VisitableExpression<E> for Export {
+    fn walk_children(
+        &mut self,
+        visitor: &mut impl ExpressionVisitor<E>,
+    ) -> Result<(), E> {
+        visitor.visit_datex_expression(&mut self.declaration)?;
+        Ok(())
+    }
+}
+
 impl<E> VisitableExpression<E> for DatexExpression {
     fn walk_children(
         &mut self,
@@ -287,9 +327,14 @@ impl<E> VisitableExpression<E> for DatexExpression {
             DatexExpressionData::ComparisonOperation(comparison_operation) => {
                 comparison_operation.walk_children(visitor)
             }
+            DatexExpressionData::TypeCast(type_cast) => {
+                type_cast.walk_children(visitor)
+            }
             DatexExpressionData::DerefAssignment(deref_assignment) => {
                 deref_assignment.walk_children(visitor)
             }
+            DatexExpressionData::Yeet(yeet) => yeet.walk_children(visitor),
+            DatexExpressionData::Try(r#try) => r#try.walk_children(visitor),
             DatexExpressionData::UnaryOperation(unary_operation) => {
                 unary_operation.walk_children(visitor)
             }
@@ -305,10 +350,15 @@ impl<E> VisitableExpression<E> for DatexExpression {
             DatexExpressionData::RemoteExecution(remote_execution) => {
                 remote_execution.walk_children(visitor)
             }
+            DatexExpressionData::Export(export) => {
+                export.walk_children(visitor)
+            }
 
             DatexExpressionData::Noop
             | DatexExpressionData::NativeImplementationIndicator
             | DatexExpressionData::VariantAccess(_)
+            | DatexExpressionData::Import(_)
+            | DatexExpressionData::Debugger
             | DatexExpressionData::PointerAddress(_)
             | DatexExpressionData::VariableAccess(_)
             | DatexExpressionData::GetReference(_)
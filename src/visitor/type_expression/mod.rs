@@ -2,8 +2,8 @@ use core::ops::Range;
 
 use crate::ast::expressions::VariableAccess;
 use crate::ast::type_expressions::{
-    CallableTypeExpression, FixedSizeList, GenericAccess, Intersection,
-    SliceList, StructuralList, StructuralMap, TypeExpression,
+    CallableTypeExpression, FixedSizeList, GenericAccess, ImplType,
+    Intersection, SliceList, StructuralList, StructuralMap, TypeExpression,
     TypeExpressionData, TypeVariantAccess, Union,
 };
 use crate::values::core_values::decimal::Decimal;
@@ -96,6 +96,9 @@ pub trait TypeExpressionVisitor<E>: Sized {
             TypeExpressionData::Union(union) => {
                 self.visit_union_type(union, &expr.span)
             }
+            TypeExpressionData::ImplType(impl_type) => {
+                self.visit_impl_type(impl_type, &expr.span)
+            }
             TypeExpressionData::GenericAccess(generic_access) => {
                 self.visit_generic_access_type(generic_access, &expr.span)
             }
@@ -224,6 +227,17 @@ pub trait TypeExpressionVisitor<E>: Sized {
         Ok(VisitAction::VisitChildren)
     }
 
+    /// Visit impl type expression
+    fn visit_impl_type(
+        &mut self,
+        impl_type: &mut ImplType,
+        span: &Range<usize>,
+    ) -> TypeExpressionVisitResult<E> {
+        let _ = span;
+        let _ = impl_type;
+        Ok(VisitAction::VisitChildren)
+    }
+
     /// Visit generic access type expression
     fn visit_generic_access_type(
         &mut self,
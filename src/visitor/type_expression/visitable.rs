@@ -1,6 +1,6 @@
 use crate::ast::type_expressions::{
-    CallableTypeExpression, FixedSizeList, GenericAccess, Intersection,
-    SliceList, StructuralList, StructuralMap, TypeExpression,
+    CallableTypeExpression, FixedSizeList, GenericAccess, ImplType,
+    Intersection, SliceList, StructuralList, StructuralMap, TypeExpression,
     TypeExpressionData, Union,
 };
 use crate::visitor::VisitAction;
@@ -72,6 +72,7 @@ impl<E> VisitableTypeExpression<E> for GenericAccess {
         &mut self,
         visitor: &mut impl TypeExpressionVisitor<E>,
     ) -> Result<(), E> {
+        visitor.visit_type_expression(&mut self.base)?;
         for arg in &mut self.access {
             visitor.visit_type_expression(arg)?;
         }
@@ -110,6 +111,19 @@ impl<E> VisitableTypeExpression<E> for StructuralMap {
     }
 }
 
+impl<E> VisitableTypeExpression<E> for ImplType {
+    fn walk_children(
+        &mut self,
+        visitor: &mut impl TypeExpressionVisitor<E>,
+    ) -> Result<(), E> {
+        visitor.visit_type_expression(&mut self.base)?;
+        for interface in &mut self.interfaces {
+            visitor.visit_type_expression(interface)?;
+        }
+        Ok(())
+    }
+}
+
 impl<E> VisitableTypeExpression<E> for TypeExpression {
     fn walk_children(
         &mut self,
@@ -129,6 +143,9 @@ impl<E> VisitableTypeExpression<E> for TypeExpression {
                 intersection.walk_children(visitor)
             }
             TypeExpressionData::Union(union) => union.walk_children(visitor),
+            TypeExpressionData::ImplType(impl_type) => {
+                impl_type.walk_children(visitor)
+            }
             TypeExpressionData::GenericAccess(generic_access) => {
                 generic_access.walk_children(visitor)
             }
@@ -7,6 +7,7 @@ use datex_core::{
         value::{DIFValue, DIFValueContainer},
     },
     libs::core::CoreLibPointerId,
+    references::type_reference::TypeReference,
     runtime::memory::Memory,
     types::definition::TypeDefinition,
     values::{
@@ -38,7 +39,13 @@ fn dif_value_with_type() {
         inner: CoreValue::Null,
         actual_type: Box::new(TypeDefinition::ImplType(
             Box::new(Type::integer()),
-            vec![PointerAddress::Local([0, 0, 0, 0, 0])],
+            vec![
+                TypeReference::anonymous(
+                    Type::unit(),
+                    Some(PointerAddress::Local([0, 0, 0, 0, 0])),
+                )
+                .as_ref_cell(),
+            ],
         )),
     });
 
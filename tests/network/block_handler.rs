@@ -3,11 +3,13 @@ use crate::network::helpers::mock_setup::{
     TEST_ENDPOINT_A, TEST_ENDPOINT_ORIGIN, get_mock_setup_and_socket,
 };
 use core::cell::RefCell;
+use core::time::Duration;
 use datex_core::global::dxb_block::{DXBBlock, IncomingSection};
 use datex_core::global::protocol_structures::block_header::{
     BlockHeader, BlockType, FlagsAndTimestamp,
 };
 use datex_core::global::protocol_structures::routing_header::RoutingHeader;
+use datex_core::network::block_handler::BlockHandler;
 use datex_core::run_async;
 use log::info;
 use std::rc::Rc;
@@ -511,3 +513,66 @@ async fn await_response_block() {
 
     }
 }
+
+#[test]
+fn dispose_idle_scope_context() {
+    init_global_context();
+    let block_handler = BlockHandler::new();
+
+    let create_block = |block_number| DXBBlock {
+        block_header: BlockHeader {
+            context_id: 1,
+            block_number,
+            flags_and_timestamp: FlagsAndTimestamp::new()
+                .with_is_end_of_section(false)
+                .with_is_end_of_context(false),
+            ..BlockHeader::default()
+        },
+        routing_header: RoutingHeader::default()
+            .with_sender(TEST_ENDPOINT_A.clone())
+            .to_owned(),
+        ..DXBBlock::default()
+    };
+
+    let first_block = create_block(0);
+    let endpoint_context_id = first_block.get_endpoint_context_id();
+    block_handler.handle_incoming_block(first_block);
+    assert_eq!(
+        block_handler
+            .incoming_sections_queue
+            .borrow_mut()
+            .drain(..)
+            .count(),
+        1
+    );
+    assert!(
+        block_handler
+            .block_cache
+            .borrow()
+            .contains_key(&endpoint_context_id)
+    );
+
+    // the scope context is not disposed before the timeout has passed
+    block_handler.dispose_idle_scope_contexts(60_000);
+    assert!(
+        block_handler
+            .block_cache
+            .borrow()
+            .contains_key(&endpoint_context_id)
+    );
+
+    std::thread::sleep(Duration::from_millis(2));
+    block_handler.dispose_idle_scope_contexts(0);
+    assert!(block_handler.block_cache.borrow().is_empty());
+    assert!(block_handler.is_scope_context_disposed(&endpoint_context_id));
+
+    // a late block for the disposed scope context is passed on as a single block section
+    block_handler.handle_incoming_block(create_block(1));
+    let sections = block_handler.incoming_sections_queue.borrow();
+    assert_eq!(sections.len(), 1);
+    assert!(core::matches!(
+        sections.front(),
+        Some(IncomingSection::SingleBlock((Some(block), ..))) if block.block_header.block_number == 1
+    ));
+    assert!(block_handler.block_cache.borrow().is_empty());
+}
@@ -32,6 +32,8 @@ use datex_core::network::com_interfaces::com_interface::{
 use datex_core::network::com_interfaces::com_interface_socket::SocketState;
 use datex_core::runtime::AsyncContext;
 use datex_core::values::core_values::endpoint::Endpoint;
+use datex_core::values::pointer::PointerAddress;
+use datex_core::global::protocol_structures::instructions::RawFullPointerAddress;
 
 #[tokio::test]
 pub async fn test_add_and_remove() {
@@ -299,6 +301,7 @@ pub async fn test_receive() {
             },
             ..DXBBlock::default()
         };
+        block.routing_header.sender = TEST_ENDPOINT_A.clone();
         block.set_receivers(vec![TEST_ENDPOINT_ORIGIN.clone()]);
         block.recalculate_struct();
 
@@ -337,8 +340,13 @@ pub async fn unencrypted_signature_prepare_block_com_hub() {
         block.set_receivers(vec![TEST_ENDPOINT_ORIGIN.clone()]);
         block.recalculate_struct();
 
+        // the block is signed by the remote endpoint
+        let (remote_com_hub, _) = get_mock_setup_with_endpoint(
+            TEST_ENDPOINT_A.clone(),
+            InterfacePriority::default(),
+        ).await;
         block.routing_header.flags.set_signature_type(SignatureType::Unencrypted);
-        block = com_hub.prepare_own_block(block).await.unwrap();
+        block = remote_com_hub.prepare_own_block(block).await.unwrap();
 
         let block_bytes = block.to_bytes().unwrap();
         {
@@ -378,8 +386,13 @@ pub async fn encrypted_signature_prepare_block_com_hub() {
         block.set_receivers(vec![TEST_ENDPOINT_ORIGIN.clone()]);
         block.recalculate_struct();
 
+        // the block is signed by the remote endpoint
+        let (remote_com_hub, _) = get_mock_setup_with_endpoint(
+            TEST_ENDPOINT_A.clone(),
+            InterfacePriority::default(),
+        ).await;
         block.routing_header.flags.set_signature_type(SignatureType::Encrypted);
-        block = com_hub.prepare_own_block(block).await.unwrap();
+        block = remote_com_hub.prepare_own_block(block).await.unwrap();
 
         let block_bytes = block.to_bytes().unwrap();
         {
@@ -435,6 +448,7 @@ pub async fn test_receive_multiple() {
 
         for block in &mut blocks {
             // set receiver to ORIGIN
+            block.routing_header.sender = TEST_ENDPOINT_A.clone();
             block.set_receivers(vec![TEST_ENDPOINT_ORIGIN.clone()]);
         }
 
@@ -455,6 +469,7 @@ pub async fn test_receive_multiple() {
         com_hub.update_async().await;
 
         let incoming_blocks = get_all_received_single_blocks_from_com_hub(&com_hub);
+        assert_eq!(incoming_blocks.len(), blocks.len());
 
         for (incoming_block, block) in incoming_blocks.iter().zip(blocks.iter()) {
             assert_eq!(
@@ -465,6 +480,44 @@ pub async fn test_receive_multiple() {
     }
 }
 
+#[tokio::test]
+pub async fn drop_blocks_with_local_sender_from_remote_socket() {
+    run_async! {
+        init_global_context();
+        let (com_hub, _, socket) = get_mock_setup_and_socket().await;
+
+        // a remote endpoint must not be able to pose as the local endpoint
+        let blocks = [Endpoint::LOCAL, TEST_ENDPOINT_ORIGIN.clone()]
+            .into_iter()
+            .enumerate()
+            .map(|(section_index, sender)| {
+                let mut block = DXBBlock {
+                    block_header: BlockHeader {
+                        section_index: section_index as u16,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                };
+                block.routing_header.sender = sender;
+                block.set_receivers(vec![TEST_ENDPOINT_ORIGIN.clone()]);
+                block
+            })
+            .collect::<Vec<_>>();
+
+        {
+            let socket_ref = socket.try_lock().unwrap();
+            let receive_queue = socket_ref.get_receive_queue();
+            let mut receive_queue_mut = receive_queue.try_lock().unwrap();
+            for block in blocks.iter() {
+                let _ = receive_queue_mut.write(&block.to_bytes().unwrap());
+            }
+        }
+        com_hub.update_async().await;
+
+        assert!(get_all_received_single_blocks_from_com_hub(&com_hub).is_empty());
+    }
+}
+
 #[tokio::test]
 pub async fn test_add_and_remove_interface_and_sockets() {
     run_async! {
@@ -652,3 +705,168 @@ pub async fn test_reconnect() {
         );
     }
 }
+
+#[tokio::test]
+pub async fn send_block_to_pointer_subscribers() {
+    run_async! {
+        init_global_context();
+        let (com_hub, com_interface) = get_mock_setup().await;
+
+        let socket_a = add_socket(com_interface.clone());
+        let socket_b = add_socket(com_interface.clone());
+        register_socket_endpoint(
+            com_interface.clone(),
+            socket_a.clone(),
+            TEST_ENDPOINT_A.clone(),
+        );
+        register_socket_endpoint(
+            com_interface.clone(),
+            socket_b.clone(),
+            TEST_ENDPOINT_B.clone(),
+        );
+        com_hub.update_async().await;
+
+        let pointer_address = PointerAddress::Local([1, 2, 3, 4, 5]);
+        assert!(com_hub
+            .add_pointer_subscriber(&pointer_address, TEST_ENDPOINT_A.clone()));
+        assert!(com_hub
+            .add_pointer_subscriber(&pointer_address, TEST_ENDPOINT_B.clone()));
+        assert!(!com_hub
+            .add_pointer_subscriber(&pointer_address, TEST_ENDPOINT_B.clone()));
+
+        let mut block = DXBBlock::default();
+        block.set_receivers(
+            pointer_address
+                .to_raw_full_address(&TEST_ENDPOINT_ORIGIN)
+                .unwrap(),
+        );
+        assert!(!block.has_exact_receiver_count());
+        com_hub.send_own_block(block).await.unwrap();
+        com_hub.update_async().await;
+
+        let mockup_interface_out = com_interface.clone();
+        let mockup_interface_out = mockup_interface_out.borrow();
+        assert_eq!(mockup_interface_out.outgoing_queue.len(), 2);
+        assert!(mockup_interface_out
+            .has_outgoing_block_for_socket(socket_a.try_lock().unwrap().uuid.clone()));
+        assert!(mockup_interface_out
+            .has_outgoing_block_for_socket(socket_b.try_lock().unwrap().uuid.clone()));
+
+        // the sent blocks are addressed to the resolved endpoints
+        let block = DXBBlock::from_bytes(&mockup_interface_out.last_block().unwrap())
+            .await
+            .unwrap();
+        assert!(!block.has_pointer_receiver());
+        assert_eq!(block.receiver_endpoints().len(), 1);
+    };
+}
+
+#[tokio::test]
+pub async fn send_block_to_resolved_pointer_receivers() {
+    run_async! {
+        init_global_context();
+        let (com_hub, com_interface) = get_mock_setup().await;
+
+        let socket = add_socket(com_interface.clone());
+        register_socket_endpoint(
+            com_interface.clone(),
+            socket.clone(),
+            TEST_ENDPOINT_A.clone(),
+        );
+        com_hub.update_async().await;
+
+        let pointer_address = PointerAddress::Remote([7u8; 26]);
+        let resolved_address = pointer_address.clone();
+        com_hub.register_pointer_receiver_resolver(move |address| {
+            if PointerAddress::from(address) == resolved_address {
+                Some(vec![TEST_ENDPOINT_A.clone()])
+            } else {
+                None
+            }
+        });
+
+        // unknown pointer cannot be resolved
+        let mut block = DXBBlock::default();
+        block.set_receivers(RawFullPointerAddress { id: [8u8; 26] });
+        assert!(com_hub.resolve_receiver_endpoints(&block).is_empty());
+        assert!(com_hub.send_own_block(block).await.is_err());
+
+        let mut block = DXBBlock::default();
+        block.set_receivers(
+            pointer_address
+                .to_raw_full_address(&TEST_ENDPOINT_ORIGIN)
+                .unwrap(),
+        );
+        assert_eq!(
+            com_hub.resolve_receiver_endpoints(&block),
+            vec![TEST_ENDPOINT_A.clone()]
+        );
+        com_hub.send_own_block(block).await.unwrap();
+        com_hub.update_async().await;
+
+        let mockup_interface_out = com_interface.clone();
+        let mockup_interface_out = mockup_interface_out.borrow();
+        assert_eq!(mockup_interface_out.outgoing_queue.len(), 1);
+        assert!(mockup_interface_out
+            .has_outgoing_block_for_socket(socket.try_lock().unwrap().uuid.clone()));
+    };
+}
+
+#[tokio::test]
+pub async fn receive_block_for_resolved_pointer_receivers() {
+    run_async! {
+        init_global_context();
+        let (com_hub, com_interface, socket_a) = get_mock_setup_and_socket().await;
+
+        let socket_b = add_socket(com_interface.clone());
+        register_socket_endpoint(
+            com_interface.clone(),
+            socket_b.clone(),
+            TEST_ENDPOINT_B.clone(),
+        );
+        com_hub.update_async().await;
+
+        // the pointer is associated with the own endpoint and @test-b
+        let pointer_address = PointerAddress::Remote([7u8; 26]);
+        let resolved_address = pointer_address.clone();
+        com_hub.register_pointer_receiver_resolver(move |address| {
+            if PointerAddress::from(address) == resolved_address {
+                Some(vec![TEST_ENDPOINT_ORIGIN.clone(), TEST_ENDPOINT_B.clone()])
+            } else {
+                None
+            }
+        });
+
+        let mut block = DXBBlock::default();
+        block.routing_header.sender = TEST_ENDPOINT_A.clone();
+        block.set_receivers(
+            pointer_address
+                .to_raw_full_address(&TEST_ENDPOINT_ORIGIN)
+                .unwrap(),
+        );
+        assert!(block.has_pointer_receiver());
+        block.recalculate_struct();
+
+        {
+            let socket_ref = socket_a.try_lock().unwrap();
+            let receive_queue = socket_ref.get_receive_queue();
+            let mut receive_queue_mut = receive_queue.try_lock().unwrap();
+            let _ = receive_queue_mut.write(&block.to_bytes().unwrap());
+        }
+        com_hub.update_async().await;
+
+        // the block is handled by the own endpoint
+        assert_eq!(get_all_received_single_blocks_from_com_hub(&com_hub).len(), 1);
+
+        // and relayed to the remaining resolved endpoint only
+        let mockup_interface_out = com_interface.clone();
+        let mockup_interface_out = mockup_interface_out.borrow();
+        assert_eq!(mockup_interface_out.outgoing_queue.len(), 1);
+        let relayed_block = mockup_interface_out
+            .find_outgoing_block_for_socket(socket_b.try_lock().unwrap().uuid.clone())
+            .unwrap();
+        let relayed_block = DXBBlock::from_bytes(&relayed_block).await.unwrap();
+        assert!(!relayed_block.has_pointer_receiver());
+        assert_eq!(relayed_block.receiver_endpoints(), vec![TEST_ENDPOINT_B.clone()]);
+    }
+}
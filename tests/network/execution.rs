@@ -1,14 +1,34 @@
 use crate::network::helpers::mock_setup::get_mock_setup_with_two_runtimes;
 use core::time::Duration;
+use datex_core::global::dxb_block::IncomingEndpointContextId;
 use datex_core::logger::init_logger_debug;
 use datex_core::run_async;
+use datex_core::runtime::execution::ExecutionError;
 use datex_core::runtime::execution::context::{
-    ExecutionContext, ExecutionMode,
+    ExecutionContext, ExecutionMode, ScriptExecutionError,
 };
 use datex_core::values::core_values::endpoint::Endpoint;
 use datex_core::values::core_values::integer::Integer;
 use datex_core::values::value_container::ValueContainer;
 
+/// Asserts that a remote execution failed with the given error,
+/// which is sent back to the caller as an error value
+fn assert_remote_error(
+    result: Result<Option<ValueContainer>, ScriptExecutionError>,
+    expected: ExecutionError,
+) {
+    let Err(ScriptExecutionError::ExecutionError(err, _)) = result else {
+        panic!("Expected remote execution error, got {result:?}");
+    };
+    let ExecutionError::Yeeted(error) = err else {
+        panic!("Expected yeeted error value, got {err:?}");
+    };
+    assert_eq!(
+        error.to_value().borrow().error_message(),
+        Some(expected.to_string())
+    );
+}
+
 #[tokio::test]
 pub async fn test_basic_remote_execution() {
     run_async! {
@@ -93,3 +113,136 @@ pub async fn test_remote_inline_implicit_context() {
         assert_eq!(result.unwrap().unwrap(), ValueContainer::from(Integer::from(3i8)));
     }
 }
+
+#[tokio::test]
+pub async fn test_try_remote_inline_yeet() {
+    run_async! {
+        let endpoint_a = Endpoint::new("@test_a");
+        let endpoint_b = Endpoint::new("@test_b");
+        let (runtime_a, runtime_b) = get_mock_setup_with_two_runtimes(endpoint_a.clone(), endpoint_b.clone()).await;
+
+        // sleep for a short time to ensure the connection is established
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        // the error value yeeted on @test_b is recovered on @test_a
+        let result = runtime_a.execute(r#"(try (@test_b :: yeet error({message: "remote", payload: 42}))).payload"#, &[], None).await;
+        assert_eq!(result.unwrap().unwrap(), ValueContainer::from(Integer::from(42)));
+
+        let result = runtime_a.execute(r#"(try (@test_b :: yeet "remote")).message"#, &[], None).await;
+        assert_eq!(result.unwrap().unwrap(), ValueContainer::from("remote"));
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_execution_context_disposed_at_end_of_context() {
+    run_async! {
+        let endpoint_a = Endpoint::new("@test_a");
+        let endpoint_b = Endpoint::new("@test_b");
+        let (runtime_a, runtime_b) = get_mock_setup_with_two_runtimes(endpoint_a.clone(), endpoint_b.clone()).await;
+
+        // sleep for a short time to ensure the connection is established
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        // a static remote context is ended with the first execution
+        let mut remote_execution_context = ExecutionContext::remote(endpoint_b);
+        let result = runtime_a.execute("1 + 2", &[], Some(&mut remote_execution_context)).await;
+        assert_eq!(result.unwrap().unwrap(), ValueContainer::from(Integer::from(3i8)));
+
+        assert!(runtime_b.internal.execution_contexts.borrow().is_empty());
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_execution_in_closed_context() {
+    run_async! {
+        let endpoint_a = Endpoint::new("@test_a");
+        let endpoint_b = Endpoint::new("@test_b");
+        let (runtime_a, runtime_b) = get_mock_setup_with_two_runtimes(endpoint_a.clone(), endpoint_b.clone()).await;
+
+        // sleep for a short time to ensure the connection is established
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        let mut remote_execution_context = ExecutionContext::remote_unbounded(endpoint_b);
+        let result = runtime_a.execute("const x = 10; x", &[], Some(&mut remote_execution_context)).await;
+        assert_eq!(result.unwrap().unwrap(), ValueContainer::from(Integer::from(10i8)));
+
+        // explicitly close the context on @test_b
+        let ExecutionContext::Remote(remote_context) = &remote_execution_context else {
+            panic!("Expected remote execution context");
+        };
+        let context_id = IncomingEndpointContextId {
+            sender: endpoint_a.clone(),
+            context_id: remote_context.context_id.unwrap(),
+        };
+        assert!(runtime_b.close_execution_context(&context_id));
+        assert!(runtime_b.internal.execution_contexts.borrow().is_empty());
+
+        // the late section is reported back to the sender as an error value
+        let result = runtime_a.execute("x + 5", &[], Some(&mut remote_execution_context)).await;
+        assert_remote_error(result, ExecutionError::ExecutionContextDisposed);
+    }
+}
+
+#[tokio::test]
+pub async fn test_cancel_remote_execution() {
+    run_async! {
+        let endpoint_a = Endpoint::new("@test_a");
+        let endpoint_b = Endpoint::new("@test_b");
+        let (runtime_a, runtime_b) = get_mock_setup_with_two_runtimes(endpoint_a.clone(), endpoint_b.clone()).await;
+
+        // sleep for a short time to ensure the connection is established
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        let mut remote_execution_context = ExecutionContext::remote_unbounded(endpoint_b);
+        let result = runtime_a.execute("const x = 10; x", &[], Some(&mut remote_execution_context)).await;
+        assert_eq!(result.unwrap().unwrap(), ValueContainer::from(Integer::from(10i8)));
+        assert_eq!(runtime_b.internal.execution_contexts.borrow().len(), 1);
+
+        // cancel the context on @test_b
+        let ExecutionContext::Remote(remote_context) = &remote_execution_context else {
+            panic!("Expected remote execution context");
+        };
+        runtime_a.cancel_remote_execution(remote_context).await.unwrap();
+
+        // wait for the cancellation to be handled by @test_b
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(runtime_b.internal.execution_contexts.borrow().is_empty());
+
+        let result = runtime_a.execute("x + 5", &[], Some(&mut remote_execution_context)).await;
+        assert_remote_error(result, ExecutionError::ExecutionContextDisposed);
+    }
+}
+
+#[tokio::test]
+pub async fn test_cancel_in_flight_remote_execution() {
+    run_async! {
+        let endpoint_a = Endpoint::new("@test_a");
+        let endpoint_b = Endpoint::new("@test_b");
+        let (runtime_a, runtime_b) = get_mock_setup_with_two_runtimes(endpoint_a.clone(), endpoint_b.clone()).await;
+
+        // sleep for a short time to ensure the connection is established
+        tokio::time::sleep(Duration::from_millis(1)).await;
+
+        let mut remote_execution_context = ExecutionContext::remote_unbounded(endpoint_b);
+        let result = runtime_a.execute("1", &[], Some(&mut remote_execution_context)).await;
+        assert_eq!(result.unwrap().unwrap(), ValueContainer::from(Integer::from(1i8)));
+        let ExecutionContext::Remote(remote_context) = &remote_execution_context else {
+            panic!("Expected remote execution context");
+        };
+        let remote_context = remote_context.clone();
+
+        // @test_b waits for a response from the unreachable @test_c until it is cancelled
+        let (result, _) = futures::join!(
+            runtime_a.execute("@test_c :: 1", &[], Some(&mut remote_execution_context)),
+            async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                assert_eq!(runtime_b.internal.running_executions.borrow().len(), 1);
+                runtime_a.cancel_remote_execution(&remote_context).await.unwrap();
+            }
+        );
+        assert_remote_error(result, ExecutionError::Cancelled);
+
+        assert!(runtime_b.internal.running_executions.borrow().is_empty());
+        assert!(runtime_b.internal.execution_contexts.borrow().is_empty());
+    }
+}
@@ -435,6 +435,7 @@ fn function_simple() {
         DatexExpressionData::CallableDeclaration(CallableDeclaration {
             name: Some("myFunction".to_string()),
             kind: CallableKind::Function,
+            generic_parameters: vec![],
             parameters: Vec::new(),
             rest_parameter: None,
             return_type: None,
@@ -460,6 +461,7 @@ fn function_with_params() {
         DatexExpressionData::CallableDeclaration(CallableDeclaration {
             name: Some("myFunction".to_string()),
             kind: CallableKind::Function,
+            generic_parameters: vec![],
             parameters: vec![(
                 "x".to_string(),
                 TypeExpressionData::Identifier("integer".to_owned())
@@ -486,6 +488,7 @@ fn function_with_params() {
         DatexExpressionData::CallableDeclaration(CallableDeclaration {
             name: Some("myFunction".to_string()),
             kind: CallableKind::Function,
+            generic_parameters: vec![],
             parameters: vec![
                 (
                     "x".to_string(),
@@ -540,6 +543,7 @@ fn test_function_with_return_type() {
         DatexExpressionData::CallableDeclaration(CallableDeclaration {
             name: Some("myFunction".to_string()),
             kind: CallableKind::Function,
+            generic_parameters: vec![],
             parameters: vec![(
                 "x".to_string(),
                 TypeExpressionData::Identifier("integer".to_owned())
@@ -2315,6 +2319,7 @@ fn property_access() {
                                             )
                                             .with_default_span()
                                         ),
+                                        optional: false,
                                     }
                                 )
                                 .with_default_span()
@@ -2323,6 +2328,7 @@ fn property_access() {
                                 DatexExpressionData::Integer(Integer::from(0))
                                     .with_default_span()
                             ),
+                            optional: false,
                         })
                         .with_default_span()
                     ),
@@ -2343,6 +2349,7 @@ fn property_access() {
                         })
                         .with_default_span()
                     ),
+                    optional: false,
                 })
                 .with_default_span()
             ),
@@ -2350,6 +2357,7 @@ fn property_access() {
                 DatexExpressionData::Text("test".to_string())
                     .with_default_span()
             ),
+            optional: false,
         })
     );
 }
@@ -2378,6 +2386,7 @@ fn property_access_assignment() {
                         DatexExpressionData::Text("props".to_string())
                             .with_default_span()
                     ),
+                    optional: false,
                 })
                 .with_default_span()
             ),
@@ -2408,6 +2417,7 @@ fn property_access_getter() {
                 DatexExpressionData::Text("myProp".to_string())
                     .with_default_span()
             ),
+            optional: false,
         })
     );
 }
@@ -2427,6 +2437,7 @@ fn property_access_scoped() {
                 DatexExpressionData::Integer(Integer::from(1))
                     .with_default_span()
             ),
+            optional: false,
         })
     );
 }
@@ -2457,6 +2468,7 @@ fn property_access_multiple() {
                                             )
                                             .with_default_span()
                                         ),
+                                        optional: false,
                                     }
                                 )
                                 .with_default_span()
@@ -2467,6 +2479,7 @@ fn property_access_multiple() {
                                 )
                                 .with_default_span()
                             ),
+                            optional: false,
                         })
                         .with_default_span()
                     ),
@@ -2487,6 +2500,7 @@ fn property_access_multiple() {
                         })
                         .with_default_span()
                     ),
+                    optional: false,
                 })
                 .with_default_span()
             ),
@@ -2501,6 +2515,7 @@ fn property_access_multiple() {
                 ))
                 .with_default_span()
             ),
+            optional: false,
         })
     );
 }
@@ -2522,6 +2537,7 @@ fn property_access_and_apply() {
                         DatexExpressionData::Text("myProp".to_string())
                             .with_default_span()
                     ),
+                    optional: false,
                 })
                 .with_default_span()
             ),
@@ -2559,6 +2575,7 @@ fn apply_and_property_access() {
                 DatexExpressionData::Text("myProp".to_string())
                     .with_default_span()
             ),
+            optional: false,
         })
     );
 }
@@ -2591,12 +2608,14 @@ fn nested_apply_and_property_access() {
                         DatexExpressionData::Text("y".to_string())
                             .with_default_span()
                     ),
+                    optional: false,
                 })
                 .with_default_span()
             ),
             property: Box::new(
                 DatexExpressionData::Text("z".to_string()).with_default_span()
             ),
+            optional: false,
         })
     );
 }
@@ -2611,6 +2630,7 @@ fn type_declaration_statement() {
             DatexExpressionData::TypeDeclaration(TypeDeclaration {
                 id: None,
                 name: "User".to_string(),
+                generic_parameters: vec![],
                 definition: TypeExpressionData::StructuralMap(StructuralMap(
                     vec![
                         (
@@ -2,6 +2,8 @@
 /// the compile and execution process.
 /// Any value passed as input should be returned exactly as it was passed in after compilation and execution.
 use datex_core::compile;
+use datex_core::compiler::optimizer::OptimizationOptions;
+use datex_core::compiler::{CompileOptions, compile_script};
 use datex_core::runtime::execution::{
     ExecutionInput, ExecutionOptions, execute_dxb_sync,
 };
@@ -11,6 +13,7 @@ use datex_core::values::core_values::integer::Integer;
 use datex_core::values::core_values::integer::typed_integer::TypedInteger;
 use datex_core::values::core_values::list::List;
 use datex_core::values::core_values::map::Map;
+use datex_core::values::value::Value;
 use datex_core::values::value_container::ValueContainer;
 
 fn compile_and_execute(input: ValueContainer) -> ValueContainer {
@@ -18,13 +21,45 @@ fn compile_and_execute(input: ValueContainer) -> ValueContainer {
 
     execute_dxb_sync(ExecutionInput::new(
         &dxb,
-        ExecutionOptions { verbose: true },
+        ExecutionOptions {
+            verbose: true,
+            ..Default::default()
+        },
         None,
     ))
     .unwrap()
     .unwrap()
 }
 
+fn compile_and_execute_script(
+    script: &str,
+    optimizations: OptimizationOptions,
+) -> Option<ValueContainer> {
+    let (dxb, _) = compile_script(
+        script,
+        CompileOptions {
+            optimizations,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    execute_dxb_sync(ExecutionInput::new(
+        &dxb,
+        ExecutionOptions::default(),
+        None,
+    ))
+    .unwrap()
+}
+
+/// Asserts that the optimized script returns the same value as the unoptimized script
+fn assert_optimization_consistency(script: &str) {
+    let expected =
+        compile_and_execute_script(script, OptimizationOptions::default());
+    let result = compile_and_execute_script(script, OptimizationOptions::all());
+    assert_eq!(result, expected, "inconsistent result for {script}");
+}
+
 #[test]
 fn test_compile_and_execute_integer() {
     let input = ValueContainer::from(Integer::from(42));
@@ -97,3 +132,63 @@ fn test_compile_and_execute_map() {
     let result = compile_and_execute(input.clone());
     assert_eq!(result, input);
 }
+
+#[test]
+fn test_optimized_constant_folding() {
+    for script in [
+        "1 + 2",
+        "1 + 2 - 3 - 4",
+        "-(1 + 2)",
+        "1u8 + 2u8",
+        "1.5 + 2.25",
+        "1 + 2.5",
+        r#""Hello " + "World""#,
+        r#"1 + "a""#,
+        "1 + 1 == 2",
+        "1 === 1",
+        r#""a" != "b""#,
+        "true == false",
+        "var x = 10; x + (1 + 2)",
+    ] {
+        assert_optimization_consistency(script);
+    }
+}
+
+#[test]
+fn test_optimized_unused_declarations() {
+    for script in [
+        "const a = 1; const b = 2; a",
+        "const a = 1; var b = [1, 2, {x: a}]; 42",
+        "var a = 1; a = 2; a",
+        "const a = 1;",
+        "const a = 1; const b = a + 1;",
+    ] {
+        assert_optimization_consistency(script);
+    }
+}
+
+#[test]
+fn test_optimized_dead_branches() {
+    // conditionals are only compiled if they can be eliminated statically
+    assert_eq!(
+        compile_and_execute_script(
+            "if (1 + 1 == 2) (\"yes\") else (\"no\")",
+            OptimizationOptions::all()
+        ),
+        Some(ValueContainer::from("yes"))
+    );
+    assert_eq!(
+        compile_and_execute_script(
+            "const x = if (false) (1) else (2 + 3); x",
+            OptimizationOptions::all()
+        ),
+        Some(ValueContainer::from(Integer::from(5)))
+    );
+    assert_eq!(
+        compile_and_execute_script(
+            "if (false) (1)",
+            OptimizationOptions::all()
+        ),
+        Some(ValueContainer::Value(Value::null()))
+    );
+}